@@ -0,0 +1,70 @@
+// Generated from the program types by `cargo run --example bindings --features idl`, do not edit
+
+import BN from "bn.js";
+
+export class CreateSender {
+  eth_address!: Uint8Array;
+  operator!: Uint8Array;
+
+  constructor(fields: CreateSender) {
+    Object.assign(this, fields);
+  }
+}
+
+export class Transfer {
+  amount!: BN;
+  id!: string;
+  eth_recipient!: Uint8Array;
+  message_version!: MessageVersion;
+  create_recipient!: number;
+  memo!: number;
+
+  constructor(fields: Transfer) {
+    Object.assign(this, fields);
+  }
+}
+
+export class SenderAccount {
+  version!: number;
+  reward_manager!: Uint8Array;
+  eth_address!: Uint8Array;
+  operator!: Uint8Array;
+  bump!: number;
+  weight!: number;
+  attestation_count!: BN;
+  last_attested_slot!: BN;
+  status!: SenderStatus;
+  endpoint_hash!: Uint8Array;
+
+  constructor(fields: SenderAccount) {
+    Object.assign(this, fields);
+  }
+}
+
+export class TransferReceipt {
+  version!: number;
+  recipient!: Uint8Array;
+  amount!: BN;
+  slot!: BN;
+
+  constructor(fields: TransferReceipt) {
+    Object.assign(this, fields);
+  }
+}
+
+export enum MessageVersion {
+  V1 = 0,
+  V2 = 1,
+}
+
+export enum SenderStatus {
+  Active = 0,
+  Suspended = 1,
+}
+
+export const SCHEMA = new Map<Function, unknown>([
+  [CreateSender, { kind: "struct", fields: [["eth_address", [20]], ["operator", [20]]] }],
+  [Transfer, { kind: "struct", fields: [["amount", "u64"], ["id", "string"], ["eth_recipient", [20]], ["message_version", "u8"], ["create_recipient", "u8"], ["memo", "u8"]] }],
+  [SenderAccount, { kind: "struct", fields: [["version", "u8"], ["reward_manager", [32]], ["eth_address", [20]], ["operator", [20]], ["bump", "u8"], ["weight", "u16"], ["attestation_count", "u64"], ["last_attested_slot", "u64"], ["status", "u8"], ["endpoint_hash", [32]]] }],
+  [TransferReceipt, { kind: "struct", fields: [["version", "u8"], ["recipient", [32]], ["amount", "u64"], ["slot", "u64"]] }],
+]);
@@ -0,0 +1,383 @@
+//! Deploys the reward manager to a local validator and seeds it with a funded vault,
+//! a bot oracle and senders, so integrations don't have to repeat the test setup.
+//!
+//! Ethereum keys are fixed and published in the manifest, never use them outside
+//! of a local validator.
+
+use audius_reward_manager::utils::EthereumAddress;
+use audius_reward_manager_client::rpc::{oracle_address, sender_address, RewardManagerClient};
+use clap::{crate_description, crate_name, crate_version, value_t_or_exit, App, Arg};
+use serde::Serialize;
+use solana_clap_utils::input_validators::{is_keypair, is_parsable, is_url};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    native_token::LAMPORTS_PER_SOL,
+    secp256k1_instruction::construct_eth_pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use std::{fs, process::Command, thread, time::Duration};
+
+type Error = Box<dyn std::error::Error>;
+
+/// Decimals of the created mint, same as the Audius token
+const MINT_DECIMALS: u8 = 8;
+/// Balance the payer is topped up to before seeding
+const PAYER_BALANCE: u64 = 10 * LAMPORTS_PER_SOL;
+/// Secret key of the bot oracle
+const ORACLE_SECRET: [u8; 32] = [0xb0; 32];
+
+/// Ethereum signer registered in the reward manager
+#[derive(Serialize)]
+struct ManifestSigner {
+    eth_address: String,
+    private_key: String,
+    account: String,
+}
+
+/// Addresses and keys of the seeded environment
+#[derive(Serialize)]
+struct Manifest {
+    url: String,
+    program_id: String,
+    claimable_tokens_program: String,
+    mint: String,
+    mint_authority: String,
+    reward_manager: String,
+    token_account: String,
+    manager: String,
+    min_votes: u8,
+    vault_balance: u64,
+    bot_oracle: ManifestSigner,
+    senders: Vec<ManifestSigner>,
+}
+
+/// Fixed secret key of the sender `index`
+fn sender_secret(index: usize) -> [u8; 32] {
+    let mut secret = [0x5e; 32];
+    secret[31] = index as u8;
+    secret
+}
+
+fn eth_address(secret: [u8; 32]) -> Result<EthereumAddress, Error> {
+    let secret_key =
+        secp256k1::SecretKey::parse(&secret).map_err(|e| format!("invalid secret key: {:?}", e))?;
+    Ok(construct_eth_pubkey(
+        &secp256k1::PublicKey::from_secret_key(&secret_key),
+    ))
+}
+
+fn send(
+    rpc_client: &RpcClient,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> Result<(), Error> {
+    let (recent_blockhash, _) = rpc_client.get_recent_blockhash()?;
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+    rpc_client.send_and_confirm_transaction(&transaction)?;
+    Ok(())
+}
+
+fn fund_payer(rpc_client: &RpcClient, payer: &Pubkey) -> Result<(), Error> {
+    let balance = rpc_client.get_balance(payer)?;
+    if balance >= PAYER_BALANCE {
+        return Ok(());
+    }
+
+    let signature = rpc_client.request_airdrop(payer, PAYER_BALANCE - balance)?;
+    while !rpc_client.confirm_transaction(&signature)? {
+        thread::sleep(Duration::from_millis(500));
+    }
+    Ok(())
+}
+
+/// Deploys `program_so` with the `solana` CLI unless `program_id` is already executable
+fn deploy(
+    rpc_client: &RpcClient,
+    url: &str,
+    payer_path: &str,
+    program_id: &Pubkey,
+    program: Option<(&str, &str)>,
+) -> Result<bool, Error> {
+    if let Ok(account) = rpc_client.get_account(program_id) {
+        if account.executable {
+            return Ok(true);
+        }
+    }
+    let (program_so, program_keypair) = match program {
+        Some(program) => program,
+        None => return Ok(false),
+    };
+
+    let keypair_id = read_keypair_file(program_keypair)?.pubkey();
+    if keypair_id != *program_id {
+        return Err(format!("{} isn't the keypair of {}", program_keypair, program_id).into());
+    }
+
+    let status = Command::new("solana")
+        .args(&["program", "deploy", "--url", url, "--keypair", payer_path])
+        .args(&["--program-id", program_keypair, program_so])
+        .status()?;
+    if !status.success() {
+        return Err(format!("deploying {} failed with {}", program_so, status).into());
+    }
+    Ok(true)
+}
+
+fn main() -> Result<(), Error> {
+    let matches = App::new(crate_name!())
+        .about(crate_description!())
+        .version(crate_version!())
+        .arg(
+            Arg::with_name("url")
+                .long("url")
+                .validator(is_url)
+                .value_name("URL")
+                .takes_value(true)
+                .default_value("http://localhost:8899")
+                .help("JSON RPC URL of the local validator"),
+        )
+        .arg(
+            Arg::with_name("keypair")
+                .long("keypair")
+                .validator(is_keypair)
+                .value_name("KEYPAIR")
+                .takes_value(true)
+                .required(true)
+                .help("Payer, also the manager and the mint authority. Airdropped on low balance"),
+        )
+        .arg(
+            Arg::with_name("program")
+                .long("program")
+                .value_name("PATH")
+                .takes_value(true)
+                .requires("program-keypair")
+                .help("Built program to deploy when it isn't deployed yet"),
+        )
+        .arg(
+            Arg::with_name("program-keypair")
+                .long("program-keypair")
+                .validator(is_keypair)
+                .value_name("KEYPAIR")
+                .takes_value(true)
+                .help("Keypair of the program id the program is built with"),
+        )
+        .arg(
+            Arg::with_name("claimable-tokens-program")
+                .long("claimable-tokens-program")
+                .value_name("PATH")
+                .takes_value(true)
+                .requires("claimable-tokens-keypair")
+                .help("Built claimable tokens program to deploy when it isn't deployed yet"),
+        )
+        .arg(
+            Arg::with_name("claimable-tokens-keypair")
+                .long("claimable-tokens-keypair")
+                .validator(is_keypair)
+                .value_name("KEYPAIR")
+                .takes_value(true)
+                .help("Keypair of the claimable tokens program id"),
+        )
+        .arg(
+            Arg::with_name("senders")
+                .long("senders")
+                .validator(is_parsable::<u8>)
+                .value_name("NUMBER")
+                .takes_value(true)
+                .default_value("3")
+                .help("Number of senders to register"),
+        )
+        .arg(
+            Arg::with_name("min-votes")
+                .long("min-votes")
+                .validator(is_parsable::<u8>)
+                .value_name("NUMBER")
+                .takes_value(true)
+                .default_value("3")
+                .help("Number of sender votes required for transfers"),
+        )
+        .arg(
+            Arg::with_name("vault-balance")
+                .long("vault-balance")
+                .validator(is_parsable::<u64>)
+                .value_name("AMOUNT")
+                .takes_value(true)
+                .default_value("100000000000")
+                .help("Tokens minted to the vault, in the smallest units"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .long("output")
+                .value_name("PATH")
+                .takes_value(true)
+                .default_value("bootstrap.json")
+                .help("Path the JSON manifest is written to"),
+        )
+        .get_matches();
+
+    let url = value_t_or_exit!(matches, "url", String);
+    let payer_path = value_t_or_exit!(matches, "keypair", String);
+    let senders_count = value_t_or_exit!(matches, "senders", u8) as usize;
+    let min_votes = value_t_or_exit!(matches, "min-votes", u8);
+    let vault_balance = value_t_or_exit!(matches, "vault-balance", u64);
+    let output = value_t_or_exit!(matches, "output", String);
+
+    let payer = read_keypair_file(&payer_path)?;
+    let rpc_client = RpcClient::new_with_commitment(url.clone(), CommitmentConfig::confirmed());
+    let client = RewardManagerClient::new(&rpc_client);
+
+    fund_payer(&rpc_client, &payer.pubkey())?;
+
+    let program = matches
+        .value_of("program")
+        .zip(matches.value_of("program-keypair"));
+    if !deploy(
+        &rpc_client,
+        &url,
+        &payer_path,
+        &audius_reward_manager::id(),
+        program,
+    )? {
+        return Err(
+            "the reward manager isn't deployed, pass --program and --program-keypair".into(),
+        );
+    }
+    let claimable_tokens_program = matches
+        .value_of("claimable-tokens-program")
+        .zip(matches.value_of("claimable-tokens-keypair"));
+    if !deploy(
+        &rpc_client,
+        &url,
+        &payer_path,
+        &claimable_tokens::id(),
+        claimable_tokens_program,
+    )? {
+        eprintln!("the claimable tokens program isn't deployed, transfers will fail");
+    }
+
+    let mint = Keypair::new();
+    let mint_balance =
+        rpc_client.get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)?;
+    send(
+        &rpc_client,
+        &[
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                mint_balance,
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_mint(
+                &spl_token::id(),
+                &mint.pubkey(),
+                &payer.pubkey(),
+                None,
+                MINT_DECIMALS,
+            )?,
+        ],
+        &payer,
+        &[&mint],
+    )?;
+
+    let reward_manager = Keypair::new();
+    let token_account = Keypair::new();
+    let mut instructions = client.init_reward_manager(
+        &payer.pubkey(),
+        &reward_manager.pubkey(),
+        &token_account.pubkey(),
+        &mint.pubkey(),
+        &payer.pubkey(),
+        min_votes,
+    )?;
+    instructions.push(spl_token::instruction::mint_to(
+        &spl_token::id(),
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &payer.pubkey(),
+        &[],
+        vault_balance,
+    )?);
+    send(
+        &rpc_client,
+        &instructions,
+        &payer,
+        &[&reward_manager, &token_account],
+    )?;
+
+    // NOTE: every signer is its own operator, attestations need distinct operators
+    let oracle_eth_address = eth_address(ORACLE_SECRET)?;
+    send(
+        &rpc_client,
+        &client.create_oracle(
+            &reward_manager.pubkey(),
+            &payer.pubkey(),
+            &payer.pubkey(),
+            oracle_eth_address,
+            oracle_eth_address,
+        )?,
+        &payer,
+        &[],
+    )?;
+    let bot_oracle = ManifestSigner {
+        eth_address: format!("0x{}", hex::encode(oracle_eth_address)),
+        private_key: hex::encode(ORACLE_SECRET),
+        account: oracle_address(&reward_manager.pubkey(), oracle_eth_address)?.to_string(),
+    };
+
+    let mut senders = Vec::new();
+    for index in 0..senders_count {
+        let eth_address = eth_address(sender_secret(index))?;
+        send(
+            &rpc_client,
+            &client.create_sender(
+                &reward_manager.pubkey(),
+                &payer.pubkey(),
+                &payer.pubkey(),
+                eth_address,
+                eth_address,
+            )?,
+            &payer,
+            &[],
+        )?;
+        senders.push(ManifestSigner {
+            eth_address: format!("0x{}", hex::encode(eth_address)),
+            private_key: hex::encode(sender_secret(index)),
+            account: sender_address(&reward_manager.pubkey(), eth_address)?.to_string(),
+        });
+    }
+
+    let manifest = Manifest {
+        url,
+        program_id: audius_reward_manager::id().to_string(),
+        claimable_tokens_program: claimable_tokens::id().to_string(),
+        mint: mint.pubkey().to_string(),
+        mint_authority: payer.pubkey().to_string(),
+        reward_manager: reward_manager.pubkey().to_string(),
+        token_account: token_account.pubkey().to_string(),
+        manager: payer.pubkey().to_string(),
+        min_votes,
+        vault_balance,
+        bot_oracle,
+        senders,
+    };
+    fs::write(&output, serde_json::to_string_pretty(&manifest)?)?;
+    println!(
+        "Reward manager {} seeded, manifest written to {}",
+        reward_manager.pubkey(),
+        output
+    );
+
+    Ok(())
+}
@@ -5,13 +5,24 @@ use clap::{
 };
 
 use audius_reward_manager::{
-    instruction::{add_sender, create_sender, delete_sender, init, transfer, Transfer},
-    processor::SENDER_SEED_PREFIX,
-    state::{RewardManager, SenderAccount},
-    utils::get_address_pair,
+    instruction::{
+        accept_manager, add_sender, announce_treasury_withdrawal, create_delegate,
+        create_event_log, create_oracle, create_sender, decode, delete_oracle, delete_sender,
+        delete_sender_public, init_v2, migrate_token_account, pause, propose_manager,
+        reinstate_sender, repair_sender, revoke_delegate, set_challenge, set_rate_limit,
+        set_recipient_blocked, set_sender_weight, set_spend_limit, set_transfer_fee,
+        suspend_sender, unpause, update_reward_manager, update_sender_metadata, withdraw_treasury,
+        AnnounceTreasuryWithdrawal, CreateDelegate, MessageVersion, SetChallenge, SetRateLimit,
+        SetRecipientBlocked, SetSpendLimit, SetTransferFee, Transfer, UpdateRewardManager,
+    },
+    processor::{DELETE_SENDER_MESSAGE_PREFIX, SENDER_METADATA_MESSAGE_PREFIX},
+    state::{DelegateAccount, RewardManager},
+    utils::endpoint_hash,
+};
+use audius_reward_manager_client::{
+    rpc::{oracle_address, sender_address, RewardManagerClient},
+    secp::sign_message,
 };
-use borsh::BorshDeserialize;
-use claimable_tokens::utils::program::get_address_pair as get_claimable_address;
 use hex::FromHex;
 use solana_clap_utils::{
     input_parsers::pubkey_of,
@@ -19,12 +30,12 @@ use solana_clap_utils::{
     keypair::signer_from_path,
 };
 use solana_client::rpc_client::RpcClient;
-use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program::pubkey::Pubkey;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
     program_pack::Pack,
+    secp256k1_instruction::construct_eth_pubkey,
     signature::{Keypair, Signer},
-    system_instruction,
     transaction::Transaction,
 };
 use spl_token::state::Account;
@@ -32,9 +43,7 @@ use spl_token::ui_amount_to_amount;
 use std::process::exit;
 use std::str::FromStr;
 use utils::Transaction as CustomTransaction;
-use utils::{
-    is_csv_file, is_eth_address, is_hex, new_secp256k1_instruction_2_0, sign_message, SenderData,
-};
+use utils::{is_csv_file, is_eth_address, is_hex, SenderData};
 
 #[allow(dead_code)]
 pub struct Config {
@@ -51,59 +60,46 @@ type CommandResult = Result<Option<Transaction>, Error>;
 const HEX_ETH_ADDRESS_DECODING_ERROR: &str = "Ethereum address decoding failed";
 const HEX_ETH_SECRET_DECODING_ERROR: &str = "Ethereum secret decoding failed";
 
-fn command_init(config: &Config, token_mint: Pubkey, min_votes: u8) -> CommandResult {
-    let mut instructions: Vec<Instruction> = Vec::new();
-
+fn command_init(
+    config: &Config,
+    token_mint: Pubkey,
+    token_program: Pubkey,
+    claimable_tokens_program: Pubkey,
+    min_votes: u8,
+) -> CommandResult {
     let reward_manager_acc = Keypair::new();
     println!(
         "Reward manager key created: {:?}",
         reward_manager_acc.pubkey()
     );
 
-    let reward_manager_acc_balance = config
-        .rpc_client
-        .get_minimum_balance_for_rent_exemption(RewardManager::LEN)?;
-
-    instructions.push(system_instruction::create_account(
-        &config.fee_payer.pubkey(),
-        &reward_manager_acc.pubkey(),
-        reward_manager_acc_balance,
-        RewardManager::LEN as u64,
-        &audius_reward_manager::id(),
-    ));
-
     let reward_manager_token_acc = Keypair::new();
     println!(
         "Reward manager token key created: {:?}",
         reward_manager_token_acc.pubkey()
     );
 
+    let reward_manager_acc_balance = config
+        .rpc_client
+        .get_minimum_balance_for_rent_exemption(RewardManager::LEN)?;
     let token_acc_balance = config
         .rpc_client
         .get_minimum_balance_for_rent_exemption(Account::LEN)?;
 
-    instructions.push(system_instruction::create_account(
-        &config.fee_payer.pubkey(),
-        &reward_manager_token_acc.pubkey(),
-        token_acc_balance,
-        Account::LEN as u64,
-        &spl_token::id(),
-    ));
-
-    instructions.push(init(
-        &audius_reward_manager::id(),
-        &reward_manager_acc.pubkey(),
-        &reward_manager_token_acc.pubkey(),
-        &token_mint,
-        &config.owner.pubkey(),
-        min_votes,
-    )?);
-
     let transaction = CustomTransaction {
-        instructions: instructions.clone(),
+        instructions: vec![init_v2(
+            &audius_reward_manager::id(),
+            &reward_manager_acc.pubkey(),
+            &reward_manager_token_acc.pubkey(),
+            &token_mint,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            &token_program,
+            &claimable_tokens_program,
+            min_votes,
+        )?],
         signers: vec![
             config.fee_payer.as_ref(),
-            config.owner.as_ref(),
             &reward_manager_acc,
             &reward_manager_token_acc,
         ],
@@ -124,18 +120,9 @@ fn command_create_sender(
     let decoded_eth_operator_address =
         <[u8; 20]>::from_hex(eth_operator_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
 
-    let new_sender_key = get_address_pair(
-        &audius_reward_manager::id(),
-        &reward_manager,
-        [
-            SENDER_SEED_PREFIX.as_ref(),
-            decoded_eth_sender_address.as_ref(),
-        ]
-        .concat(),
-    )?;
     println!(
         "New sender account created: {:?}",
-        new_sender_key.derive.address
+        sender_address(&reward_manager, decoded_eth_sender_address)?
     );
 
     let transaction = CustomTransaction {
@@ -160,6 +147,10 @@ fn command_delete_sender(
 ) -> CommandResult {
     let decoded_eth_sender_address =
         <[u8; 20]>::from_hex(eth_sender_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+    let sender = RewardManagerClient::new(&config.rpc_client).get_sender(&sender_address(
+        &reward_manager,
+        decoded_eth_sender_address,
+    )?)?;
 
     let transaction = CustomTransaction {
         instructions: vec![delete_sender(
@@ -168,6 +159,110 @@ fn command_delete_sender(
             &config.owner.pubkey(),
             &config.fee_payer.pubkey(),
             decoded_eth_sender_address,
+            sender.operator,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_set_sender_weight(
+    config: &Config,
+    reward_manager: Pubkey,
+    eth_sender_address: String,
+    weight: u16,
+) -> CommandResult {
+    let decoded_eth_sender_address =
+        <[u8; 20]>::from_hex(eth_sender_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
+    let transaction = CustomTransaction {
+        instructions: vec![set_sender_weight(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            decoded_eth_sender_address,
+            weight,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_repair_sender(
+    config: &Config,
+    reward_manager: Pubkey,
+    eth_sender_address: String,
+    eth_operator_address: String,
+) -> CommandResult {
+    let decoded_eth_sender_address =
+        <[u8; 20]>::from_hex(eth_sender_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
+    let decoded_eth_operator_address =
+        <[u8; 20]>::from_hex(eth_operator_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
+    let transaction = CustomTransaction {
+        instructions: vec![repair_sender(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            decoded_eth_sender_address,
+            decoded_eth_operator_address,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_create_oracle(
+    config: &Config,
+    reward_manager: Pubkey,
+    eth_oracle_address: String,
+    eth_operator_address: String,
+) -> CommandResult {
+    let decoded_eth_oracle_address =
+        <[u8; 20]>::from_hex(eth_oracle_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
+    let decoded_eth_operator_address =
+        <[u8; 20]>::from_hex(eth_operator_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
+    println!(
+        "New bot oracle account created: {:?}",
+        oracle_address(&reward_manager, decoded_eth_oracle_address)?
+    );
+
+    let transaction = CustomTransaction {
+        instructions: vec![create_oracle(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            decoded_eth_oracle_address,
+            decoded_eth_operator_address,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_delete_oracle(
+    config: &Config,
+    reward_manager: Pubkey,
+    eth_oracle_address: String,
+) -> CommandResult {
+    let decoded_eth_oracle_address =
+        <[u8; 20]>::from_hex(eth_oracle_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
+    let transaction = CustomTransaction {
+        instructions: vec![delete_oracle(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            decoded_eth_oracle_address,
         )?],
         signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
     };
@@ -189,9 +284,14 @@ fn command_add_sender(
     let mut rdr = csv::Reader::from_path(&senders_secrets)?;
 
     let new_sender = <[u8; 20]>::from_hex(new_sender).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
-    let message_to_sign = [reward_manager.as_ref(), new_sender.as_ref()].concat();
     let operator_address =
         <[u8; 20]>::from_hex(operator_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+    let message_to_sign = [
+        reward_manager.as_ref(),
+        new_sender.as_ref(),
+        operator_address.as_ref(),
+    ]
+    .concat();
 
     println!("Signing message with senders private keys...");
 
@@ -215,99 +315,130 @@ fn command_add_sender(
         &senders,
     )?);
 
-    let new_sender_solana_key = get_address_pair(
-        &audius_reward_manager::id(),
-        &reward_manager,
-        [SENDER_SEED_PREFIX.as_ref(), new_sender.as_ref()].concat(),
-    )?;
-
     println!(
         "New sender account was created: {:?}",
-        new_sender_solana_key.derive.address
+        sender_address(&reward_manager, new_sender)?
     );
 
     let transaction = CustomTransaction {
         instructions,
-        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+        signers: vec![config.fee_payer.as_ref()],
     };
 
     transaction.sign(config, 0)
 }
 
-fn command_transfer(
+fn command_delete_sender_public(
     config: &Config,
     reward_manager: Pubkey,
-    bot_oracle: Pubkey,
-    bot_oracle_secret: String,
+    eth_sender_address: String,
     senders_secrets: String,
-    transfer_id: String,
-    eth_address_recipient: String,
-    amount: u64,
 ) -> CommandResult {
-    let reward_manager_data = config.rpc_client.get_account_data(&reward_manager)?;
-    let reward_manager_data = RewardManager::try_from_slice(reward_manager_data.as_slice())?;
-
-    let bot_oracle_data = config.rpc_client.get_account_data(&bot_oracle)?;
-    let bot_oracle_data = SenderAccount::try_from_slice(bot_oracle_data.as_slice())?;
+    let mut instructions = Vec::new();
 
-    let decoded_recipient_address =
-        <[u8; 20]>::from_hex(eth_address_recipient).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+    let mut senders = Vec::new();
+    let mut secrets = Vec::new();
+    let mut rdr = csv::Reader::from_path(&senders_secrets)?;
 
-    let mut instructions = Vec::new();
+    let decoded_eth_sender_address =
+        <[u8; 20]>::from_hex(eth_sender_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+    let message_to_sign = [
+        DELETE_SENDER_MESSAGE_PREFIX.as_bytes(),
+        reward_manager.as_ref(),
+        decoded_eth_sender_address.as_ref(),
+    ]
+    .concat();
 
-    let vault_acc_data = config
-        .rpc_client
-        .get_account_data(&reward_manager_data.token_account)?;
-    let vault_acc_data = Account::unpack(vault_acc_data.as_slice())?;
+    println!("Signing message with senders private keys...");
 
-    let claimable_token_acc = get_claimable_address(
-        &claimable_tokens::id(),
-        &vault_acc_data.mint,
-        decoded_recipient_address,
-    )?;
-    // Checking if the derived address of recipient does not exist
-    // then we must add instruction to create it
-    let derived_token_acc_data = config
-        .rpc_client
-        .get_account_data(&claimable_token_acc.derive.address);
-    if derived_token_acc_data.is_err() {
-        instructions.push(claimable_tokens::instruction::init(
-            &claimable_tokens::id(),
-            &config.fee_payer.pubkey(),
-            &vault_acc_data.mint,
-            claimable_tokens::instruction::CreateTokenAccount {
-                eth_address: decoded_recipient_address,
-            },
-        )?);
+    for key in rdr.deserialize() {
+        let deserialized_sender_data: SenderData = key?;
+        let decoded_secret = <[u8; 32]>::from_hex(deserialized_sender_data.eth_secret)
+            .expect(HEX_ETH_SECRET_DECODING_ERROR);
 
-        println!(
-            "Create new derived token account for recipient: {:?}",
-            claimable_token_acc.derive.address
-        );
+        senders.push(Pubkey::from_str(&deserialized_sender_data.solana_key)?);
+        secrets.push(secp256k1::SecretKey::parse(&decoded_secret)?);
     }
 
-    let sender_message = [
-        decoded_recipient_address.as_ref(),
-        b"_".as_ref(),
-        amount.to_le_bytes().as_ref(),
-        b"_".as_ref(),
-        &transfer_id.as_bytes(),
-        b"_".as_ref(),
-        bot_oracle_data.eth_address.as_ref(),
-    ]
-    .concat();
+    instructions.append(&mut sign_message(message_to_sign.as_ref(), secrets));
+
+    let sender = RewardManagerClient::new(&config.rpc_client).get_sender(&sender_address(
+        &reward_manager,
+        decoded_eth_sender_address,
+    )?)?;
+
+    instructions.push(delete_sender_public(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &config.fee_payer.pubkey(),
+        decoded_eth_sender_address,
+        sender.operator,
+        &senders,
+    )?);
 
-    let bot_oracle_message = [
-        decoded_recipient_address.as_ref(),
-        b"_".as_ref(),
-        amount.to_le_bytes().as_ref(),
-        b"_".as_ref(),
-        &transfer_id.as_bytes(),
+    let transaction = CustomTransaction {
+        instructions,
+        signers: vec![config.fee_payer.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_update_sender_metadata(
+    config: &Config,
+    reward_manager: Pubkey,
+    sender_secret: String,
+    endpoint: String,
+) -> CommandResult {
+    let sender_secret = <[u8; 32]>::from_hex(sender_secret).expect(HEX_ETH_SECRET_DECODING_ERROR);
+    let sender_secret = secp256k1::SecretKey::parse(&sender_secret)?;
+    let eth_sender_address =
+        construct_eth_pubkey(&secp256k1::PublicKey::from_secret_key(&sender_secret));
+
+    let sender = RewardManagerClient::new(&config.rpc_client)
+        .get_sender(&sender_address(&reward_manager, eth_sender_address)?)?;
+    let endpoint_hash = endpoint_hash(&endpoint);
+    let message_to_sign = [
+        SENDER_METADATA_MESSAGE_PREFIX.as_bytes(),
+        reward_manager.as_ref(),
+        sender.endpoint_hash.as_ref(),
+        endpoint_hash.as_ref(),
     ]
     .concat();
 
+    println!("Signing message with sender private key...");
+
+    let mut instructions = sign_message(message_to_sign.as_ref(), vec![sender_secret]);
+    instructions.push(update_sender_metadata(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        eth_sender_address,
+        endpoint_hash,
+    )?);
+
+    let transaction = CustomTransaction {
+        instructions,
+        signers: vec![config.fee_payer.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_transfer(
+    config: &Config,
+    reward_manager: Pubkey,
+    bot_oracle: Pubkey,
+    bot_oracle_secret: String,
+    senders_secrets: String,
+    transfer_id: String,
+    eth_address_recipient: String,
+    amount: u64,
+    memo: bool,
+) -> CommandResult {
+    let decoded_recipient_address =
+        <[u8; 20]>::from_hex(eth_address_recipient).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
     let mut senders = Vec::new();
-    let mut secrets = Vec::new();
 
     println!("Signing message with senders and bot oracle private keys...");
 
@@ -318,122 +449,1038 @@ fn command_transfer(
         let decoded_secret = <[u8; 32]>::from_hex(deserialized_sender_data.eth_secret)
             .expect(HEX_ETH_SECRET_DECODING_ERROR);
 
-        senders.push(Pubkey::from_str(&deserialized_sender_data.solana_key)?);
-        secrets.push(secp256k1::SecretKey::parse(&decoded_secret)?);
+        senders.push((
+            Pubkey::from_str(&deserialized_sender_data.solana_key)?,
+            secp256k1::SecretKey::parse(&decoded_secret)?,
+        ));
     }
 
-    instructions.append(&mut sign_message(sender_message.as_ref(), secrets));
-
     let bot_oracle_secret =
         <[u8; 32]>::from_hex(bot_oracle_secret).expect(HEX_ETH_SECRET_DECODING_ERROR);
 
-    instructions.push(new_secp256k1_instruction_2_0(
-        &secp256k1::SecretKey::parse(&bot_oracle_secret)?,
-        bot_oracle_message.as_ref(),
-        instructions.len() as u8,
-    ));
-
-    instructions.push(transfer(
-        &audius_reward_manager::id(),
+    let instructions = RewardManagerClient::new(&config.rpc_client).evaluate_transfer(
         &reward_manager,
-        &claimable_token_acc.derive.address,
-        &reward_manager_data.token_account,
         &bot_oracle,
-        &config.fee_payer.pubkey(),
+        &secp256k1::SecretKey::parse(&bot_oracle_secret)?,
         senders,
+        &config.fee_payer.pubkey(),
         Transfer {
             amount,
             id: transfer_id,
             eth_recipient: decoded_recipient_address,
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo,
         },
-    )?);
+    )?;
 
     let transaction = CustomTransaction {
         instructions,
+        signers: vec![config.fee_payer.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_update_reward_manager(
+    config: &Config,
+    reward_manager: Pubkey,
+    params: UpdateRewardManager,
+) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            params,
+        )?],
         signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
     };
 
     transaction.sign(config, 0)
 }
 
-fn main() {
-    let matches = App::new(crate_name!())
-        .about(crate_description!())
-        .version(crate_version!())
-        .setting(AppSettings::SubcommandRequiredElseHelp)
-        .arg({
-            let arg = Arg::with_name("config_file")
-                .short("C")
-                .long("config")
-                .value_name("PATH")
-                .takes_value(true)
-                .global(true)
-                .help("Configuration file to use");
-            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
-                arg.default_value(&config_file)
-            } else {
-                arg
-            }
-        })
-        .arg(
-            Arg::with_name("verbose")
-                .long("verbose")
-                .short("v")
-                .takes_value(false)
-                .global(true)
-                .help("Show additional information"),
-        )
-        .arg(
-            Arg::with_name("json_rpc_url")
-                .long("url")
-                .value_name("URL")
-                .takes_value(true)
-                .validator(is_url)
-                .help("JSON RPC URL for the cluster.  Default from the configuration file."),
-        )
+fn command_propose_manager(
+    config: &Config,
+    reward_manager: Pubkey,
+    new_manager: Pubkey,
+) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![propose_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &new_manager,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_accept_manager(config: &Config, reward_manager: Pubkey) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![accept_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_announce_treasury_withdrawal(
+    config: &Config,
+    reward_manager: Pubkey,
+    params: AnnounceTreasuryWithdrawal,
+) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![announce_treasury_withdrawal(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            params,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_withdraw_treasury(config: &Config, reward_manager: Pubkey) -> CommandResult {
+    let reward_manager_data =
+        RewardManagerClient::new(&config.rpc_client).get_reward_manager(&reward_manager)?;
+
+    let transaction = CustomTransaction {
+        instructions: vec![withdraw_treasury(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &reward_manager_data.token_account,
+            &reward_manager_data.pending_withdrawal_destination,
+            &config.owner.pubkey(),
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_migrate_token_account(
+    config: &Config,
+    reward_manager: Pubkey,
+    token_account: Pubkey,
+) -> CommandResult {
+    let reward_manager_data =
+        RewardManagerClient::new(&config.rpc_client).get_reward_manager(&reward_manager)?;
+
+    let transaction = CustomTransaction {
+        instructions: vec![migrate_token_account(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &reward_manager_data.token_account,
+            &token_account,
+            &config.owner.pubkey(),
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_set_transfer_fee(
+    config: &Config,
+    reward_manager: Pubkey,
+    fee_bps: u16,
+    fee_account: Option<Pubkey>,
+) -> CommandResult {
+    let reward_manager_data =
+        RewardManagerClient::new(&config.rpc_client).get_reward_manager(&reward_manager)?;
+
+    let transaction = CustomTransaction {
+        instructions: vec![set_transfer_fee(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &reward_manager_data.token_account,
+            &fee_account.unwrap_or_default(),
+            SetTransferFee { fee_bps },
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_set_spend_limit(
+    config: &Config,
+    reward_manager: Pubkey,
+    params: SetSpendLimit,
+) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![set_spend_limit(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            params,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_set_rate_limit(
+    config: &Config,
+    reward_manager: Pubkey,
+    params: SetRateLimit,
+) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![set_rate_limit(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            params,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_set_paused(config: &Config, reward_manager: Pubkey, paused: bool) -> CommandResult {
+    let instruction = if paused {
+        pause(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+        )?
+    } else {
+        unpause(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+        )?
+    };
+
+    let transaction = CustomTransaction {
+        instructions: vec![instruction],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_set_sender_suspended(
+    config: &Config,
+    reward_manager: Pubkey,
+    eth_sender_address: String,
+    suspended: bool,
+) -> CommandResult {
+    let decoded_eth_sender_address =
+        <[u8; 20]>::from_hex(eth_sender_address).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
+    let instruction = if suspended {
+        suspend_sender(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            decoded_eth_sender_address,
+        )?
+    } else {
+        reinstate_sender(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            decoded_eth_sender_address,
+        )?
+    };
+
+    let transaction = CustomTransaction {
+        instructions: vec![instruction],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_set_recipient_blocked(
+    config: &Config,
+    reward_manager: Pubkey,
+    eth_recipient: String,
+    blocked: bool,
+) -> CommandResult {
+    let decoded_eth_recipient =
+        <[u8; 20]>::from_hex(eth_recipient).expect(HEX_ETH_ADDRESS_DECODING_ERROR);
+
+    let transaction = CustomTransaction {
+        instructions: vec![set_recipient_blocked(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            SetRecipientBlocked {
+                eth_recipient: decoded_eth_recipient,
+                blocked,
+            },
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_set_challenge(
+    config: &Config,
+    reward_manager: Pubkey,
+    id: String,
+    oracles: Vec<String>,
+) -> CommandResult {
+    let oracles = oracles
+        .into_iter()
+        .map(|oracle| <[u8; 20]>::from_hex(oracle).expect(HEX_ETH_ADDRESS_DECODING_ERROR))
+        .collect();
+
+    let transaction = CustomTransaction {
+        instructions: vec![set_challenge(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            SetChallenge { id, oracles },
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_create_event_log(config: &Config, reward_manager: Pubkey) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![create_event_log(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_decode_instruction(data: Vec<u8>) -> CommandResult {
+    println!("{}", decode(&data)?);
+    Ok(None)
+}
+
+fn command_create_delegate(
+    config: &Config,
+    reward_manager: Pubkey,
+    params: CreateDelegate,
+) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![create_delegate(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            params,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+fn command_revoke_delegate(
+    config: &Config,
+    reward_manager: Pubkey,
+    delegate: Pubkey,
+) -> CommandResult {
+    let transaction = CustomTransaction {
+        instructions: vec![revoke_delegate(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            &delegate,
+        )?],
+        signers: vec![config.fee_payer.as_ref(), config.owner.as_ref()],
+    };
+
+    transaction.sign(config, 0)
+}
+
+/// Maps the `--permission` values to `DelegateAccount` bitflags
+fn permission_flag(permission: &str) -> u8 {
+    match permission {
+        "create-sender" => DelegateAccount::CREATE_SENDER,
+        "delete-sender" => DelegateAccount::DELETE_SENDER,
+        "create-oracle" => DelegateAccount::CREATE_ORACLE,
+        "delete-oracle" => DelegateAccount::DELETE_ORACLE,
+        "pause" => DelegateAccount::PAUSE,
+        _ => unreachable!(),
+    }
+}
+
+fn main() {
+    let matches = App::new(crate_name!())
+        .about(crate_description!())
+        .version(crate_version!())
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg({
+            let arg = Arg::with_name("config_file")
+                .short("C")
+                .long("config")
+                .value_name("PATH")
+                .takes_value(true)
+                .global(true)
+                .help("Configuration file to use");
+            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
+                arg.default_value(&config_file)
+            } else {
+                arg
+            }
+        })
+        .arg(
+            Arg::with_name("verbose")
+                .long("verbose")
+                .short("v")
+                .takes_value(false)
+                .global(true)
+                .help("Show additional information"),
+        )
+        .arg(
+            Arg::with_name("json_rpc_url")
+                .long("url")
+                .value_name("URL")
+                .takes_value(true)
+                .validator(is_url)
+                .help("JSON RPC URL for the cluster.  Default from the configuration file."),
+        )
         .arg(
             Arg::with_name("owner")
                 .long("owner")
                 .value_name("KEYPAIR")
                 .validator(is_keypair)
                 .takes_value(true)
-                .help(
-                    "Specify the market/pool's owner. \
-                     This may be a keypair file, the ASK keyword. \
-                     Defaults to the client keypair.",
-                ),
-        )
-        .arg(
-            Arg::with_name("fee_payer")
-                .long("fee-payer")
-                .value_name("KEYPAIR")
-                .validator(is_keypair)
+                .help(
+                    "Specify the reward manager's manager, signs admin instructions only. \
+                     This may be a keypair file, the ASK keyword. \
+                     Defaults to the client keypair.",
+                ),
+        )
+        .arg(
+            Arg::with_name("fee_payer")
+                .long("fee-payer")
+                .value_name("KEYPAIR")
+                .validator(is_keypair)
+                .takes_value(true)
+                .help(
+                    "Specify the fee-payer account, also pays rent for the accounts created. \
+                     This may be a keypair file, the ASK keyword. \
+                     Defaults to the client keypair.",
+                ),
+        )
+        .subcommand(SubCommand::with_name("init").about("Init a new reward manager")
+            .arg(
+                Arg::with_name("token-mint")
+                    .long("token-mint")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Mint with which the new token account will be associated on initialization."),
+            )
+            .arg(
+                Arg::with_name("min-votes")
+                    .long("min-votes")
+                    .validator(is_parsable::<u8>)
+                    .takes_value(true)
+                    .required(true)
+                    .help("Number of signer votes required for sending rewards."),
+            )
+            .arg(
+                Arg::with_name("token-program")
+                    .long("token-program")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .help("Token program owning the vault. Defaults to the SPL Token program."),
+            )
+            .arg(
+                Arg::with_name("claimable-tokens-program")
+                    .long("claimable-tokens-program")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .help("Program the transfer recipients are claimable tokens accounts of. Defaults to the deployed claimable tokens program."),
+            ))
+        .subcommand(SubCommand::with_name("create-sender").about("Admin method creating new authorized sender")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-sender-address")
+                    .long("eth-sender-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum sender address"),
+            )
+            .arg(
+                Arg::with_name("eth-operator-address")
+                    .long("eth-operator-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum operator address"),
+            ))
+        .subcommand(SubCommand::with_name("delete-sender").about("Admin method deleting sender")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-sender-address")
+                    .long("eth-sender-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum sender address"),
+            ))
+        .subcommand(SubCommand::with_name("set-sender-weight").about("Admin method setting the vote weight of a sender")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-sender-address")
+                    .long("eth-sender-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum sender address"),
+            )
+            .arg(
+                Arg::with_name("weight")
+                    .long("weight")
+                    .validator(is_parsable::<u16>)
+                    .value_name("NUMBER")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Vote weight of the sender attestations"),
+            ))
+        .subcommand(SubCommand::with_name("repair-sender").about("Admin method rewriting a sender account holding corrupted data")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-sender-address")
+                    .long("eth-sender-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum sender address"),
+            )
+            .arg(
+                Arg::with_name("eth-operator-address")
+                    .long("eth-operator-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum operator address the sender is registered under"),
+            ))
+        .subcommand(SubCommand::with_name("suspend-sender").about("Admin method rejecting the sender attestations until it's reinstated")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-sender-address")
+                    .long("eth-sender-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum sender address"),
+            ))
+        .subcommand(SubCommand::with_name("reinstate-sender").about("Admin method accepting the attestations of a suspended sender again")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-sender-address")
+                    .long("eth-sender-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum sender address"),
+            ))
+        .subcommand(SubCommand::with_name("block-recipient").about("Admin method rejecting transfers to an Ethereum recipient")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-recipient")
+                    .long("eth-recipient")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum recipient address"),
+            ))
+        .subcommand(SubCommand::with_name("unblock-recipient").about("Admin method allowing transfers to a blocked Ethereum recipient again")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-recipient")
+                    .long("eth-recipient")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum recipient address"),
+            ))
+        .subcommand(SubCommand::with_name("set-challenge").about("Admin method registering a challenge transfer IDs are prefixed with")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("id")
+                    .long("id")
+                    .value_name("CHALLENGE_ID")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Challenge ID, transfer IDs of the challenge are `<CHALLENGE_ID>:...`"),
+            )
+            .arg(
+                Arg::with_name("oracle")
+                    .long("oracle")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .multiple(true)
+                    .help("Bot oracle authorized to attest transfers of the challenge. Replaces the oracles of a registered challenge, none removes it."),
+            ))
+        .subcommand(SubCommand::with_name("create-oracle").about("Admin method creating the bot oracle")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-oracle-address")
+                    .long("eth-oracle-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum bot oracle address"),
+            )
+            .arg(
+                Arg::with_name("eth-operator-address")
+                    .long("eth-operator-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum operator address"),
+            ))
+        .subcommand(SubCommand::with_name("delete-oracle").about("Admin method deleting the bot oracle")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-oracle-address")
+                    .long("eth-oracle-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum bot oracle address"),
+            ))
+        .subcommand(SubCommand::with_name("add-sender").about("Add new sender")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("new-sender")
+                    .long("new-sender")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("New Ethereum sender address"),
+            )
+            .arg(
+                Arg::with_name("operator-address")
+                    .long("operator-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum operator address"),
+            )
+            .arg(
+                Arg::with_name("senders-secrets")
+                .long("senders-secrets")
+                .validator(is_csv_file)
+                .value_name("PATH")
+                .takes_value(true)
+                .required(true)
+                .help("CSV file with senders Ethereum secret keys"),
+            ))
+        .subcommand(SubCommand::with_name("delete-sender-public").about("Delete sender on the attestations of other senders")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("eth-sender-address")
+                    .long("eth-sender-address")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum sender address"),
+            )
+            .arg(
+                Arg::with_name("senders-secrets")
+                .long("senders-secrets")
+                .validator(is_csv_file)
+                .value_name("PATH")
                 .takes_value(true)
-                .help(
-                    "Specify the fee-payer account. \
-                     This may be a keypair file, the ASK keyword. \
-                     Defaults to the client keypair.",
-                ),
-        )
-        .subcommand(SubCommand::with_name("init").about("Init a new reward manager")
+                .required(true)
+                .help("CSV file with senders Ethereum secret keys"),
+            ))
+        .subcommand(SubCommand::with_name("update-sender-metadata").about("Set the hash of the sender service endpoint, signed by the sender")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("sender-secret")
+                    .long("sender-secret")
+                    .validator(is_hex)
+                    .value_name("ETH_SECRET")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum sender secret key"),
+            )
+            .arg(
+                Arg::with_name("endpoint")
+                    .long("endpoint")
+                    .value_name("URL")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Service endpoint URL of the sender"),
+            ))
+        .subcommand(SubCommand::with_name("transfer").about("Make transfer")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("bot-oracle")
+                    .long("bot-oracle")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Bot oracle"),
+            )
+            .arg(
+                Arg::with_name("bot-oracle-secret")
+                    .long("bot-oracle-secret")
+                    .validator(is_hex)
+                    .value_name("ETH_SECRET")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Ethereum bot oracle secret key"),
+            )
+            .arg(
+                Arg::with_name("senders-secrets")
+                    .long("senders-secrets")
+                    .validator(is_csv_file)
+                    .value_name("PATH")
+                    .takes_value(true)
+                    .required(true)
+                    .help("CSV file with senders Ethereum secret keys"),
+            )
+            .arg(
+                Arg::with_name("transfer-id")
+                    .long("transfer-id")
+                    .validator(is_parsable::<String>)
+                    .value_name("STRING")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Transfer ID"),
+            )
+            .arg(
+                Arg::with_name("eth-address-recipient")
+                    .long("eth-address-recipient")
+                    .validator(is_eth_address)
+                    .value_name("ETH_ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Recipient Ethereum address"),
+            )
+            .arg(
+                Arg::with_name("amount")
+                    .long("amount")
+                    .validator(is_parsable::<f64>)
+                    .value_name("NUMBER")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Amount to transfer"),
+            )
+            .arg(
+                Arg::with_name("memo")
+                    .long("memo")
+                    .takes_value(false)
+                    .help("Log the transfer ID and its challenge through the SPL Memo program"),
+            ))
+        .subcommand(SubCommand::with_name("update-reward-manager").about("Admin method updating reward manager settings")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("min-votes")
+                    .long("min-votes")
+                    .validator(is_parsable::<u8>)
+                    .takes_value(true)
+                    .help("Number of signer votes required for sending rewards."),
+            )
+            .arg(
+                Arg::with_name("max-attestations")
+                    .long("max-attestations")
+                    .validator(is_parsable::<u8>)
+                    .takes_value(true)
+                    .help("Maximum number of attestations evaluated per instruction."),
+            )
+            .arg(
+                Arg::with_name("max-transfer-amount")
+                    .long("max-transfer-amount")
+                    .validator(is_parsable::<f64>)
+                    .value_name("AMOUNT")
+                    .takes_value(true)
+                    .help("Maximum amount of a single transfer."),
+            )
+            .arg(
+                Arg::with_name("sender-creation-gap")
+                    .long("sender-creation-gap")
+                    .validator(is_parsable::<u64>)
+                    .value_name("SLOTS")
+                    .takes_value(true)
+                    .help("Minimum number of slots between sender creations."),
+            )
+            .arg(
+                Arg::with_name("min-oracle-votes")
+                    .long("min-oracle-votes")
+                    .validator(is_parsable::<u8>)
+                    .value_name("NUMBER")
+                    .takes_value(true)
+                    .help("Number of bot oracle votes required for sending rewards."),
+            )
+            .arg(
+                Arg::with_name("min-balance-threshold")
+                    .long("min-balance-threshold")
+                    .validator(is_parsable::<f64>)
+                    .value_name("AMOUNT")
+                    .takes_value(true)
+                    .help("Vault balance transfers must leave in place."),
+            )
+            .arg(
+                Arg::with_name("v1-messages-allowed")
+                    .long("v1-messages-allowed")
+                    .validator(is_parsable::<bool>)
+                    .value_name("BOOL")
+                    .takes_value(true)
+                    .help("Whether transfers attested with the deprecated `_` joined messages are accepted."),
+            )
+            .arg(
+                Arg::with_name("min-vote-weight")
+                    .long("min-vote-weight")
+                    .validator(is_parsable::<u32>)
+                    .value_name("NUMBER")
+                    .takes_value(true)
+                    .help("Summed sender weight required for sending rewards, zero disables weighting."),
+            )
+            .arg(
+                Arg::with_name("withdrawal-delay")
+                    .long("withdrawal-delay")
+                    .validator(is_parsable::<u64>)
+                    .value_name("SLOTS")
+                    .takes_value(true)
+                    .help("Slots an announced treasury withdrawal waits before it may execute."),
+            )
+            .arg(
+                Arg::with_name("claimable-tokens-program")
+                    .long("claimable-tokens-program")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .help("Program the transfer recipients are claimable tokens accounts of."),
+            ))
+        .subcommand(SubCommand::with_name("propose-manager").about("Admin method proposing a new manager, it may accept after a timelock")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            )
+            .arg(
+                Arg::with_name("new-manager")
+                    .long("new-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("New manager account. The current one cancels a pending change."),
+            ))
+        .subcommand(SubCommand::with_name("accept-manager").about("Take over the reward manager as the proposed manager")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
+            ))
+        .subcommand(SubCommand::with_name("announce-treasury-withdrawal").about("Admin method announcing a withdrawal of unspent tokens, it may execute after a timelock")
             .arg(
-                Arg::with_name("token-mint")
-                    .long("token-mint")
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
                     .validator(is_pubkey)
                     .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("Mint with which the new token account will be associated on initialization."),
+                    .help("Reward manager"),
             )
             .arg(
-                Arg::with_name("min-votes")
-                    .long("min-votes")
-                    .validator(is_parsable::<u8>)
+                Arg::with_name("amount")
+                    .long("amount")
+                    .validator(is_parsable::<f64>)
+                    .value_name("NUMBER")
                     .takes_value(true)
                     .required(true)
-                    .help("Number of signer votes required for sending rewards."),
+                    .help("Amount to withdraw. Zero cancels a pending withdrawal."),
+            )
+            .arg(
+                Arg::with_name("destination")
+                    .long("destination")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Token account receiving the tokens"),
             ))
-        .subcommand(SubCommand::with_name("create-sender").about("Admin method creating new authorized sender")
+        .subcommand(SubCommand::with_name("withdraw-treasury").about("Admin method executing the announced treasury withdrawal")
             .arg(
                 Arg::with_name("reward-manager")
                     .long("reward-manager")
@@ -442,26 +1489,27 @@ fn main() {
                     .takes_value(true)
                     .required(true)
                     .help("Reward manager"),
-            )
+            ))
+        .subcommand(SubCommand::with_name("migrate-token-account").about("Admin method moving the reward manager to a new vault token account")
             .arg(
-                Arg::with_name("eth-sender-address")
-                    .long("eth-sender-address")
-                    .validator(is_eth_address)
-                    .value_name("ETH_ADDRESS")
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("Ethereum sender address"),
+                    .help("Reward manager"),
             )
             .arg(
-                Arg::with_name("eth-operator-address")
-                    .long("eth-operator-address")
-                    .validator(is_eth_address)
-                    .value_name("ETH_ADDRESS")
+                Arg::with_name("token-account")
+                    .long("token-account")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("Ethereum operator address"),
+                    .help("New vault token account owned by the reward manager authority"),
             ))
-        .subcommand(SubCommand::with_name("delete-sender").about("Admin method deleting sender")
+        .subcommand(SubCommand::with_name("set-transfer-fee").about("Admin method setting the part of every transfer sent to a fee token account")
             .arg(
                 Arg::with_name("reward-manager")
                     .long("reward-manager")
@@ -472,15 +1520,23 @@ fn main() {
                     .help("Reward manager"),
             )
             .arg(
-                Arg::with_name("eth-sender-address")
-                    .long("eth-sender-address")
-                    .validator(is_eth_address)
-                    .value_name("ETH_ADDRESS")
+                Arg::with_name("fee-bps")
+                    .long("fee-bps")
+                    .validator(is_parsable::<u16>)
+                    .value_name("BASIS_POINTS")
                     .takes_value(true)
                     .required(true)
-                    .help("Ethereum sender address"),
+                    .help("Basis points of every transfer sent to the fee account, zero disables the fee"),
+            )
+            .arg(
+                Arg::with_name("fee-account")
+                    .long("fee-account")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .help("Token account of the vault mint receiving the fees, required unless disabling the fee"),
             ))
-        .subcommand(SubCommand::with_name("add-sender").about("Add new sender")
+        .subcommand(SubCommand::with_name("set-spend-limit").about("Admin method setting the amount disbursed per window")
             .arg(
                 Arg::with_name("reward-manager")
                     .long("reward-manager")
@@ -491,33 +1547,50 @@ fn main() {
                     .help("Reward manager"),
             )
             .arg(
-                Arg::with_name("new-sender")
-                    .long("new-sender")
-                    .validator(is_eth_address)
-                    .value_name("ETH_ADDRESS")
+                Arg::with_name("budget")
+                    .long("budget")
+                    .validator(is_parsable::<f64>)
+                    .value_name("AMOUNT")
                     .takes_value(true)
                     .required(true)
-                    .help("New Ethereum sender address"),
+                    .help("Maximum amount disbursed per window."),
             )
             .arg(
-                Arg::with_name("operator-address")
-                    .long("operator-address")
-                    .validator(is_eth_address)
-                    .value_name("ETH_ADDRESS")
+                Arg::with_name("window-slots")
+                    .long("window-slots")
+                    .validator(is_parsable::<u64>)
+                    .value_name("SLOTS")
+                    .takes_value(true)
+                    .help("Window length in slots. Spending is tracked per epoch if omitted."),
+            ))
+        .subcommand(SubCommand::with_name("set-rate-limit").about("Admin method setting the number of transfers the senders of an operator attest per window")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("Ethereum operator address"),
+                    .help("Reward manager"),
             )
             .arg(
-                Arg::with_name("senders-secrets")
-                .long("senders-secrets")
-                .validator(is_csv_file)
-                .value_name("PATH")
-                .takes_value(true)
-                .required(true)
-                .help("CSV file with senders Ethereum secret keys"),
+                Arg::with_name("max-attestations")
+                    .long("max-attestations")
+                    .validator(is_parsable::<u32>)
+                    .value_name("NUMBER")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Maximum number of transfers the senders of an operator attest per window."),
+            )
+            .arg(
+                Arg::with_name("window-slots")
+                    .long("window-slots")
+                    .validator(is_parsable::<u64>)
+                    .value_name("SLOTS")
+                    .takes_value(true)
+                    .help("Window length in slots. Attestations are counted per epoch if omitted."),
             ))
-        .subcommand(SubCommand::with_name("transfer").about("Make transfer")
+        .subcommand(SubCommand::with_name("create-event-log").about("Admin method creating the log of the latest transfers for light clients")
             .arg(
                 Arg::with_name("reward-manager")
                     .long("reward-manager")
@@ -526,60 +1599,83 @@ fn main() {
                     .takes_value(true)
                     .required(true)
                     .help("Reward manager"),
-            )
+            ))
+        .subcommand(SubCommand::with_name("decode-instruction").about("Print the parameters of a reward manager instruction")
             .arg(
-                Arg::with_name("bot-oracle")
-                    .long("bot-oracle")
+                Arg::with_name("data")
+                    .validator(is_hex)
+                    .value_name("HEX")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Instruction data as shown by explorers"),
+            ))
+        .subcommand(SubCommand::with_name("pause").about("Admin method halting transfers")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
                     .validator(is_pubkey)
                     .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("Bot oracle"),
-            )
+                    .help("Reward manager"),
+            ))
+        .subcommand(SubCommand::with_name("unpause").about("Admin method resuming transfers")
             .arg(
-                Arg::with_name("bot-oracle-secret")
-                    .long("bot-oracle-secret")
-                    .validator(is_hex)
-                    .value_name("ETH_SECRET")
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("Ethereum bot oracle secret key"),
-            )
+                    .help("Reward manager"),
+            ))
+        .subcommand(SubCommand::with_name("create-delegate").about("Admin method authorizing a key to call some of the admin methods")
             .arg(
-                Arg::with_name("senders-secrets")
-                    .long("senders-secrets")
-                    .validator(is_csv_file)
-                    .value_name("PATH")
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("CSV file with senders Ethereum secret keys"),
+                    .help("Reward manager"),
             )
             .arg(
-                Arg::with_name("transfer-id")
-                    .long("transfer-id")
-                    .validator(is_parsable::<String>)
-                    .value_name("STRING")
+                Arg::with_name("delegate")
+                    .long("delegate")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("Transfer ID"),
+                    .help("Key allowed to sign instead of the manager"),
             )
             .arg(
-                Arg::with_name("eth-address-recipient")
-                    .long("eth-address-recipient")
-                    .validator(is_eth_address)
-                    .value_name("ETH_ADDRESS")
+                Arg::with_name("permission")
+                    .long("permission")
+                    .value_name("PERMISSION")
                     .takes_value(true)
+                    .multiple(true)
                     .required(true)
-                    .help("Recipient Ethereum address"),
+                    .possible_values(&["create-sender", "delete-sender", "create-oracle", "delete-oracle", "pause"])
+                    .help("Admin method the delegate may call. Replaces the permissions of an existing delegate."),
+            ))
+        .subcommand(SubCommand::with_name("revoke-delegate").about("Admin method revoking the delegate")
+            .arg(
+                Arg::with_name("reward-manager")
+                    .long("reward-manager")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
+                    .takes_value(true)
+                    .required(true)
+                    .help("Reward manager"),
             )
             .arg(
-                Arg::with_name("amount")
-                    .long("amount")
-                    .validator(is_parsable::<f64>)
-                    .value_name("NUMBER")
+                Arg::with_name("delegate")
+                    .long("delegate")
+                    .validator(is_pubkey)
+                    .value_name("ADDRESS")
                     .takes_value(true)
                     .required(true)
-                    .help("Amount to transfer"),
+                    .help("Revoked key"),
             ))
         .get_matches();
 
@@ -630,7 +1726,17 @@ fn main() {
         ("init", Some(arg_matches)) => {
             let token_mint: Pubkey = pubkey_of(arg_matches, "token-mint").unwrap();
             let min_votes: u8 = value_t_or_exit!(arg_matches, "min-votes", u8);
-            command_init(&config, token_mint, min_votes)
+            let token_program =
+                pubkey_of(arg_matches, "token-program").unwrap_or_else(spl_token::id);
+            let claimable_tokens_program = pubkey_of(arg_matches, "claimable-tokens-program")
+                .unwrap_or_else(claimable_tokens::id);
+            command_init(
+                &config,
+                token_mint,
+                token_program,
+                claimable_tokens_program,
+                min_votes,
+            )
         }
         ("create-sender", Some(arg_matches)) => {
             let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
@@ -655,6 +1761,54 @@ fn main() {
                 String::from(eth_sender_address.get(2..).unwrap()),
             )
         }
+        ("set-sender-weight", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_sender_address: String =
+                value_t_or_exit!(arg_matches, "eth-sender-address", String);
+            let weight: u16 = value_t_or_exit!(arg_matches, "weight", u16);
+            command_set_sender_weight(
+                &config,
+                reward_manager,
+                String::from(eth_sender_address.get(2..).unwrap()),
+                weight,
+            )
+        }
+        ("repair-sender", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_sender_address: String =
+                value_t_or_exit!(arg_matches, "eth-sender-address", String);
+            let eth_operator_address: String =
+                value_t_or_exit!(arg_matches, "eth-operator-address", String);
+            command_repair_sender(
+                &config,
+                reward_manager,
+                String::from(eth_sender_address.get(2..).unwrap()),
+                String::from(eth_operator_address.get(2..).unwrap()),
+            )
+        }
+        ("create-oracle", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_oracle_address: String =
+                value_t_or_exit!(arg_matches, "eth-oracle-address", String);
+            let eth_operator_address: String =
+                value_t_or_exit!(arg_matches, "eth-operator-address", String);
+            command_create_oracle(
+                &config,
+                reward_manager,
+                String::from(eth_oracle_address.get(2..).unwrap()),
+                String::from(eth_operator_address.get(2..).unwrap()),
+            )
+        }
+        ("delete-oracle", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_oracle_address: String =
+                value_t_or_exit!(arg_matches, "eth-oracle-address", String);
+            command_delete_oracle(
+                &config,
+                reward_manager,
+                String::from(eth_oracle_address.get(2..).unwrap()),
+            )
+        }
         ("add-sender", Some(arg_matches)) => {
             let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
             let new_sender: String = value_t_or_exit!(arg_matches, "new-sender", String);
@@ -669,6 +1823,24 @@ fn main() {
                 senders_secrets,
             )
         }
+        ("delete-sender-public", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_sender_address: String =
+                value_t_or_exit!(arg_matches, "eth-sender-address", String);
+            let senders_secrets: String = value_t_or_exit!(arg_matches, "senders-secrets", String);
+            command_delete_sender_public(
+                &config,
+                reward_manager,
+                String::from(eth_sender_address.get(2..).unwrap()),
+                senders_secrets,
+            )
+        }
+        ("update-sender-metadata", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let sender_secret: String = value_t_or_exit!(arg_matches, "sender-secret", String);
+            let endpoint: String = value_t_or_exit!(arg_matches, "endpoint", String);
+            command_update_sender_metadata(&config, reward_manager, sender_secret, endpoint)
+        }
         ("transfer", Some(arg_matches)) => {
             let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
             let bot_oracle: Pubkey = pubkey_of(arg_matches, "bot-oracle").unwrap();
@@ -690,8 +1862,168 @@ fn main() {
                 transfer_id,
                 String::from(eth_address_recipient.get(2..).unwrap()),
                 amount,
+                arg_matches.is_present("memo"),
+            )
+        }
+        ("update-reward-manager", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let params = UpdateRewardManager {
+                min_votes: value_t!(arg_matches, "min-votes", u8).ok(),
+                max_attestations: value_t!(arg_matches, "max-attestations", u8).ok(),
+                max_transfer_amount: value_t!(arg_matches, "max-transfer-amount", f64)
+                    .ok()
+                    .map(|amount| ui_amount_to_amount(amount, spl_token::native_mint::DECIMALS)),
+                sender_creation_gap: value_t!(arg_matches, "sender-creation-gap", u64).ok(),
+                min_oracle_votes: value_t!(arg_matches, "min-oracle-votes", u8).ok(),
+                min_balance_threshold: value_t!(arg_matches, "min-balance-threshold", f64)
+                    .ok()
+                    .map(|amount| ui_amount_to_amount(amount, spl_token::native_mint::DECIMALS)),
+                v1_messages_allowed: value_t!(arg_matches, "v1-messages-allowed", bool).ok(),
+                min_vote_weight: value_t!(arg_matches, "min-vote-weight", u32).ok(),
+                withdrawal_delay: value_t!(arg_matches, "withdrawal-delay", u64).ok(),
+                claimable_tokens_program: pubkey_of(arg_matches, "claimable-tokens-program"),
+            };
+            command_update_reward_manager(&config, reward_manager, params)
+        }
+        ("propose-manager", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let new_manager: Pubkey = pubkey_of(arg_matches, "new-manager").unwrap();
+            command_propose_manager(&config, reward_manager, new_manager)
+        }
+        ("accept-manager", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            command_accept_manager(&config, reward_manager)
+        }
+        ("announce-treasury-withdrawal", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let amount: f64 = value_t_or_exit!(arg_matches, "amount", f64);
+            let params = AnnounceTreasuryWithdrawal {
+                amount: ui_amount_to_amount(amount, spl_token::native_mint::DECIMALS),
+                destination: pubkey_of(arg_matches, "destination").unwrap(),
+            };
+            command_announce_treasury_withdrawal(&config, reward_manager, params)
+        }
+        ("withdraw-treasury", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            command_withdraw_treasury(&config, reward_manager)
+        }
+        ("migrate-token-account", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let token_account: Pubkey = pubkey_of(arg_matches, "token-account").unwrap();
+            command_migrate_token_account(&config, reward_manager, token_account)
+        }
+        ("set-transfer-fee", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let fee_bps = value_t_or_exit!(arg_matches, "fee-bps", u16);
+            let fee_account: Option<Pubkey> = pubkey_of(arg_matches, "fee-account");
+            command_set_transfer_fee(&config, reward_manager, fee_bps, fee_account)
+        }
+        ("set-spend-limit", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let budget = value_t_or_exit!(arg_matches, "budget", f64);
+            let params = SetSpendLimit {
+                budget: ui_amount_to_amount(budget, spl_token::native_mint::DECIMALS),
+                window_slots: value_t!(arg_matches, "window-slots", u64).unwrap_or(0),
+            };
+            command_set_spend_limit(&config, reward_manager, params)
+        }
+        ("set-rate-limit", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let params = SetRateLimit {
+                max_attestations: value_t_or_exit!(arg_matches, "max-attestations", u32),
+                window_slots: value_t!(arg_matches, "window-slots", u64).unwrap_or(0),
+            };
+            command_set_rate_limit(&config, reward_manager, params)
+        }
+        ("suspend-sender", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_sender_address: String =
+                value_t_or_exit!(arg_matches, "eth-sender-address", String);
+            command_set_sender_suspended(
+                &config,
+                reward_manager,
+                String::from(eth_sender_address.get(2..).unwrap()),
+                true,
+            )
+        }
+        ("reinstate-sender", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_sender_address: String =
+                value_t_or_exit!(arg_matches, "eth-sender-address", String);
+            command_set_sender_suspended(
+                &config,
+                reward_manager,
+                String::from(eth_sender_address.get(2..).unwrap()),
+                false,
+            )
+        }
+        ("block-recipient", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_recipient: String = value_t_or_exit!(arg_matches, "eth-recipient", String);
+            command_set_recipient_blocked(
+                &config,
+                reward_manager,
+                String::from(eth_recipient.get(2..).unwrap()),
+                true,
+            )
+        }
+        ("unblock-recipient", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let eth_recipient: String = value_t_or_exit!(arg_matches, "eth-recipient", String);
+            command_set_recipient_blocked(
+                &config,
+                reward_manager,
+                String::from(eth_recipient.get(2..).unwrap()),
+                false,
             )
         }
+        ("set-challenge", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let id: String = value_t_or_exit!(arg_matches, "id", String);
+            let oracles = arg_matches
+                .values_of("oracle")
+                .map(|oracles| {
+                    oracles
+                        .map(|oracle| String::from(oracle.get(2..).unwrap()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            command_set_challenge(&config, reward_manager, id, oracles)
+        }
+        ("create-event-log", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            command_create_event_log(&config, reward_manager)
+        }
+        ("decode-instruction", Some(arg_matches)) => {
+            let data = hex::decode(value_t_or_exit!(arg_matches, "data", String)).unwrap();
+            command_decode_instruction(data)
+        }
+        ("pause", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            command_set_paused(&config, reward_manager, true)
+        }
+        ("unpause", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            command_set_paused(&config, reward_manager, false)
+        }
+        ("create-delegate", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let params = CreateDelegate {
+                delegate: pubkey_of(arg_matches, "delegate").unwrap(),
+                permissions: arg_matches
+                    .values_of("permission")
+                    .unwrap()
+                    .fold(0, |permissions, permission| {
+                        permissions | permission_flag(permission)
+                    }),
+            };
+            command_create_delegate(&config, reward_manager, params)
+        }
+        ("revoke-delegate", Some(arg_matches)) => {
+            let reward_manager: Pubkey = pubkey_of(arg_matches, "reward-manager").unwrap();
+            let delegate: Pubkey = pubkey_of(arg_matches, "delegate").unwrap();
+            command_revoke_delegate(&config, reward_manager, delegate)
+        }
         _ => unreachable!(),
     }
     .and_then(|transaction| {
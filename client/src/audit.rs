@@ -0,0 +1,173 @@
+//! Reconstruction of the attestations behind executed transfers
+
+use crate::rpc::Error;
+use audius_reward_manager::{
+    instruction::{Instructions, Transfer, TransferBatch, TransferWithSignatures},
+    state::{OracleAccount, SenderAccount},
+    utils::{EthereumAddress, SecpPayload},
+};
+use borsh::BorshDeserialize;
+use solana_client::rpc_client::RpcClient;
+use solana_program::{pubkey::Pubkey, secp256k1_program};
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+
+/// Index of the bot oracle in the `Transfer` instruction accounts
+const TRANSFER_BOT_ORACLE_INDEX: usize = 4;
+/// Number of the `Transfer` instruction accounts preceding senders
+const TRANSFER_FIXED_ACCOUNTS: usize = 16;
+/// Number of the `TransferWithSignatures` instruction accounts preceding senders
+const TRANSFER_WITH_SIGNATURES_FIXED_ACCOUNTS: usize = 15;
+/// Index of the bot oracle in the `TransferBatch` instruction accounts
+const TRANSFER_BATCH_BOT_ORACLE_INDEX: usize = 3;
+/// Number of the `TransferBatch` instruction accounts preceding transfer accounts
+const TRANSFER_BATCH_FIXED_ACCOUNTS: usize = 12;
+
+/// Message signed in one of the secp256k1 instructions of the transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedMessage {
+    /// Ethereum address recovered by the secp256k1 program
+    pub signer: EthereumAddress,
+    /// Signed message
+    pub message: Vec<u8>,
+}
+
+/// Account the program verified an attestation of
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attester {
+    /// Sender or bot oracle account
+    pub account: Pubkey,
+    /// Ethereum address, `None` once the account is removed
+    pub eth_address: Option<EthereumAddress>,
+    /// Operator represented by the attester, `None` once the account is removed
+    pub operator: Option<EthereumAddress>,
+}
+
+/// Audit artifact of a single `Transfer`, `TransferWithSignatures` or `TransferBatch` instruction
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisbursementAudit {
+    /// Slot the transaction was processed at
+    pub slot: u64,
+    /// Disbursed transfers
+    pub transfers: Vec<Transfer>,
+    /// Bot oracle approving the transfers
+    pub bot_oracle: Attester,
+    /// Senders attesting the transfers
+    pub senders: Vec<Attester>,
+    /// Messages signed ahead of the instruction, as checked by the program.
+    /// Signatures recovered by `TransferWithSignatures` itself aren't listed
+    pub signed_messages: Vec<SignedMessage>,
+}
+
+fn attester<F>(rpc_client: &RpcClient, account: Pubkey, fields: F) -> Attester
+where
+    F: Fn(&[u8]) -> Option<(EthereumAddress, EthereumAddress)>,
+{
+    let fields = rpc_client
+        .get_account_data(&account)
+        .ok()
+        .and_then(|data| fields(data.as_slice()));
+
+    Attester {
+        account,
+        eth_address: fields.map(|(eth_address, _)| eth_address),
+        operator: fields.map(|(_, operator)| operator),
+    }
+}
+
+/// Reconstructs attestations of every disbursement made by the transaction.
+///
+/// Sender and bot oracle details are read from the current state,
+/// they are missing for accounts removed since the transfer.
+pub fn audit_transfer(
+    rpc_client: &RpcClient,
+    signature: &Signature,
+) -> Result<Vec<DisbursementAudit>, Error> {
+    let confirmed = rpc_client.get_transaction(signature, UiTransactionEncoding::Base64)?;
+    if let Some(err) = confirmed
+        .transaction
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.err.clone())
+    {
+        return Err(err.into());
+    }
+    let transaction = confirmed
+        .transaction
+        .transaction
+        .decode()
+        .ok_or("transaction can't be decoded")?;
+    let message = &transaction.message;
+
+    let mut signed_messages = Vec::new();
+    let mut audits = Vec::new();
+
+    for instruction in message.instructions.iter() {
+        let program_id = message.account_keys[instruction.program_id_index as usize];
+        let accounts: Vec<Pubkey> = instruction
+            .accounts
+            .iter()
+            .map(|index| message.account_keys[*index as usize])
+            .collect();
+
+        if program_id == secp256k1_program::id() {
+            let payload = SecpPayload::parse(instruction.data.as_ref())?;
+            signed_messages.push(SignedMessage {
+                signer: payload.signer,
+                message: payload.message,
+            });
+            continue;
+        }
+        if program_id != audius_reward_manager::id() {
+            continue;
+        }
+
+        let (transfers, bot_oracle, senders) =
+            match Instructions::try_from_slice(instruction.data.as_ref())? {
+                Instructions::Transfer(transfer) => (
+                    vec![transfer],
+                    accounts[TRANSFER_BOT_ORACLE_INDEX],
+                    &accounts[TRANSFER_FIXED_ACCOUNTS..],
+                ),
+                Instructions::TransferWithSignatures(TransferWithSignatures {
+                    transfer, ..
+                }) => (
+                    vec![transfer],
+                    accounts[TRANSFER_BOT_ORACLE_INDEX],
+                    &accounts[TRANSFER_WITH_SIGNATURES_FIXED_ACCOUNTS..],
+                ),
+                Instructions::TransferBatch(TransferBatch { transfers }) => {
+                    let senders_start = TRANSFER_BATCH_FIXED_ACCOUNTS + transfers.len() * 2;
+                    (
+                        transfers,
+                        accounts[TRANSFER_BATCH_BOT_ORACLE_INDEX],
+                        &accounts[senders_start..],
+                    )
+                }
+                _ => continue,
+            };
+
+        audits.push(DisbursementAudit {
+            slot: confirmed.slot,
+            transfers,
+            bot_oracle: attester(rpc_client, bot_oracle, |data| {
+                OracleAccount::try_from_slice(data)
+                    .ok()
+                    .map(|oracle| (oracle.eth_address, oracle.operator))
+            }),
+            senders: senders
+                .iter()
+                .map(|sender| {
+                    attester(rpc_client, *sender, |data| {
+                        SenderAccount::unpack(data)
+                            .ok()
+                            .map(|sender| (sender.eth_address, sender.operator))
+                    })
+                })
+                .collect(),
+            signed_messages: signed_messages.clone(),
+        });
+    }
+
+    Ok(audits)
+}
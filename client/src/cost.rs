@@ -0,0 +1,105 @@
+//! SOL cost of disbursements, planned and actual
+
+use crate::rpc::Error;
+use audius_reward_manager::{
+    instruction::Transfer,
+    state::{RewardManager, TransferReceipt},
+};
+use claimable_tokens::utils::program::get_address_pair as get_claimable_address;
+use solana_client::rpc_client::RpcClient;
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+use spl_token::state::Account as TokenAccount;
+
+/// Lamports spent on a disbursement
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DisbursementCost {
+    /// Transaction fee, secp256k1 signatures included
+    pub fee: u64,
+    /// Rent of the transfer receipts and recipient token accounts created
+    pub rent: u64,
+}
+
+impl DisbursementCost {
+    /// Fee and rent together
+    pub fn total(&self) -> u64 {
+        self.fee.saturating_add(self.rent)
+    }
+}
+
+/// Estimates the cost of disbursing `transfers` in one transaction signed by
+/// `signers` Solana keys and attested by `senders` senders and the bot oracle.
+///
+/// Solana 1.7 has no prioritization fees, the fee is charged per signature only.
+pub fn estimate_transfer_cost(
+    rpc_client: &RpcClient,
+    reward_manager: &Pubkey,
+    signers: usize,
+    senders: usize,
+    transfers: &[Transfer],
+) -> Result<DisbursementCost, Error> {
+    let (_, fee_calculator) = rpc_client.get_recent_blockhash()?;
+    // NOTE: +1 it's bot oracle
+    let signatures = (signers + senders + 1) as u64;
+
+    let reward_manager_data =
+        RewardManager::unpack(rpc_client.get_account_data(reward_manager)?.as_slice())?;
+    let vault_data = TokenAccount::unpack(
+        rpc_client
+            .get_account_data(&reward_manager_data.token_account)?
+            .as_slice(),
+    )?;
+
+    let mut rent = rpc_client
+        .get_minimum_balance_for_rent_exemption(TransferReceipt::LEN)?
+        .saturating_mul(transfers.len() as u64);
+
+    let token_account_rent =
+        rpc_client.get_minimum_balance_for_rent_exemption(TokenAccount::LEN)?;
+    for transfer in transfers.iter() {
+        let recipient = get_claimable_address(
+            &claimable_tokens::id(),
+            &vault_data.mint,
+            transfer.eth_recipient,
+        )?;
+        if rpc_client
+            .get_account_data(&recipient.derive.address)
+            .is_err()
+        {
+            rent = rent.saturating_add(token_account_rent);
+        }
+    }
+
+    Ok(DisbursementCost {
+        fee: fee_calculator
+            .lamports_per_signature
+            .saturating_mul(signatures),
+        rent,
+    })
+}
+
+/// Reports what the confirmed transaction cost. Rent is the balance of every
+/// account the transaction funded from zero.
+pub fn transfer_cost(
+    rpc_client: &RpcClient,
+    signature: &Signature,
+) -> Result<DisbursementCost, Error> {
+    let confirmed = rpc_client.get_transaction(signature, UiTransactionEncoding::Base64)?;
+    let meta = confirmed
+        .transaction
+        .meta
+        .ok_or("transaction status is missing")?;
+
+    let rent = meta
+        .pre_balances
+        .iter()
+        .zip(meta.post_balances.iter())
+        .filter(|(pre, _)| **pre == 0)
+        .fold(0u64, |rent, (_, post)| rent.saturating_add(*post));
+
+    Ok(DisbursementCost {
+        fee: meta.fee,
+        rent,
+    })
+}
@@ -0,0 +1,59 @@
+//! Merkle trees of transfers distributed under a single attestation round
+
+use crate::rpc::Error;
+use audius_reward_manager::{
+    instruction::Transfer,
+    utils::{distribution_leaf, distribution_parent},
+};
+use solana_program::hash::Hash;
+
+/// Tree of `Transfer` leaves, a node without a sibling is carried to the next level as is
+pub struct DistributionTree {
+    layers: Vec<Vec<Hash>>,
+}
+
+impl DistributionTree {
+    /// Builds the tree of the transfers kept in their order
+    pub fn new(transfers: &[Transfer]) -> Result<Self, Error> {
+        if transfers.is_empty() {
+            return Err("distribution has no transfers".into());
+        }
+
+        let mut layers = vec![transfers
+            .iter()
+            .map(distribution_leaf)
+            .collect::<Result<Vec<Hash>, _>>()?];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => distribution_parent(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Ok(Self { layers })
+    }
+
+    /// Root the senders attest to
+    pub fn root(&self) -> Hash {
+        self.layers.last().unwrap()[0]
+    }
+
+    /// Proof of the transfer at `index` to claim it with
+    pub fn proof(&self, mut index: usize) -> Vec<Hash> {
+        let mut proof = Vec::new();
+        for layer in self.layers.iter().take(self.layers.len() - 1) {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
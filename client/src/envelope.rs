@@ -0,0 +1,266 @@
+//! JSON envelope attestations are exchanged in between discovery nodes and relayers
+
+use crate::secp::{message_hash, secp256k1_instruction_from_signature};
+use audius_reward_manager::{
+    instruction::{MessageVersion, Transfer},
+    utils::{build_oracle_message, build_senders_message, EthereumAddress},
+};
+use serde::{Deserialize, Serialize};
+use solana_program::instruction::Instruction;
+use solana_sdk::secp256k1_instruction::{construct_eth_pubkey, SIGNATURE_SERIALIZED_SIZE};
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+/// Envelope version produced and accepted by this client
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// Reasons an envelope is rejected
+#[derive(Debug)]
+pub enum EnvelopeError {
+    /// Malformed JSON or fields not in the schema
+    Json(serde_json::Error),
+    /// Envelope version other than `ENVELOPE_VERSION`
+    UnsupportedVersion(u8),
+    /// Field is not a `0x` prefixed hex string of the expected length
+    InvalidHex(&'static str),
+    /// Amount is not a decimal `u64`
+    InvalidAmount,
+    /// Transfer message version the program doesn't know
+    UnsupportedMessageVersion(u8),
+    /// Message hash doesn't match the message the transfer requires
+    MessageHashMismatch,
+    /// Signature doesn't recover the signer address
+    InvalidSignature,
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EnvelopeError::Json(err) => write!(f, "malformed envelope: {}", err),
+            EnvelopeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported envelope version {}", version)
+            }
+            EnvelopeError::InvalidHex(field) => write!(f, "invalid hex in `{}`", field),
+            EnvelopeError::InvalidAmount => write!(f, "invalid amount"),
+            EnvelopeError::UnsupportedMessageVersion(version) => {
+                write!(f, "unsupported message version {}", version)
+            }
+            EnvelopeError::MessageHashMismatch => write!(f, "message hash mismatch"),
+            EnvelopeError::InvalidSignature => write!(f, "signature doesn't match the signer"),
+        }
+    }
+}
+
+impl std::error::Error for EnvelopeError {}
+
+impl From<serde_json::Error> for EnvelopeError {
+    fn from(err: serde_json::Error) -> Self {
+        EnvelopeError::Json(err)
+    }
+}
+
+/// Transfer the attestation approves
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TransferSpec {
+    /// Transfer ID
+    pub id: String,
+    /// Amount in the smallest token units, decimal string to survive JSON number precision
+    pub amount: String,
+    /// `0x` prefixed recipient Ethereum address
+    pub eth_recipient: String,
+    /// Encoding of the signed message, 1 for the `_` joined messages when absent
+    #[serde(default = "v1_message_version")]
+    pub message_version: u8,
+}
+
+fn v1_message_version() -> u8 {
+    1
+}
+
+/// Attestation of a single transfer by a sender or the bot oracle
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AttestationEnvelope {
+    /// Envelope version
+    pub version: u8,
+    /// Attested transfer
+    pub transfer: TransferSpec,
+    /// `0x` prefixed Ethereum address of the bot oracle approving the transfer
+    pub bot_oracle: String,
+    /// `0x` prefixed Ethereum address of the signer, the bot oracle signs its own message
+    pub signer: String,
+    /// `0x` prefixed signature followed by the recovery id
+    pub signature: String,
+    /// `0x` prefixed Keccak256 hash of the signed message
+    pub message_hash: String,
+}
+
+/// Envelope contents which passed validation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attestation {
+    /// Attested transfer
+    pub transfer: Transfer,
+    /// Bot oracle approving the transfer
+    pub bot_oracle: EthereumAddress,
+    /// Signer
+    pub signer: EthereumAddress,
+    /// Signature
+    pub signature: [u8; SIGNATURE_SERIALIZED_SIZE],
+    /// Signature recovery id
+    pub recovery_id: u8,
+    /// Signed message
+    pub message: Vec<u8>,
+}
+
+impl Attestation {
+    /// Secp256k1 instruction carrying the attestation, expected to be placed
+    /// in the transaction at `instruction_index`
+    pub fn instruction(&self, instruction_index: u8) -> Instruction {
+        secp256k1_instruction_from_signature(
+            &self.signer,
+            &self.signature,
+            self.recovery_id,
+            &self.message,
+            instruction_index,
+        )
+    }
+}
+
+impl AttestationEnvelope {
+    /// Signs the transfer as a sender, or as the bot oracle if `secret` belongs to it
+    pub fn sign(
+        transfer: &Transfer,
+        bot_oracle: EthereumAddress,
+        secret: &secp256k1::SecretKey,
+    ) -> Self {
+        let signer = construct_eth_pubkey(&secp256k1::PublicKey::from_secret_key(secret));
+        let message = attested_message(transfer, bot_oracle, signer);
+        let hash = message_hash(&message);
+        let (signature, recovery_id) = secp256k1::sign(&secp256k1::Message::parse(&hash), secret);
+
+        Self {
+            version: ENVELOPE_VERSION,
+            transfer: TransferSpec {
+                id: transfer.id.clone(),
+                amount: transfer.amount.to_string(),
+                eth_recipient: to_hex(&transfer.eth_recipient),
+                message_version: match transfer.message_version {
+                    MessageVersion::V1 => 1,
+                    MessageVersion::V2 => 2,
+                },
+            },
+            bot_oracle: to_hex(&bot_oracle),
+            signer: to_hex(&signer),
+            signature: to_hex(
+                &[signature.serialize().as_ref(), &[recovery_id.serialize()]].concat(),
+            ),
+            message_hash: to_hex(&hash),
+        }
+    }
+
+    /// Parses and validates the envelope
+    pub fn from_json(json: &str) -> Result<Attestation, EnvelopeError> {
+        serde_json::from_str::<Self>(json)?.verify()
+    }
+
+    /// Serializes the envelope
+    pub fn to_json(&self) -> Result<String, EnvelopeError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Checks the version, field formats, message hash and signature
+    pub fn verify(&self) -> Result<Attestation, EnvelopeError> {
+        if self.version != ENVELOPE_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(self.version));
+        }
+
+        // NOTE: `u64` parsing accepts a leading `+`, only plain digits are canonical
+        if !self
+            .transfer
+            .amount
+            .bytes()
+            .all(|byte| byte.is_ascii_digit())
+        {
+            return Err(EnvelopeError::InvalidAmount);
+        }
+        let transfer = Transfer {
+            amount: self
+                .transfer
+                .amount
+                .parse()
+                .map_err(|_| EnvelopeError::InvalidAmount)?,
+            id: self.transfer.id.clone(),
+            eth_recipient: from_hex(&self.transfer.eth_recipient, "transfer.ethRecipient")?,
+            message_version: match self.transfer.message_version {
+                1 => MessageVersion::V1,
+                2 => MessageVersion::V2,
+                version => return Err(EnvelopeError::UnsupportedMessageVersion(version)),
+            },
+            create_recipient: false,
+            memo: false,
+        };
+        let bot_oracle = from_hex(&self.bot_oracle, "botOracle")?;
+        let signer = from_hex(&self.signer, "signer")?;
+        let signature: [u8; SIGNATURE_SERIALIZED_SIZE + 1] =
+            from_hex(&self.signature, "signature")?;
+        let hash: [u8; 32] = from_hex(&self.message_hash, "messageHash")?;
+
+        let message = attested_message(&transfer, bot_oracle, signer);
+        if message_hash(&message) != hash {
+            return Err(EnvelopeError::MessageHashMismatch);
+        }
+
+        let (signature, recovery_id) = signature.split_at(SIGNATURE_SERIALIZED_SIZE);
+        let signature: [u8; SIGNATURE_SERIALIZED_SIZE] = signature.try_into().unwrap();
+        let recovered = secp256k1::recover(
+            &secp256k1::Message::parse(&hash),
+            &secp256k1::Signature::parse(&signature),
+            &secp256k1::RecoveryId::parse(recovery_id[0])
+                .map_err(|_| EnvelopeError::InvalidSignature)?,
+        )
+        .map_err(|_| EnvelopeError::InvalidSignature)?;
+        if construct_eth_pubkey(&recovered) != signer {
+            return Err(EnvelopeError::InvalidSignature);
+        }
+
+        Ok(Attestation {
+            transfer,
+            bot_oracle,
+            signer,
+            signature,
+            recovery_id: recovery_id[0],
+            message,
+        })
+    }
+}
+
+/// Message `signer` must sign to attest the transfer
+fn attested_message(
+    transfer: &Transfer,
+    bot_oracle: EthereumAddress,
+    signer: EthereumAddress,
+) -> Vec<u8> {
+    if signer == bot_oracle {
+        build_oracle_message(transfer)
+    } else {
+        build_senders_message(transfer, &bot_oracle)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn from_hex<T>(value: &str, field: &'static str) -> Result<T, EnvelopeError>
+where
+    T: for<'a> TryFrom<&'a [u8]>,
+{
+    value
+        .strip_prefix("0x")
+        .and_then(|value| hex::decode(value).ok())
+        .and_then(|bytes| T::try_from(bytes.as_slice()).ok())
+        .ok_or(EnvelopeError::InvalidHex(field))
+}
@@ -0,0 +1,324 @@
+//! Transfer transactions sized to the packet limit and carrying compute budget requests
+
+use crate::{
+    rpc::Error,
+    secp::{new_secp256k1_instruction_2_0, recoverable_signature, sign_message_at},
+};
+use audius_reward_manager::{
+    instruction::{
+        memo_account, recipient_creation_accounts, transfer, transfer_with_signatures,
+        Instructions, Transfer, TransferWithSignatures,
+    },
+    state::RewardManager,
+    utils::{build_oracle_message, build_senders_message, MAX_RECOVERED_SIGNATURES},
+};
+use claimable_tokens::utils::program::get_address_pair as get_claimable_address;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{
+    packet::PACKET_DATA_SIZE, secp256k1_instruction::construct_eth_pubkey, transaction::Transaction,
+};
+
+/// Compute budget program instructions.
+///
+/// solana-sdk 1.7 predates the program, so the instructions are encoded here the
+/// way the program decodes them. Clusters without the program reject
+/// transactions carrying them.
+pub mod compute_budget {
+    use solana_program::instruction::Instruction;
+
+    solana_program::declare_id!("ComputeBudget111111111111111111111111111111");
+
+    /// Sets the compute units the transaction may consume
+    pub fn set_compute_unit_limit(units: u32) -> Instruction {
+        let mut data = vec![2];
+        data.extend_from_slice(&units.to_le_bytes());
+        Instruction {
+            program_id: id(),
+            accounts: vec![],
+            data,
+        }
+    }
+
+    /// Sets the price in micro-lamports of every requested compute unit
+    pub fn set_compute_unit_price(micro_lamports: u64) -> Instruction {
+        let mut data = vec![3];
+        data.extend_from_slice(&micro_lamports.to_le_bytes());
+        Instruction {
+            program_id: id(),
+            accounts: vec![],
+            data,
+        }
+    }
+}
+
+/// Builds the transactions disbursing a transfer attested by the bot oracle and senders.
+///
+/// The transfer reads the secp256k1 instructions of its own transaction, so the
+/// attestations can't be spread over several transactions. When they don't fit
+/// in a packet next to the transfer, recipient creation moves into a preceding
+/// transaction of its own, and then the attestations are passed as recovered
+/// signatures through `TransferWithSignatures`.
+pub struct TransferFlowBuilder {
+    reward_manager: Pubkey,
+    vault: Pubkey,
+    fee_account: Pubkey,
+    claimable_tokens_program: Pubkey,
+    mint: Pubkey,
+    bot_oracle: Pubkey,
+    bot_oracle_secret: secp256k1::SecretKey,
+    senders: Vec<(Pubkey, secp256k1::SecretKey)>,
+    funder: Pubkey,
+    fee_payer: Pubkey,
+    transfer: Transfer,
+    compute_budget: bool,
+    compute_unit_price: Option<u64>,
+}
+
+impl TransferFlowBuilder {
+    /// Creates new `TransferFlowBuilder` for the reward manager vault of `mint`,
+    /// transactions are paid by the funder unless `fee_payer` is set
+    pub fn new(
+        reward_manager: Pubkey,
+        reward_manager_data: &RewardManager,
+        mint: Pubkey,
+        bot_oracle: Pubkey,
+        bot_oracle_secret: secp256k1::SecretKey,
+        funder: Pubkey,
+        transfer: Transfer,
+    ) -> Self {
+        Self {
+            reward_manager,
+            vault: reward_manager_data.token_account,
+            fee_account: reward_manager_data.fee_account,
+            claimable_tokens_program: reward_manager_data.claimable_tokens_program,
+            mint,
+            bot_oracle,
+            bot_oracle_secret,
+            senders: Vec::new(),
+            funder,
+            fee_payer: funder,
+            transfer,
+            compute_budget: true,
+            compute_unit_price: None,
+        }
+    }
+
+    /// Senders attesting the transfer along with the bot oracle
+    pub fn senders(mut self, senders: Vec<(Pubkey, secp256k1::SecretKey)>) -> Self {
+        self.senders = senders;
+        self
+    }
+
+    /// Whether the recipient token account is missing and has to be created
+    pub fn create_recipient(mut self, create_recipient: bool) -> Self {
+        self.transfer.create_recipient = create_recipient;
+        self
+    }
+
+    /// Account paying the transaction fees, counted in the transactions size
+    pub fn fee_payer(mut self, fee_payer: Pubkey) -> Self {
+        self.fee_payer = fee_payer;
+        self
+    }
+
+    /// Price in micro-lamports of every compute unit requested
+    pub fn compute_unit_price(mut self, micro_lamports: u64) -> Self {
+        self.compute_unit_price = Some(micro_lamports);
+        self
+    }
+
+    /// Leaves out the compute budget instructions, for clusters without the program
+    pub fn without_compute_budget(mut self) -> Self {
+        self.compute_budget = false;
+        self
+    }
+
+    /// Instructions of every transaction to send, in order
+    pub fn build(&self) -> Result<Vec<Vec<Instruction>>, Error> {
+        let create_recipient = self.transfer.create_recipient;
+        if let Some(transactions) = self.fit(vec![self.secp_transfer(create_recipient)?]) {
+            return Ok(transactions);
+        }
+
+        // NOTE: recipient creation isn't attested, so it can precede the transfer
+        let mut transactions = Vec::new();
+        if create_recipient {
+            transactions.push(self.recipient_creation()?);
+            let mut split = transactions.clone();
+            split.push(self.secp_transfer(false)?);
+            if let Some(transactions) = self.fit(split) {
+                return Ok(transactions);
+            }
+        }
+
+        // NOTE: the bot oracle signature takes one of the slots
+        if self.senders.len() < MAX_RECOVERED_SIGNATURES {
+            transactions.push(self.recovered_transfer()?);
+            if let Some(transactions) = self.fit(transactions) {
+                return Ok(transactions);
+            }
+        }
+
+        Err("transfer attestations don't fit in a transaction".into())
+    }
+
+    fn fit(&self, transactions: Vec<Vec<Instruction>>) -> Option<Vec<Vec<Instruction>>> {
+        let fits = transactions.iter().all(|instructions| {
+            let transaction = Transaction::new_with_payer(instructions, Some(&self.fee_payer));
+            bincode::serialized_size(&transaction)
+                .map(|size| size as usize <= PACKET_DATA_SIZE)
+                .unwrap_or(false)
+        });
+        if fits {
+            Some(transactions)
+        } else {
+            None
+        }
+    }
+
+    /// Compute budget requests placed at the beginning of the transaction
+    fn compute_budget_instructions(&self, units: Option<u32>) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+        if !self.compute_budget {
+            return instructions;
+        }
+        if let Some(units) = units {
+            instructions.push(compute_budget::set_compute_unit_limit(units));
+        }
+        if let Some(micro_lamports) = self.compute_unit_price {
+            instructions.push(compute_budget::set_compute_unit_price(micro_lamports));
+        }
+        instructions
+    }
+
+    fn recipient(&self) -> Result<Pubkey, Error> {
+        let recipient = get_claimable_address(
+            &self.claimable_tokens_program,
+            &self.mint,
+            self.transfer.eth_recipient,
+        )?;
+        Ok(recipient.derive.address)
+    }
+
+    fn recipient_creation(&self) -> Result<Vec<Instruction>, Error> {
+        let mut instructions = self.compute_budget_instructions(None);
+        instructions.push(claimable_tokens::instruction::init(
+            &self.claimable_tokens_program,
+            &self.funder,
+            &self.mint,
+            claimable_tokens::instruction::CreateTokenAccount {
+                eth_address: self.transfer.eth_recipient,
+            },
+        )?);
+        Ok(instructions)
+    }
+
+    /// Appends the optional accounts requested by the transfer
+    fn append_optional_accounts(
+        &self,
+        instruction: &mut Instruction,
+        transfer_data: &Transfer,
+    ) -> Result<(), Error> {
+        if transfer_data.create_recipient {
+            instruction
+                .accounts
+                .append(&mut recipient_creation_accounts(
+                    &self.claimable_tokens_program,
+                    &self.mint,
+                    transfer_data.eth_recipient,
+                )?);
+        }
+        if transfer_data.memo {
+            instruction.accounts.push(memo_account());
+        }
+        Ok(())
+    }
+
+    /// `Transfer` preceded by the secp256k1 instructions of the attestations
+    fn secp_transfer(&self, create_recipient: bool) -> Result<Vec<Instruction>, Error> {
+        let mut transfer_data = self.transfer.clone();
+        transfer_data.create_recipient = create_recipient;
+
+        let units = Instructions::Transfer(transfer_data.clone())
+            .recommended_compute_units(self.senders.len());
+        let mut instructions = self.compute_budget_instructions(Some(units));
+
+        let bot_oracle_eth_address = construct_eth_pubkey(&secp256k1::PublicKey::from_secret_key(
+            &self.bot_oracle_secret,
+        ));
+        let first_index = instructions.len() as u8;
+        let senders_count = self.senders.len() as u8;
+        instructions.append(&mut sign_message_at(
+            build_senders_message(&transfer_data, &bot_oracle_eth_address).as_ref(),
+            self.senders
+                .iter()
+                .map(|(_, secret)| secret.clone())
+                .collect(),
+            first_index,
+        ));
+        instructions.push(new_secp256k1_instruction_2_0(
+            &self.bot_oracle_secret,
+            build_oracle_message(&transfer_data).as_ref(),
+            first_index + senders_count,
+        ));
+
+        let mut transfer_instruction = transfer(
+            &audius_reward_manager::id(),
+            &self.reward_manager,
+            &self.recipient()?,
+            &self.vault,
+            &self.bot_oracle,
+            &self.funder,
+            &self.fee_account,
+            self.senders.iter().map(|(sender, _)| *sender),
+            transfer_data.clone(),
+        )?;
+        self.append_optional_accounts(&mut transfer_instruction, &transfer_data)?;
+        instructions.push(transfer_instruction);
+
+        Ok(instructions)
+    }
+
+    /// `TransferWithSignatures` recovering the attestations itself,
+    /// the recipient is expected to exist
+    fn recovered_transfer(&self) -> Result<Vec<Instruction>, Error> {
+        let mut transfer_data = self.transfer.clone();
+        transfer_data.create_recipient = false;
+
+        let bot_oracle_signature = recoverable_signature(
+            &self.bot_oracle_secret,
+            build_oracle_message(&transfer_data).as_ref(),
+        );
+        let senders_message = build_senders_message(&transfer_data, &bot_oracle_signature.signer);
+        let mut signatures = vec![bot_oracle_signature];
+        signatures.extend(
+            self.senders
+                .iter()
+                .map(|(_, secret)| recoverable_signature(secret, senders_message.as_ref())),
+        );
+        let params = TransferWithSignatures {
+            transfer: transfer_data.clone(),
+            signatures,
+        };
+
+        let units = Instructions::TransferWithSignatures(params.clone())
+            .recommended_compute_units(self.senders.len());
+        let mut instructions = self.compute_budget_instructions(Some(units));
+
+        let mut transfer_instruction = transfer_with_signatures(
+            &audius_reward_manager::id(),
+            &self.reward_manager,
+            &self.recipient()?,
+            &self.vault,
+            &self.bot_oracle,
+            &self.funder,
+            &self.fee_account,
+            self.senders.iter().map(|(sender, _)| *sender),
+            params,
+        )?;
+        self.append_optional_accounts(&mut transfer_instruction, &transfer_data)?;
+        instructions.push(transfer_instruction);
+
+        Ok(instructions)
+    }
+}
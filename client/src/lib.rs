@@ -0,0 +1,15 @@
+#![deny(missing_docs)]
+
+//! Audius Reward Manager client helpers
+
+pub mod audit;
+pub mod cost;
+pub mod distribution;
+pub mod envelope;
+pub mod flow;
+pub mod nonce;
+pub mod rpc;
+pub mod secp;
+
+// Export the program crate for downstream users building transactions
+pub use audius_reward_manager;
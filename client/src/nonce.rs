@@ -0,0 +1,101 @@
+//! Admin transactions built against durable nonce accounts, so that offline
+//! manager keys can sign them with no blockhash expiry
+
+use crate::rpc::Error;
+use audius_reward_manager::{
+    instruction::{create_sender, update_reward_manager, UpdateRewardManager},
+    utils::EthereumAddress,
+};
+use solana_program::{hash::Hash, instruction::Instruction, pubkey::Pubkey, system_instruction};
+use solana_sdk::{
+    nonce::state::{State, Versions},
+    transaction::Transaction,
+};
+
+/// Nonce stored in the durable nonce account data, used in place of the recent blockhash
+pub fn nonce_hash(nonce_account_data: &[u8]) -> Result<Hash, Error> {
+    let versions: Versions = bincode::deserialize(nonce_account_data)?;
+    match versions.convert_to_current() {
+        State::Initialized(data) => Ok(data.blockhash),
+        State::Uninitialized => Err("nonce account is not initialized".into()),
+    }
+}
+
+/// Builds admin transactions advancing a durable nonce account first.
+///
+/// The built transaction is unsigned: the manager signs it offline with
+/// `Transaction::partial_sign` and the fee payer completes it before sending,
+/// for as long as the nonce isn't advanced by another transaction.
+pub struct NonceTxBuilder {
+    nonce_account: Pubkey,
+    nonce_authority: Pubkey,
+    fee_payer: Pubkey,
+    instructions: Vec<Instruction>,
+}
+
+impl NonceTxBuilder {
+    /// Creates new `NonceTxBuilder`, `nonce_authority` signs the transaction as well
+    pub fn new(nonce_account: Pubkey, nonce_authority: Pubkey, fee_payer: Pubkey) -> Self {
+        Self {
+            nonce_account,
+            nonce_authority,
+            fee_payer,
+            instructions: Vec::new(),
+        }
+    }
+
+    /// Registers new sender by the manager
+    pub fn create_sender(
+        mut self,
+        reward_manager: &Pubkey,
+        manager: &Pubkey,
+        funder: &Pubkey,
+        eth_address: EthereumAddress,
+        operator: EthereumAddress,
+    ) -> Result<Self, Error> {
+        self.instructions.push(create_sender(
+            &audius_reward_manager::id(),
+            reward_manager,
+            manager,
+            funder,
+            eth_address,
+            operator,
+        )?);
+        Ok(self)
+    }
+
+    /// Updates the reward manager settings by the manager
+    pub fn update_reward_manager(
+        mut self,
+        reward_manager: &Pubkey,
+        manager: &Pubkey,
+        params: UpdateRewardManager,
+    ) -> Result<Self, Error> {
+        self.instructions.push(update_reward_manager(
+            &audius_reward_manager::id(),
+            reward_manager,
+            manager,
+            params,
+        )?);
+        Ok(self)
+    }
+
+    /// Appends any other instruction
+    pub fn instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Unsigned transaction using `nonce`, the current `nonce_hash` of the nonce account
+    pub fn build(&self, nonce: Hash) -> Transaction {
+        let mut instructions = vec![system_instruction::advance_nonce_account(
+            &self.nonce_account,
+            &self.nonce_authority,
+        )];
+        instructions.extend(self.instructions.iter().cloned());
+
+        let mut transaction = Transaction::new_with_payer(&instructions, Some(&self.fee_payer));
+        transaction.message.recent_blockhash = nonce;
+        transaction
+    }
+}
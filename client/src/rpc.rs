@@ -0,0 +1,345 @@
+//! Transaction flows backed by the RPC client
+
+use crate::{
+    flow::TransferFlowBuilder,
+    nonce::nonce_hash,
+    secp::{new_secp256k1_instruction_2_0, sign_message_at},
+};
+use audius_reward_manager::{
+    instruction::{create_oracle, create_sender, init_v2, memo_account, transfer, Transfer},
+    processor::{EVENT_LOG_SEED_PREFIX, ORACLE_SEED_PREFIX},
+    state::{
+        EventLog, EventLogEntry, OperatorAccount, OracleAccount, RewardManager, SenderAccount,
+    },
+    utils::{
+        build_oracle_message, build_senders_message, get_address_pair, get_legacy_sender_address,
+        get_operator_address, get_sender_address, EthereumAddress,
+    },
+};
+use borsh::BorshDeserialize;
+use claimable_tokens::utils::program::get_address_pair as get_claimable_address;
+use solana_client::rpc_client::RpcClient;
+use solana_program::{hash::Hash, instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use spl_token::state::Account as TokenAccount;
+
+/// Error returned by the client flows
+pub type Error = Box<dyn std::error::Error>;
+
+/// Address of the sender account registered for `eth_address`
+pub fn sender_address(
+    reward_manager: &Pubkey,
+    eth_address: EthereumAddress,
+) -> Result<Pubkey, Error> {
+    let (address, _) =
+        get_sender_address(&audius_reward_manager::id(), reward_manager, &eth_address);
+    Ok(address)
+}
+
+/// Address of the bot oracle account registered for `eth_address`
+pub fn oracle_address(
+    reward_manager: &Pubkey,
+    eth_address: EthereumAddress,
+) -> Result<Pubkey, Error> {
+    let pair = get_address_pair(
+        &audius_reward_manager::id(),
+        reward_manager,
+        [ORACLE_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+    )?;
+    Ok(pair.derive.address)
+}
+
+/// Builds the program instruction sets using the on-chain state.
+///
+/// Instructions are returned unsigned, the caller decides on the fee payer
+/// and collects the signatures.
+pub struct RewardManagerClient<'a> {
+    rpc_client: &'a RpcClient,
+}
+
+impl<'a> RewardManagerClient<'a> {
+    /// Creates new `RewardManagerClient`
+    pub fn new(rpc_client: &'a RpcClient) -> Self {
+        Self { rpc_client }
+    }
+
+    /// Fetches reward manager state
+    pub fn get_reward_manager(&self, reward_manager: &Pubkey) -> Result<RewardManager, Error> {
+        let data = self.rpc_client.get_account_data(reward_manager)?;
+        Ok(RewardManager::unpack(data.as_slice())?)
+    }
+
+    /// Fetches the nonce of a durable nonce account, see `NonceTxBuilder`
+    pub fn get_nonce(&self, nonce_account: &Pubkey) -> Result<Hash, Error> {
+        let data = self.rpc_client.get_account_data(nonce_account)?;
+        nonce_hash(data.as_slice())
+    }
+
+    /// Fetches sender state
+    pub fn get_sender(&self, sender: &Pubkey) -> Result<SenderAccount, Error> {
+        let data = self.rpc_client.get_account_data(sender)?;
+        Ok(SenderAccount::unpack(data.as_slice())?)
+    }
+
+    /// Address `eth_address` is registered at, the legacy one if the sender
+    /// was created with seed and not recreated since
+    pub fn resolve_sender(
+        &self,
+        reward_manager: &Pubkey,
+        eth_address: EthereumAddress,
+    ) -> Result<Pubkey, Error> {
+        let address = sender_address(reward_manager, eth_address)?;
+        if self.rpc_client.get_account_data(&address).is_ok() {
+            return Ok(address);
+        }
+
+        let legacy_address =
+            get_legacy_sender_address(&audius_reward_manager::id(), reward_manager, &eth_address)?;
+        if self.rpc_client.get_account_data(&legacy_address).is_ok() {
+            return Ok(legacy_address);
+        }
+
+        Ok(address)
+    }
+
+    /// Fetches the senders registered for `operator`, empty while the operator
+    /// has no account
+    pub fn get_operator_senders(
+        &self,
+        reward_manager: &Pubkey,
+        operator: EthereumAddress,
+    ) -> Result<Vec<EthereumAddress>, Error> {
+        let (address, _) =
+            get_operator_address(&audius_reward_manager::id(), reward_manager, &operator);
+        let data = match self.rpc_client.get_account_data(&address) {
+            Ok(data) => data,
+            Err(_) => return Ok(Vec::new()),
+        };
+        Ok(OperatorAccount::try_from_slice(data.as_slice())?
+            .senders()
+            .to_vec())
+    }
+
+    /// Fetches bot oracle state
+    pub fn get_oracle(&self, oracle: &Pubkey) -> Result<OracleAccount, Error> {
+        let data = self.rpc_client.get_account_data(oracle)?;
+        Ok(OracleAccount::try_from_slice(data.as_slice())?)
+    }
+
+    /// Fetches the transfer evaluations recorded in the event log, the oldest first,
+    /// empty while the event log isn't created
+    pub fn get_recent_transfers(
+        &self,
+        reward_manager: &Pubkey,
+    ) -> Result<Vec<EventLogEntry>, Error> {
+        let pair = get_address_pair(
+            &audius_reward_manager::id(),
+            reward_manager,
+            EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
+        )?;
+        let data = match self.rpc_client.get_account_data(&pair.derive.address) {
+            Ok(data) => data,
+            Err(_) => return Ok(Vec::new()),
+        };
+        Ok(EventLog::try_from_slice(data.as_slice())?.entries())
+    }
+
+    /// Instruction creating reward manager and its token account and initializing them
+    /// with the default token and claimable tokens programs.
+    /// Both `reward_manager` and `token_account` must sign the transaction
+    pub fn init_reward_manager(
+        &self,
+        payer: &Pubkey,
+        reward_manager: &Pubkey,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        manager: &Pubkey,
+        min_votes: u8,
+    ) -> Result<Vec<Instruction>, Error> {
+        Ok(vec![init_v2(
+            &audius_reward_manager::id(),
+            reward_manager,
+            token_account,
+            mint,
+            manager,
+            payer,
+            &spl_token::id(),
+            &claimable_tokens::id(),
+            min_votes,
+        )?])
+    }
+
+    /// Instructions registering new sender by the manager
+    pub fn create_sender(
+        &self,
+        reward_manager: &Pubkey,
+        manager: &Pubkey,
+        funder: &Pubkey,
+        eth_address: EthereumAddress,
+        operator: EthereumAddress,
+    ) -> Result<Vec<Instruction>, Error> {
+        Ok(vec![create_sender(
+            &audius_reward_manager::id(),
+            reward_manager,
+            manager,
+            funder,
+            eth_address,
+            operator,
+        )?])
+    }
+
+    /// Instructions registering the bot oracle by the manager
+    pub fn create_oracle(
+        &self,
+        reward_manager: &Pubkey,
+        manager: &Pubkey,
+        funder: &Pubkey,
+        eth_address: EthereumAddress,
+        operator: EthereumAddress,
+    ) -> Result<Vec<Instruction>, Error> {
+        Ok(vec![create_oracle(
+            &audius_reward_manager::id(),
+            reward_manager,
+            manager,
+            funder,
+            eth_address,
+            operator,
+        )?])
+    }
+
+    /// Secp256k1 instructions attesting the transfer by senders followed by bot oracle,
+    /// expected to be placed in the transaction starting from `first_index`
+    pub fn submit_attestation(
+        &self,
+        bot_oracle: &Pubkey,
+        bot_oracle_secret: &secp256k1::SecretKey,
+        senders_secrets: Vec<secp256k1::SecretKey>,
+        transfer_data: &Transfer,
+        first_index: u8,
+    ) -> Result<Vec<Instruction>, Error> {
+        let bot_oracle_data = self.get_oracle(bot_oracle)?;
+
+        let senders_count = senders_secrets.len() as u8;
+        let mut instructions = sign_message_at(
+            build_senders_message(transfer_data, &bot_oracle_data.eth_address).as_ref(),
+            senders_secrets,
+            first_index,
+        );
+        instructions.push(new_secp256k1_instruction_2_0(
+            bot_oracle_secret,
+            build_oracle_message(transfer_data).as_ref(),
+            first_index + senders_count,
+        ));
+
+        Ok(instructions)
+    }
+
+    /// Complete transfer instruction set: recipient token account creation
+    /// if it's missing, attestations and the transfer itself
+    pub fn evaluate_transfer(
+        &self,
+        reward_manager: &Pubkey,
+        bot_oracle: &Pubkey,
+        bot_oracle_secret: &secp256k1::SecretKey,
+        senders: Vec<(Pubkey, secp256k1::SecretKey)>,
+        funder: &Pubkey,
+        transfer_data: Transfer,
+    ) -> Result<Vec<Instruction>, Error> {
+        let reward_manager_data = self.get_reward_manager(reward_manager)?;
+        let vault_data = self
+            .rpc_client
+            .get_account_data(&reward_manager_data.token_account)?;
+        let vault_data = TokenAccount::unpack(vault_data.as_slice())?;
+
+        let mut instructions = Vec::new();
+
+        let recipient = get_claimable_address(
+            &reward_manager_data.claimable_tokens_program,
+            &vault_data.mint,
+            transfer_data.eth_recipient,
+        )?;
+        if self
+            .rpc_client
+            .get_account_data(&recipient.derive.address)
+            .is_err()
+        {
+            instructions.push(claimable_tokens::instruction::init(
+                &reward_manager_data.claimable_tokens_program,
+                funder,
+                &vault_data.mint,
+                claimable_tokens::instruction::CreateTokenAccount {
+                    eth_address: transfer_data.eth_recipient,
+                },
+            )?);
+        }
+
+        let (senders, senders_secrets): (Vec<Pubkey>, Vec<secp256k1::SecretKey>) =
+            senders.into_iter().unzip();
+        let mut attestations = self.submit_attestation(
+            bot_oracle,
+            bot_oracle_secret,
+            senders_secrets,
+            &transfer_data,
+            instructions.len() as u8,
+        )?;
+        instructions.append(&mut attestations);
+
+        let memo = transfer_data.memo;
+        let mut transfer_instruction = transfer(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &recipient.derive.address,
+            &reward_manager_data.token_account,
+            bot_oracle,
+            funder,
+            &reward_manager_data.fee_account,
+            senders,
+            transfer_data,
+        )?;
+        if memo {
+            transfer_instruction.accounts.push(memo_account());
+        }
+        instructions.push(transfer_instruction);
+
+        Ok(instructions)
+    }
+
+    /// `TransferFlowBuilder` for the transfer, creating the recipient token
+    /// account if it's missing
+    pub fn transfer_flow(
+        &self,
+        reward_manager: &Pubkey,
+        bot_oracle: &Pubkey,
+        bot_oracle_secret: &secp256k1::SecretKey,
+        senders: Vec<(Pubkey, secp256k1::SecretKey)>,
+        funder: &Pubkey,
+        transfer_data: Transfer,
+    ) -> Result<TransferFlowBuilder, Error> {
+        let reward_manager_data = self.get_reward_manager(reward_manager)?;
+        let vault_data = self
+            .rpc_client
+            .get_account_data(&reward_manager_data.token_account)?;
+        let vault_data = TokenAccount::unpack(vault_data.as_slice())?;
+
+        let recipient = get_claimable_address(
+            &reward_manager_data.claimable_tokens_program,
+            &vault_data.mint,
+            transfer_data.eth_recipient,
+        )?;
+        let create_recipient = self
+            .rpc_client
+            .get_account_data(&recipient.derive.address)
+            .is_err();
+
+        Ok(TransferFlowBuilder::new(
+            *reward_manager,
+            &reward_manager_data,
+            vault_data.mint,
+            *bot_oracle,
+            bot_oracle_secret.clone(),
+            *funder,
+            transfer_data,
+        )
+        .senders(senders)
+        .create_recipient(create_recipient))
+    }
+}
@@ -0,0 +1,139 @@
+//! Secp256k1 program instructions construction
+
+use audius_reward_manager::instruction::RecoverableSignature;
+use sha3::Digest;
+use solana_program::instruction::Instruction;
+use solana_sdk::secp256k1_instruction::{
+    construct_eth_pubkey, SecpSignatureOffsets, DATA_START, HASHED_PUBKEY_SERIALIZED_SIZE,
+    SIGNATURE_SERIALIZED_SIZE,
+};
+
+/// Sign message by every signer, each signature in a separate instruction
+/// expected to be placed at the beginning of the transaction
+pub fn sign_message(message: &[u8], signers: Vec<secp256k1::SecretKey>) -> Vec<Instruction> {
+    sign_message_at(message, signers, 0)
+}
+
+/// Sign message by every signer, each signature in a separate instruction
+/// expected to be placed in the transaction starting from `first_index`
+pub fn sign_message_at(
+    message: &[u8],
+    signers: Vec<secp256k1::SecretKey>,
+    first_index: u8,
+) -> Vec<Instruction> {
+    let mut secp_instructions = Vec::new();
+
+    for (index, signer) in signers.iter().enumerate() {
+        secp_instructions.push(new_secp256k1_instruction_2_0(
+            signer,
+            message,
+            first_index + index as u8,
+        ));
+    }
+
+    secp_instructions
+}
+
+/// Keccak256 hash of the message, the one secp256k1 signatures are made over
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    let mut hasher = sha3::Keccak256::new();
+    hasher.update(message);
+    let mut message_hash = [0u8; 32];
+    message_hash.copy_from_slice(hasher.finalize().as_slice());
+    message_hash
+}
+
+/// Sign message to be passed to `TransferWithSignatures`, which recovers
+/// the signer itself instead of relying on a secp256k1 instruction
+pub fn recoverable_signature(
+    priv_key: &secp256k1::SecretKey,
+    message_arr: &[u8],
+) -> RecoverableSignature {
+    let secp_pubkey = secp256k1::PublicKey::from_secret_key(priv_key);
+    let message = secp256k1::Message::parse(&message_hash(message_arr));
+    let (signature, recovery_id) = secp256k1::sign(&message, priv_key);
+
+    let mut recoverable = [0u8; SIGNATURE_SERIALIZED_SIZE + 1];
+    recoverable[..SIGNATURE_SERIALIZED_SIZE].copy_from_slice(&signature.serialize());
+    recoverable[SIGNATURE_SERIALIZED_SIZE] = recovery_id.serialize();
+
+    RecoverableSignature {
+        signer: construct_eth_pubkey(&secp_pubkey),
+        signature: recoverable,
+    }
+}
+
+/// Create secp256k1 program instruction with a single signature which
+/// offsets point to the instruction placed at `instruction_index`
+pub fn new_secp256k1_instruction_2_0(
+    priv_key: &secp256k1::SecretKey,
+    message_arr: &[u8],
+    instruction_index: u8,
+) -> Instruction {
+    let secp_pubkey = secp256k1::PublicKey::from_secret_key(priv_key);
+    let eth_pubkey = construct_eth_pubkey(&secp_pubkey);
+    let message = secp256k1::Message::parse(&message_hash(message_arr));
+    let (signature, recovery_id) = secp256k1::sign(&message, priv_key);
+
+    secp256k1_instruction_from_signature(
+        &eth_pubkey,
+        &signature.serialize(),
+        recovery_id.serialize(),
+        message_arr,
+        instruction_index,
+    )
+}
+
+/// Create secp256k1 program instruction from a signature made elsewhere,
+/// offsets point to the instruction placed at `instruction_index`
+pub fn secp256k1_instruction_from_signature(
+    eth_pubkey: &[u8; HASHED_PUBKEY_SERIALIZED_SIZE],
+    signature_arr: &[u8; SIGNATURE_SERIALIZED_SIZE],
+    recovery_id: u8,
+    message_arr: &[u8],
+    instruction_index: u8,
+) -> Instruction {
+    let mut instruction_data = vec![];
+    instruction_data.resize(
+        DATA_START
+            .saturating_add(eth_pubkey.len())
+            .saturating_add(signature_arr.len())
+            .saturating_add(message_arr.len())
+            .saturating_add(1),
+        0,
+    );
+    let eth_address_offset = DATA_START;
+    instruction_data[eth_address_offset..eth_address_offset.saturating_add(eth_pubkey.len())]
+        .copy_from_slice(eth_pubkey);
+
+    let signature_offset = DATA_START.saturating_add(eth_pubkey.len());
+    instruction_data[signature_offset..signature_offset.saturating_add(signature_arr.len())]
+        .copy_from_slice(signature_arr);
+
+    instruction_data[signature_offset.saturating_add(signature_arr.len())] = recovery_id;
+
+    let message_data_offset = signature_offset
+        .saturating_add(signature_arr.len())
+        .saturating_add(1);
+    instruction_data[message_data_offset..].copy_from_slice(message_arr);
+
+    let num_signatures = 1;
+    instruction_data[0] = num_signatures;
+    let offsets = SecpSignatureOffsets {
+        signature_offset: signature_offset as u16,
+        signature_instruction_index: instruction_index,
+        eth_address_offset: eth_address_offset as u16,
+        eth_address_instruction_index: instruction_index,
+        message_data_offset: message_data_offset as u16,
+        message_data_size: message_arr.len() as u16,
+        message_instruction_index: instruction_index,
+    };
+    let writer = std::io::Cursor::new(&mut instruction_data[1..DATA_START]);
+    bincode::serialize_into(writer, &offsets).unwrap();
+
+    Instruction {
+        program_id: solana_sdk::secp256k1_program::id(),
+        accounts: vec![],
+        data: instruction_data,
+    }
+}
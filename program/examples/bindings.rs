@@ -0,0 +1,6 @@
+//! Prints the TypeScript bindings, run with
+//! `cargo run --example bindings --features idl > ../bindings/ts/src/index.ts`
+
+fn main() {
+    print!("{}", audius_reward_manager::idl::typescript::bindings());
+}
@@ -0,0 +1,8 @@
+//! Prints the program IDL, run with `cargo run --example idl --features idl`
+
+fn main() {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&audius_reward_manager::idl::idl()).unwrap()
+    );
+}
@@ -11,55 +11,222 @@ use solana_program::{
 use thiserror::Error;
 
 /// Errors that may be returned by the Template program.
+///
+/// Discriminants are part of the program interface and must never change,
+/// new errors are only appended.
 #[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
 pub enum AudiusProgramError {
     /// Signature with an already met principal
     #[error("Signature with an already met principal")]
-    SignCollission,
+    SignCollission = 0,
 
     /// Unexpected signer met
     #[error("Unexpected signer met")]
-    WrongSigner,
+    WrongSigner = 1,
 
     /// Wrong sender account
     #[error("Incorect sender account")]
-    IncorectSenderAccount,
+    IncorectSenderAccount = 2,
 
     /// Wrong manager account
     #[error("Incorect account manager")]
-    IncorectManagerAccount,
+    IncorectManagerAccount = 3,
 
     /// Wrong reward manager key
     #[error("Wrong reward manager key")]
-    WrongRewardManagerKey,
+    WrongRewardManagerKey = 4,
 
     /// Wrong recipient Solana key
     #[error("Wrong recipient Solana key")]
-    WrongRecipientKey,
+    WrongRecipientKey = 5,
 
     /// Isn't enough signers keys
     #[error("Isn't enough signers keys")]
-    NotEnoughSigners,
+    NotEnoughSigners = 6,
 
     /// Secp256 instruction missing
     #[error("Secp256 instruction missing")]
-    Secp256InstructionMissing,
+    Secp256InstructionMissing = 7,
 
     /// Instruction load error
     #[error("Instruction load error")]
-    InstructionLoadError,
+    InstructionLoadError = 8,
 
     /// Repeated senders
     #[error("Repeated sender")]
-    RepeatedSenders,
+    RepeatedSenders = 9,
 
     /// Signature verification failed
     #[error("Signature verification failed")]
-    SignatureVerificationFailed,
+    SignatureVerificationFailed = 10,
 
     /// Some signers have same operators
     #[error("Some signers have same operators")]
-    OperatorCollision,
+    OperatorCollision = 11,
+
+    /// Secp256 instruction offsets don't match the expected layout
+    #[error("Secp256 instruction offsets don't match the expected layout")]
+    InvalidSecpOffsets = 12,
+
+    /// More accounts than the instruction expects
+    #[error("More accounts than the instruction expects")]
+    TooManyAccounts = 13,
+
+    /// More attestations than the reward manager allows to evaluate
+    #[error("More attestations than the reward manager allows to evaluate")]
+    TooManyAttestations = 14,
+
+    /// Reward manager is paused
+    #[error("Reward manager is paused")]
+    ProgramPaused = 15,
+
+    /// Transfer with the same ID was already disbursed
+    #[error("Transfer with the same ID was already disbursed")]
+    AlreadyClaimed = 16,
+
+    /// Instruction invoked through another program
+    #[error("Instruction must not be invoked through another program")]
+    CrossProgramInvocation = 17,
+
+    /// Fewer senders registered than votes required
+    #[error("Fewer senders registered than votes required")]
+    NotEnoughRegisteredSenders = 18,
+
+    /// Transfer amount is above the reward manager cap
+    #[error("Transfer amount is above the reward manager cap")]
+    AmountExceedsCap = 19,
+
+    /// Sender created too soon after the previous one
+    #[error("Sender created too soon after the previous one")]
+    SenderCreationRateLimited = 20,
+
+    /// Transfer exceeds the spending budget of the current window
+    #[error("Transfer exceeds the spending budget of the current window")]
+    SpendLimitExceeded = 21,
+
+    /// Merkle proof doesn't lead to the distribution root
+    #[error("Merkle proof doesn't lead to the distribution root")]
+    InvalidMerkleProof = 22,
+
+    /// Sender is already registered under its legacy address
+    #[error("Sender is already registered under its legacy address")]
+    SenderAlreadyExists = 23,
+
+    /// Vault would fall below the balance floor
+    #[error("Vault would fall below the balance floor")]
+    InsufficientFunding = 24,
+
+    /// Delegate lacks the permission for the instruction
+    #[error("Delegate lacks the permission for the instruction")]
+    MissingPermission = 25,
+
+    /// No manager change is pending
+    #[error("No manager change is pending")]
+    NoPendingManager = 26,
+
+    /// Proposed manager accepted before the timelock expired
+    #[error("Proposed manager accepted before the timelock expired")]
+    ManagerChangeTimelocked = 27,
+
+    /// Transfer attested with a message version the reward manager no longer accepts
+    #[error("Transfer attested with a message version the reward manager no longer accepts")]
+    DeprecatedMessageVersion = 28,
+
+    /// Admin action approved by fewer multisig keys than the threshold
+    #[error("Admin action approved by fewer multisig keys than the threshold")]
+    NotEnoughApprovals = 29,
+
+    /// Attesting senders weigh less than the required vote weight
+    #[error("Attesting senders weigh less than the required vote weight")]
+    NotEnoughVoteWeight = 30,
+
+    /// Operator registered the maximum number of senders
+    #[error("Operator registered the maximum number of senders")]
+    OperatorSendersLimitReached = 31,
+
+    /// Account data length differs from the expected one
+    #[error("Account data length differs from the expected one")]
+    InvalidAccountSize = 32,
+
+    /// Account balance doesn't cover the rent exemption
+    #[error("Account balance doesn't cover the rent exemption")]
+    NotRentExempt = 33,
+
+    /// Account passed in place of a sysvar isn't that sysvar
+    #[error("Account passed in place of a sysvar isn't that sysvar")]
+    InvalidSysvar = 34,
+
+    /// No treasury withdrawal was announced
+    #[error("No treasury withdrawal was announced")]
+    NoPendingWithdrawal = 35,
+
+    /// Treasury withdrawal delay hasn't passed yet
+    #[error("Treasury withdrawal delay hasn't passed yet")]
+    WithdrawalTimelocked = 36,
+
+    /// Vault token account isn't the reward manager one
+    #[error("Vault token account isn't the reward manager one")]
+    WrongVaultAccount = 37,
+
+    /// Vault still holds tokens of a mint the new token account can't receive
+    #[error("Vault still holds tokens of a mint the new token account can't receive")]
+    VaultNotEmpty = 38,
+
+    /// Sender is suspended by the manager
+    #[error("Sender is suspended by the manager")]
+    SenderSuspended = 39,
+
+    /// Transfer recipient is blocked by the recipient policy
+    #[error("Transfer recipient is blocked by the recipient policy")]
+    RecipientBlocked = 40,
+
+    /// Recipient policy blocks the maximum number of recipients
+    #[error("Recipient policy blocks the maximum number of recipients")]
+    RecipientPolicyFull = 41,
+
+    /// Fee account doesn't match the reward manager one
+    #[error("Fee account doesn't match the reward manager one")]
+    WrongFeeAccount = 42,
+
+    /// Arithmetic overflow
+    #[error("Arithmetic overflow")]
+    MathOverflow = 43,
+
+    /// Transfer ID longer than `MAX_TRANSFER_ID_LEN` bytes
+    #[error("Transfer ID longer than the transfer receipt seed allows")]
+    TransferIdTooLong = 44,
+
+    /// Challenge ID is empty, too long or has a separator, or has too many oracles
+    #[error("Challenge ID or oracles don't fit the challenge registry")]
+    InvalidChallenge = 45,
+
+    /// Challenge registry holds the maximum number of challenges
+    #[error("Challenge registry holds the maximum number of challenges")]
+    ChallengeRegistryFull = 46,
+
+    /// Transfer ID isn't prefixed with a registered challenge ID
+    #[error("Transfer ID isn't prefixed with a registered challenge ID")]
+    UnregisteredChallenge = 47,
+
+    /// Bot oracle isn't authorized to attest transfers of the challenge
+    #[error("Bot oracle isn't authorized to attest transfers of the challenge")]
+    OracleNotAuthorizedForChallenge = 48,
+
+    /// Transfer of zero tokens
+    #[error("Transfer amount is zero")]
+    ZeroAmount = 49,
+
+    /// Transfer recipient is the vault it is paid from
+    #[error("Transfer recipient is the reward manager vault")]
+    InvalidDestination = 50,
+
+    /// Senders of an operator attested the maximum number of transfers within the window
+    #[error("Operator reached its attestations limit within the window")]
+    OperatorRateLimited = 51,
+
+    /// Rate limiter tracks the maximum number of operators within the window
+    #[error("Rate limiter tracks the maximum number of operators")]
+    RateLimiterFull = 52,
 }
 impl From<AudiusProgramError> for ProgramError {
     fn from(e: AudiusProgramError) -> Self {
@@ -81,6 +248,12 @@ impl PrintProgramError for AudiusProgramError {
     }
 }
 
+/// Decodes the code of a `ProgramError::Custom` returned by the program,
+/// `None` for codes the program doesn't define
+pub fn decode_custom_error(code: u32) -> Option<AudiusProgramError> {
+    AudiusProgramError::from_u32(code)
+}
+
 /// Convert SanitizeError to AudiusProgramError
 pub fn to_audius_program_error(_e: SanitizeError) -> AudiusProgramError {
     AudiusProgramError::InstructionLoadError
@@ -0,0 +1,167 @@
+//! Structured events logged for indexers
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{msg, pubkey::Pubkey};
+
+use crate::{state::SenderStatus, utils::EthereumAddress};
+
+/// Prefix of the program log lines carrying an event
+pub const EVENT_LOG_PREFIX: &str = "Event: ";
+
+/// Event logged as the Borsh serialized enum, base58 encoded
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub enum Event {
+    /// Tokens were sent to the recipient
+    TransferEvaluated {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Transfer ID
+        id: String,
+        /// Recipient Ethereum address
+        eth_recipient: EthereumAddress,
+        /// Transferred amount
+        amount: u64,
+    },
+    /// Sender was registered
+    SenderCreated {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Ethereum address
+        eth_address: EthereumAddress,
+        /// Sender operator
+        operator: EthereumAddress,
+    },
+    /// Sender was removed
+    SenderDeleted {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Ethereum address
+        eth_address: EthereumAddress,
+    },
+    /// Sender moved to a new Ethereum address
+    SenderRotated {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Previous Ethereum address
+        eth_address: EthereumAddress,
+        /// New Ethereum address
+        new_eth_address: EthereumAddress,
+    },
+    /// Tokens were sent to the associated token account of the wallet
+    WalletTransferEvaluated {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Transfer ID
+        id: String,
+        /// Recipient wallet
+        wallet: Pubkey,
+        /// Transferred amount
+        amount: u64,
+    },
+    /// Manager announced a withdrawal of unspent tokens from the vault
+    TreasuryWithdrawalAnnounced {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Destination token account
+        destination: Pubkey,
+        /// Announced amount, zero if the pending withdrawal was cancelled
+        amount: u64,
+        /// Slot the withdrawal may execute from
+        unlock_slot: u64,
+    },
+    /// Unspent tokens were withdrawn from the vault
+    TreasuryWithdrawn {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Destination token account
+        destination: Pubkey,
+        /// Withdrawn amount
+        amount: u64,
+    },
+    /// Reward manager moved to a new vault token account
+    TokenAccountMigrated {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// New vault token account
+        token_account: Pubkey,
+        /// Mint of the new vault token account
+        mint: Pubkey,
+        /// Amount moved from the previous vault
+        amount: u64,
+    },
+    /// Sender was suspended or reinstated by the manager
+    SenderStatusChanged {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Ethereum address
+        eth_address: EthereumAddress,
+        /// New status
+        status: SenderStatus,
+    },
+    /// Recipient was blocked or unblocked by the manager
+    RecipientPolicyChanged {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Ethereum recipient
+        eth_recipient: EthereumAddress,
+        /// Whether transfers to the recipient are rejected
+        blocked: bool,
+    },
+    /// Transfer fee was changed by the manager
+    TransferFeeChanged {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Basis points of every transfer amount sent to the fee account
+        fee_bps: u16,
+        /// Token account receiving transfer fees
+        fee_account: Pubkey,
+    },
+    /// Part of the transfer was sent to the fee account
+    TransferFeeCharged {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Transfer ID
+        id: String,
+        /// Token account receiving transfer fees
+        fee_account: Pubkey,
+        /// Fee amount, excluded from the amount sent to the recipient
+        fee: u64,
+    },
+    /// Challenge was registered, updated or removed by the manager
+    ChallengeChanged {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Challenge ID
+        id: String,
+        /// Bot oracles authorized to attest transfers of the challenge, empty
+        /// when removed
+        oracles: Vec<EthereumAddress>,
+    },
+    /// Sender updated the hash of its service endpoint
+    SenderMetadataUpdated {
+        /// Reward manager
+        reward_manager: Pubkey,
+        /// Ethereum address
+        eth_address: EthereumAddress,
+        /// Keccak256 hash of the endpoint URL
+        endpoint_hash: [u8; 32],
+    },
+}
+
+impl Event {
+    /// Writes the event to the program log
+    pub fn emit(&self) {
+        // NOTE: serialization into a vector can't fail
+        let data = self.try_to_vec().unwrap();
+        msg!("{}{}", EVENT_LOG_PREFIX, bs58::encode(data).into_string());
+    }
+
+    /// Parses the event from a program log message, `None` if it's not an event
+    pub fn from_log(message: &str) -> Option<Self> {
+        let data = message
+            .trim_start_matches("Program log: ")
+            .strip_prefix(EVENT_LOG_PREFIX)?;
+        let data = bs58::decode(data).into_vec().ok()?;
+        Self::try_from_slice(&data).ok()
+    }
+}
@@ -0,0 +1,654 @@
+//! JSON IDL of the program in the Anchor layout, compiled in with the `idl` feature.
+//!
+//! Instruction arguments and account layouts are read from the `BorshSchema` of
+//! the Rust types, only the accounts ordering of every instruction is listed here.
+
+use crate::{
+    error::AudiusProgramError,
+    instruction::Instructions,
+    state::{
+        AdminAction, ChallengeRegistry, DelegateAccount, DistributionRoot, EventLog,
+        ManagerMultisig, OperatorAccount, OracleAccount, RateLimiter, RecipientPolicy,
+        RewardManager, SenderAccount, SpendCounter, TransferReceipt,
+    },
+};
+use borsh::{
+    schema::{Declaration, Definition, Fields},
+    BorshSchema,
+};
+use num_traits::FromPrimitive;
+use serde_json::{json, Value};
+use std::collections::{BTreeSet, HashMap};
+
+pub mod typescript;
+
+/// Instruction account: name, writable, signer
+type IdlAccount = (&'static str, bool, bool);
+
+const DELEGATE: &str = "Optional delegate account, when signed by the delegate";
+const ATTESTERS: &str =
+    "Bot oracles voting in addition to the first one, `min_oracle_votes - 1`, then senders";
+const ATTESTERS_THEN_RECIPIENT_CREATION: &str =
+    "Bot oracles voting in addition to the first one, `min_oracle_votes - 1`, then senders, \
+     then the claimable tokens program, mint, claimable tokens authority and rent sysvar \
+     when `createRecipient` is set, then the SPL Memo program when `memo` is set";
+
+/// Accounts of the instruction followed by the description of the remaining ones
+fn instruction_accounts(instruction: &str) -> (Vec<IdlAccount>, Option<&'static str>) {
+    let reward_manager = ("rewardManager", false, false);
+    let reward_manager_mut = ("rewardManager", true, false);
+    let authority = ("rewardManagerAuthority", false, false);
+    let manager = ("manager", false, true);
+    let funder = ("funder", true, true);
+    let refunder = ("refunder", true, false);
+    let instructions = ("instructionsSysvar", false, false);
+    let rent = ("rent", false, false);
+    let system_program = ("systemProgram", false, false);
+    let token_program = ("tokenProgram", false, false);
+    let recipient_policy = ("recipientPolicy", false, false);
+    let fee_account = ("feeAccount", true, false);
+    let challenge_registry = ("challengeRegistry", false, false);
+    let event_log = ("eventLog", true, false);
+    let rate_limiter = ("rateLimiter", true, false);
+    let transfer_accounts = vec![
+        reward_manager_mut,
+        authority,
+        ("recipient", true, false),
+        ("vaultTokenAccount", true, false),
+        ("botOracle", false, false),
+        funder,
+        ("transferReceipt", true, false),
+        instructions,
+        token_program,
+        system_program,
+        ("spendCounter", true, false),
+    ];
+
+    match instruction {
+        "InitRewardManager" => (
+            vec![
+                reward_manager_mut,
+                ("tokenAccount", true, false),
+                ("mint", false, false),
+                ("manager", false, false),
+                authority,
+                token_program,
+                rent,
+            ],
+            None,
+        ),
+        "CreateSender" => (
+            vec![
+                reward_manager_mut,
+                manager,
+                authority,
+                funder,
+                ("sender", true, false),
+                system_program,
+                rent,
+                ("legacySender", false, false),
+                ("operator", true, false),
+            ],
+            Some(DELEGATE),
+        ),
+        "DeleteSender" => (
+            vec![
+                reward_manager_mut,
+                manager,
+                ("sender", true, false),
+                refunder,
+                system_program,
+                ("operator", true, false),
+            ],
+            Some(DELEGATE),
+        ),
+        "AddSender" => (
+            vec![
+                reward_manager_mut,
+                authority,
+                funder,
+                ("sender", true, false),
+                instructions,
+                rent,
+                system_program,
+                ("legacySender", false, false),
+                ("operator", true, false),
+            ],
+            Some("Senders attesting the new one"),
+        ),
+        "Transfer" => (
+            [
+                transfer_accounts,
+                vec![
+                    recipient_policy,
+                    fee_account,
+                    challenge_registry,
+                    event_log,
+                    rate_limiter,
+                ],
+            ]
+            .concat(),
+            Some(ATTESTERS_THEN_RECIPIENT_CREATION),
+        ),
+        "TransferToWallet" => (
+            [
+                transfer_accounts,
+                vec![fee_account, challenge_registry, event_log, rate_limiter],
+            ]
+            .concat(),
+            Some(ATTESTERS),
+        ),
+        "TransferWithSignatures" => (
+            transfer_accounts
+                .into_iter()
+                .filter(|account| *account != instructions)
+                .chain(vec![
+                    recipient_policy,
+                    fee_account,
+                    challenge_registry,
+                    event_log,
+                    rate_limiter,
+                ])
+                .collect(),
+            Some(ATTESTERS_THEN_RECIPIENT_CREATION),
+        ),
+        "UpdateRewardManager" | "ProposeManager" | "AnnounceTreasuryWithdrawal" => {
+            (vec![reward_manager_mut, manager], None)
+        }
+        "WithdrawTreasury" => (
+            vec![
+                reward_manager_mut,
+                authority,
+                ("vaultTokenAccount", true, false),
+                ("destination", true, false),
+                manager,
+                token_program,
+            ],
+            None,
+        ),
+        "MigrateTokenAccount" => (
+            vec![
+                reward_manager_mut,
+                authority,
+                ("vaultTokenAccount", true, false),
+                ("newTokenAccount", true, false),
+                manager,
+                token_program,
+            ],
+            None,
+        ),
+        "SetTransferFee" => (
+            vec![
+                reward_manager_mut,
+                manager,
+                ("vaultTokenAccount", false, false),
+                ("feeAccount", false, false),
+            ],
+            None,
+        ),
+        "VerifyOnly" => (
+            vec![reward_manager, ("botOracle", false, false), instructions],
+            Some(ATTESTERS),
+        ),
+        "Pause" | "Unpause" => (vec![reward_manager_mut, manager], Some(DELEGATE)),
+        "AcceptManager" => (vec![reward_manager_mut, ("newManager", false, true)], None),
+        "TransferBatch" => (
+            vec![
+                reward_manager_mut,
+                authority,
+                ("vaultTokenAccount", true, false),
+                ("botOracle", false, false),
+                funder,
+                instructions,
+                token_program,
+                system_program,
+                ("spendCounter", true, false),
+                recipient_policy,
+                fee_account,
+                challenge_registry,
+                event_log,
+                rate_limiter,
+            ],
+            Some(
+                "Recipient and transfer receipt of every transfer, then bot oracles voting \
+                in addition to the first one, `min_oracle_votes - 1`, then senders",
+            ),
+        ),
+        "DeleteSenderPublic" => (
+            vec![
+                reward_manager_mut,
+                ("sender", true, false),
+                refunder,
+                instructions,
+                ("operator", true, false),
+            ],
+            Some("Senders attesting the removal"),
+        ),
+        "CreateOracle" => (
+            vec![
+                reward_manager,
+                manager,
+                authority,
+                funder,
+                ("oracle", true, false),
+                system_program,
+                rent,
+            ],
+            Some(DELEGATE),
+        ),
+        "DeleteOracle" => (
+            vec![reward_manager, manager, ("oracle", true, false), refunder],
+            Some(DELEGATE),
+        ),
+        "SetSpendLimit" => (
+            vec![
+                reward_manager,
+                manager,
+                authority,
+                funder,
+                ("spendCounter", true, false),
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "SetRecipientBlocked" => (
+            vec![
+                reward_manager,
+                manager,
+                authority,
+                funder,
+                ("recipientPolicy", true, false),
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "SetChallenge" => (
+            vec![
+                reward_manager,
+                manager,
+                authority,
+                funder,
+                ("challengeRegistry", true, false),
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "CreateEventLog" => (
+            vec![
+                reward_manager,
+                manager,
+                authority,
+                funder,
+                event_log,
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "SetRateLimit" => (
+            vec![
+                reward_manager,
+                manager,
+                authority,
+                funder,
+                rate_limiter,
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "InitRewardManagerV2" => (
+            vec![
+                ("rewardManager", true, true),
+                ("tokenAccount", true, true),
+                ("mint", false, false),
+                ("manager", false, false),
+                authority,
+                funder,
+                token_program,
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "RepairSender" => (
+            vec![
+                reward_manager,
+                manager,
+                ("sender", true, false),
+                ("operator", false, false),
+            ],
+            None,
+        ),
+        "UpdateSenderMetadata" => (
+            vec![reward_manager, ("sender", true, false), instructions],
+            None,
+        ),
+        "SubmitDistributionRoot" => (
+            vec![
+                reward_manager,
+                authority,
+                ("botOracle", false, false),
+                funder,
+                ("distributionRoot", true, false),
+                instructions,
+                system_program,
+                rate_limiter,
+            ],
+            Some(ATTESTERS),
+        ),
+        "ClaimFromRoot" => (
+            vec![
+                reward_manager_mut,
+                authority,
+                ("distributionRoot", false, false),
+                ("recipient", true, false),
+                ("vaultTokenAccount", true, false),
+                funder,
+                ("transferReceipt", true, false),
+                token_program,
+                system_program,
+                ("spendCounter", true, false),
+                recipient_policy,
+                fee_account,
+                challenge_registry,
+                event_log,
+            ],
+            None,
+        ),
+        "RotateSenderKey" => (
+            vec![
+                reward_manager,
+                funder,
+                ("sender", true, false),
+                ("newSender", true, false),
+                ("legacyNewSender", false, false),
+                instructions,
+                system_program,
+                rent,
+                ("operator", true, false),
+            ],
+            None,
+        ),
+        "CreateDelegate" => (
+            vec![
+                reward_manager,
+                manager,
+                funder,
+                ("delegate", true, false),
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "RevokeDelegate" => (
+            vec![reward_manager, manager, ("delegate", true, false), refunder],
+            None,
+        ),
+        "CreateManagerMultisig" => (
+            vec![
+                reward_manager,
+                manager,
+                funder,
+                ("multisig", true, false),
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "ProposeAdminAction" => (
+            vec![
+                reward_manager,
+                ("multisig", true, false),
+                ("adminAction", true, false),
+                ("proposer", false, true),
+                funder,
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        "ApproveAdminAction" => (
+            vec![
+                reward_manager,
+                ("multisig", false, false),
+                ("adminAction", true, false),
+                ("approver", false, true),
+            ],
+            None,
+        ),
+        "ExecuteAdminAction" => (
+            vec![
+                reward_manager,
+                ("multisig", false, false),
+                ("adminAction", true, false),
+                ("proposer", true, false),
+                ("program", false, false),
+            ],
+            Some("Accounts of the admin action instruction"),
+        ),
+        "SetSenderWeight" | "SuspendSender" | "ReinstateSender" => {
+            (vec![reward_manager, manager, ("sender", true, false)], None)
+        }
+        "SetOperatorSendersLimit" => (
+            vec![
+                reward_manager,
+                manager,
+                funder,
+                ("operator", true, false),
+                system_program,
+                rent,
+            ],
+            None,
+        ),
+        _ => panic!("accounts of the {} instruction aren't listed", instruction),
+    }
+}
+
+/// `snake_case` field or `PascalCase` variant name in `camelCase`
+fn camel_case(name: &str) -> String {
+    let mut camel = String::with_capacity(name.len());
+    let mut upper = false;
+    for (index, c) in name.chars().enumerate() {
+        if c == '_' {
+            upper = true;
+        } else if index == 0 {
+            camel.extend(c.to_lowercase());
+        } else if upper {
+            camel.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+/// Borsh schema definitions converted to the IDL, collecting the defined types met
+struct Schema {
+    definitions: HashMap<Declaration, Definition>,
+    defined: BTreeSet<Declaration>,
+}
+
+impl Schema {
+    fn new() -> Self {
+        Self {
+            definitions: HashMap::new(),
+            defined: BTreeSet::new(),
+        }
+    }
+
+    fn add<T: BorshSchema>(&mut self) -> Declaration {
+        T::add_definitions_recursively(&mut self.definitions);
+        T::declaration()
+    }
+
+    fn definition(&self, declaration: &str) -> &Definition {
+        self.definitions
+            .get(declaration)
+            .unwrap_or_else(|| panic!("{} has no schema definition", declaration))
+    }
+
+    fn idl_type(&mut self, declaration: &str) -> Value {
+        match declaration {
+            "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "bool" | "string" => return json!(declaration),
+            "Pubkey" => return json!("publicKey"),
+            _ => {}
+        }
+
+        match self.definition(declaration).clone() {
+            Definition::Array { length, elements } => {
+                json!({ "array": [self.idl_type(&elements), length] })
+            }
+            Definition::Sequence { elements } if elements == "u8" => json!("bytes"),
+            Definition::Sequence { elements } => json!({ "vec": self.idl_type(&elements) }),
+            Definition::Enum { variants } if declaration.starts_with("Option<") => {
+                json!({ "option": self.idl_type(&variants[1].1) })
+            }
+            // NOTE: newtypes such as `Hash` are laid out as the wrapped type
+            Definition::Struct {
+                fields: Fields::UnnamedFields(fields),
+            } if fields.len() == 1 => self.idl_type(&fields[0]),
+            _ => {
+                self.defined.insert(declaration.to_string());
+                json!({ "defined": declaration })
+            }
+        }
+    }
+
+    fn idl_fields(&mut self, declaration: &str) -> Vec<Value> {
+        match self.definition(declaration).clone() {
+            Definition::Struct {
+                fields: Fields::NamedFields(fields),
+            } => fields
+                .iter()
+                .map(|(name, field)| json!({ "name": camel_case(name), "type": self.idl_type(field) }))
+                .collect(),
+            Definition::Struct {
+                fields: Fields::Empty,
+            } => Vec::new(),
+            _ => panic!("{} isn't a struct with named fields", declaration),
+        }
+    }
+
+    fn idl_type_def(&mut self, declaration: &str) -> Value {
+        let ty = match self.definition(declaration).clone() {
+            Definition::Enum { variants } => json!({
+                "kind": "enum",
+                "variants": variants
+                    .iter()
+                    .map(|(name, _)| json!({ "name": name }))
+                    .collect::<Vec<_>>(),
+            }),
+            _ => json!({ "kind": "struct", "fields": self.idl_fields(declaration) }),
+        };
+        json!({ "name": declaration, "type": ty })
+    }
+}
+
+fn idl_instructions(schema: &mut Schema) -> Vec<Value> {
+    let instructions = schema.add::<Instructions>();
+    let variants = match schema.definition(&instructions).clone() {
+        Definition::Enum { variants } => variants,
+        _ => unreachable!(),
+    };
+
+    variants
+        .iter()
+        .enumerate()
+        .map(|(discriminant, (name, variant))| {
+            // NOTE: every variant wraps its parameters struct or carries nothing
+            let args = match schema.definition(variant).clone() {
+                Definition::Struct {
+                    fields: Fields::UnnamedFields(fields),
+                } => schema.idl_fields(&fields[0]),
+                _ => Vec::new(),
+            };
+            let (accounts, remaining_accounts) = instruction_accounts(name);
+
+            let mut instruction = json!({
+                "name": camel_case(name),
+                "accounts": accounts
+                    .iter()
+                    .map(|(name, is_mut, is_signer)| {
+                        json!({ "name": name, "isMut": is_mut, "isSigner": is_signer })
+                    })
+                    .collect::<Vec<_>>(),
+                "args": args,
+                "discriminant": { "type": "u8", "value": discriminant },
+            });
+            if let Some(remaining_accounts) = remaining_accounts {
+                instruction["docs"] =
+                    json!([format!("Remaining accounts: {}", remaining_accounts)]);
+            }
+            instruction
+        })
+        .collect()
+}
+
+fn idl_errors() -> Vec<Value> {
+    let mut errors = Vec::new();
+    let mut code = 0;
+    while let Some(error) = AudiusProgramError::from_u32(code) {
+        errors.push(json!({
+            "code": code,
+            "name": format!("{:?}", error),
+            "msg": error.to_string(),
+        }));
+        code += 1;
+    }
+    errors
+}
+
+/// IDL of the instructions with their accounts ordering, program accounts, types and errors
+pub fn idl() -> Value {
+    let mut schema = Schema::new();
+
+    let instructions = idl_instructions(&mut schema);
+
+    let accounts = vec![
+        schema.add::<RewardManager>(),
+        schema.add::<SenderAccount>(),
+        schema.add::<OperatorAccount>(),
+        schema.add::<OracleAccount>(),
+        schema.add::<DelegateAccount>(),
+        schema.add::<ManagerMultisig>(),
+        schema.add::<AdminAction>(),
+        schema.add::<TransferReceipt>(),
+        schema.add::<DistributionRoot>(),
+        schema.add::<SpendCounter>(),
+        schema.add::<RecipientPolicy>(),
+        schema.add::<ChallengeRegistry>(),
+        schema.add::<EventLog>(),
+        schema.add::<RateLimiter>(),
+    ]
+    .iter()
+    .map(|account| schema.idl_type_def(account))
+    .collect::<Vec<_>>();
+
+    // NOTE: type definitions may refer to types not met yet
+    let mut types = Vec::new();
+    let mut listed = BTreeSet::new();
+    loop {
+        let declaration = match schema.defined.difference(&listed).next() {
+            Some(declaration) => declaration.clone(),
+            None => break,
+        };
+        types.push(schema.idl_type_def(&declaration));
+        listed.insert(declaration);
+    }
+
+    json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "name": "audius_reward_manager",
+        "instructions": instructions,
+        "accounts": accounts,
+        "types": types,
+        "errors": idl_errors(),
+        "metadata": { "address": crate::id().to_string() },
+    })
+}
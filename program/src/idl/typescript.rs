@@ -0,0 +1,139 @@
+//! TypeScript bindings of the client facing layouts for `borsh-js`, generated from the
+//! `BorshSchema` of the Rust types like the IDL
+
+use super::Schema;
+use crate::{
+    instruction::{CreateSender, Transfer},
+    state::{SenderAccount, TransferReceipt},
+};
+use borsh::schema::{Definition, Fields};
+use std::fmt::Write;
+
+const HEADER: &str = "// Generated from the program types by \
+`cargo run --example bindings --features idl`, do not edit\n\nimport BN from \"bn.js\";\n";
+
+/// Field layout in the `borsh-js` schema and its TypeScript type
+fn field_type(schema: &mut Schema, declaration: &str) -> (String, String) {
+    match declaration {
+        "u8" | "u16" | "u32" | "i8" | "i16" | "i32" => {
+            return (format!("\"{}\"", declaration), "number".to_string())
+        }
+        "u64" | "u128" | "i64" | "i128" => {
+            return (format!("\"{}\"", declaration), "BN".to_string())
+        }
+        "string" => return ("\"string\"".to_string(), "string".to_string()),
+        // NOTE: `borsh-js` has no boolean layout, booleans are encoded as a 0 or 1 byte
+        "bool" => return ("\"u8\"".to_string(), "number".to_string()),
+        _ => {}
+    }
+
+    match schema.definition(declaration).clone() {
+        Definition::Array { length, elements } if elements == "u8" => {
+            (format!("[{}]", length), "Uint8Array".to_string())
+        }
+        Definition::Sequence { elements } => {
+            let (layout, ty) = field_type(schema, &elements);
+            (format!("[{}]", layout), format!("{}[]", ty))
+        }
+        // NOTE: newtypes such as `Pubkey` are laid out as the wrapped type
+        Definition::Struct {
+            fields: Fields::UnnamedFields(fields),
+        } if fields.len() == 1 => field_type(schema, &fields[0]),
+        // NOTE: enums without fields are encoded as their variant index
+        Definition::Enum { .. } if !declaration.starts_with("Option<") => {
+            schema.defined.insert(declaration.to_string());
+            ("\"u8\"".to_string(), declaration.to_string())
+        }
+        Definition::Struct {
+            fields: Fields::NamedFields(_),
+        } => {
+            schema.defined.insert(declaration.to_string());
+            (declaration.to_string(), declaration.to_string())
+        }
+        _ => panic!("{} has no borsh-js layout", declaration),
+    }
+}
+
+/// Appends the enum or class definition and the schema entry of the classes
+fn type_def(
+    schema: &mut Schema,
+    declaration: &str,
+    definitions: &mut String,
+    layouts: &mut String,
+) {
+    match schema.definition(declaration).clone() {
+        Definition::Enum { variants } => {
+            writeln!(definitions, "\nexport enum {} {{", declaration).unwrap();
+            for (index, (name, variant)) in variants.iter().enumerate() {
+                match schema.definition(variant) {
+                    Definition::Struct {
+                        fields: Fields::Empty,
+                    } => {}
+                    _ => panic!("{}::{} has fields", declaration, name),
+                }
+                writeln!(definitions, "  {} = {},", name, index).unwrap();
+            }
+            writeln!(definitions, "}}").unwrap();
+        }
+        Definition::Struct {
+            fields: Fields::NamedFields(fields),
+        } => {
+            writeln!(definitions, "\nexport class {} {{", declaration).unwrap();
+            let mut field_layouts = Vec::new();
+            for (name, field) in fields.iter() {
+                let (layout, ty) = field_type(schema, field);
+                writeln!(definitions, "  {}!: {};", name, ty).unwrap();
+                field_layouts.push(format!("[\"{}\", {}]", name, layout));
+            }
+            writeln!(
+                definitions,
+                "\n  constructor(fields: {}) {{\n    Object.assign(this, fields);\n  }}\n}}",
+                declaration
+            )
+            .unwrap();
+            writeln!(
+                layouts,
+                "  [{}, {{ kind: \"struct\", fields: [{}] }}],",
+                declaration,
+                field_layouts.join(", ")
+            )
+            .unwrap();
+        }
+        _ => panic!(
+            "{} isn't a struct with named fields or an enum",
+            declaration
+        ),
+    }
+}
+
+/// Source of `bindings/ts/src/index.ts`, the classes and `borsh-js` schema of the
+/// `Transfer` and `CreateSender` parameters and the sender and transfer receipt accounts
+pub fn bindings() -> String {
+    let mut schema = Schema::new();
+    let mut declarations = vec![
+        schema.add::<CreateSender>(),
+        schema.add::<Transfer>(),
+        schema.add::<SenderAccount>(),
+        schema.add::<TransferReceipt>(),
+    ];
+
+    let mut definitions = String::new();
+    let mut layouts = String::new();
+    // NOTE: definitions may refer to types not met yet, they follow the listed ones
+    let mut index = 0;
+    while index < declarations.len() {
+        let declaration = declarations[index].clone();
+        type_def(&mut schema, &declaration, &mut definitions, &mut layouts);
+        for defined in schema.defined.iter() {
+            if !declarations.contains(defined) {
+                declarations.push(defined.clone());
+            }
+        }
+        index += 1;
+    }
+
+    format!(
+        "{}{}\nexport const SCHEMA = new Map<Function, unknown>([\n{}]);\n",
+        HEADER, definitions, layouts
+    )
+}
@@ -1,27 +1,45 @@
 //! Instruction types
 
+#[cfg(feature = "idl")]
+use borsh::BorshSchema;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
+    hash::Hash,
     instruction::{AccountMeta, Instruction},
     program_error::ProgramError,
     pubkey::Pubkey,
     system_program, sysvar,
 };
+use spl_associated_token_account::get_associated_token_address;
 
 use crate::{
-    processor::{SENDER_SEED_PREFIX, TRANSFER_SEED_PREFIX},
-    utils::{get_address_pair, get_base_address, EthereumAddress},
+    processor::{
+        CHALLENGE_REGISTRY_SEED_PREFIX, DISTRIBUTION_SEED_PREFIX, EVENT_LOG_SEED_PREFIX,
+        ORACLE_SEED_PREFIX, RATE_LIMITER_SEED_PREFIX, RECIPIENT_POLICY_SEED_PREFIX,
+        SPEND_COUNTER_SEED_PREFIX,
+    },
+    state::AdminActionAccount,
+    utils::{
+        get_address_pair, get_admin_action_address, get_base_address, get_delegate_address,
+        get_legacy_sender_address, get_multisig_address, get_operator_address, get_sender_address,
+        get_transfer_account_address, EthereumAddress, HexAddress, SECP_SIGNATURE_SIZE,
+    },
 };
+use std::fmt;
 
 /// `InitRewardManager` instruction parameters
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
 pub struct InitRewardManager {
     /// Number of signer votes required for sending rewards
     pub min_votes: u8,
+    /// Program the transfer recipients are claimable tokens accounts of
+    pub claimable_tokens_program: Pubkey,
 }
 
 /// `CreateSender` instruction parameters
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
 pub struct CreateSender {
     /// Ethereum address
     pub eth_address: EthereumAddress,
@@ -31,6 +49,7 @@ pub struct CreateSender {
 
 /// `AddSender` instruction parameters
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
 pub struct AddSender {
     /// Ethereum address
     pub eth_address: EthereumAddress,
@@ -38,8 +57,19 @@ pub struct AddSender {
     pub operator: EthereumAddress,
 }
 
+/// Encoding of the messages senders and bot oracle sign to attest a transfer
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub enum MessageVersion {
+    /// Fields joined with `_`, deprecated as IDs may contain the delimiter
+    V1,
+    /// Version byte followed by fixed width fields and the length prefixed ID
+    V2,
+}
+
 /// `Transfer` instruction parameters
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
 pub struct Transfer {
     /// Amount to transfer
     pub amount: u64,
@@ -47,94 +77,2465 @@ pub struct Transfer {
     pub id: String,
     /// Recipient's Eth address
     pub eth_recipient: EthereumAddress,
+    /// Encoding of the attestation messages, batches and distributions attest
+    /// transfers through a digest and ignore it
+    pub message_version: MessageVersion,
+    /// Create the recipient through the claimable tokens program if it doesn't
+    /// exist, the accounts it needs follow the senders. Not attested, batches
+    /// and distributions ignore it
+    pub create_recipient: bool,
+    /// Log the transfer ID and its challenge through the SPL Memo program for
+    /// wallet histories, its account follows the recipient creation accounts.
+    /// Not attested, batches and distributions ignore it
+    pub memo: bool,
+}
+
+/// `CreateOracle` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct CreateOracle {
+    /// Ethereum address
+    pub eth_address: EthereumAddress,
+    /// Oracle operator
+    pub operator: EthereumAddress,
+}
+
+/// `SetSpendLimit` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SetSpendLimit {
+    /// Maximum amount disbursed per window
+    pub budget: u64,
+    /// Window length in slots, 0 tracks spending per epoch
+    pub window_slots: u64,
+}
+
+/// `SubmitDistributionRoot` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SubmitDistributionRoot {
+    /// Distribution ID
+    pub id: String,
+    /// Merkle root of the distributed transfers
+    pub root: Hash,
+}
+
+/// `ClaimFromRoot` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct ClaimFromRoot {
+    /// Claimed transfer, a leaf of the distribution tree
+    pub transfer: Transfer,
+    /// Sibling nodes on the way from the leaf to the root
+    pub proof: Vec<Hash>,
+}
+
+/// `RotateSenderKey` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct RotateSenderKey {
+    /// Ethereum address replacing the current one
+    pub new_eth_address: EthereumAddress,
+}
+
+/// `TransferToWallet` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct TransferToWallet {
+    /// Amount to transfer
+    pub amount: u64,
+    /// ID generated on backend, shares the namespace of `Transfer` IDs
+    pub id: String,
+    /// Recipient's Solana wallet, tokens go to its associated token account
+    pub wallet: Pubkey,
+}
+
+/// `CreateDelegate` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct CreateDelegate {
+    /// Key allowed to sign instead of the manager
+    pub delegate: Pubkey,
+    /// `DelegateAccount` permission bitflags
+    pub permissions: u8,
+}
+
+/// `ProposeManager` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct ProposeManager {
+    /// Manager account to take over, the current one cancels a pending change
+    pub new_manager: Pubkey,
+}
+
+/// `CreateManagerMultisig` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct CreateManagerMultisig {
+    /// Number of approvals an admin action needs
+    pub threshold: u8,
+    /// Keys allowed to propose and approve admin actions
+    pub signers: Vec<Pubkey>,
+}
+
+/// `ProposeAdminAction` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct ProposeAdminAction {
+    /// Accounts of the program instruction to run
+    pub accounts: Vec<AdminActionAccount>,
+    /// Data of the program instruction to run
+    pub data: Vec<u8>,
+}
+
+/// `SetSenderWeight` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SetSenderWeight {
+    /// New vote weight of the sender attestations
+    pub weight: u16,
+}
+
+/// `SetOperatorSendersLimit` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SetOperatorSendersLimit {
+    /// Operator Ethereum address
+    pub operator: EthereumAddress,
+    /// Number of senders the operator may register, at most `MAX_OPERATOR_SENDERS`
+    pub max_senders: u8,
+}
+
+/// Signature of an attester, recovered by the program rather than the secp256k1 program
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct RecoverableSignature {
+    /// Ethereum address of the signer
+    pub signer: EthereumAddress,
+    /// Signature along with its recovery id
+    pub signature: [u8; SECP_SIGNATURE_SIZE],
+}
+
+/// `TransferWithSignatures` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct TransferWithSignatures {
+    /// Transfer attested by the signatures
+    pub transfer: Transfer,
+    /// Bot oracles and senders signatures of the transfer messages,
+    /// at most `MAX_RECOVERED_SIGNATURES`
+    pub signatures: Vec<RecoverableSignature>,
+}
+
+/// `TransferBatch` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct TransferBatch {
+    /// Transfers approved by a single set of attestations over their digest
+    pub transfers: Vec<Transfer>,
+}
+
+/// `UpdateRewardManager` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Default)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct UpdateRewardManager {
+    /// New number of signer votes required for sending rewards, `None` keeps the current one
+    pub min_votes: Option<u8>,
+    /// New maximum number of attestations evaluated per instruction, `None` keeps the current one
+    pub max_attestations: Option<u8>,
+    /// New maximum amount of a single transfer, `None` keeps the current one
+    pub max_transfer_amount: Option<u64>,
+    /// New minimum number of slots between `CreateSender` calls, `None` keeps the current one
+    pub sender_creation_gap: Option<u64>,
+    /// New number of bot oracle votes required for sending rewards, `None` keeps the current one
+    pub min_oracle_votes: Option<u8>,
+    /// New vault balance transfers must leave in place, `None` keeps the current one
+    pub min_balance_threshold: Option<u64>,
+    /// Whether transfers attested with `MessageVersion::V1` are accepted, `None` keeps the current setting
+    pub v1_messages_allowed: Option<bool>,
+    /// New summed sender weight required for sending rewards, zero disables weighting,
+    /// `None` keeps the current one
+    pub min_vote_weight: Option<u32>,
+    /// New number of slots an announced treasury withdrawal waits before it may execute,
+    /// `None` keeps the current one
+    pub withdrawal_delay: Option<u64>,
+    /// New program the transfer recipients are claimable tokens accounts of, `None`
+    /// keeps the current one
+    pub claimable_tokens_program: Option<Pubkey>,
+}
+
+/// `AnnounceTreasuryWithdrawal` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct AnnounceTreasuryWithdrawal {
+    /// Amount to withdraw from the vault, zero cancels the pending withdrawal
+    pub amount: u64,
+    /// Token account of the vault mint receiving the tokens
+    pub destination: Pubkey,
+}
+
+/// `SetRecipientBlocked` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SetRecipientBlocked {
+    /// Ethereum recipient
+    pub eth_recipient: EthereumAddress,
+    /// Whether transfers to the recipient are rejected
+    pub blocked: bool,
+}
+
+/// `SetChallenge` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SetChallenge {
+    /// Challenge ID transfer IDs of the challenge are prefixed with
+    pub id: String,
+    /// Bot oracles authorized to attest transfers of the challenge, empty to
+    /// remove the challenge
+    pub oracles: Vec<EthereumAddress>,
+}
+
+/// `SetTransferFee` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SetTransferFee {
+    /// Basis points of every transfer amount sent to the fee account, zero disables the fee
+    pub fee_bps: u16,
+}
+
+/// `SetRateLimit` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SetRateLimit {
+    /// Maximum number of transfers the senders of an operator attest per window
+    pub max_attestations: u32,
+    /// Window length in slots, 0 counts attestations per epoch
+    pub window_slots: u64,
+}
+
+/// `RepairSender` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct RepairSender {
+    /// Ethereum address of the sender
+    pub eth_address: EthereumAddress,
+    /// Operator the sender is registered under
+    pub operator: EthereumAddress,
+}
+
+/// `UpdateSenderMetadata` instruction parameters
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct UpdateSenderMetadata {
+    /// Keccak256 hash of the sender service endpoint URL, see `utils::endpoint_hash`
+    pub endpoint_hash: [u8; 32],
+}
+
+/// Instruction definition
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub enum Instructions {
+    ///   Initialize `Reward Manager`
+    ///
+    ///   0. `[w]` Account that will be initialized as `Reward Manager`.
+    ///   1. `[w]` The new account that to be initialized as the token account.
+    ///   2. `[]`  Mint with which the new token account will be associated on initialization.
+    ///   3. `[]`  Manager account to be set as the `Reward Manager`.
+    ///   4. `[]`  `Reward Manager` authority.
+    ///   5. `[]`  Token program, stored and used for every token instruction of the `Reward Manager`
+    ///   6. `[]`  Rent sysvar
+    InitRewardManager(InitRewardManager),
+
+    ///   Admin method creating new authorized sender,
+    ///   at most once per `sender_creation_gap` slots
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[s]` Manager account or delegate
+    ///   2. `[]`  `Reward Manager` authority
+    ///   3. `[sw]` Funder account. Pays rent for the sender, may differ from the manager
+    ///   4. `[]`  Addidable sender
+    ///   5. `[]`  System program id
+    ///   6. `[]`  Rent sysvar
+    ///   7. `[]`  Legacy address of the sender, must not be in use
+    ///   8. `[w]` Operator account, created for the first sender of the operator
+    ///   9. `[]`  Optional delegate account, when signed by the delegate
+    CreateSender(CreateSender),
+
+    ///   Admin method removing sender
+    ///  
+    ///   0. `[w]`  `Reward Manager`
+    ///   1. `[s]`  Manager account or delegate
+    ///   2. `[w]`  Removed sender
+    ///   3. `[w]`  Refunder account
+    ///   4. `[]`   System program id
+    ///   5. `[w]`  Operator account of the sender
+    ///   6. `[]`   Optional delegate account, when signed by the delegate
+    DeleteSender,
+
+    ///   Add sender on the attestations of other senders, signing the reward manager,
+    ///   the new sender and its operator addresses concatenated
+    ///
+    /// 0. `[w]`  reward_manager
+    /// 1. `[r]`  `Reward Manager` authority
+    /// 2. `[ws]` funder. Pays rent for the new sender
+    /// 3. `[w]`  new_sender
+    /// 4. `[r]`  Sysvar instruction id
+    /// 5. `[r]`  Rent sysvar
+    /// 6. `[r]`  System program
+    /// 7. `[r]`  Legacy address of the new sender, must not be in use
+    /// 8. `[w]`  Operator account, created for the first sender of the operator
+    /// 9. `[w]`  old_sender_0
+    /// ... Bunch of old senders which prove adding new one
+    /// n. `[w]`  old_sender_n
+    AddSender(AddSender),
+
+    ///   Transfer tokens to pointed receiver
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[]` `Reward Manager` authority. Program account
+    ///   2. `[w]` Recipient. Key generated from Eth address
+    ///   3. `[w]` Vault with all the "reward" tokens. Program is authority
+    ///   4. `[]` Bot oracle
+    ///   5. `[sw]` Funder. Account which pay for new account creation
+    ///   6. `[w]` Transfer receipt account to create
+    ///   7. `[]` Sysvar instruction id
+    ///   8. `[]` SPL Token id
+    ///   9. `[]` System program
+    ///   10. `[w]` Spend counter. Spending is unlimited while not created
+    ///   11. `[]` Recipient policy. No recipient is blocked while not created
+    ///   12. `[w]` Fee token account. Unchecked while no transfer fee is set
+    ///   13. `[]` Challenge registry. Transfer IDs are unrestricted while not created
+    ///   14. `[w]` Event log. Nothing is recorded while not created
+    ///   15. `[w]` Rate limiter. Operator attestations are unlimited while not created
+    ///   16. `[w]` Bot oracles voting in addition to the first one, `min_oracle_votes - 1`, then senders
+    ///   ...
+    ///   n. `[w]`
+    ///   n+1. `[]` Claimable tokens program, when `create_recipient` is set
+    ///   n+2. `[]` Mint of the vault, when `create_recipient` is set
+    ///   n+3. `[]` Claimable tokens authority of the mint, when `create_recipient` is set
+    ///   n+4. `[]` Rent sysvar, when `create_recipient` is set
+    ///   last. `[]` SPL Memo program, when `memo` is set
+    Transfer(Transfer),
+
+    ///   Admin method updating `Reward Manager` settings
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[s]` Current manager account
+    UpdateRewardManager(UpdateRewardManager),
+
+    ///   Admin method halting transfers
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[s]` Manager account or delegate
+    ///   2. `[]`  Optional delegate account, when signed by the delegate
+    Pause,
+
+    ///   Admin method resuming transfers
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[s]` Manager account or delegate
+    ///   2. `[]`  Optional delegate account, when signed by the delegate
+    Unpause,
+
+    ///   Transfer tokens to several receivers at once
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[]` `Reward Manager` authority. Program account
+    ///   2. `[w]` Vault with all the "reward" tokens. Program is authority
+    ///   3. `[]` Bot oracle
+    ///   4. `[sw]` Funder. Account which pay for new accounts creation
+    ///   5. `[]` Sysvar instruction id
+    ///   6. `[]` SPL Token id
+    ///   7. `[]` System program
+    ///   8. `[w]` Spend counter. Spending is unlimited while not created
+    ///   9. `[]` Recipient policy. No recipient is blocked while not created
+    ///   10. `[w]` Fee token account. Unchecked while no transfer fee is set
+    ///   11. `[]` Challenge registry. Transfer IDs are unrestricted while not created
+    ///   12. `[w]` Event log. Nothing is recorded while not created
+    ///   13. `[w]` Rate limiter. Operator attestations are unlimited while not created
+    ///   14. `[w]` Recipient of the first transfer. Key generated from Eth address
+    ///   15. `[w]` Transfer receipt account of the first transfer to create
+    ///   ... Recipient and transfer receipt account of every following transfer
+    ///   14 + 2 * transfers. `[w]` Bot oracles voting in addition to the first one, `min_oracle_votes - 1`, then senders
+    ///   ...
+    ///   n. `[w]`
+    TransferBatch(TransferBatch),
+
+    ///   Remove sender on the attestations of other senders
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[w]` Removed sender
+    ///   2. `[w]` Refunder account
+    ///   3. `[]` Sysvar instruction id
+    ///   4. `[w]` Operator account of the removed sender
+    ///   5. `[w]` Senders
+    ///   ...
+    ///   n. `[w]`
+    DeleteSenderPublic,
+
+    ///   Admin method creating the bot oracle
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account or delegate
+    ///   2. `[]`  `Reward Manager` authority
+    ///   3. `[sw]` Funder account. Pays rent for the oracle
+    ///   4. `[w]` Oracle account to create
+    ///   5. `[]`  System program id
+    ///   6. `[]`  Rent sysvar
+    ///   7. `[]`  Optional delegate account, when signed by the delegate
+    CreateOracle(CreateOracle),
+
+    ///   Admin method removing the bot oracle
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account or delegate
+    ///   2. `[w]` Removed oracle
+    ///   3. `[w]` Refunder account
+    ///   4. `[]`  Optional delegate account, when signed by the delegate
+    DeleteOracle,
+
+    ///   Admin method setting the budget disbursed per window, creates the spend counter
+    ///   on first use
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[]`  `Reward Manager` authority
+    ///   3. `[sw]` Funder account. Pays rent for the spend counter
+    ///   4. `[w]` Spend counter
+    ///   5. `[]`  System program id
+    ///   6. `[]`  Rent sysvar
+    SetSpendLimit(SetSpendLimit),
+
+    ///   Record the Merkle root of transfers approved by one round of attestations
+    ///
+    ///   0. `[]` `Reward Manager`
+    ///   1. `[]` `Reward Manager` authority. Program account
+    ///   2. `[]` Bot oracle
+    ///   3. `[sw]` Funder. Account which pay for new account creation
+    ///   4. `[w]` Distribution root account to create
+    ///   5. `[]` Sysvar instruction id
+    ///   6. `[]` System program
+    ///   7. `[w]` Rate limiter. Operator attestations are unlimited while not created
+    ///   8. `[w]` Bot oracles voting in addition to the first one, `min_oracle_votes - 1`, then senders
+    ///   ...
+    ///   n. `[w]`
+    SubmitDistributionRoot(SubmitDistributionRoot),
+
+    ///   Transfer tokens approved by a distribution root, anyone holding the proof may claim
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[]` `Reward Manager` authority. Program account
+    ///   2. `[]` Distribution root
+    ///   3. `[w]` Recipient. Key generated from Eth address
+    ///   4. `[w]` Vault with all the "reward" tokens. Program is authority
+    ///   5. `[sw]` Funder. Account which pay for new account creation
+    ///   6. `[w]` Transfer receipt account to create
+    ///   7. `[]` SPL Token id
+    ///   8. `[]` System program
+    ///   9. `[w]` Spend counter. Spending is unlimited while not created
+    ///   10. `[]` Recipient policy. No recipient is blocked while not created
+    ///   11. `[w]` Fee token account. Unchecked while no transfer fee is set
+    ///   12. `[]` Challenge registry. Checked for the bot oracle which attested the root,
+    ///   transfer IDs are unrestricted while not created
+    ///   13. `[w]` Event log. Nothing is recorded while not created
+    ClaimFromRoot(ClaimFromRoot),
+
+    ///   Move the sender to a new Ethereum address signed off by the current one
+    ///   over the sender attestation count, the operator is kept and the rotation
+    ///   counts as an attestation
+    ///
+    ///   0. `[]` `Reward Manager`
+    ///   1. `[sw]` Funder. Pays rent for the new sender account, refunded the old one
+    ///   2. `[w]` Rotated sender
+    ///   3. `[w]` Sender account to create for the new address
+    ///   4. `[]` Legacy sender address of the new address, must be empty
+    ///   5. `[]` Sysvar instruction id
+    ///   6. `[]` System program id
+    ///   7. `[]` Rent sysvar
+    ///   8. `[w]` Operator account of the sender
+    RotateSenderKey(RotateSenderKey),
+
+    ///   Transfer tokens to the associated token account of a Solana wallet
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[]` `Reward Manager` authority. Program account
+    ///   2. `[w]` Recipient. Associated token account of the wallet for the vault mint
+    ///   3. `[w]` Vault with all the "reward" tokens. Program is authority
+    ///   4. `[]` Bot oracle
+    ///   5. `[sw]` Funder. Account which pay for new account creation
+    ///   6. `[w]` Transfer receipt account to create
+    ///   7. `[]` Sysvar instruction id
+    ///   8. `[]` SPL Token id
+    ///   9. `[]` System program
+    ///   10. `[w]` Spend counter. Spending is unlimited while not created
+    ///   11. `[w]` Fee token account. Unchecked while no transfer fee is set
+    ///   12. `[]` Challenge registry. Transfer IDs are unrestricted while not created
+    ///   13. `[w]` Event log. Nothing is recorded while not created
+    ///   14. `[w]` Rate limiter. Operator attestations are unlimited while not created
+    ///   15. `[w]` Bot oracles voting in addition to the first one, `min_oracle_votes - 1`, then senders
+    ///   ...
+    ///   n. `[w]`
+    TransferToWallet(TransferToWallet),
+
+    ///   Admin method authorizing a key to call some of the admin instructions,
+    ///   updates the permissions of an existing delegate. Delegates are disabled
+    ///   while the manager multisig is the manager
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[sw]` Funder account. Pays rent for the delegate account
+    ///   3. `[w]` Delegate account
+    ///   4. `[]`  System program id
+    ///   5. `[]`  Rent sysvar
+    CreateDelegate(CreateDelegate),
+
+    ///   Admin method revoking the delegate
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[w]` Revoked delegate account
+    ///   3. `[w]` Refunder account
+    RevokeDelegate,
+
+    ///   Admin method proposing a new manager, which may accept after
+    ///   `MANAGER_CHANGE_DELAY` slots. Replaces a pending proposal
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[s]` Current manager account
+    ProposeManager(ProposeManager),
+
+    ///   Make the proposed manager the `Reward Manager` manager once the timelock expires
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[s]` Proposed manager account
+    AcceptManager,
+
+    ///   Admin method creating the manager multisig, or replacing its keys when
+    ///   run by the multisig itself. The multisig takes over once the manager
+    ///   proposes it through `ProposeManager` and the multisig runs `AcceptManager`
+    ///   as an admin action after `MANAGER_CHANGE_DELAY` slots. From then on the
+    ///   delegates are disabled and every admin instruction needs `threshold` keys
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[sw]` Funder account. Pays rent for the multisig account
+    ///   3. `[w]` Manager multisig account
+    ///   4. `[]`  System program id
+    ///   5. `[]`  Rent sysvar
+    CreateManagerMultisig(CreateManagerMultisig),
+
+    ///   Propose a program instruction to be run as the manager multisig,
+    ///   counts as the proposer approval
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[w]` Manager multisig account
+    ///   2. `[w]` Admin action account, derived with the multisig proposal count
+    ///   3. `[s]` Proposer, one of the multisig keys
+    ///   4. `[sw]` Funder account. Pays rent for the admin action account
+    ///   5. `[]`  System program id
+    ///   6. `[]`  Rent sysvar
+    ProposeAdminAction(ProposeAdminAction),
+
+    ///   Approve an admin action
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[]`  Manager multisig account
+    ///   2. `[w]` Admin action account
+    ///   3. `[s]` Approver, one of the multisig keys
+    ApproveAdminAction,
+
+    ///   Run an admin action approved by `threshold` multisig keys and close it
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[]`  Manager multisig account
+    ///   2. `[w]` Admin action account
+    ///   3. `[w]` Proposer account, receives the admin action rent
+    ///   4. `[]`  Reward manager program id
+    ///   5. ..5+N `[]` Accounts of the admin action instruction
+    ExecuteAdminAction,
+
+    ///   Admin method setting the vote weight of a sender
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[w]` Sender account
+    SetSenderWeight(SetSenderWeight),
+
+    ///   Admin method capping the number of senders an operator may register,
+    ///   senders registered above a lowered limit stay
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[sw]` Funder account. Pays rent for the operator account if it's created
+    ///   3. `[w]` Operator account
+    ///   4. `[]`  System program id
+    ///   5. `[]`  Rent sysvar
+    SetOperatorSendersLimit(SetOperatorSendersLimit),
+
+    ///   Transfer tokens to pointed receiver, attestation signatures are passed
+    ///   in the instruction data and recovered by the program, so no secp256k1
+    ///   instructions have to precede it
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[]` `Reward Manager` authority. Program account
+    ///   2. `[w]` Recipient. Key generated from Eth address
+    ///   3. `[w]` Vault with all the "reward" tokens. Program is authority
+    ///   4. `[]` Bot oracle
+    ///   5. `[sw]` Funder. Account which pay for new account creation
+    ///   6. `[w]` Transfer receipt account to create
+    ///   7. `[]` SPL Token id
+    ///   8. `[]` System program
+    ///   9. `[w]` Spend counter. Spending is unlimited while not created
+    ///   10. `[]` Recipient policy. No recipient is blocked while not created
+    ///   11. `[w]` Fee token account. Unchecked while no transfer fee is set
+    ///   12. `[]` Challenge registry. Transfer IDs are unrestricted while not created
+    ///   13. `[w]` Event log. Nothing is recorded while not created
+    ///   14. `[w]` Rate limiter. Operator attestations are unlimited while not created
+    ///   15. `[w]` Bot oracles voting in addition to the first one, `min_oracle_votes - 1`, then senders
+    ///   ...
+    ///   n. `[w]`
+    ///   n+1. `[]` Claimable tokens program, when `create_recipient` is set
+    ///   n+2. `[]` Mint of the vault, when `create_recipient` is set
+    ///   n+3. `[]` Claimable tokens authority of the mint, when `create_recipient` is set
+    ///   n+4. `[]` Rent sysvar, when `create_recipient` is set
+    ///   last. `[]` SPL Memo program, when `memo` is set
+    TransferWithSignatures(TransferWithSignatures),
+
+    ///   Admin method announcing a withdrawal of unspent tokens from the vault,
+    ///   it may execute once the reward manager withdrawal delay passes.
+    ///   Replaces the pending withdrawal and restarts the delay
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[s]` Manager account
+    AnnounceTreasuryWithdrawal(AnnounceTreasuryWithdrawal),
+
+    ///   Admin method sending the announced amount from the vault to the announced
+    ///   destination once the withdrawal delay passed
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[]` `Reward Manager` authority. Program account
+    ///   2. `[w]` Vault with all the "reward" tokens. Program is authority
+    ///   3. `[w]` Destination token account
+    ///   4. `[s]` Manager account
+    ///   5. `[]` SPL Token id
+    WithdrawTreasury,
+
+    ///   Admin method moving the reward manager to a new vault token account owned by
+    ///   the reward manager authority, possibly of another mint. Remaining tokens are
+    ///   moved to it if the mint is the same, otherwise the vault must be emptied first.
+    ///   Cancels the pending treasury withdrawal
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[]` `Reward Manager` authority. Program account
+    ///   2. `[w]` Current vault token account
+    ///   3. `[w]` New vault token account
+    ///   4. `[s]` Manager account
+    ///   5. `[]` SPL Token id
+    MigrateTokenAccount,
+
+    ///   Admin method rejecting the sender attestations until it's reinstated
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[w]` Sender account
+    SuspendSender,
+
+    ///   Admin method accepting the attestations of a suspended sender again
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[w]` Sender account
+    ReinstateSender,
+
+    ///   Admin method blocking or unblocking transfers to an Ethereum recipient, creates
+    ///   the recipient policy on first use
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[]`  `Reward Manager` authority
+    ///   3. `[sw]` Funder account. Pays rent for the recipient policy
+    ///   4. `[w]` Recipient policy
+    ///   5. `[]`  System program id
+    ///   6. `[]`  Rent sysvar
+    SetRecipientBlocked(SetRecipientBlocked),
+
+    ///   Admin method setting the part of every disbursed transfer sent to a fee token account
+    ///   of the vault mint, e.g. to fund relayer costs. Zero basis points disable the fee
+    ///
+    ///   0. `[w]` `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[]`  Vault token account
+    ///   3. `[]`  Fee token account, unchecked when disabling the fee
+    SetTransferFee(SetTransferFee),
+
+    ///   Verifies the attestations of a transfer the way `Transfer` does without
+    ///   writing anything, so relayers may check an assembled bundle on-chain.
+    ///   Neither the recipient, the funding nor the transfer receipt are checked
+    ///
+    ///   0. `[]` `Reward Manager`
+    ///   1. `[]` Bot oracle
+    ///   2. `[]` Sysvar instruction id
+    ///   3. `[]` Bot oracles voting in addition to the first one, `min_oracle_votes - 1`, then senders
+    ///   ...
+    ///   n. `[]`
+    VerifyOnly(Transfer),
+
+    ///   Admin method registering, updating or removing a challenge transfer IDs are
+    ///   namespaced by, creates the challenge registry on first use
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[]`  `Reward Manager` authority
+    ///   3. `[sw]` Funder account. Pays rent for the challenge registry
+    ///   4. `[w]` Challenge registry
+    ///   5. `[]`  System program id
+    ///   6. `[]`  Rent sysvar
+    SetChallenge(SetChallenge),
+
+    ///   Admin method creating the event log transfers record their evaluations in
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[]`  `Reward Manager` authority
+    ///   3. `[sw]` Funder account. Pays rent for the event log
+    ///   4. `[w]` Event log
+    ///   5. `[]`  System program id
+    ///   6. `[]`  Rent sysvar
+    CreateEventLog,
+
+    ///   Admin method setting the number of transfers the senders of an operator
+    ///   attest per window, creates the rate limiter on first use
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[]`  `Reward Manager` authority
+    ///   3. `[sw]` Funder account. Pays rent for the rate limiter
+    ///   4. `[w]` Rate limiter
+    ///   5. `[]`  System program id
+    ///   6. `[]`  Rent sysvar
+    SetRateLimit(SetRateLimit),
+
+    ///   Creates the `Reward Manager` and its token account, funded for rent
+    ///   exemption, and initializes them in one instruction
+    ///
+    ///   0. `[sw]` New account to be created as `Reward Manager`
+    ///   1. `[sw]` New account to be created as the token account
+    ///   2. `[]`  Mint with which the new token account will be associated on initialization.
+    ///   3. `[]`  Manager account to be set as the `Reward Manager`.
+    ///   4. `[]`  `Reward Manager` authority.
+    ///   5. `[sw]` Funder account. Pays rent for both accounts
+    ///   6. `[]`  Token program, stored and used for every token instruction of the `Reward Manager`
+    ///   7. `[]`  System program id
+    ///   8. `[]`  Rent sysvar
+    InitRewardManagerV2(InitRewardManager),
+
+    ///   Admin method rewriting a sender account holding data that doesn't deserialize
+    ///   to the sender derived at its address. The sender must be registered under the
+    ///   operator, its weight, counters and status are reset
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[s]` Manager account
+    ///   2. `[w]` Sender account
+    ///   3. `[]`  Operator account of the sender
+    RepairSender(RepairSender),
+
+    ///   Set the hash of the sender service endpoint, signed off by the sender
+    ///   over its current hash. Senders created before endpoint metadata must be
+    ///   rotated first
+    ///
+    ///   0. `[]`  `Reward Manager`
+    ///   1. `[w]` Sender account
+    ///   2. `[]`  Sysvar instruction id
+    UpdateSenderMetadata(UpdateSenderMetadata),
+}
+
+/// Decodes instruction data of the program
+pub fn decode(input: &[u8]) -> Result<Instructions, ProgramError> {
+    Ok(Instructions::try_from_slice(input)?)
+}
+
+impl Instructions {
+    /// Name of the instruction
+    pub fn name(&self) -> &'static str {
+        match self {
+            Instructions::InitRewardManager(_) => "InitRewardManager",
+            Instructions::CreateSender(_) => "CreateSender",
+            Instructions::DeleteSender => "DeleteSender",
+            Instructions::AddSender(_) => "AddSender",
+            Instructions::Transfer(_) => "Transfer",
+            Instructions::UpdateRewardManager(_) => "UpdateRewardManager",
+            Instructions::Pause => "Pause",
+            Instructions::Unpause => "Unpause",
+            Instructions::TransferBatch(_) => "TransferBatch",
+            Instructions::DeleteSenderPublic => "DeleteSenderPublic",
+            Instructions::CreateOracle(_) => "CreateOracle",
+            Instructions::DeleteOracle => "DeleteOracle",
+            Instructions::SetSpendLimit(_) => "SetSpendLimit",
+            Instructions::SubmitDistributionRoot(_) => "SubmitDistributionRoot",
+            Instructions::ClaimFromRoot(_) => "ClaimFromRoot",
+            Instructions::RotateSenderKey(_) => "RotateSenderKey",
+            Instructions::TransferToWallet(_) => "TransferToWallet",
+            Instructions::CreateDelegate(_) => "CreateDelegate",
+            Instructions::RevokeDelegate => "RevokeDelegate",
+            Instructions::ProposeManager(_) => "ProposeManager",
+            Instructions::AcceptManager => "AcceptManager",
+            Instructions::CreateManagerMultisig(_) => "CreateManagerMultisig",
+            Instructions::ProposeAdminAction(_) => "ProposeAdminAction",
+            Instructions::ApproveAdminAction => "ApproveAdminAction",
+            Instructions::ExecuteAdminAction => "ExecuteAdminAction",
+            Instructions::SetSenderWeight(_) => "SetSenderWeight",
+            Instructions::SetOperatorSendersLimit(_) => "SetOperatorSendersLimit",
+            Instructions::TransferWithSignatures(_) => "TransferWithSignatures",
+            Instructions::AnnounceTreasuryWithdrawal(_) => "AnnounceTreasuryWithdrawal",
+            Instructions::WithdrawTreasury => "WithdrawTreasury",
+            Instructions::MigrateTokenAccount => "MigrateTokenAccount",
+            Instructions::SuspendSender => "SuspendSender",
+            Instructions::ReinstateSender => "ReinstateSender",
+            Instructions::SetRecipientBlocked(_) => "SetRecipientBlocked",
+            Instructions::SetTransferFee(_) => "SetTransferFee",
+            Instructions::VerifyOnly(_) => "VerifyOnly",
+            Instructions::SetChallenge(_) => "SetChallenge",
+            Instructions::CreateEventLog => "CreateEventLog",
+            Instructions::SetRateLimit(_) => "SetRateLimit",
+            Instructions::InitRewardManagerV2(_) => "InitRewardManagerV2",
+            Instructions::RepairSender(_) => "RepairSender",
+            Instructions::UpdateSenderMetadata(_) => "UpdateSenderMetadata",
+        }
+    }
+
+    /// Displays the instruction parameters, nothing for instructions without any
+    pub fn params(&self) -> InstructionParams<'_> {
+        InstructionParams(self)
+    }
+}
+
+impl fmt::Display for Instructions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let params = self.params().to_string();
+        if params.is_empty() {
+            f.write_str(self.name())
+        } else {
+            write!(f, "{}: {}", self.name(), params)
+        }
+    }
+}
+
+impl fmt::Display for Transfer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "amount: {}, transfer_id: {}, eth_recipient: {}, message_version: {:?}, \
+             create_recipient: {}, memo: {}",
+            self.amount,
+            self.id,
+            HexAddress(&self.eth_recipient),
+            self.message_version,
+            self.create_recipient,
+            self.memo
+        )
+    }
+}
+
+/// Parameters of an instruction rendered with amounts in the smallest token
+/// units and Ethereum addresses as hex
+pub struct InstructionParams<'a>(&'a Instructions);
+
+impl fmt::Display for InstructionParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Instructions::InitRewardManager(InitRewardManager {
+                min_votes,
+                claimable_tokens_program,
+            })
+            | Instructions::InitRewardManagerV2(InitRewardManager {
+                min_votes,
+                claimable_tokens_program,
+            }) => write!(
+                f,
+                "min_votes: {}, claimable_tokens_program: {}",
+                min_votes, claimable_tokens_program
+            ),
+            Instructions::CreateSender(CreateSender {
+                eth_address,
+                operator,
+            })
+            | Instructions::AddSender(AddSender {
+                eth_address,
+                operator,
+            })
+            | Instructions::CreateOracle(CreateOracle {
+                eth_address,
+                operator,
+            })
+            | Instructions::RepairSender(RepairSender {
+                eth_address,
+                operator,
+            }) => write!(
+                f,
+                "eth_address: {}, operator: {}",
+                HexAddress(eth_address),
+                HexAddress(operator)
+            ),
+            Instructions::Transfer(transfer) | Instructions::VerifyOnly(transfer) => {
+                write!(f, "{}", transfer)
+            }
+            Instructions::UpdateRewardManager(params) => write!(f, "{:?}", params),
+            Instructions::TransferBatch(TransferBatch { transfers }) => {
+                for (index, transfer) in transfers.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str("; ")?;
+                    }
+                    write!(f, "{}", transfer)?;
+                }
+                Ok(())
+            }
+            Instructions::SetSpendLimit(SetSpendLimit {
+                budget,
+                window_slots,
+            }) => write!(f, "budget: {}, window_slots: {}", budget, window_slots),
+            Instructions::SubmitDistributionRoot(SubmitDistributionRoot { id, root }) => {
+                write!(f, "distribution_id: {}, root: {}", id, root)
+            }
+            Instructions::ClaimFromRoot(ClaimFromRoot { transfer, proof }) => {
+                write!(f, "{}, proof_len: {}", transfer, proof.len())
+            }
+            Instructions::RotateSenderKey(RotateSenderKey { new_eth_address }) => {
+                write!(f, "new_eth_address: {}", HexAddress(new_eth_address))
+            }
+            Instructions::UpdateSenderMetadata(UpdateSenderMetadata { endpoint_hash }) => {
+                write!(f, "endpoint_hash: {}", Hash::new_from_array(*endpoint_hash))
+            }
+            Instructions::TransferToWallet(TransferToWallet { amount, id, wallet }) => write!(
+                f,
+                "amount: {}, transfer_id: {}, wallet: {}",
+                amount, id, wallet
+            ),
+            Instructions::CreateDelegate(CreateDelegate {
+                delegate,
+                permissions,
+            }) => write!(
+                f,
+                "delegate: {}, permissions: {:#04x}",
+                delegate, permissions
+            ),
+            Instructions::ProposeManager(ProposeManager { new_manager }) => {
+                write!(f, "new_manager: {}", new_manager)
+            }
+            Instructions::CreateManagerMultisig(CreateManagerMultisig { threshold, signers }) => {
+                write!(f, "threshold: {}, signers: {:?}", threshold, signers)
+            }
+            Instructions::ProposeAdminAction(ProposeAdminAction { accounts, data }) => {
+                write!(f, "accounts: {}, data_len: {}", accounts.len(), data.len())
+            }
+            Instructions::SetSenderWeight(SetSenderWeight { weight }) => {
+                write!(f, "weight: {}", weight)
+            }
+            Instructions::SetOperatorSendersLimit(SetOperatorSendersLimit {
+                operator,
+                max_senders,
+            }) => write!(
+                f,
+                "operator: {}, max_senders: {}",
+                HexAddress(operator),
+                max_senders
+            ),
+            Instructions::TransferWithSignatures(TransferWithSignatures {
+                transfer,
+                signatures,
+            }) => write!(f, "{}, signatures: {}", transfer, signatures.len()),
+            Instructions::AnnounceTreasuryWithdrawal(AnnounceTreasuryWithdrawal {
+                amount,
+                destination,
+            }) => write!(f, "amount: {}, destination: {}", amount, destination),
+            Instructions::SetRecipientBlocked(SetRecipientBlocked {
+                eth_recipient,
+                blocked,
+            }) => write!(
+                f,
+                "eth_recipient: {}, blocked: {}",
+                HexAddress(eth_recipient),
+                blocked
+            ),
+            Instructions::SetTransferFee(SetTransferFee { fee_bps }) => {
+                write!(f, "fee_bps: {}", fee_bps)
+            }
+            Instructions::SetRateLimit(SetRateLimit {
+                max_attestations,
+                window_slots,
+            }) => write!(
+                f,
+                "max_attestations: {}, window_slots: {}",
+                max_attestations, window_slots
+            ),
+            Instructions::SetChallenge(SetChallenge { id, oracles }) => {
+                write!(f, "id: {}, oracles: [", id)?;
+                for (index, oracle) in oracles.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", HexAddress(oracle))?;
+                }
+                f.write_str("]")
+            }
+            Instructions::DeleteSender
+            | Instructions::Pause
+            | Instructions::Unpause
+            | Instructions::DeleteSenderPublic
+            | Instructions::DeleteOracle
+            | Instructions::RevokeDelegate
+            | Instructions::AcceptManager
+            | Instructions::ApproveAdminAction
+            | Instructions::ExecuteAdminAction
+            | Instructions::WithdrawTreasury
+            | Instructions::MigrateTokenAccount
+            | Instructions::SuspendSender
+            | Instructions::ReinstateSender
+            | Instructions::CreateEventLog => Ok(()),
+        }
+    }
+}
+
+/// Upper bound of compute units a transaction may request
+pub const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Compute units spent on every attesting sender: secp256k1 instruction
+/// lookup, sender account deserialization and address derivation
+const COMPUTE_UNITS_PER_SIGNER: u32 = 12_000;
+
+/// Compute units spent on every disbursement: token transfer and receipt creation
+const COMPUTE_UNITS_PER_TRANSFER: u32 = 25_000;
+/// Compute units spent on the token transfer of the transfer fee
+const COMPUTE_UNITS_PER_FEE: u32 = 10_000;
+/// Compute units spent on the claimable tokens program creating a missing recipient
+const COMPUTE_UNITS_PER_RECIPIENT_CREATION: u32 = 30_000;
+/// Compute units hashing a single Merkle proof node
+const COMPUTE_UNITS_PER_PROOF_NODE: u32 = 1_000;
+/// Compute units spent on every signature recovered by the program: the
+/// `secp256k1_recover` syscall and hashing of the message and public key
+const COMPUTE_UNITS_PER_RECOVERY: u32 = 30_000;
+/// Compute units the SPL Memo program spends logging the transfer memo
+const COMPUTE_UNITS_PER_MEMO: u32 = 5_000;
+
+/// Compute units the claimable tokens program may spend on the transfer recipient
+fn recipient_creation_units(transfer: &Transfer) -> u32 {
+    if transfer.create_recipient {
+        COMPUTE_UNITS_PER_RECIPIENT_CREATION
+    } else {
+        0
+    }
+}
+
+/// Compute units the SPL Memo program may spend on the transfer memo
+fn memo_units(transfer: &Transfer) -> u32 {
+    if transfer.memo {
+        COMPUTE_UNITS_PER_MEMO
+    } else {
+        0
+    }
+}
+
+impl Instructions {
+    /// Compute units to request for the instruction verified by `signers` senders
+    /// and additional bot oracles, the first bot oracle excluded. Estimates are rounded
+    /// up to leave a margin for the runtime
+    pub fn recommended_compute_units(&self, signers: usize) -> u32 {
+        let signers = signers as u32;
+        let units = match self {
+            Instructions::InitRewardManager(_) => 20_000,
+            Instructions::InitRewardManagerV2(_) => 30_000,
+            Instructions::CreateSender(_)
+            | Instructions::CreateOracle(_)
+            | Instructions::SetSpendLimit(_)
+            | Instructions::CreateDelegate(_)
+            | Instructions::CreateManagerMultisig(_)
+            | Instructions::SetOperatorSendersLimit(_)
+            | Instructions::ProposeAdminAction(_)
+            | Instructions::SetRecipientBlocked(_)
+            | Instructions::SetChallenge(_)
+            | Instructions::CreateEventLog
+            | Instructions::SetRateLimit(_) => 20_000,
+            Instructions::RotateSenderKey(_) => 20_000 + COMPUTE_UNITS_PER_SIGNER,
+            Instructions::UpdateSenderMetadata(_) => 10_000 + COMPUTE_UNITS_PER_SIGNER,
+            Instructions::DeleteSender
+            | Instructions::DeleteOracle
+            | Instructions::RevokeDelegate
+            | Instructions::ProposeManager(_)
+            | Instructions::AcceptManager
+            | Instructions::ApproveAdminAction
+            | Instructions::SetSenderWeight(_)
+            | Instructions::RepairSender(_)
+            | Instructions::SuspendSender
+            | Instructions::ReinstateSender
+            | Instructions::UpdateRewardManager(_)
+            | Instructions::Pause
+            | Instructions::Unpause
+            | Instructions::AnnounceTreasuryWithdrawal(_)
+            | Instructions::SetTransferFee(_) => 10_000,
+            Instructions::WithdrawTreasury | Instructions::MigrateTokenAccount => {
+                10_000 + COMPUTE_UNITS_PER_TRANSFER
+            }
+            // NOTE: admin actions run admin instructions needing at most 20_000
+            Instructions::ExecuteAdminAction => 40_000,
+            Instructions::AddSender(_) => 20_000 + signers * COMPUTE_UNITS_PER_SIGNER,
+            Instructions::DeleteSenderPublic => 10_000 + signers * COMPUTE_UNITS_PER_SIGNER,
+            // NOTE: +1 it's bot oracle
+            Instructions::Transfer(transfer) => {
+                10_000
+                    + COMPUTE_UNITS_PER_TRANSFER
+                    + COMPUTE_UNITS_PER_FEE
+                    + recipient_creation_units(transfer)
+                    + memo_units(transfer)
+                    + (signers + 1) * COMPUTE_UNITS_PER_SIGNER
+            }
+            Instructions::TransferToWallet(_) => {
+                10_000
+                    + COMPUTE_UNITS_PER_TRANSFER
+                    + COMPUTE_UNITS_PER_FEE
+                    + (signers + 1) * COMPUTE_UNITS_PER_SIGNER
+            }
+            Instructions::VerifyOnly(_) => 10_000 + (signers + 1) * COMPUTE_UNITS_PER_SIGNER,
+            Instructions::SubmitDistributionRoot(_) => {
+                20_000 + (signers + 1) * COMPUTE_UNITS_PER_SIGNER
+            }
+            Instructions::ClaimFromRoot(ClaimFromRoot { proof, .. }) => {
+                10_000
+                    + COMPUTE_UNITS_PER_TRANSFER
+                    + COMPUTE_UNITS_PER_FEE
+                    + proof.len() as u32 * COMPUTE_UNITS_PER_PROOF_NODE
+            }
+            Instructions::TransferBatch(TransferBatch { transfers }) => {
+                10_000
+                    + transfers.len() as u32 * (COMPUTE_UNITS_PER_TRANSFER + COMPUTE_UNITS_PER_FEE)
+                    + (signers + 1) * COMPUTE_UNITS_PER_SIGNER
+            }
+            // NOTE: every signature, bot oracle ones included, is recovered
+            Instructions::TransferWithSignatures(TransferWithSignatures {
+                transfer,
+                signatures,
+            }) => {
+                10_000
+                    + COMPUTE_UNITS_PER_TRANSFER
+                    + COMPUTE_UNITS_PER_FEE
+                    + recipient_creation_units(transfer)
+                    + memo_units(transfer)
+                    + signatures.len() as u32 * COMPUTE_UNITS_PER_RECOVERY
+            }
+        };
+
+        units.min(MAX_COMPUTE_UNITS)
+    }
+}
+
+/// Create `InitRewardManager` instruction
+#[allow(clippy::too_many_arguments)]
+pub fn init(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    token_account: &Pubkey,
+    mint: &Pubkey,
+    manager: &Pubkey,
+    token_program: &Pubkey,
+    claimable_tokens_program: &Pubkey,
+    min_votes: u8,
+) -> Result<Instruction, ProgramError> {
+    let init_data = Instructions::InitRewardManager(InitRewardManager {
+        min_votes,
+        claimable_tokens_program: *claimable_tokens_program,
+    });
+    let data = init_data.try_to_vec()?;
+
+    let (base, _) = get_base_address(program_id, reward_manager);
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new(*token_account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*manager, false),
+        AccountMeta::new_readonly(base, false),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `InitRewardManagerV2` instruction, both `reward_manager` and
+/// `token_account` must sign the transaction
+#[allow(clippy::too_many_arguments)]
+pub fn init_v2(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    token_account: &Pubkey,
+    mint: &Pubkey,
+    manager: &Pubkey,
+    funder: &Pubkey,
+    token_program: &Pubkey,
+    claimable_tokens_program: &Pubkey,
+    min_votes: u8,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::InitRewardManagerV2(InitRewardManager {
+        min_votes,
+        claimable_tokens_program: *claimable_tokens_program,
+    })
+    .try_to_vec()?;
+
+    let (base, _) = get_base_address(program_id, reward_manager);
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, true),
+        AccountMeta::new(*token_account, true),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*manager, false),
+        AccountMeta::new_readonly(base, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `CreateSender` instruction
+pub fn create_sender(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    funder_account: &Pubkey,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) -> Result<Instruction, ProgramError> {
+    let create_data = Instructions::CreateSender(CreateSender {
+        eth_address,
+        operator,
+    });
+    let data = create_data.try_to_vec()?;
+
+    let (base, _) = get_base_address(program_id, reward_manager);
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new_readonly(base, false),
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new(sender, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(
+            get_legacy_sender_address(program_id, reward_manager, &eth_address)?,
+            false,
+        ),
+        AccountMeta::new(
+            get_operator_address(program_id, reward_manager, &operator).0,
+            false,
+        ),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `DeleteSender` instruction
+pub fn delete_sender(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    refunder_account: &Pubkey,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) -> Result<Instruction, ProgramError> {
+    let delete_data = Instructions::DeleteSender;
+    let data = delete_data.try_to_vec()?;
+
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(sender, false),
+        AccountMeta::new(*refunder_account, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(
+            get_operator_address(program_id, reward_manager, &operator).0,
+            false,
+        ),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `AddSender` instruction
+pub fn add_sender<'a, I>(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    funder: &Pubkey,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+    signers: I,
+) -> Result<Instruction, ProgramError>
+where
+    I: IntoIterator<Item = &'a Pubkey>,
+{
+    let data = Instructions::AddSender(AddSender {
+        eth_address,
+        operator,
+    })
+    .try_to_vec()?;
+
+    let (base, _) = get_base_address(program_id, reward_manager);
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
+
+    let mut accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(base, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new(sender, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(
+            get_legacy_sender_address(program_id, reward_manager, &eth_address)?,
+            false,
+        ),
+        AccountMeta::new(
+            get_operator_address(program_id, reward_manager, &operator).0,
+            false,
+        ),
+    ];
+    let iter = signers.into_iter().map(|i| AccountMeta::new(*i, false));
+    accounts.extend(iter);
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `Transfer` instruction, `fee_account` is the reward manager one
+/// and may be any key while no transfer fee is set. Transfers creating the
+/// recipient need `recipient_creation_accounts` appended
+#[allow(clippy::too_many_arguments)]
+pub fn transfer<I>(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    recipient: &Pubkey,
+    vault_token_account: &Pubkey,
+    bot_oracle: &Pubkey,
+    funder: &Pubkey,
+    fee_account: &Pubkey,
+    senders: I,
+    params: Transfer,
+) -> Result<Instruction, ProgramError>
+where
+    I: IntoIterator<Item = Pubkey>,
+{
+    let data = Instructions::Transfer(Transfer {
+        amount: params.amount,
+        id: params.id.clone(),
+        eth_recipient: params.eth_recipient,
+        message_version: params.message_version,
+        create_recipient: params.create_recipient,
+        memo: params.memo,
+    })
+    .try_to_vec()?;
+
+    let transfer_acc_to_create =
+        get_transfer_account_address(program_id, reward_manager, &params.id)?;
+    let spend_counter = get_address_pair(
+        program_id,
+        reward_manager,
+        SPEND_COUNTER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let recipient_policy = get_address_pair(
+        program_id,
+        reward_manager,
+        RECIPIENT_POLICY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let challenge_registry = get_address_pair(
+        program_id,
+        reward_manager,
+        CHALLENGE_REGISTRY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let event_log = get_address_pair(
+        program_id,
+        reward_manager,
+        EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let rate_limiter = get_address_pair(
+        program_id,
+        reward_manager,
+        RATE_LIMITER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+
+    let mut accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(spend_counter.base.address, false),
+        AccountMeta::new(*recipient, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new_readonly(*bot_oracle, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new(transfer_acc_to_create, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(spend_counter.derive.address, false),
+        AccountMeta::new_readonly(recipient_policy.derive.address, false),
+        AccountMeta::new(*fee_account, false),
+        AccountMeta::new_readonly(challenge_registry.derive.address, false),
+        AccountMeta::new(event_log.derive.address, false),
+        AccountMeta::new(rate_limiter.derive.address, false),
+    ];
+    let iter = senders.into_iter().map(|i| AccountMeta::new(i, false));
+    accounts.extend(iter);
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `UpdateRewardManager` instruction
+pub fn update_reward_manager(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    params: UpdateRewardManager,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::UpdateRewardManager(params).try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `Pause` instruction
+pub fn pause(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::Pause.try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `Unpause` instruction
+pub fn unpause(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::Unpause.try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `TransferBatch` instruction, recipients are derived with the default
+/// claimable tokens program
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_batch<I>(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    vault_token_account: &Pubkey,
+    mint: &Pubkey,
+    bot_oracle: &Pubkey,
+    funder: &Pubkey,
+    fee_account: &Pubkey,
+    senders: I,
+    transfers: Vec<Transfer>,
+) -> Result<Instruction, ProgramError>
+where
+    I: IntoIterator<Item = Pubkey>,
+{
+    let spend_counter = get_address_pair(
+        program_id,
+        reward_manager,
+        SPEND_COUNTER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let recipient_policy = get_address_pair(
+        program_id,
+        reward_manager,
+        RECIPIENT_POLICY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let event_log = get_address_pair(
+        program_id,
+        reward_manager,
+        EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let rate_limiter = get_address_pair(
+        program_id,
+        reward_manager,
+        RATE_LIMITER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let challenge_registry = get_address_pair(
+        program_id,
+        reward_manager,
+        CHALLENGE_REGISTRY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+
+    let mut accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(spend_counter.base.address, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new_readonly(*bot_oracle, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(spend_counter.derive.address, false),
+        AccountMeta::new_readonly(recipient_policy.derive.address, false),
+        AccountMeta::new(*fee_account, false),
+        AccountMeta::new_readonly(challenge_registry.derive.address, false),
+        AccountMeta::new(event_log.derive.address, false),
+        AccountMeta::new(rate_limiter.derive.address, false),
+    ];
+    for transfer in transfers.iter() {
+        let recipient = claimable_tokens::utils::program::get_address_pair(
+            &claimable_tokens::id(),
+            mint,
+            transfer.eth_recipient,
+        )?;
+        let transfer_acc_to_create =
+            get_transfer_account_address(program_id, reward_manager, &transfer.id)?;
+        accounts.push(AccountMeta::new(recipient.derive.address, false));
+        accounts.push(AccountMeta::new(transfer_acc_to_create, false));
+    }
+    let iter = senders.into_iter().map(|i| AccountMeta::new(i, false));
+    accounts.extend(iter);
+
+    let data = Instructions::TransferBatch(TransferBatch { transfers }).try_to_vec()?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `DeleteSenderPublic` instruction
+pub fn delete_sender_public<'a, I>(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    refunder_account: &Pubkey,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+    signers: I,
+) -> Result<Instruction, ProgramError>
+where
+    I: IntoIterator<Item = &'a Pubkey>,
+{
+    let data = Instructions::DeleteSenderPublic.try_to_vec()?;
+
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
+
+    let mut accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new(sender, false),
+        AccountMeta::new(*refunder_account, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new(
+            get_operator_address(program_id, reward_manager, &operator).0,
+            false,
+        ),
+    ];
+    let iter = signers.into_iter().map(|i| AccountMeta::new(*i, false));
+    accounts.extend(iter);
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `CreateOracle` instruction
+pub fn create_oracle(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    funder_account: &Pubkey,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::CreateOracle(CreateOracle {
+        eth_address,
+        operator,
+    })
+    .try_to_vec()?;
+
+    let pair = get_address_pair(
+        program_id,
+        reward_manager,
+        [ORACLE_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+    )?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new_readonly(pair.base.address, false),
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new(pair.derive.address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `DeleteOracle` instruction
+pub fn delete_oracle(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    refunder_account: &Pubkey,
+    eth_address: EthereumAddress,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::DeleteOracle.try_to_vec()?;
+
+    let pair = get_address_pair(
+        program_id,
+        reward_manager,
+        [ORACLE_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+    )?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(pair.derive.address, false),
+        AccountMeta::new(*refunder_account, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `SetSpendLimit` instruction
+pub fn set_spend_limit(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    funder_account: &Pubkey,
+    params: SetSpendLimit,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::SetSpendLimit(params).try_to_vec()?;
+
+    let pair = get_address_pair(
+        program_id,
+        reward_manager,
+        SPEND_COUNTER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new_readonly(pair.base.address, false),
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new(pair.derive.address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `SubmitDistributionRoot` instruction
+pub fn submit_distribution_root<I>(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    bot_oracle: &Pubkey,
+    funder: &Pubkey,
+    senders: I,
+    params: SubmitDistributionRoot,
+) -> Result<Instruction, ProgramError>
+where
+    I: IntoIterator<Item = Pubkey>,
+{
+    let distribution = get_address_pair(
+        program_id,
+        reward_manager,
+        [DISTRIBUTION_SEED_PREFIX.as_bytes(), params.id.as_bytes()].concat(),
+    )?;
+    let rate_limiter = get_address_pair(
+        program_id,
+        reward_manager,
+        RATE_LIMITER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(distribution.base.address, false),
+        AccountMeta::new_readonly(*bot_oracle, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new(distribution.derive.address, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(rate_limiter.derive.address, false),
+    ];
+    let iter = senders.into_iter().map(|i| AccountMeta::new(i, false));
+    accounts.extend(iter);
+
+    let data = Instructions::SubmitDistributionRoot(params).try_to_vec()?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `ClaimFromRoot` instruction, the recipient is derived with the default
+/// claimable tokens program
+pub fn claim_from_root(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    vault_token_account: &Pubkey,
+    mint: &Pubkey,
+    funder: &Pubkey,
+    fee_account: &Pubkey,
+    distribution_id: &str,
+    params: ClaimFromRoot,
+) -> Result<Instruction, ProgramError> {
+    let distribution = get_address_pair(
+        program_id,
+        reward_manager,
+        [
+            DISTRIBUTION_SEED_PREFIX.as_bytes(),
+            distribution_id.as_bytes(),
+        ]
+        .concat(),
+    )?;
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        mint,
+        params.transfer.eth_recipient,
+    )?;
+    let transfer_acc_to_create =
+        get_transfer_account_address(program_id, reward_manager, &params.transfer.id)?;
+    let spend_counter = get_address_pair(
+        program_id,
+        reward_manager,
+        SPEND_COUNTER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let recipient_policy = get_address_pair(
+        program_id,
+        reward_manager,
+        RECIPIENT_POLICY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let event_log = get_address_pair(
+        program_id,
+        reward_manager,
+        EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let challenge_registry = get_address_pair(
+        program_id,
+        reward_manager,
+        CHALLENGE_REGISTRY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(distribution.base.address, false),
+        AccountMeta::new_readonly(distribution.derive.address, false),
+        AccountMeta::new(recipient.derive.address, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new(transfer_acc_to_create, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(spend_counter.derive.address, false),
+        AccountMeta::new_readonly(recipient_policy.derive.address, false),
+        AccountMeta::new(*fee_account, false),
+        AccountMeta::new_readonly(challenge_registry.derive.address, false),
+        AccountMeta::new(event_log.derive.address, false),
+    ];
+
+    let data = Instructions::ClaimFromRoot(params).try_to_vec()?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `RotateSenderKey` instruction, `sender` is the current sender account
+/// which may still be at its legacy address
+pub fn rotate_sender_key(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    sender: &Pubkey,
+    funder: &Pubkey,
+    new_eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) -> Result<Instruction, ProgramError> {
+    let (new_sender, _) = get_sender_address(program_id, reward_manager, &new_eth_address);
+    let legacy_new_sender =
+        get_legacy_sender_address(program_id, reward_manager, &new_eth_address)?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new(*sender, false),
+        AccountMeta::new(new_sender, false),
+        AccountMeta::new_readonly(legacy_new_sender, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new(
+            get_operator_address(program_id, reward_manager, &operator).0,
+            false,
+        ),
+    ];
+
+    let data = Instructions::RotateSenderKey(RotateSenderKey { new_eth_address }).try_to_vec()?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `TransferToWallet` instruction, the recipient is the wallet's
+/// associated token account for `mint`
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_to_wallet<I>(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    vault_token_account: &Pubkey,
+    mint: &Pubkey,
+    bot_oracle: &Pubkey,
+    funder: &Pubkey,
+    fee_account: &Pubkey,
+    senders: I,
+    params: TransferToWallet,
+) -> Result<Instruction, ProgramError>
+where
+    I: IntoIterator<Item = Pubkey>,
+{
+    let recipient = get_associated_token_address(&params.wallet, mint);
+    let transfer_acc_to_create =
+        get_transfer_account_address(program_id, reward_manager, &params.id)?;
+    let spend_counter = get_address_pair(
+        program_id,
+        reward_manager,
+        SPEND_COUNTER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let challenge_registry = get_address_pair(
+        program_id,
+        reward_manager,
+        CHALLENGE_REGISTRY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let event_log = get_address_pair(
+        program_id,
+        reward_manager,
+        EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let rate_limiter = get_address_pair(
+        program_id,
+        reward_manager,
+        RATE_LIMITER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+
+    let mut accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(spend_counter.base.address, false),
+        AccountMeta::new(recipient, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new_readonly(*bot_oracle, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new(transfer_acc_to_create, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(spend_counter.derive.address, false),
+        AccountMeta::new(*fee_account, false),
+        AccountMeta::new_readonly(challenge_registry.derive.address, false),
+        AccountMeta::new(event_log.derive.address, false),
+        AccountMeta::new(rate_limiter.derive.address, false),
+    ];
+    accounts.extend(senders.into_iter().map(|i| AccountMeta::new(i, false)));
+
+    let data = Instructions::TransferToWallet(params).try_to_vec()?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `CreateDelegate` instruction
+pub fn create_delegate(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    funder_account: &Pubkey,
+    params: CreateDelegate,
+) -> Result<Instruction, ProgramError> {
+    let (delegate_account, _) = get_delegate_address(program_id, reward_manager, &params.delegate);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new(delegate_account, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    let data = Instructions::CreateDelegate(params).try_to_vec()?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `RevokeDelegate` instruction
+pub fn revoke_delegate(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    refunder_account: &Pubkey,
+    delegate: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (delegate_account, _) = get_delegate_address(program_id, reward_manager, delegate);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(delegate_account, false),
+        AccountMeta::new(*refunder_account, false),
+    ];
+
+    let data = Instructions::RevokeDelegate.try_to_vec()?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Appends the account of `delegate` to an admin instruction built with `delegate`
+/// in place of the manager account
+pub fn delegated(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    delegate: &Pubkey,
+    mut instruction: Instruction,
+) -> Instruction {
+    let (delegate_account, _) = get_delegate_address(program_id, reward_manager, delegate);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(delegate_account, false));
+    instruction
+}
+
+/// Create `ProposeManager` instruction
+pub fn propose_manager(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    new_manager: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::ProposeManager(ProposeManager {
+        new_manager: *new_manager,
+    })
+    .try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `AcceptManager` instruction
+pub fn accept_manager(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    new_manager: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::AcceptManager.try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*new_manager, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `CreateManagerMultisig` instruction
+pub fn create_manager_multisig(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    funder_account: &Pubkey,
+    params: CreateManagerMultisig,
+) -> Result<Instruction, ProgramError> {
+    let (multisig, _) = get_multisig_address(program_id, reward_manager);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new(multisig, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    let data = Instructions::CreateManagerMultisig(params).try_to_vec()?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `ProposeAdminAction` instruction proposing `instruction` built with
+/// the multisig address as the manager account, `index` is the multisig proposal count
+pub fn propose_admin_action(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    proposer: &Pubkey,
+    funder_account: &Pubkey,
+    index: u64,
+    instruction: &Instruction,
+) -> Result<Instruction, ProgramError> {
+    let (multisig, _) = get_multisig_address(program_id, reward_manager);
+    let (admin_action, _) = get_admin_action_address(program_id, &multisig, index);
+
+    let data = Instructions::ProposeAdminAction(ProposeAdminAction {
+        accounts: instruction
+            .accounts
+            .iter()
+            .map(|meta| AdminActionAccount {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: instruction.data.clone(),
+    })
+    .try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new(multisig, false),
+        AccountMeta::new(admin_action, false),
+        AccountMeta::new_readonly(*proposer, true),
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `ApproveAdminAction` instruction
+pub fn approve_admin_action(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    approver: &Pubkey,
+    admin_action: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (multisig, _) = get_multisig_address(program_id, reward_manager);
+
+    let data = Instructions::ApproveAdminAction.try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(multisig, false),
+        AccountMeta::new(*admin_action, false),
+        AccountMeta::new_readonly(*approver, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `ExecuteAdminAction` instruction running the proposed `instruction`
+pub fn execute_admin_action(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    admin_action: &Pubkey,
+    proposer: &Pubkey,
+    instruction: &Instruction,
+) -> Result<Instruction, ProgramError> {
+    let (multisig, _) = get_multisig_address(program_id, reward_manager);
+
+    let data = Instructions::ExecuteAdminAction.try_to_vec()?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(multisig, false),
+        AccountMeta::new(*admin_action, false),
+        AccountMeta::new(*proposer, false),
+        AccountMeta::new_readonly(*program_id, false),
+    ];
+    // NOTE: the program signs for the multisig
+    accounts.extend(instruction.accounts.iter().map(|meta| AccountMeta {
+        pubkey: meta.pubkey,
+        is_signer: meta.is_signer && meta.pubkey != multisig,
+        is_writable: meta.is_writable,
+    }));
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `SetSenderWeight` instruction
+pub fn set_sender_weight(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    eth_address: EthereumAddress,
+    weight: u16,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::SetSenderWeight(SetSenderWeight { weight }).try_to_vec()?;
+
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(sender, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `SetOperatorSendersLimit` instruction
+pub fn set_operator_senders_limit(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    funder_account: &Pubkey,
+    operator: EthereumAddress,
+    max_senders: u8,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::SetOperatorSendersLimit(SetOperatorSendersLimit {
+        operator,
+        max_senders,
+    })
+    .try_to_vec()?;
+
+    let (operator_account, _) = get_operator_address(program_id, reward_manager, &operator);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new(operator_account, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `TransferWithSignatures` instruction, `fee_account` is the reward manager
+/// one and may be any key while no transfer fee is set. Transfers creating the
+/// recipient need `recipient_creation_accounts` appended
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_with_signatures<I>(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    recipient: &Pubkey,
+    vault_token_account: &Pubkey,
+    bot_oracle: &Pubkey,
+    funder: &Pubkey,
+    fee_account: &Pubkey,
+    senders: I,
+    params: TransferWithSignatures,
+) -> Result<Instruction, ProgramError>
+where
+    I: IntoIterator<Item = Pubkey>,
+{
+    let transfer_acc_to_create =
+        get_transfer_account_address(program_id, reward_manager, &params.transfer.id)?;
+    let spend_counter = get_address_pair(
+        program_id,
+        reward_manager,
+        SPEND_COUNTER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let recipient_policy = get_address_pair(
+        program_id,
+        reward_manager,
+        RECIPIENT_POLICY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let challenge_registry = get_address_pair(
+        program_id,
+        reward_manager,
+        CHALLENGE_REGISTRY_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let event_log = get_address_pair(
+        program_id,
+        reward_manager,
+        EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+    let rate_limiter = get_address_pair(
+        program_id,
+        reward_manager,
+        RATE_LIMITER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+
+    let data = Instructions::TransferWithSignatures(params).try_to_vec()?;
+
+    let mut accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(spend_counter.base.address, false),
+        AccountMeta::new(*recipient, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new_readonly(*bot_oracle, false),
+        AccountMeta::new(*funder, true),
+        AccountMeta::new(transfer_acc_to_create, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(spend_counter.derive.address, false),
+        AccountMeta::new_readonly(recipient_policy.derive.address, false),
+        AccountMeta::new(*fee_account, false),
+        AccountMeta::new_readonly(challenge_registry.derive.address, false),
+        AccountMeta::new(event_log.derive.address, false),
+        AccountMeta::new(rate_limiter.derive.address, false),
+    ];
+    let iter = senders.into_iter().map(|i| AccountMeta::new(i, false));
+    accounts.extend(iter);
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Accounts to append to the `Transfer` and `TransferWithSignatures` instructions
+/// whose transfer sets `create_recipient`
+pub fn recipient_creation_accounts(
+    claimable_tokens_program: &Pubkey,
+    mint: &Pubkey,
+    eth_recipient: EthereumAddress,
+) -> Result<Vec<AccountMeta>, ProgramError> {
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        claimable_tokens_program,
+        mint,
+        eth_recipient,
+    )?;
+
+    Ok(vec![
+        AccountMeta::new_readonly(*claimable_tokens_program, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(recipient.base.address, false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ])
+}
+
+/// Account to append to the `Transfer` and `TransferWithSignatures` instructions
+/// whose transfer sets `memo`, after the recipient creation accounts
+pub fn memo_account() -> AccountMeta {
+    AccountMeta::new_readonly(spl_memo::id(), false)
+}
+
+/// Create `AnnounceTreasuryWithdrawal` instruction
+pub fn announce_treasury_withdrawal(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    params: AnnounceTreasuryWithdrawal,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::AnnounceTreasuryWithdrawal(params).try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `WithdrawTreasury` instruction
+pub fn withdraw_treasury(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    vault_token_account: &Pubkey,
+    destination: &Pubkey,
+    manager_account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::WithdrawTreasury.try_to_vec()?;
+    let (reward_manager_authority, _) = get_base_address(program_id, reward_manager);
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(reward_manager_authority, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*destination, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
 }
 
-/// Instruction definition
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
-pub enum Instructions {
-    ///   Initialize `Reward Manager`
-    ///
-    ///   0. `[w]` Account that will be initialized as `Reward Manager`.
-    ///   1. `[w]` The new account that to be initialized as the token account.
-    ///   2. `[]`  Mint with which the new token account will be associated on initialization.
-    ///   3. `[]`  Manager account to be set as the `Reward Manager`.
-    ///   4. `[]`  `Reward Manager` authority.
-    ///   5. `[]`  Token program
-    ///   6. `[]`  Rent sysvar
-    InitRewardManager(InitRewardManager),
+/// Create `MigrateTokenAccount` instruction
+pub fn migrate_token_account(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    vault_token_account: &Pubkey,
+    new_token_account: &Pubkey,
+    manager_account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::MigrateTokenAccount.try_to_vec()?;
+    let (reward_manager_authority, _) = get_base_address(program_id, reward_manager);
 
-    ///   Admin method creating new authorized sender
-    ///
-    ///   0. `[]`  `Reward Manager`
-    ///   1. `[s]` Manager account
-    ///   2. `[]`  `Reward Manager` authority
-    ///   3. `[]`  Funder account
-    ///   4. `[]`  Addidable sender
-    ///   5. `[]`  System program id
-    ///   6. `[]`  Rent sysvar
-    CreateSender(CreateSender),
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(reward_manager_authority, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*new_token_account, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
 
-    ///   Admin method removing sender
-    ///  
-    ///   0. `[]`   `Reward Manager`
-    ///   1. `[s]`  Manager account
-    ///   2. `[]`   `Reward Manager` authority
-    ///   3. `[w]`  Removed sender
-    ///   4. `[]`   Refunder account
-    DeleteSender,
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
 
-    ///
-    ///
-    /// 0. `[r]`  reward_manager
-    /// 1. `[r]`  `Reward Manager` authority
-    /// 2. `[ws]` funder
-    /// 3. `[w]`  new_sender
-    /// 4. `[r]`  old_sender_0
-    /// ... Bunch of old senders which prove adding new one
-    /// n. `[r]`  old_sender_n
-    AddSender(AddSender),
+/// Create `SuspendSender` instruction
+pub fn suspend_sender(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    eth_address: EthereumAddress,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::SuspendSender.try_to_vec()?;
 
-    ///   Transfer tokens to pointed receiver
-    ///
-    ///   0. `[]` `Reward Manager`
-    ///   1. `[]` `Reward Manager` authority. Program account
-    ///   2. `[w]` Recipient. Key generated from Eth address
-    ///   3. `[w]` Vault with all the "reward" tokens. Program is authority
-    ///   4. `[]` Bot oracle
-    ///   5. `[sw]` Funder. Account which pay for new account creation
-    ///   6. `[w]` Transfer account to create
-    ///   7. `[]` Sysvar instruction id
-    ///   8. `[]` SPL Token id
-    ///   9. `[]` System program
-    ///   10. `[]` Senders
-    ///   ...
-    ///   n. `[]`
-    Transfer(Transfer),
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(sender, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
 }
 
-/// Create `InitRewardManager` instruction
-pub fn init(
+/// Create `ReinstateSender` instruction
+pub fn reinstate_sender(
     program_id: &Pubkey,
     reward_manager: &Pubkey,
-    token_account: &Pubkey,
-    mint: &Pubkey,
-    manager: &Pubkey,
-    min_votes: u8,
+    manager_account: &Pubkey,
+    eth_address: EthereumAddress,
 ) -> Result<Instruction, ProgramError> {
-    let init_data = Instructions::InitRewardManager(InitRewardManager { min_votes });
-    let data = init_data.try_to_vec()?;
+    let data = Instructions::ReinstateSender.try_to_vec()?;
 
-    let (base, _) = get_base_address(program_id, reward_manager);
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
 
     let accounts = vec![
-        AccountMeta::new(*reward_manager, false),
-        AccountMeta::new(*token_account, false),
-        AccountMeta::new_readonly(*mint, false),
-        AccountMeta::new_readonly(*manager, false),
-        AccountMeta::new_readonly(base, false),
-        AccountMeta::new_readonly(spl_token::id(), false),
-        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(sender, false),
     ];
+
     Ok(Instruction {
         program_id: *program_id,
         accounts,
@@ -142,25 +2543,20 @@ pub fn init(
     })
 }
 
-/// Create `CreateSender` instruction
-pub fn create_sender(
+/// Create `SetRecipientBlocked` instruction
+pub fn set_recipient_blocked(
     program_id: &Pubkey,
     reward_manager: &Pubkey,
     manager_account: &Pubkey,
     funder_account: &Pubkey,
-    eth_address: EthereumAddress,
-    operator: EthereumAddress,
+    params: SetRecipientBlocked,
 ) -> Result<Instruction, ProgramError> {
-    let create_data = Instructions::CreateSender(CreateSender {
-        eth_address,
-        operator,
-    });
-    let data = create_data.try_to_vec()?;
+    let data = Instructions::SetRecipientBlocked(params).try_to_vec()?;
 
     let pair = get_address_pair(
         program_id,
         reward_manager,
-        [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+        RECIPIENT_POLICY_SEED_PREFIX.as_bytes().to_vec(),
     )?;
 
     let accounts = vec![
@@ -180,29 +2576,30 @@ pub fn create_sender(
     })
 }
 
-/// Create `DeleteSender` instruction
-pub fn delete_sender(
+/// Create `SetChallenge` instruction
+pub fn set_challenge(
     program_id: &Pubkey,
     reward_manager: &Pubkey,
     manager_account: &Pubkey,
-    refunder_account: &Pubkey,
-    eth_address: EthereumAddress,
+    funder_account: &Pubkey,
+    params: SetChallenge,
 ) -> Result<Instruction, ProgramError> {
-    let delete_data = Instructions::DeleteSender;
-    let data = delete_data.try_to_vec()?;
+    let data = Instructions::SetChallenge(params).try_to_vec()?;
 
     let pair = get_address_pair(
         program_id,
         reward_manager,
-        [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+        CHALLENGE_REGISTRY_SEED_PREFIX.as_bytes().to_vec(),
     )?;
 
     let accounts = vec![
         AccountMeta::new_readonly(*reward_manager, false),
         AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new_readonly(pair.base.address, false),
+        AccountMeta::new(*funder_account, true),
         AccountMeta::new(pair.derive.address, false),
-        AccountMeta::new(*refunder_account, false),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
     ];
 
     Ok(Instruction {
@@ -212,43 +2609,63 @@ pub fn delete_sender(
     })
 }
 
-/// Create `AddSender` instruction
-pub fn add_sender<'a, I>(
+/// Create `CreateEventLog` instruction
+pub fn create_event_log(
     program_id: &Pubkey,
     reward_manager: &Pubkey,
-    funder: &Pubkey,
-    eth_address: EthereumAddress,
-    operator: EthereumAddress,
-    signers: I,
-) -> Result<Instruction, ProgramError>
-where
-    I: IntoIterator<Item = &'a Pubkey>,
-{
-    let data = Instructions::AddSender(AddSender {
-        eth_address,
-        operator,
-    })
-    .try_to_vec()?;
+    manager_account: &Pubkey,
+    funder_account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::CreateEventLog.try_to_vec()?;
 
     let pair = get_address_pair(
         program_id,
         reward_manager,
-        [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+        EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
     )?;
 
-    let mut accounts = vec![
+    let accounts = vec![
         AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
         AccountMeta::new_readonly(pair.base.address, false),
-        AccountMeta::new(*funder, true),
+        AccountMeta::new(*funder_account, true),
         AccountMeta::new(pair.derive.address, false),
-        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
         AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `SetRateLimit` instruction
+pub fn set_rate_limit(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
+    funder_account: &Pubkey,
+    params: SetRateLimit,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::SetRateLimit(params).try_to_vec()?;
+
+    let pair = get_address_pair(
+        program_id,
+        reward_manager,
+        RATE_LIMITER_SEED_PREFIX.as_bytes().to_vec(),
+    )?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new_readonly(pair.base.address, false),
+        AccountMeta::new(*funder_account, true),
+        AccountMeta::new(pair.derive.address, false),
         AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
     ];
-    let iter = signers
-        .into_iter()
-        .map(|i| AccountMeta::new_readonly(*i, false));
-    accounts.extend(iter);
 
     Ok(Instruction {
         program_id: *program_id,
@@ -257,44 +2674,105 @@ where
     })
 }
 
-/// Create `Transfer` instruction
-pub fn transfer<I>(
+/// Create `RepairSender` instruction
+pub fn repair_sender(
     program_id: &Pubkey,
     reward_manager: &Pubkey,
-    recipient: &Pubkey,
+    manager_account: &Pubkey,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::RepairSender(RepairSender {
+        eth_address,
+        operator,
+    })
+    .try_to_vec()?;
+
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
+    let (operator_account, _) = get_operator_address(program_id, reward_manager, &operator);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new(sender, false),
+        AccountMeta::new_readonly(operator_account, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `UpdateSenderMetadata` instruction, it must follow the secp256k1
+/// instruction of the sender signature
+pub fn update_sender_metadata(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    eth_address: EthereumAddress,
+    endpoint_hash: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let data =
+        Instructions::UpdateSenderMetadata(UpdateSenderMetadata { endpoint_hash }).try_to_vec()?;
+
+    let (sender, _) = get_sender_address(program_id, reward_manager, &eth_address);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_manager, false),
+        AccountMeta::new(sender, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `SetTransferFee` instruction
+pub fn set_transfer_fee(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    manager_account: &Pubkey,
     vault_token_account: &Pubkey,
+    fee_account: &Pubkey,
+    params: SetTransferFee,
+) -> Result<Instruction, ProgramError> {
+    let data = Instructions::SetTransferFee(params).try_to_vec()?;
+
+    let accounts = vec![
+        AccountMeta::new(*reward_manager, false),
+        AccountMeta::new_readonly(*manager_account, true),
+        AccountMeta::new_readonly(*vault_token_account, false),
+        AccountMeta::new_readonly(*fee_account, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Create `VerifyOnly` instruction
+pub fn verify_only<I>(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
     bot_oracle: &Pubkey,
-    funder: &Pubkey,
     senders: I,
     params: Transfer,
 ) -> Result<Instruction, ProgramError>
 where
     I: IntoIterator<Item = Pubkey>,
 {
-    let data = Instructions::Transfer(Transfer {
-        amount: params.amount,
-        id: params.id.clone(),
-        eth_recipient: params.eth_recipient,
-    })
-    .try_to_vec()?;
-
-    let transfer_acc_to_create = get_address_pair(
-        program_id,
-        reward_manager,
-        [TRANSFER_SEED_PREFIX.as_bytes().as_ref(), params.id.as_ref()].concat(),
-    )?;
+    let data = Instructions::VerifyOnly(params).try_to_vec()?;
 
     let mut accounts = vec![
         AccountMeta::new_readonly(*reward_manager, false),
-        AccountMeta::new_readonly(transfer_acc_to_create.base.address, false),
-        AccountMeta::new(*recipient, false),
-        AccountMeta::new(*vault_token_account, false),
         AccountMeta::new_readonly(*bot_oracle, false),
-        AccountMeta::new(*funder, true),
-        AccountMeta::new(transfer_acc_to_create.derive.address, false),
         AccountMeta::new_readonly(sysvar::instructions::id(), false),
-        AccountMeta::new_readonly(spl_token::id(), false),
-        AccountMeta::new_readonly(system_program::id(), false),
     ];
     let iter = senders
         .into_iter()
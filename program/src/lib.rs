@@ -1,13 +1,24 @@
-#![feature(trait_alias)]
 #![deny(missing_docs)]
 
 //! Audius Reward Manager program
+//!
+//! Programs invoking the reward manager depend on this crate with the
+//! `no-entrypoint` feature, which leaves out its entrypoint.
 
 pub mod error;
+pub mod event;
+#[cfg(all(feature = "idl", not(target_arch = "bpf")))]
+pub mod idl;
 pub mod instruction;
+#[cfg(feature = "verbose-logs")]
+pub mod log;
 pub mod processor;
 pub mod state;
 pub mod utils;
+pub mod view;
+
+#[cfg(all(feature = "sdk", not(target_arch = "bpf")))]
+pub mod sdk;
 
 /// Current program version
 pub const PROGRAM_VERSION: u8 = 1;
@@ -18,4 +29,6 @@ pub mod entrypoint;
 // Export current sdk types for downstream users building with a different sdk version
 pub use solana_program;
 
+pub use utils::get_transfer_account_address;
+
 solana_program::declare_id!("H9uadnVJwp9dd8UBwsCcyQvSQKPKyPizpKhP6DQo3Un4");
@@ -0,0 +1,15 @@
+//! Program log lines with the decoded instruction parameters, compiled in
+//! with the `verbose-logs` feature
+
+use crate::instruction::Instructions;
+use solana_program::msg;
+
+pub use crate::utils::HexAddress;
+
+/// Logs the parameters of the instruction, instructions without parameters log nothing
+pub fn log_params(instruction: &Instructions) {
+    let params = instruction.params().to_string();
+    if !params.is_empty() {
+        msg!("{}", params);
+    }
+}
@@ -1,18 +1,33 @@
 //! Program state processor
 
 use crate::{
-    error::AudiusProgramError,
-    instruction::{AddSender, CreateSender, InitRewardManager, Instructions, Transfer},
+    error::{to_audius_program_error, AudiusProgramError},
+    event::Event,
+    instruction::{
+        decode, AddSender, AnnounceTreasuryWithdrawal, ClaimFromRoot, CreateDelegate,
+        CreateManagerMultisig, CreateOracle, CreateSender, InitRewardManager, Instructions,
+        ProposeAdminAction, ProposeManager, RecoverableSignature, RepairSender, RotateSenderKey,
+        SetChallenge, SetOperatorSendersLimit, SetRateLimit, SetRecipientBlocked, SetSenderWeight,
+        SetSpendLimit, SetTransferFee, SubmitDistributionRoot, Transfer, TransferBatch,
+        TransferToWallet, TransferWithSignatures, UpdateRewardManager, UpdateSenderMetadata,
+    },
     is_owner,
-    state::{RewardManager, SenderAccount},
+    state::{
+        AdminAction, ChallengeRegistry, DelegateAccount, DistributionRoot, EventLog, EventLogEntry,
+        ManagerMultisig, OperatorAccount, OracleAccount, RateLimiter, RecipientPolicy,
+        RewardManager, SenderAccount, SenderStatus, SpendCounter, TransferReceipt,
+        MANAGER_CHANGE_DELAY, MAX_MULTISIG_SIGNERS, MAX_OPERATOR_SENDERS, MAX_TRANSFER_FEE_BPS,
+    },
     utils::*,
+    view::{is_funded, is_transfer_disbursed},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::next_account_info,
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::ProgramResult,
-    instruction::Instruction,
+    instruction::{AccountMeta, Instruction},
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
@@ -23,16 +38,93 @@ use solana_program::{
     system_instruction, sysvar,
     sysvar::Sysvar,
 };
+use spl_associated_token_account::get_associated_token_address;
 use spl_token::state::Account as TokenAccount;
+use std::collections::BTreeSet;
 
 /// Sender program account seed
 pub const SENDER_SEED_PREFIX: &str = "S_";
+/// Bot oracle program account seed
+pub const ORACLE_SEED_PREFIX: &str = "O_";
 /// Transfer program account seed
 pub const TRANSFER_SEED_PREFIX: &str = "T_";
-/// Transfer account balance
-pub const TRANSFER_ACC_BALANCE: u8 = 1;
-/// Transfer account space
-pub const TRANSFER_ACC_SPACE: u8 = 0;
+/// Spend counter program account seed
+pub const SPEND_COUNTER_SEED_PREFIX: &str = "C_";
+/// Distribution root program account seed
+pub const DISTRIBUTION_SEED_PREFIX: &str = "R_";
+/// Delegate program account seed
+pub const DELEGATE_SEED_PREFIX: &str = "D_";
+/// Manager multisig program account seed
+pub const MULTISIG_SEED_PREFIX: &str = "M_";
+/// Admin action program account seed
+pub const ADMIN_ACTION_SEED_PREFIX: &str = "A_";
+/// Operator program account seed
+pub const OPERATOR_SEED_PREFIX: &str = "P_";
+/// Recipient policy program account seed
+pub const RECIPIENT_POLICY_SEED_PREFIX: &str = "B_";
+/// Challenge registry program account seed
+pub const CHALLENGE_REGISTRY_SEED_PREFIX: &str = "H_";
+/// Event log program account seed
+pub const EVENT_LOG_SEED_PREFIX: &str = "E_";
+/// Rate limiter program account seed
+pub const RATE_LIMITER_SEED_PREFIX: &str = "L_";
+/// Prefix of the messages signed to approve a batch of transfers
+pub const TRANSFER_BATCH_MESSAGE_PREFIX: &str = "transferBatch";
+/// Prefix of the message senders sign to remove a sender
+pub const DELETE_SENDER_MESSAGE_PREFIX: &str = "deleteSender";
+/// Prefix of the message a sender signs to move to a new Ethereum address,
+/// followed by the reward manager, the sender attestation count and the new address
+pub const ROTATE_SENDER_MESSAGE_PREFIX: &str = "rotateSender";
+/// Prefix of the message a sender signs to update its endpoint metadata
+pub const SENDER_METADATA_MESSAGE_PREFIX: &str = "senderMetadata";
+/// Prefix of the messages signed to approve a distribution Merkle root
+pub const DISTRIBUTION_ROOT_MESSAGE_PREFIX: &str = "distributionRoot";
+/// Prefix of the message bot oracle signs to approve a transfer to a Solana wallet
+pub const WALLET_TRANSFER_MESSAGE_PREFIX: &str = "walletTransfer";
+/// Leading byte of the `MessageVersion::V2` transfer messages
+pub const TRANSFER_MESSAGE_V2_VERSION: u8 = 2;
+
+/// Where the attestations of a transfer are passed
+enum Attestations<'a, 'b> {
+    /// secp256k1 instructions preceding the current one, read through the instructions sysvar
+    SecpInstructions(&'a AccountInfo<'b>),
+    /// Signatures in the instruction data, recovered by the program
+    Signatures(Vec<RecoverableSignature>),
+}
+
+/// Accounts the claimable tokens program creates a missing transfer recipient with
+struct RecipientCreation<'a, 'b> {
+    claimable_tokens_program: &'a AccountInfo<'b>,
+    mint: &'a AccountInfo<'b>,
+    claimable_authority: &'a AccountInfo<'b>,
+    rent: &'a AccountInfo<'b>,
+    spl_token_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> RecipientCreation<'a, 'b> {
+    /// Splits the creation accounts off the end of the remaining transfer accounts
+    fn split_off(
+        accounts: &mut Vec<&'a AccountInfo<'b>>,
+        spl_token_program: &'a AccountInfo<'b>,
+        system_program: &'a AccountInfo<'b>,
+    ) -> Result<Self, ProgramError> {
+        let at = accounts
+            .len()
+            .checked_sub(4)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let creation = accounts.split_off(at);
+
+        Ok(Self {
+            claimable_tokens_program: creation[0],
+            mint: creation[1],
+            claimable_authority: creation[2],
+            rent: creation[3],
+            spl_token_program,
+            system_program,
+        })
+    }
+}
 
 /// Program state handler.
 pub struct Processor;
@@ -42,11 +134,266 @@ impl Processor {
     pub fn transfer_all(source: &AccountInfo, receiver: &AccountInfo) -> Result<(), ProgramError> {
         let mut from = source.try_borrow_mut_lamports()?;
         let mut to = receiver.try_borrow_mut_lamports()?;
-        **to += **from;
+        **to = (**to)
+            .checked_add(**from)
+            .ok_or(AudiusProgramError::MathOverflow)?;
         **from = 0;
         Ok(())
     }
 
+    /// Write the newly created sender and count it in the reward manager registry
+    fn register_sender(
+        reward_manager_info: &AccountInfo,
+        reward_manager: &mut RewardManager,
+        sender_info: &AccountInfo,
+        sender: SenderAccount,
+    ) -> ProgramResult {
+        sender.serialize(&mut *sender_info.data.borrow_mut())?;
+
+        reward_manager.sender_count = reward_manager
+            .sender_count
+            .checked_add(1)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        Event::SenderCreated {
+            reward_manager: *reward_manager_info.key,
+            eth_address: sender.eth_address,
+            operator: sender.operator,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Remove the sender from the reward manager registry refunding its rent
+    fn unregister_sender(
+        reward_manager_info: &AccountInfo,
+        mut reward_manager: RewardManager,
+        sender_info: &AccountInfo,
+        sender: SenderAccount,
+        refunder_info: &AccountInfo,
+    ) -> ProgramResult {
+        // NOTE: legacy reward managers don't count their senders
+        reward_manager.sender_count = reward_manager.sender_count.saturating_sub(1);
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        // NOTE: the account is only purged at the end of the transaction,
+        // wiping it keeps the sender from being deleted twice
+        sender_info.data.borrow_mut().fill(0);
+        Self::transfer_all(sender_info, refunder_info)?;
+
+        Event::SenderDeleted {
+            reward_manager: *reward_manager_info.key,
+            eth_address: sender.eth_address,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Load the account listing the senders of `operator`, it's created on first use
+    fn load_or_create_operator<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        funder_info: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+        operator: EthereumAddress,
+    ) -> Result<OperatorAccount, ProgramError> {
+        let (operator_address, bump) =
+            get_operator_address(program_id, reward_manager_info.key, &operator);
+        if *operator_info.key != operator_address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if !operator_info.data_is_empty() {
+            is_owner!(*program_id, operator_info)?;
+            check_account_size(operator_info, OperatorAccount::LEN)?;
+            return Ok(OperatorAccount::try_from_slice(
+                &operator_info.data.borrow(),
+            )?);
+        }
+
+        let rent = rent_from_account_info(rent_info)?;
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_info.key,
+                operator_info.key,
+                rent.minimum_balance(OperatorAccount::LEN),
+                OperatorAccount::LEN as _,
+                program_id,
+            ),
+            &[funder_info.clone(), operator_info.clone()],
+            &[&[
+                reward_manager_info.key.as_ref(),
+                OPERATOR_SEED_PREFIX.as_bytes(),
+                operator.as_ref(),
+                &[bump],
+            ]],
+        )?;
+
+        Ok(OperatorAccount::new(
+            *reward_manager_info.key,
+            operator,
+            bump,
+        ))
+    }
+
+    /// List the new sender in its operator account
+    fn list_operator_sender<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        funder_info: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+        sender: &SenderAccount,
+    ) -> ProgramResult {
+        let mut operator = Self::load_or_create_operator(
+            program_id,
+            reward_manager_info,
+            funder_info,
+            operator_info,
+            rent_info,
+            sender.operator,
+        )?;
+        operator.add_sender(sender.eth_address)?;
+        operator.serialize(&mut *operator_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Load the operator account of the sender, `None` for the senders
+    /// registered before operator accounts which are not listed
+    fn load_sender_operator(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo,
+        operator_info: &AccountInfo,
+        sender: &SenderAccount,
+    ) -> Result<Option<OperatorAccount>, ProgramError> {
+        let (operator_address, _) =
+            get_operator_address(program_id, reward_manager_info.key, &sender.operator);
+        if *operator_info.key != operator_address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if operator_info.data_is_empty() {
+            return Ok(None);
+        }
+
+        is_owner!(*program_id, operator_info)?;
+        check_account_size(operator_info, OperatorAccount::LEN)?;
+        Ok(Some(OperatorAccount::try_from_slice(
+            &operator_info.data.borrow(),
+        )?))
+    }
+
+    /// Unlist the deleted sender from its operator account
+    fn unlist_operator_sender(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo,
+        operator_info: &AccountInfo,
+        sender: &SenderAccount,
+    ) -> ProgramResult {
+        if let Some(mut operator) =
+            Self::load_sender_operator(program_id, reward_manager_info, operator_info, sender)?
+        {
+            if operator.remove_sender(&sender.eth_address) {
+                operator.serialize(&mut *operator_info.data.borrow_mut())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create the program derived account of a new sender, returns its bump seed
+    fn create_sender_account<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        funder_info: &AccountInfo<'a>,
+        sender_info: &AccountInfo<'a>,
+        legacy_sender_info: &AccountInfo<'a>,
+        eth_address: EthereumAddress,
+        rent_info: &AccountInfo<'a>,
+    ) -> Result<u8, ProgramError> {
+        let (sender_address, bump) =
+            get_sender_address(program_id, reward_manager_info.key, &eth_address);
+        if *sender_info.key != sender_address {
+            return Err(AudiusProgramError::IncorectSenderAccount.into());
+        }
+
+        // NOTE: senders created with seed keep working, one Ethereum address
+        // must not be registered under both addresses
+        if *legacy_sender_info.key
+            != get_legacy_sender_address(program_id, reward_manager_info.key, &eth_address)?
+        {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if !legacy_sender_info.data_is_empty() {
+            return Err(AudiusProgramError::SenderAlreadyExists.into());
+        }
+
+        let rent = rent_from_account_info(rent_info)?;
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_info.key,
+                sender_info.key,
+                rent.minimum_balance(SenderAccount::LEN),
+                SenderAccount::LEN as _,
+                program_id,
+            ),
+            &[funder_info.clone(), sender_info.clone()],
+            &[&[
+                reward_manager_info.key.as_ref(),
+                SENDER_SEED_PREFIX.as_bytes(),
+                eth_address.as_ref(),
+                &[bump],
+            ]],
+        )?;
+
+        Ok(bump)
+    }
+
+    /// Checks the signer is the manager or a delegate holding `permission`,
+    /// a delegate passes its account as well
+    fn check_manager_or_delegate(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo,
+        reward_manager: &RewardManager,
+        signer_info: &AccountInfo,
+        delegate_info: Option<&AccountInfo>,
+        permission: u8,
+    ) -> ProgramResult {
+        if reward_manager.manager == *signer_info.key {
+            return Ok(());
+        }
+        let delegate_info = delegate_info.ok_or(AudiusProgramError::IncorectManagerAccount)?;
+
+        // NOTE: delegates are disabled once the manager multisig took over,
+        // every admin instruction then needs the multisig threshold
+        let (multisig, _) = get_multisig_address(program_id, reward_manager_info.key);
+        if reward_manager.manager == multisig {
+            return Err(AudiusProgramError::MissingPermission.into());
+        }
+
+        is_owner!(*program_id, delegate_info)?;
+
+        let delegate = DelegateAccount::try_from_slice(&delegate_info.data.borrow())?;
+        if !delegate.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if delegate.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+        if delegate.delegate != *signer_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+        if !delegate.can(permission) {
+            return Err(AudiusProgramError::MissingPermission.into());
+        }
+
+        Ok(())
+    }
+
     /// Process example instruction
     #[allow(clippy::too_many_arguments)]
     fn process_init_instruction<'a>(
@@ -59,20 +406,29 @@ impl Processor {
         spl_token_info: &AccountInfo<'a>,
         rent: &AccountInfo<'a>,
         min_votes: u8,
+        claimable_tokens_program: Pubkey,
     ) -> ProgramResult {
-        let reward_manager = RewardManager::try_from_slice(&reward_manager_info.data.borrow())?;
+        is_owner!(*program_id, reward_manager_info)?;
+        check_account_size(reward_manager_info, RewardManager::LEN)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
         if reward_manager.is_initialized() {
             return Err(ProgramError::AccountAlreadyInitialized);
         }
+        check_rent_exempt(&rent_from_account_info(rent)?, reward_manager_info)?;
 
         let (base, _) = get_base_address(program_id, reward_manager_info.key);
         if base != *authority_info.key {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        let mut reward_manager =
+            RewardManager::new(*token_account_info.key, *manager_info.key, min_votes);
+        Self::check_attestation_room(&reward_manager)?;
+
         invoke(
             &spl_token::instruction::initialize_account(
-                &spl_token::id(),
+                spl_token_info.key,
                 token_account_info.key,
                 mint_info.key,
                 &base,
@@ -86,12 +442,71 @@ impl Processor {
             ],
         )?;
 
-        RewardManager::new(*token_account_info.key, *manager_info.key, min_votes)
-            .serialize(&mut *reward_manager_info.data.borrow_mut())?;
+        reward_manager.token_program = *spl_token_info.key;
+        reward_manager.claimable_tokens_program = claimable_tokens_program;
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
 
         Ok(())
     }
 
+    /// Creates the `Reward Manager` and its token account sized and funded
+    /// for rent exemption, then initializes both
+    #[allow(clippy::too_many_arguments)]
+    fn process_init_v2_instruction<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        token_account_info: &AccountInfo<'a>,
+        mint_info: &AccountInfo<'a>,
+        manager_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+        funder_info: &AccountInfo<'a>,
+        spl_token_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+        min_votes: u8,
+        claimable_tokens_program: Pubkey,
+    ) -> ProgramResult {
+        Self::check_attestation_room(&RewardManager::new(
+            *token_account_info.key,
+            *manager_info.key,
+            min_votes,
+        ))?;
+
+        let rent = rent_from_account_info(rent_info)?;
+        invoke(
+            &system_instruction::create_account(
+                funder_info.key,
+                reward_manager_info.key,
+                rent.minimum_balance(RewardManager::LEN),
+                RewardManager::LEN as _,
+                program_id,
+            ),
+            &[funder_info.clone(), reward_manager_info.clone()],
+        )?;
+        invoke(
+            &system_instruction::create_account(
+                funder_info.key,
+                token_account_info.key,
+                rent.minimum_balance(TokenAccount::LEN),
+                TokenAccount::LEN as _,
+                spl_token_info.key,
+            ),
+            &[funder_info.clone(), token_account_info.clone()],
+        )?;
+
+        Self::process_init_instruction(
+            program_id,
+            reward_manager_info,
+            token_account_info,
+            mint_info,
+            manager_info,
+            authority_info,
+            spl_token_info,
+            rent_info,
+            min_votes,
+            claimable_tokens_program,
+        )
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn process_create_sender<'a>(
         program_id: &Pubkey,
@@ -99,419 +514,4021 @@ impl Processor {
         operator: EthereumAddress,
         reward_manager_info: &AccountInfo<'a>,
         manager_account_info: &AccountInfo<'a>,
-        authority_info: &AccountInfo<'a>,
+        _authority_info: &AccountInfo<'a>,
         funder_account_info: &AccountInfo<'a>,
         sender_info: &AccountInfo<'a>,
         _sys_prog_info: &AccountInfo<'a>,
         rent_info: &AccountInfo<'a>,
+        legacy_sender_info: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        delegate_info: Option<&AccountInfo<'a>>,
     ) -> ProgramResult {
-        if !manager_account_info.is_signer {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        let reward_manager = RewardManager::try_from_slice(&reward_manager_info.data.borrow())?;
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
         if !reward_manager.is_initialized() {
             return Err(ProgramError::UninitializedAccount);
         }
 
-        if reward_manager.manager != *manager_account_info.key {
-            return Err(AudiusProgramError::IncorectManagerAccount.into());
-        }
-
-        let pair = get_address_pair(
+        Self::check_manager_or_delegate(
             program_id,
-            reward_manager_info.key,
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+            reward_manager_info,
+            &reward_manager,
+            manager_account_info,
+            delegate_info,
+            DelegateAccount::CREATE_SENDER,
         )?;
-        if *sender_info.key != pair.derive.address {
-            return Err(AudiusProgramError::IncorectSenderAccount.into());
-        }
 
-        let signature = &[&reward_manager_info.key.to_bytes()[..32], &[pair.base.seed]];
+        // NOTE: zero slot means no sender was created by the manager yet
+        let slot = Clock::get()?.slot;
+        if reward_manager.last_sender_creation_slot != 0
+            && slot
+                < reward_manager
+                    .last_sender_creation_slot
+                    .saturating_add(reward_manager.sender_creation_gap)
+        {
+            return Err(AudiusProgramError::SenderCreationRateLimited.into());
+        }
+        reward_manager.last_sender_creation_slot = slot;
 
-        let rent = Rent::from_account_info(rent_info)?;
-        invoke_signed(
-            &system_instruction::create_account_with_seed(
-                funder_account_info.key,
-                sender_info.key,
-                &pair.base.address,
-                pair.derive.seed.as_str(),
-                rent.minimum_balance(SenderAccount::LEN),
-                SenderAccount::LEN as _,
-                program_id,
-            ),
-            &[
-                funder_account_info.clone(),
-                sender_info.clone(),
-                authority_info.clone(),
-            ],
-            &[signature],
+        let bump = Self::create_sender_account(
+            program_id,
+            reward_manager_info,
+            funder_account_info,
+            sender_info,
+            legacy_sender_info,
+            eth_address,
+            rent_info,
         )?;
 
-        SenderAccount::new(*reward_manager_info.key, eth_address, operator)
-            .serialize(&mut *sender_info.data.borrow_mut())?;
+        let sender = SenderAccount::new(*reward_manager_info.key, eth_address, operator, bump);
+        Self::list_operator_sender(
+            program_id,
+            reward_manager_info,
+            funder_account_info,
+            operator_info,
+            rent_info,
+            &sender,
+        )?;
 
-        Ok(())
+        Self::register_sender(
+            reward_manager_info,
+            &mut reward_manager,
+            sender_info,
+            sender,
+        )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn process_delete_sender<'a>(
-        _program_id: &Pubkey,
+        program_id: &Pubkey,
         reward_manager_info: &AccountInfo<'a>,
         manager_account_info: &AccountInfo<'a>,
         sender_info: &AccountInfo<'a>,
         refunder_account_info: &AccountInfo<'a>,
         _sys_prog: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        delegate_info: Option<&AccountInfo<'a>>,
     ) -> ProgramResult {
         if !manager_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
-        let sender = SenderAccount::try_from_slice(&sender_info.data.borrow())?;
+
+        is_owner!(*program_id, sender_info)?;
+
+        let sender = SenderAccount::unpack(&sender_info.data.borrow())?;
         if sender.reward_manager != *reward_manager_info.key {
             return Err(AudiusProgramError::WrongRewardManagerKey.into());
         }
 
-        let reward_manager = RewardManager::try_from_slice(&reward_manager_info.data.borrow())?;
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        Self::check_manager_or_delegate(
+            program_id,
+            reward_manager_info,
+            &reward_manager,
+            manager_account_info,
+            delegate_info,
+            DelegateAccount::DELETE_SENDER,
+        )?;
+
+        Self::unlist_operator_sender(program_id, reward_manager_info, operator_info, &sender)?;
+        Self::unregister_sender(
+            reward_manager_info,
+            reward_manager,
+            sender_info,
+            sender,
+            refunder_account_info,
+        )
+    }
+
+    fn process_set_sender_weight<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        sender_info: &AccountInfo<'a>,
+        weight: u16,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info, sender_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
         if reward_manager.manager != *manager_account_info.key {
             return Err(AudiusProgramError::IncorectManagerAccount.into());
         }
 
-        Self::transfer_all(sender_info, refunder_account_info)?;
+        // NOTE: accounts can't grow, senders created before weights must be rotated first
+        if sender_info.data_len() != SenderAccount::UNTRACKED_LEN
+            && sender_info.data_len() != SenderAccount::STATUSLESS_LEN
+            && sender_info.data_len() != SenderAccount::METADATALESS_LEN
+        {
+            check_account_size(sender_info, SenderAccount::LEN)?;
+        }
+        let mut sender = SenderAccount::unpack(&sender_info.data.borrow())?;
+        if !sender.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if sender.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+
+        sender.weight = weight;
+        sender.pack(&mut sender_info.data.borrow_mut())?;
 
         Ok(())
     }
 
-    /// Checks that the user signed message with his ethereum private key
-    fn check_secp_signs(
+    fn process_set_sender_status<'a>(
         program_id: &Pubkey,
-        reward_manager_info: &AccountInfo,
-        instruction_info: &AccountInfo,
-        expected_signers: Vec<&AccountInfo>,
-        extraction_depth: usize,
-        verifier: impl VerifierFn,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        sender_info: &AccountInfo<'a>,
+        status: SenderStatus,
     ) -> ProgramResult {
-        let reward_manager = RewardManager::try_from_slice(&reward_manager_info.data.borrow())?;
-        if expected_signers.len() < reward_manager.min_votes as _ {
-            return Err(AudiusProgramError::NotEnoughSigners.into());
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
         }
 
-        let index = sysvar::instructions::load_current_index(&instruction_info.data.borrow());
-        // instruction can't be first in transaction
-        // because must follow after `new_secp256k1_instruction`
-        if index == 0 {
-            return Err(AudiusProgramError::Secp256InstructionMissing.into());
+        is_owner!(*program_id, reward_manager_info, sender_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
         }
 
-        let secp_instructions = get_secp_instructions(index, extraction_depth, instruction_info)?;
+        // NOTE: accounts can't grow, senders created before suspension must be rotated first
+        if sender_info.data_len() != SenderAccount::METADATALESS_LEN {
+            check_account_size(sender_info, SenderAccount::LEN)?;
+        }
+        let mut sender = SenderAccount::unpack(&sender_info.data.borrow())?;
+        if !sender.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if sender.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
 
-        let (senders_eth_addresses, operators_set) =
-            get_eth_addresses(program_id, reward_manager_info.key, expected_signers)?;
+        sender.status = status;
+        sender.pack(&mut sender_info.data.borrow_mut())?;
+
+        Event::SenderStatusChanged {
+            reward_manager: *reward_manager_info.key,
+            eth_address: sender.eth_address,
+            status,
+        }
+        .emit();
 
-        verifier(secp_instructions, senders_eth_addresses, operators_set)
+        Ok(())
     }
 
-    fn process_add_sender<'a>(
+    fn process_repair_sender<'a>(
         program_id: &Pubkey,
         reward_manager_info: &AccountInfo<'a>,
-        authority_info: &AccountInfo<'a>,
-        funder_info: &AccountInfo<'a>,
-        new_sender_info: &AccountInfo<'a>,
-        instructions_info: &AccountInfo<'a>,
-        rent_info: &AccountInfo<'a>,
-        signers_info: Vec<&AccountInfo>,
-        eth_address: EthereumAddress,
-        operator: EthereumAddress,
+        manager_account_info: &AccountInfo<'a>,
+        sender_info: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        params: RepairSender,
     ) -> ProgramResult {
-        let reward_manager = RewardManager::try_from_slice(&reward_manager_info.data.borrow())?;
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info, sender_info, operator_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
         if !reward_manager.is_initialized() {
             return Err(ProgramError::UninitializedAccount);
         }
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
 
-        let verifier = build_verify_secp_add_sender(reward_manager_info.key.clone(), eth_address);
-        Self::check_secp_signs(
-            program_id,
-            &reward_manager_info,
-            instructions_info,
+        let (sender_address, bump) =
+            get_sender_address(program_id, reward_manager_info.key, &params.eth_address);
+        if *sender_info.key != sender_address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if sender_info.data_len() != SenderAccount::METADATALESS_LEN {
+            check_account_size(sender_info, SenderAccount::LEN)?;
+        }
+
+        // NOTE: senders deserializing to themselves keep their weight, counters, status
+        // and endpoint hash
+        let expected = SenderAccount::new(
+            *reward_manager_info.key,
+            params.eth_address,
+            params.operator,
+            bump,
+        );
+        if let Ok(sender) = SenderAccount::unpack(&sender_info.data.borrow()) {
+            if sender.is_initialized()
+                && sender.reward_manager == expected.reward_manager
+                && sender.eth_address == expected.eth_address
+                && sender.operator == expected.operator
+                && sender.bump == expected.bump
+            {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+        }
+
+        let (operator_address, _) =
+            get_operator_address(program_id, reward_manager_info.key, &params.operator);
+        if *operator_info.key != operator_address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        check_account_size(operator_info, OperatorAccount::LEN)?;
+        let operator = OperatorAccount::try_from_slice(&operator_info.data.borrow())?;
+        if !operator.senders().contains(&params.eth_address) {
+            return Err(AudiusProgramError::IncorectSenderAccount.into());
+        }
+
+        expected.pack(&mut sender_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_update_sender_metadata<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        sender_info: &AccountInfo<'a>,
+        instructions_info: &AccountInfo<'a>,
+        endpoint_hash: [u8; 32],
+    ) -> ProgramResult {
+        is_owner!(*program_id, reward_manager_info, sender_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        // NOTE: accounts can't grow, senders created before endpoint metadata must be rotated first
+        check_account_size(sender_info, SenderAccount::LEN)?;
+        let mut sender = SenderAccount::unpack(&sender_info.data.borrow())?;
+        if !sender.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if sender.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+        check_sender_address(program_id, reward_manager_info.key, sender_info, &sender)?;
+
+        // NOTE: the sender is the only signer, signing over the current hash
+        // keeps earlier updates from being replayed
+        let verifier = SenderMetadataVerifier {
+            reward_manager: *reward_manager_info.key,
+            current_endpoint_hash: sender.endpoint_hash,
+            endpoint_hash,
+        };
+        verifier.verify(
+            Self::load_secp_payloads(program_id, instructions_info, 1)?,
+            vec![sender.eth_address],
+            BTreeSet::new(),
+        )?;
+
+        sender.endpoint_hash = endpoint_hash;
+        sender.serialize(&mut *sender_info.data.borrow_mut())?;
+
+        Event::SenderMetadataUpdated {
+            reward_manager: *reward_manager_info.key,
+            eth_address: sender.eth_address,
+            endpoint_hash,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_set_operator_senders_limit<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+        operator: EthereumAddress,
+        max_senders: u8,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        if max_senders as usize > MAX_OPERATOR_SENDERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // NOTE: listed senders stay registered when the limit drops below their number
+        let mut operator_account = Self::load_or_create_operator(
+            program_id,
+            reward_manager_info,
+            funder_account_info,
+            operator_info,
+            rent_info,
+            operator,
+        )?;
+        operator_account.max_senders = max_senders;
+        operator_account.serialize(&mut *operator_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_delete_sender_public<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        sender_info: &AccountInfo<'a>,
+        refunder_account_info: &AccountInfo<'a>,
+        instructions_info: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        signers_info: Vec<&AccountInfo>,
+    ) -> ProgramResult {
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        is_owner!(*program_id, sender_info)?;
+
+        let sender = SenderAccount::unpack(&sender_info.data.borrow())?;
+        if sender.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+
+        // Evicted sender can't vote for itself
+        if signers_info
+            .iter()
+            .any(|signer| signer.key == sender_info.key)
+        {
+            return Err(AudiusProgramError::WrongSigner.into());
+        }
+
+        let verifier = DeleteSenderVerifier {
+            reward_manager: *reward_manager_info.key,
+            sender: sender.eth_address,
+        };
+        Self::check_secp_signs(
+            program_id,
+            reward_manager_info,
+            instructions_info,
             signers_info.clone(),
             signers_info.len(),
             verifier,
         )?;
 
-        let pair = get_address_pair(
+        Self::unlist_operator_sender(program_id, reward_manager_info, operator_info, &sender)?;
+        Self::unregister_sender(
+            reward_manager_info,
+            reward_manager,
+            sender_info,
+            sender,
+            refunder_account_info,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_rotate_sender_key<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        funder_info: &AccountInfo<'a>,
+        sender_info: &AccountInfo<'a>,
+        new_sender_info: &AccountInfo<'a>,
+        legacy_new_sender_info: &AccountInfo<'a>,
+        instructions_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        new_eth_address: EthereumAddress,
+    ) -> ProgramResult {
+        if !funder_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info, sender_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        let sender = SenderAccount::unpack(&sender_info.data.borrow())?;
+        if !sender.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if sender.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+        check_sender_address(program_id, reward_manager_info.key, sender_info, &sender)?;
+
+        // NOTE: the current key is the only signer, no votes of other senders are needed
+        let verifier = RotateSenderVerifier {
+            reward_manager: *reward_manager_info.key,
+            attestation_count: sender.attestation_count,
+            new_sender: new_eth_address,
+        };
+        verifier.verify(
+            Self::load_secp_payloads(program_id, instructions_info, 1)?,
+            vec![sender.eth_address],
+            BTreeSet::new(),
+        )?;
+
+        let bump = Self::create_sender_account(
+            program_id,
+            reward_manager_info,
+            funder_info,
+            new_sender_info,
+            legacy_new_sender_info,
+            new_eth_address,
+            rent_info,
+        )?;
+        // NOTE: the new key stays suspended if the current one is, and the rotation
+        // counts as an attestation so that its signature can't be replayed later
+        SenderAccount {
+            weight: sender.weight,
+            attestation_count: sender.attestation_count.saturating_add(1),
+            last_attested_slot: Clock::get()?.slot,
+            status: sender.status,
+            endpoint_hash: sender.endpoint_hash,
+            ..SenderAccount::new(
+                *reward_manager_info.key,
+                new_eth_address,
+                sender.operator,
+                bump,
+            )
+        }
+        .serialize(&mut *new_sender_info.data.borrow_mut())?;
+
+        if let Some(mut operator) =
+            Self::load_sender_operator(program_id, reward_manager_info, operator_info, &sender)?
+        {
+            if operator.replace_sender(&sender.eth_address, new_eth_address) {
+                operator.serialize(&mut *operator_info.data.borrow_mut())?;
+            }
+        }
+
+        // NOTE: wiping keeps the old key from voting until the account is purged
+        sender_info.data.borrow_mut().fill(0);
+        Self::transfer_all(sender_info, funder_info)?;
+
+        Event::SenderRotated {
+            reward_manager: *reward_manager_info.key,
+            eth_address: sender.eth_address,
+            new_eth_address,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_create_oracle<'a>(
+        program_id: &Pubkey,
+        eth_address: EthereumAddress,
+        operator: EthereumAddress,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        oracle_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+        delegate_info: Option<&AccountInfo<'a>>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Self::check_manager_or_delegate(
+            program_id,
+            reward_manager_info,
+            &reward_manager,
+            manager_account_info,
+            delegate_info,
+            DelegateAccount::CREATE_ORACLE,
+        )?;
+
+        let seeds = [ORACLE_SEED_PREFIX.as_bytes(), eth_address.as_ref()].concat();
+        let pair = get_address_pair(program_id, reward_manager_info.key, seeds.clone())?;
+        if *oracle_info.key != pair.derive.address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let rent = rent_from_account_info(rent_info)?;
+        create_account_with_seed(
             program_id,
+            funder_account_info,
+            oracle_info,
+            authority_info,
             reward_manager_info.key,
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+            seeds,
+            rent.minimum_balance(OracleAccount::LEN),
+            OracleAccount::LEN as _,
+            program_id,
         )?;
 
-        let signature = &[&reward_manager_info.key.to_bytes()[..32], &[pair.base.seed]];
+        OracleAccount::new(*reward_manager_info.key, eth_address, operator)
+            .serialize(&mut *oracle_info.data.borrow_mut())?;
 
-        let rent = Rent::from_account_info(rent_info)?;
-        invoke_signed(
-            &system_instruction::create_account_with_seed(
-                funder_info.key,
-                &pair.derive.address,
-                &pair.base.address,
-                pair.derive.seed.as_str(),
-                rent.minimum_balance(SenderAccount::LEN),
-                SenderAccount::LEN as _,
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_set_spend_limit<'a>(
+        program_id: &Pubkey,
+        params: SetSpendLimit,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        spend_counter_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        let seeds = SPEND_COUNTER_SEED_PREFIX.as_bytes().to_vec();
+        let pair = get_address_pair(program_id, reward_manager_info.key, seeds.clone())?;
+        if *spend_counter_info.key != pair.derive.address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let mut spend_counter = if spend_counter_info.data_is_empty() {
+            let rent = rent_from_account_info(rent_info)?;
+            create_account_with_seed(
                 program_id,
-            ),
-            &[
-                funder_info.clone(),
-                new_sender_info.clone(),
-                authority_info.clone(),
-            ],
-            &[signature],
-        )?;
+                funder_account_info,
+                spend_counter_info,
+                authority_info,
+                reward_manager_info.key,
+                seeds,
+                rent.minimum_balance(SpendCounter::LEN),
+                SpendCounter::LEN as _,
+                program_id,
+            )?;
+
+            SpendCounter::new(*reward_manager_info.key, params.budget, params.window_slots)
+        } else {
+            is_owner!(*program_id, spend_counter_info)?;
+            SpendCounter::try_from_slice(&spend_counter_info.data.borrow())?
+        };
 
-        SenderAccount::new(*reward_manager_info.key, eth_address, operator)
-            .serialize(&mut *new_sender_info.data.borrow_mut())?;
+        // NOTE: spending of a window with a different length doesn't count
+        if spend_counter.window_slots != params.window_slots {
+            spend_counter.window_slots = params.window_slots;
+            spend_counter.window_start = 0;
+            spend_counter.spent = 0;
+        }
+        spend_counter.budget = params.budget;
+
+        spend_counter.serialize(&mut *spend_counter_info.data.borrow_mut())?;
 
         Ok(())
     }
 
-    fn process_transfer<'a>(
+    #[allow(clippy::too_many_arguments)]
+    fn process_set_rate_limit<'a>(
         program_id: &Pubkey,
-        reward_manager: &AccountInfo<'a>,
-        reward_manager_authority: &AccountInfo<'a>,
-        recipient: &AccountInfo<'a>,
-        vault_token_account: &AccountInfo<'a>,
-        bot_oracle: &AccountInfo<'a>,
-        funder: &AccountInfo<'a>,
-        transfer_acc_to_create: &AccountInfo<'a>,
-        instruction_info: &AccountInfo<'a>,
-        transfer_data: Transfer,
-        senders: Vec<&AccountInfo<'a>>,
+        params: SetRateLimit,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        rate_limiter_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
     ) -> ProgramResult {
-        let reward_manager_data = RewardManager::try_from_slice(&reward_manager.data.borrow())?;
-        if !reward_manager_data.is_initialized() {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        let seeds = RATE_LIMITER_SEED_PREFIX.as_bytes().to_vec();
+        let pair = get_address_pair(program_id, reward_manager_info.key, seeds.clone())?;
+        if *rate_limiter_info.key != pair.derive.address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let mut rate_limiter = if rate_limiter_info.data_is_empty() {
+            let rent = rent_from_account_info(rent_info)?;
+            create_account_with_seed(
+                program_id,
+                funder_account_info,
+                rate_limiter_info,
+                authority_info,
+                reward_manager_info.key,
+                seeds,
+                rent.minimum_balance(RateLimiter::LEN),
+                RateLimiter::LEN as _,
+                program_id,
+            )?;
+
+            RateLimiter::new(
+                *reward_manager_info.key,
+                params.max_attestations,
+                params.window_slots,
+            )
+        } else {
+            is_owner!(*program_id, rate_limiter_info)?;
+            RateLimiter::try_from_slice(&rate_limiter_info.data.borrow())?
+        };
+
+        // NOTE: attestations of a window with a different length don't count
+        if rate_limiter.window_slots != params.window_slots {
+            rate_limiter.window_slots = params.window_slots;
+            rate_limiter.window_start = 0;
+            rate_limiter.reset();
+        }
+        rate_limiter.max_attestations = params.max_attestations;
+
+        rate_limiter.serialize(&mut *rate_limiter_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_set_recipient_blocked<'a>(
+        program_id: &Pubkey,
+        params: SetRecipientBlocked,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        recipient_policy_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
             return Err(ProgramError::UninitializedAccount);
         }
 
-        let bot_oracle_data = SenderAccount::try_from_slice(&bot_oracle.data.borrow())?;
-        if !bot_oracle_data.is_initialized() {
-            return Err(ProgramError::UninitializedAccount);
-        }
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        let seeds = RECIPIENT_POLICY_SEED_PREFIX.as_bytes().to_vec();
+        let pair = get_address_pair(program_id, reward_manager_info.key, seeds.clone())?;
+        if *recipient_policy_info.key != pair.derive.address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let mut policy = if recipient_policy_info.data_is_empty() {
+            let rent = rent_from_account_info(rent_info)?;
+            create_account_with_seed(
+                program_id,
+                funder_account_info,
+                recipient_policy_info,
+                authority_info,
+                reward_manager_info.key,
+                seeds,
+                rent.minimum_balance(RecipientPolicy::LEN),
+                RecipientPolicy::LEN as _,
+                program_id,
+            )?;
+
+            RecipientPolicy::new(*reward_manager_info.key)
+        } else {
+            is_owner!(*program_id, recipient_policy_info)?;
+            RecipientPolicy::try_from_slice(&recipient_policy_info.data.borrow())?
+        };
+
+        if params.blocked {
+            policy.block(params.eth_recipient)?;
+        } else {
+            policy.unblock(&params.eth_recipient);
+        }
+        policy.serialize(&mut *recipient_policy_info.data.borrow_mut())?;
+
+        Event::RecipientPolicyChanged {
+            reward_manager: *reward_manager_info.key,
+            eth_recipient: params.eth_recipient,
+            blocked: params.blocked,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_set_challenge<'a>(
+        program_id: &Pubkey,
+        params: SetChallenge,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        challenge_registry_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        let seeds = CHALLENGE_REGISTRY_SEED_PREFIX.as_bytes().to_vec();
+        let pair = get_address_pair(program_id, reward_manager_info.key, seeds.clone())?;
+        if *challenge_registry_info.key != pair.derive.address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let mut registry = if challenge_registry_info.data_is_empty() {
+            let rent = rent_from_account_info(rent_info)?;
+            create_account_with_seed(
+                program_id,
+                funder_account_info,
+                challenge_registry_info,
+                authority_info,
+                reward_manager_info.key,
+                seeds,
+                rent.minimum_balance(ChallengeRegistry::LEN),
+                ChallengeRegistry::LEN as _,
+                program_id,
+            )?;
+
+            ChallengeRegistry::new(*reward_manager_info.key)
+        } else {
+            is_owner!(*program_id, challenge_registry_info)?;
+            ChallengeRegistry::try_from_slice(&challenge_registry_info.data.borrow())?
+        };
+
+        registry.set(&params.id, &params.oracles)?;
+        registry.serialize(&mut *challenge_registry_info.data.borrow_mut())?;
+
+        Event::ChallengeChanged {
+            reward_manager: *reward_manager_info.key,
+            id: params.id,
+            oracles: params.oracles,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    fn process_create_event_log<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        event_log_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        let seeds = EVENT_LOG_SEED_PREFIX.as_bytes().to_vec();
+        let pair = get_address_pair(program_id, reward_manager_info.key, seeds.clone())?;
+        if *event_log_info.key != pair.derive.address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let rent = rent_from_account_info(rent_info)?;
+        create_account_with_seed(
+            program_id,
+            funder_account_info,
+            event_log_info,
+            authority_info,
+            reward_manager_info.key,
+            seeds,
+            rent.minimum_balance(EventLog::LEN),
+            EventLog::LEN as _,
+            program_id,
+        )?;
+
+        EventLog::new(*reward_manager_info.key)
+            .serialize(&mut *event_log_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_delete_oracle<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        oracle_info: &AccountInfo<'a>,
+        refunder_account_info: &AccountInfo<'a>,
+        delegate_info: Option<&AccountInfo<'a>>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, oracle_info)?;
+
+        let oracle = OracleAccount::try_from_slice(&oracle_info.data.borrow())?;
+        if oracle.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        Self::check_manager_or_delegate(
+            program_id,
+            reward_manager_info,
+            &reward_manager,
+            manager_account_info,
+            delegate_info,
+            DelegateAccount::DELETE_ORACLE,
+        )?;
+
+        oracle_info.data.borrow_mut().fill(0);
+        Self::transfer_all(oracle_info, refunder_account_info)
+    }
+
+    fn process_create_delegate<'a>(
+        program_id: &Pubkey,
+        params: CreateDelegate,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        delegate_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        if params.permissions == 0 || params.permissions & !DelegateAccount::ALL_PERMISSIONS != 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (delegate_address, bump) =
+            get_delegate_address(program_id, reward_manager_info.key, &params.delegate);
+        if *delegate_info.key != delegate_address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if delegate_info.data_is_empty() {
+            let rent = rent_from_account_info(rent_info)?;
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    delegate_info.key,
+                    rent.minimum_balance(DelegateAccount::LEN),
+                    DelegateAccount::LEN as _,
+                    program_id,
+                ),
+                &[funder_account_info.clone(), delegate_info.clone()],
+                &[&[
+                    reward_manager_info.key.as_ref(),
+                    DELEGATE_SEED_PREFIX.as_bytes(),
+                    params.delegate.as_ref(),
+                    &[bump],
+                ]],
+            )?;
+        } else {
+            is_owner!(*program_id, delegate_info)?;
+        }
+
+        DelegateAccount::new(
+            *reward_manager_info.key,
+            params.delegate,
+            params.permissions,
+            bump,
+        )
+        .serialize(&mut *delegate_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_revoke_delegate<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        delegate_info: &AccountInfo<'a>,
+        refunder_account_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, delegate_info)?;
+
+        let delegate = DelegateAccount::try_from_slice(&delegate_info.data.borrow())?;
+        if delegate.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        delegate_info.data.borrow_mut().fill(0);
+        Self::transfer_all(delegate_info, refunder_account_info)
+    }
+
+    fn process_update_reward_manager<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        params: UpdateRewardManager,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        if let Some(min_votes) = params.min_votes {
+            if min_votes == 0 {
+                return Err(ProgramError::InvalidArgument);
+            }
+            reward_manager.min_votes = min_votes;
+        }
+        if let Some(max_attestations) = params.max_attestations {
+            reward_manager.max_attestations = max_attestations;
+        }
+        if let Some(max_transfer_amount) = params.max_transfer_amount {
+            reward_manager.max_transfer_amount = max_transfer_amount;
+        }
+        if let Some(sender_creation_gap) = params.sender_creation_gap {
+            reward_manager.sender_creation_gap = sender_creation_gap;
+        }
+        if let Some(min_oracle_votes) = params.min_oracle_votes {
+            if min_oracle_votes == 0 {
+                return Err(ProgramError::InvalidArgument);
+            }
+            reward_manager.min_oracle_votes = min_oracle_votes;
+        }
+        if let Some(min_balance_threshold) = params.min_balance_threshold {
+            reward_manager.min_balance_threshold = min_balance_threshold;
+        }
+        if let Some(v1_messages_allowed) = params.v1_messages_allowed {
+            reward_manager.v1_messages_allowed = v1_messages_allowed;
+        }
+        if let Some(min_vote_weight) = params.min_vote_weight {
+            reward_manager.min_vote_weight = min_vote_weight;
+        }
+        if let Some(withdrawal_delay) = params.withdrawal_delay {
+            reward_manager.withdrawal_delay = withdrawal_delay;
+        }
+        if let Some(claimable_tokens_program) = params.claimable_tokens_program {
+            reward_manager.claimable_tokens_program = claimable_tokens_program;
+        }
+        Self::check_attestation_room(&reward_manager)?;
+
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_propose_manager<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        new_manager: Pubkey,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        if new_manager == reward_manager.manager {
+            reward_manager.pending_manager = Pubkey::default();
+            reward_manager.manager_activation_slot = 0;
+        } else {
+            reward_manager.pending_manager = new_manager;
+            reward_manager.manager_activation_slot =
+                Clock::get()?.slot.saturating_add(MANAGER_CHANGE_DELAY);
+        }
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_accept_manager<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        new_manager_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !new_manager_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.pending_manager == Pubkey::default() {
+            return Err(AudiusProgramError::NoPendingManager.into());
+        }
+        if reward_manager.pending_manager != *new_manager_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+        if Clock::get()?.slot < reward_manager.manager_activation_slot {
+            return Err(AudiusProgramError::ManagerChangeTimelocked.into());
+        }
+
+        reward_manager.manager = reward_manager.pending_manager;
+        reward_manager.pending_manager = Pubkey::default();
+        reward_manager.manager_activation_slot = 0;
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_announce_treasury_withdrawal<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        params: AnnounceTreasuryWithdrawal,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        if params.amount == 0 {
+            reward_manager.pending_withdrawal_destination = Pubkey::default();
+            reward_manager.withdrawal_unlock_slot = 0;
+        } else {
+            reward_manager.pending_withdrawal_destination = params.destination;
+            reward_manager.withdrawal_unlock_slot = Clock::get()?
+                .slot
+                .saturating_add(reward_manager.withdrawal_delay);
+        }
+        reward_manager.pending_withdrawal_amount = params.amount;
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        Event::TreasuryWithdrawalAnnounced {
+            reward_manager: *reward_manager_info.key,
+            destination: reward_manager.pending_withdrawal_destination,
+            amount: reward_manager.pending_withdrawal_amount,
+            unlock_slot: reward_manager.withdrawal_unlock_slot,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    fn process_withdraw_treasury<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        destination: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+        if reward_manager.token_account != *vault_token_account.key {
+            return Err(AudiusProgramError::WrongVaultAccount.into());
+        }
+        if reward_manager.pending_withdrawal_amount == 0 {
+            return Err(AudiusProgramError::NoPendingWithdrawal.into());
+        }
+        if reward_manager.pending_withdrawal_destination != *destination.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+        if Clock::get()?.slot < reward_manager.withdrawal_unlock_slot {
+            return Err(AudiusProgramError::WithdrawalTimelocked.into());
+        }
+
+        let amount = reward_manager.pending_withdrawal_amount;
+        reward_manager.pending_withdrawal_amount = 0;
+        reward_manager.pending_withdrawal_destination = Pubkey::default();
+        reward_manager.withdrawal_unlock_slot = 0;
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        token_transfer(
+            program_id,
+            &reward_manager.token_program,
+            reward_manager_info.key,
+            vault_token_account,
+            destination,
+            reward_manager_authority,
+            amount,
+        )?;
+
+        Event::TreasuryWithdrawn {
+            reward_manager: *reward_manager_info.key,
+            destination: *destination.key,
+            amount,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    fn process_migrate_token_account<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        new_token_account: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        is_owner!(reward_manager.token_program, new_token_account)?;
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+        if reward_manager.token_account != *vault_token_account.key {
+            return Err(AudiusProgramError::WrongVaultAccount.into());
+        }
+        if new_token_account.key == vault_token_account.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // NOTE: nobody but the program may move tokens out of the new vault
+        let (base, _) = get_base_address(program_id, reward_manager_info.key);
+        let new_token_acc_data = TokenAccount::unpack(&new_token_account.data.borrow())?;
+        if new_token_acc_data.owner != base
+            || new_token_acc_data.delegate.is_some()
+            || new_token_acc_data.close_authority.is_some()
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let vault_token_acc_data = TokenAccount::unpack(&vault_token_account.data.borrow())?;
+        let amount = vault_token_acc_data.amount;
+        if amount > 0 {
+            if vault_token_acc_data.mint != new_token_acc_data.mint {
+                return Err(AudiusProgramError::VaultNotEmpty.into());
+            }
+            token_transfer(
+                program_id,
+                &reward_manager.token_program,
+                reward_manager_info.key,
+                vault_token_account,
+                new_token_account,
+                reward_manager_authority,
+                amount,
+            )?;
+        }
+
+        reward_manager.token_account = *new_token_account.key;
+        // NOTE: the announced destination may be of the previous mint
+        reward_manager.pending_withdrawal_amount = 0;
+        reward_manager.pending_withdrawal_destination = Pubkey::default();
+        reward_manager.withdrawal_unlock_slot = 0;
+        if vault_token_acc_data.mint != new_token_acc_data.mint {
+            reward_manager.transfer_fee_bps = 0;
+            reward_manager.fee_account = Pubkey::default();
+        }
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        Event::TokenAccountMigrated {
+            reward_manager: *reward_manager_info.key,
+            token_account: *new_token_account.key,
+            mint: new_token_acc_data.mint,
+            amount,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    fn process_set_transfer_fee<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        fee_account_info: &AccountInfo<'a>,
+        params: SetTransferFee,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+        if reward_manager.token_account != *vault_token_account.key {
+            return Err(AudiusProgramError::WrongVaultAccount.into());
+        }
+        if params.fee_bps > MAX_TRANSFER_FEE_BPS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if params.fee_bps == 0 {
+            reward_manager.fee_account = Pubkey::default();
+        } else {
+            is_owner!(reward_manager.token_program, fee_account_info)?;
+            if fee_account_info.key == vault_token_account.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let vault_token_acc_data = TokenAccount::unpack(&vault_token_account.data.borrow())?;
+            let fee_token_acc_data = TokenAccount::unpack(&fee_account_info.data.borrow())?;
+            if fee_token_acc_data.mint != vault_token_acc_data.mint {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            reward_manager.fee_account = *fee_account_info.key;
+        }
+        reward_manager.transfer_fee_bps = params.fee_bps;
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        Event::TransferFeeChanged {
+            reward_manager: *reward_manager_info.key,
+            fee_bps: reward_manager.transfer_fee_bps,
+            fee_account: reward_manager.fee_account,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    fn process_verify_only<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        bot_oracle: &AccountInfo<'a>,
+        instruction_info: &AccountInfo<'a>,
+        transfer_data: Transfer,
+        signers: Vec<&AccountInfo<'a>>,
+    ) -> ProgramResult {
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        check_message_version(&reward_manager, transfer_data.message_version)?;
+
+        let bot_oracle_data = Self::check_bot_oracle(program_id, reward_manager_info, bot_oracle)?;
+        let (additional_oracles, senders) =
+            Self::split_additional_oracles(program_id, reward_manager_info, signers)?;
+
+        let verifier = TransferVerifier {
+            bot_oracle: bot_oracle_data,
+            additional_oracles,
+            transfer_data,
+        };
+        let extraction_depth = senders.len() + verifier.extra_signatures();
+        check_attestations_count(&reward_manager, senders.len(), extraction_depth)?;
+
+        // NOTE: unlike `verify_payloads` the sender activity isn't recorded
+        let payloads = Self::load_secp_payloads(program_id, instruction_info, extraction_depth)?;
+        let senders = load_senders(program_id, reward_manager_info.key, &senders)?;
+        verify_attestations(&reward_manager, &senders, payloads, verifier)
+    }
+
+    fn process_create_manager_multisig<'a>(
+        program_id: &Pubkey,
+        params: CreateManagerMultisig,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        multisig_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        if reward_manager.manager != *manager_account_info.key {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        let unique_signers = params.signers.iter().collect::<BTreeSet<_>>();
+        if params.threshold == 0
+            || params.signers.len() < params.threshold as usize
+            || params.signers.len() > MAX_MULTISIG_SIGNERS
+            || unique_signers.len() != params.signers.len()
+            || params.signers.contains(&Pubkey::default())
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (multisig_address, bump) = get_multisig_address(program_id, reward_manager_info.key);
+        if *multisig_info.key != multisig_address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let multisig = if multisig_info.data_is_empty() {
+            let rent = rent_from_account_info(rent_info)?;
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    multisig_info.key,
+                    rent.minimum_balance(ManagerMultisig::LEN),
+                    ManagerMultisig::LEN as _,
+                    program_id,
+                ),
+                &[funder_account_info.clone(), multisig_info.clone()],
+                &[&[
+                    reward_manager_info.key.as_ref(),
+                    MULTISIG_SEED_PREFIX.as_bytes(),
+                    &[bump],
+                ]],
+            )?;
+
+            ManagerMultisig::new(
+                *reward_manager_info.key,
+                params.threshold,
+                &params.signers,
+                bump,
+            )?
+        } else {
+            is_owner!(*program_id, multisig_info)?;
+            check_account_size(multisig_info, ManagerMultisig::LEN)?;
+
+            // NOTE: the proposal count carries over so admin action addresses never repeat
+            let mut multisig = ManagerMultisig::try_from_slice(&multisig_info.data.borrow())?;
+            multisig.set_signers(params.threshold, &params.signers)?;
+            multisig
+        };
+        multisig.serialize(&mut *multisig_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Checks transfers have room for min votes and the bot oracles
+    fn check_attestation_room(reward_manager: &RewardManager) -> ProgramResult {
+        if (reward_manager.max_attestations as usize)
+            < reward_manager.min_votes as usize + reward_manager.min_oracle_votes as usize
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Checks the multisig belongs to the reward manager and returns its data
+    fn check_multisig(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo,
+        multisig_info: &AccountInfo,
+    ) -> Result<ManagerMultisig, ProgramError> {
+        is_owner!(*program_id, multisig_info)?;
+
+        let multisig = ManagerMultisig::try_from_slice(&multisig_info.data.borrow())?;
+        if !multisig.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if multisig.reward_manager != *reward_manager_info.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+
+        Ok(multisig)
+    }
+
+    /// Checks the admin action is voted on by the multisig and returns its data
+    fn check_admin_action(
+        program_id: &Pubkey,
+        multisig_info: &AccountInfo,
+        admin_action_info: &AccountInfo,
+    ) -> Result<AdminAction, ProgramError> {
+        is_owner!(*program_id, admin_action_info)?;
+
+        let admin_action = AdminAction::try_from_slice(&admin_action_info.data.borrow())?;
+        if !admin_action.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if admin_action.multisig != *multisig_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(admin_action)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_propose_admin_action<'a>(
+        program_id: &Pubkey,
+        params: ProposeAdminAction,
+        reward_manager_info: &AccountInfo<'a>,
+        multisig_info: &AccountInfo<'a>,
+        admin_action_info: &AccountInfo<'a>,
+        proposer_info: &AccountInfo<'a>,
+        funder_account_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !proposer_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let mut multisig = Self::check_multisig(program_id, reward_manager_info, multisig_info)?;
+        if !multisig.is_signer(proposer_info.key) {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        // NOTE: admin actions run admin instructions only, never other admin actions
+        match Instructions::try_from_slice(&params.data)? {
+            Instructions::ProposeAdminAction(_)
+            | Instructions::ApproveAdminAction
+            | Instructions::ExecuteAdminAction => return Err(ProgramError::InvalidArgument),
+            _ => {}
+        }
+
+        let (admin_action_address, bump) =
+            get_admin_action_address(program_id, multisig_info.key, multisig.proposal_count);
+        if *admin_action_info.key != admin_action_address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let admin_action = AdminAction::new(
+            *multisig_info.key,
+            *proposer_info.key,
+            params.accounts,
+            params.data,
+        )
+        .try_to_vec()?;
+
+        let rent = rent_from_account_info(rent_info)?;
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                admin_action_info.key,
+                rent.minimum_balance(admin_action.len()),
+                admin_action.len() as _,
+                program_id,
+            ),
+            &[funder_account_info.clone(), admin_action_info.clone()],
+            &[&[
+                multisig_info.key.as_ref(),
+                ADMIN_ACTION_SEED_PREFIX.as_bytes(),
+                multisig.proposal_count.to_le_bytes().as_ref(),
+                &[bump],
+            ]],
+        )?;
+        admin_action_info
+            .data
+            .borrow_mut()
+            .copy_from_slice(&admin_action);
+
+        multisig.proposal_count = multisig
+            .proposal_count
+            .checked_add(1)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        multisig.serialize(&mut *multisig_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_approve_admin_action<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        multisig_info: &AccountInfo<'a>,
+        admin_action_info: &AccountInfo<'a>,
+        approver_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if !approver_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let multisig = Self::check_multisig(program_id, reward_manager_info, multisig_info)?;
+        if !multisig.is_signer(approver_info.key) {
+            return Err(AudiusProgramError::IncorectManagerAccount.into());
+        }
+
+        let mut admin_action =
+            Self::check_admin_action(program_id, multisig_info, admin_action_info)?;
+        if admin_action.approvers.contains(approver_info.key) {
+            return Ok(());
+        }
+
+        // NOTE: slots of keys removed from the multisig are reused
+        let slot = admin_action
+            .approvers
+            .iter_mut()
+            .find(|approver| !multisig.is_signer(approver))
+            .ok_or(ProgramError::InvalidAccountData)?;
+        *slot = *approver_info.key;
+        admin_action.serialize(&mut *admin_action_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_execute_admin_action<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        multisig_info: &AccountInfo<'a>,
+        admin_action_info: &AccountInfo<'a>,
+        proposer_info: &AccountInfo<'a>,
+        accounts: &[AccountInfo<'a>],
+    ) -> ProgramResult {
+        let multisig = Self::check_multisig(program_id, reward_manager_info, multisig_info)?;
+        let admin_action = Self::check_admin_action(program_id, multisig_info, admin_action_info)?;
+
+        if admin_action.approvals(&multisig) < multisig.threshold as usize {
+            return Err(AudiusProgramError::NotEnoughApprovals.into());
+        }
+        if admin_action.proposer != *proposer_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let instruction = Instruction {
+            program_id: *program_id,
+            accounts: admin_action
+                .accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.pubkey,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: admin_action.data,
+        };
+        invoke_signed(
+            &instruction,
+            accounts,
+            &[&[
+                reward_manager_info.key.as_ref(),
+                MULTISIG_SEED_PREFIX.as_bytes(),
+                &[multisig.bump],
+            ]],
+        )?;
+
+        admin_action_info.data.borrow_mut().fill(0);
+        Self::transfer_all(admin_action_info, proposer_info)
+    }
+
+    fn process_set_paused<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        manager_account_info: &AccountInfo<'a>,
+        delegate_info: Option<&AccountInfo<'a>>,
+        paused: bool,
+    ) -> ProgramResult {
+        if !manager_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        is_owner!(*program_id, reward_manager_info)?;
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Self::check_manager_or_delegate(
+            program_id,
+            reward_manager_info,
+            &reward_manager,
+            manager_account_info,
+            delegate_info,
+            DelegateAccount::PAUSE,
+        )?;
+
+        reward_manager.paused = paused;
+        reward_manager.pack(&mut reward_manager_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Loads the payloads of the secp instructions preceding the current one,
+    /// which must be a top level instruction of this program
+    fn load_secp_payloads(
+        program_id: &Pubkey,
+        instruction_info: &AccountInfo,
+        extraction_depth: usize,
+    ) -> Result<Vec<SecpPayload>, ProgramError> {
+        check_sysvar_id(instruction_info, &sysvar::instructions::id())?;
+
+        let index = sysvar::instructions::load_current_index(&instruction_info.data.borrow());
+        // instruction can't be first in transaction
+        // because must follow after `new_secp256k1_instruction`
+        if index == 0 {
+            return Err(AudiusProgramError::Secp256InstructionMissing.into());
+        }
+
+        // Attestations are collected relative to the top level instruction,
+        // so it must be ours rather than a program invoking us
+        let (current_program_id, _) =
+            load_instruction_parts(&instruction_info.data.borrow(), index as usize)?;
+        if current_program_id != *program_id {
+            return Err(AudiusProgramError::CrossProgramInvocation.into());
+        }
+
+        get_secp_instructions(index, extraction_depth, instruction_info)?
+            .iter()
+            .map(|instruction| SecpPayload::parse(instruction.data.as_ref()).map_err(Into::into))
+            .collect()
+    }
+
+    /// Checks that the expected signers carry enough vote weight and the payloads
+    /// are signed by them, then records their activity
+    fn verify_payloads(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo,
+        reward_manager: &RewardManager,
+        expected_signers: Vec<&AccountInfo>,
+        payloads: Vec<SecpPayload>,
+        verifier: impl Verifier,
+    ) -> ProgramResult {
+        let mut senders = load_senders(program_id, reward_manager_info.key, &expected_signers)?;
+        verify_attestations(reward_manager, &senders, payloads, verifier)?;
+        record_attestations(&expected_signers, &mut senders, Clock::get()?.slot)
+    }
+
+    /// Checks that the user signed message with his ethereum private key
+    fn check_secp_signs(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo,
+        instruction_info: &AccountInfo,
+        expected_signers: Vec<&AccountInfo>,
+        extraction_depth: usize,
+        verifier: impl Verifier,
+    ) -> ProgramResult {
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        check_attestations_count(&reward_manager, expected_signers.len(), extraction_depth)?;
+
+        let secp_payloads =
+            Self::load_secp_payloads(program_id, instruction_info, extraction_depth)?;
+
+        Self::verify_payloads(
+            program_id,
+            reward_manager_info,
+            &reward_manager,
+            expected_signers,
+            secp_payloads,
+            verifier,
+        )
+    }
+
+    /// Checks signatures passed in the instruction data, recovering their signers
+    /// with the `secp256k1_recover` syscall instead of the secp256k1 program
+    fn check_recovered_signs(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo,
+        signatures: &[RecoverableSignature],
+        expected_signers: Vec<&AccountInfo>,
+        verifier: impl Verifier,
+    ) -> ProgramResult {
+        let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if signatures.len() > MAX_RECOVERED_SIGNATURES {
+            return Err(AudiusProgramError::TooManyAttestations.into());
+        }
+        check_attestations_count(&reward_manager, expected_signers.len(), signatures.len())?;
+
+        let payloads = recover_payloads(&verifier, signatures)?;
+
+        Self::verify_payloads(
+            program_id,
+            reward_manager_info,
+            &reward_manager,
+            expected_signers,
+            payloads,
+            verifier,
+        )
+    }
+
+    /// Checks attestations of a transfer wherever they're passed
+    fn check_attestations(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo,
+        attestations: Attestations,
+        expected_signers: Vec<&AccountInfo>,
+        extraction_depth: usize,
+        verifier: impl Verifier,
+    ) -> ProgramResult {
+        match attestations {
+            Attestations::SecpInstructions(instruction_info) => Self::check_secp_signs(
+                program_id,
+                reward_manager_info,
+                instruction_info,
+                expected_signers,
+                extraction_depth,
+                verifier,
+            ),
+            Attestations::Signatures(signatures) => Self::check_recovered_signs(
+                program_id,
+                reward_manager_info,
+                &signatures,
+                expected_signers,
+                verifier,
+            ),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_add_sender<'a>(
+        program_id: &Pubkey,
+        reward_manager_info: &AccountInfo<'a>,
+        _authority_info: &AccountInfo<'a>,
+        funder_info: &AccountInfo<'a>,
+        new_sender_info: &AccountInfo<'a>,
+        instructions_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+        legacy_sender_info: &AccountInfo<'a>,
+        operator_info: &AccountInfo<'a>,
+        signers_info: Vec<&AccountInfo>,
+        eth_address: EthereumAddress,
+        operator: EthereumAddress,
+    ) -> ProgramResult {
+        if !funder_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let mut reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+        if !reward_manager.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        let verifier = AddSenderVerifier {
+            reward_manager: *reward_manager_info.key,
+            new_sender: eth_address,
+            operator,
+        };
+        Self::check_secp_signs(
+            program_id,
+            &reward_manager_info,
+            instructions_info,
+            signers_info.clone(),
+            signers_info.len(),
+            verifier,
+        )?;
+
+        let bump = Self::create_sender_account(
+            program_id,
+            reward_manager_info,
+            funder_info,
+            new_sender_info,
+            legacy_sender_info,
+            eth_address,
+            rent_info,
+        )?;
+
+        let sender = SenderAccount::new(*reward_manager_info.key, eth_address, operator, bump);
+        Self::list_operator_sender(
+            program_id,
+            reward_manager_info,
+            funder_info,
+            operator_info,
+            rent_info,
+            &sender,
+        )?;
+
+        Self::register_sender(
+            reward_manager_info,
+            &mut reward_manager,
+            new_sender_info,
+            sender,
+        )
+    }
+
+    /// Checks the reward manager allows transfers of the `amounts`
+    fn check_transfers_allowed(
+        reward_manager: &AccountInfo,
+        funder: &AccountInfo,
+        amounts: &[u64],
+    ) -> ProgramResult {
+        if !funder.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let reward_manager_data = RewardManager::unpack(&reward_manager.data.borrow())?;
+        if !reward_manager_data.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if reward_manager_data.paused {
+            return Err(AudiusProgramError::ProgramPaused.into());
+        }
+        if amounts.contains(&0) {
+            return Err(AudiusProgramError::ZeroAmount.into());
+        }
+        if amounts
+            .iter()
+            .any(|amount| *amount > reward_manager_data.max_transfer_amount)
+        {
+            return Err(AudiusProgramError::AmountExceedsCap.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the vault is the reward manager token account and keeps the balance
+    /// floor after disbursing `amount`
+    fn check_funding(
+        reward_manager: &AccountInfo,
+        vault_token_account: &Pubkey,
+        vault_token_acc_data: &TokenAccount,
+        amount: u64,
+    ) -> ProgramResult {
+        let reward_manager_data = RewardManager::unpack(&reward_manager.data.borrow())?;
+        if reward_manager_data.token_account != *vault_token_account {
+            return Err(AudiusProgramError::WrongVaultAccount.into());
+        }
+        if !is_funded(&reward_manager_data, vault_token_acc_data.amount, amount) {
+            return Err(AudiusProgramError::InsufficientFunding.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the reward manager allows transfers and returns verified bot oracle data
+    fn check_transfer_preconditions<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        bot_oracle: &AccountInfo<'a>,
+        funder: &AccountInfo<'a>,
+        amounts: &[u64],
+    ) -> Result<OracleAccount, ProgramError> {
+        Self::check_transfers_allowed(reward_manager, funder, amounts)?;
+
+        Self::check_bot_oracle(program_id, reward_manager, bot_oracle)
+    }
+
+    /// Checks the bot oracle account belongs to the reward manager and returns its data
+    fn check_bot_oracle<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        bot_oracle: &AccountInfo<'a>,
+    ) -> Result<OracleAccount, ProgramError> {
+        let bot_oracle_data = OracleAccount::try_from_slice(&bot_oracle.data.borrow())?;
+        if !bot_oracle_data.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        is_owner!(*program_id, reward_manager, bot_oracle)?;
+
+        if bot_oracle_data.reward_manager != *reward_manager.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+
+        // NOTE: oracle seeds keep a sender account from being passed as the oracle
+        let generated_bot_oracle_key = get_address_pair(
+            program_id,
+            reward_manager.key,
+            [
+                ORACLE_SEED_PREFIX.as_ref(),
+                bot_oracle_data.eth_address.as_ref(),
+            ]
+            .concat(),
+        )?;
+
+        if generated_bot_oracle_key.derive.address != *bot_oracle.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(bot_oracle_data)
+    }
+
+    /// Splits the bot oracles voting in addition to the first one off the head of
+    /// the attesting accounts, returns their verified data and the senders
+    fn split_additional_oracles<'a, 'b>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        mut attesters: Vec<&'b AccountInfo<'a>>,
+    ) -> Result<(Vec<OracleAccount>, Vec<&'b AccountInfo<'a>>), ProgramError> {
+        let reward_manager_data = RewardManager::unpack(&reward_manager.data.borrow())?;
+        let additional_oracles = reward_manager_data.min_oracle_votes.saturating_sub(1) as usize;
+        if attesters.len() < additional_oracles {
+            return Err(AudiusProgramError::NotEnoughSigners.into());
+        }
+
+        let senders = attesters.split_off(additional_oracles);
+        let oracles = attesters
+            .into_iter()
+            .map(|oracle| Self::check_bot_oracle(program_id, reward_manager, oracle))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((oracles, senders))
+    }
+
+    /// Checks recipient and transfer receipt accounts of a single transfer
+    fn check_transfer_accounts<'a>(
+        program_id: &Pubkey,
+        reward_manager_key: &Pubkey,
+        claimable_tokens_program: &Pubkey,
+        mint: &Pubkey,
+        recipient: &AccountInfo<'a>,
+        transfer_acc_to_create: &AccountInfo<'a>,
+        transfer_data: &Transfer,
+    ) -> ProgramResult {
+        Self::check_transfer_receipt(
+            program_id,
+            reward_manager_key,
+            transfer_acc_to_create,
+            &transfer_data.id,
+        )?;
+
+        let generated_recipient_key = claimable_tokens::utils::program::get_address_pair(
+            claimable_tokens_program,
+            mint,
+            transfer_data.eth_recipient,
+        )?;
+
+        if generated_recipient_key.derive.address != *recipient.key {
+            return Err(AudiusProgramError::WrongRecipientKey.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the receipt account of the transfer `id` is not created yet
+    fn check_transfer_receipt(
+        program_id: &Pubkey,
+        reward_manager_key: &Pubkey,
+        transfer_acc_to_create: &AccountInfo,
+        id: &str,
+    ) -> ProgramResult {
+        if get_transfer_account_address(program_id, reward_manager_key, id)?
+            != *transfer_acc_to_create.key
+        {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if is_transfer_disbursed(
+            program_id,
+            transfer_acc_to_create.owner,
+            transfer_acc_to_create.lamports(),
+        ) {
+            return Err(AudiusProgramError::AlreadyClaimed.into());
+        }
+
+        Ok(())
+    }
+
+    /// Loads the optional account the manager creates for the reward manager with
+    /// the seed `prefix`, `None` while it isn't created
+    fn load_optional_config<T: BorshDeserialize>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo,
+        config_info: &AccountInfo,
+        prefix: &str,
+    ) -> Result<Option<T>, ProgramError> {
+        let pair = get_address_pair(program_id, reward_manager.key, prefix.as_bytes().to_vec())?;
+        if *config_info.key != pair.derive.address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if config_info.data_is_empty() {
+            return Ok(None);
+        }
+        is_owner!(*program_id, config_info)?;
+
+        Ok(Some(T::try_from_slice(&config_info.data.borrow())?))
+    }
+
+    /// Accounts the disbursed amount against the spend counter if the manager set a budget
+    fn charge_spend_counter<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        spend_counter_info: &AccountInfo<'a>,
+        amount: u64,
+    ) -> ProgramResult {
+        let mut spend_counter = match Self::load_optional_config::<SpendCounter>(
+            program_id,
+            reward_manager,
+            spend_counter_info,
+            SPEND_COUNTER_SEED_PREFIX,
+        )? {
+            Some(spend_counter) => spend_counter,
+            None => return Ok(()),
+        };
+
+        let clock = Clock::get()?;
+        spend_counter.charge(amount, clock.slot, clock.epoch)?;
+        spend_counter.serialize(&mut *spend_counter_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Counts an attestation of every sender operator against the rate limiter,
+    /// if created
+    fn charge_rate_limiter(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo,
+        rate_limiter_info: &AccountInfo,
+        senders: &[&AccountInfo],
+    ) -> ProgramResult {
+        let mut rate_limiter = match Self::load_optional_config::<RateLimiter>(
+            program_id,
+            reward_manager,
+            rate_limiter_info,
+            RATE_LIMITER_SEED_PREFIX,
+        )? {
+            Some(rate_limiter) => rate_limiter,
+            None => return Ok(()),
+        };
+
+        // NOTE: senders are verified already, their operators are distinct
+        let operators = senders
+            .iter()
+            .map(|sender| SenderAccount::unpack(&sender.data.borrow()).map(|data| data.operator))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let clock = Clock::get()?;
+        rate_limiter.charge(&operators, clock.slot, clock.epoch)?;
+        rate_limiter.serialize(&mut *rate_limiter_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Checks none of the Ethereum recipients is blocked by the recipient policy,
+    /// if created
+    fn check_recipient_policy(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo,
+        recipient_policy_info: &AccountInfo,
+        eth_recipients: &[EthereumAddress],
+    ) -> ProgramResult {
+        let policy = Self::load_optional_config::<RecipientPolicy>(
+            program_id,
+            reward_manager,
+            recipient_policy_info,
+            RECIPIENT_POLICY_SEED_PREFIX,
+        )?;
+        if let Some(policy) = policy {
+            if eth_recipients
+                .iter()
+                .any(|eth_recipient| policy.is_blocked(eth_recipient))
+            {
+                return Err(AudiusProgramError::RecipientBlocked.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the transfer ID is prefixed with a challenge of the challenge registry
+    /// the bot oracle is authorized for, if created
+    fn check_challenge_registry(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo,
+        challenge_registry_info: &AccountInfo,
+        transfer_id: &str,
+        bot_oracle: &EthereumAddress,
+    ) -> ProgramResult {
+        let registry = Self::load_optional_config::<ChallengeRegistry>(
+            program_id,
+            reward_manager,
+            challenge_registry_info,
+            CHALLENGE_REGISTRY_SEED_PREFIX,
+        )?;
+        if let Some(registry) = registry {
+            registry.authorize(transfer_id, bot_oracle)?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the reward manager transfer fee out of `amount` of the transfer `id` to
+    /// the fee account, returns the fee
+    fn charge_transfer_fee<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        fee_account: &AccountInfo<'a>,
+        id: &str,
+        amount: u64,
+    ) -> Result<u64, ProgramError> {
+        let reward_manager_data = RewardManager::unpack(&reward_manager.data.borrow())?;
+        let fee = reward_manager_data.transfer_fee(amount);
+        if fee == 0 {
+            return Ok(0);
+        }
+        if reward_manager_data.fee_account != *fee_account.key {
+            return Err(AudiusProgramError::WrongFeeAccount.into());
+        }
+
+        token_transfer(
+            program_id,
+            &reward_manager_data.token_program,
+            reward_manager.key,
+            vault_token_account,
+            fee_account,
+            reward_manager_authority,
+            fee,
+        )?;
+
+        Event::TransferFeeCharged {
+            reward_manager: *reward_manager.key,
+            id: id.to_string(),
+            fee_account: *fee_account.key,
+            fee,
+        }
+        .emit();
+
+        Ok(fee)
+    }
+
+    /// Sends tokens less the already charged `fee` to the recipient and records
+    /// the transfer receipt
+    #[allow(clippy::too_many_arguments)]
+    fn disburse<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        funder: &AccountInfo<'a>,
+        transfer_acc_to_create: &AccountInfo<'a>,
+        event_log: &AccountInfo<'a>,
+        transfer_data: &Transfer,
+        fee: u64,
+    ) -> ProgramResult {
+        let amount = transfer_data
+            .amount
+            .checked_sub(fee)
+            .ok_or(AudiusProgramError::MathOverflow)?;
+        Self::send_with_receipt(
+            program_id,
+            reward_manager,
+            reward_manager_authority,
+            vault_token_account,
+            recipient,
+            funder,
+            transfer_acc_to_create,
+            &transfer_data.id,
+            amount,
+        )?;
+
+        Event::TransferEvaluated {
+            reward_manager: *reward_manager.key,
+            id: transfer_data.id.clone(),
+            eth_recipient: transfer_data.eth_recipient,
+            amount,
+        }
+        .emit();
+
+        Self::record_transfer_evaluation(
+            program_id,
+            reward_manager,
+            event_log,
+            &transfer_data.id,
+            transfer_data.eth_recipient,
+            Pubkey::default(),
+            amount,
+        )
+    }
+
+    /// Records the evaluation of the transfer `id` to either the Ethereum recipient
+    /// or the wallet in the event log, if created
+    fn record_transfer_evaluation(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo,
+        event_log_info: &AccountInfo,
+        id: &str,
+        eth_recipient: EthereumAddress,
+        wallet: Pubkey,
+        amount: u64,
+    ) -> ProgramResult {
+        let mut event_log = match Self::load_optional_config::<EventLog>(
+            program_id,
+            reward_manager,
+            event_log_info,
+            EVENT_LOG_SEED_PREFIX,
+        )? {
+            Some(event_log) => event_log,
+            None => return Ok(()),
+        };
+
+        event_log.record(EventLogEntry {
+            eth_recipient,
+            wallet,
+            amount,
+            id_hash: transfer_id_hash(id),
+            slot: Clock::get()?.slot,
+        });
+        event_log.serialize(&mut *event_log_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Sends `amount` tokens to the recipient, records the receipt of the transfer `id`
+    /// and adds it to the reward manager payout totals
+    #[allow(clippy::too_many_arguments)]
+    fn send_with_receipt<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        funder: &AccountInfo<'a>,
+        transfer_acc_to_create: &AccountInfo<'a>,
+        id: &str,
+        amount: u64,
+    ) -> ProgramResult {
+        if recipient.key == vault_token_account.key {
+            return Err(AudiusProgramError::InvalidDestination.into());
+        }
+
+        let mut reward_manager_data = RewardManager::unpack(&reward_manager.data.borrow())?;
+        token_transfer(
+            program_id,
+            &reward_manager_data.token_program,
+            reward_manager.key,
+            vault_token_account,
+            recipient,
+            reward_manager_authority,
+            amount,
+        )?;
+
+        create_account_with_seed(
+            program_id,
+            funder,
+            transfer_acc_to_create,
+            reward_manager_authority,
+            reward_manager.key,
+            [TRANSFER_SEED_PREFIX.as_bytes().as_ref(), id.as_ref()].concat(),
+            Rent::get()?.minimum_balance(TransferReceipt::LEN),
+            TransferReceipt::LEN as u64,
+            program_id,
+        )?;
+
+        TransferReceipt::new(*recipient.key, amount, Clock::get()?.slot)
+            .serialize(&mut *transfer_acc_to_create.data.borrow_mut())?;
+
+        reward_manager_data.record_disbursement(amount);
+        reward_manager_data.pack(&mut reward_manager.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Creates the claimable token account of `eth_recipient` through the claimable
+    /// tokens program unless it exists already
+    fn create_missing_recipient<'a>(
+        reward_manager_data: &RewardManager,
+        funder: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        accounts: RecipientCreation<'_, 'a>,
+        eth_recipient: EthereumAddress,
+    ) -> ProgramResult {
+        if !recipient.data_is_empty() {
+            return Ok(());
+        }
+        if *accounts.claimable_tokens_program.key != reward_manager_data.claimable_tokens_program {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // NOTE: the recipient is already checked to derive from the vault mint,
+        // the claimable tokens program derives it from `mint` and rejects another one
+        invoke(
+            &claimable_tokens::instruction::init(
+                accounts.claimable_tokens_program.key,
+                funder.key,
+                accounts.mint.key,
+                claimable_tokens::instruction::CreateTokenAccount {
+                    eth_address: eth_recipient,
+                },
+            )?,
+            &[
+                funder.clone(),
+                accounts.mint.clone(),
+                accounts.claimable_authority.clone(),
+                recipient.clone(),
+                accounts.rent.clone(),
+                accounts.spl_token_program.clone(),
+                accounts.system_program.clone(),
+                accounts.claimable_tokens_program.clone(),
+            ],
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_transfer<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        bot_oracle: &AccountInfo<'a>,
+        funder: &AccountInfo<'a>,
+        transfer_acc_to_create: &AccountInfo<'a>,
+        attestations: Attestations<'_, 'a>,
+        spend_counter: &AccountInfo<'a>,
+        recipient_policy: &AccountInfo<'a>,
+        fee_account: &AccountInfo<'a>,
+        challenge_registry: &AccountInfo<'a>,
+        event_log: &AccountInfo<'a>,
+        rate_limiter: &AccountInfo<'a>,
+        recipient_creation: Option<RecipientCreation<'_, 'a>>,
+        memo_program: Option<&AccountInfo<'a>>,
+        transfer_data: Transfer,
+        senders: Vec<&AccountInfo<'a>>,
+    ) -> ProgramResult {
+        let reward_manager_data = RewardManager::unpack(&reward_manager.data.borrow())?;
+        check_message_version(&reward_manager_data, transfer_data.message_version)?;
+        let bot_oracle_data = Self::check_transfer_preconditions(
+            program_id,
+            reward_manager,
+            bot_oracle,
+            funder,
+            &[transfer_data.amount],
+        )?;
+        Self::check_challenge_registry(
+            program_id,
+            reward_manager,
+            challenge_registry,
+            &transfer_data.id,
+            &bot_oracle_data.eth_address,
+        )?;
+        let (additional_oracles, senders) =
+            Self::split_additional_oracles(program_id, reward_manager, senders)?;
+
+        let vault_token_acc_data = TokenAccount::unpack(&vault_token_account.data.borrow())?;
+        Self::check_funding(
+            reward_manager,
+            vault_token_account.key,
+            &vault_token_acc_data,
+            transfer_data.amount,
+        )?;
+
+        Self::check_transfer_accounts(
+            program_id,
+            reward_manager.key,
+            &reward_manager_data.claimable_tokens_program,
+            &vault_token_acc_data.mint,
+            recipient,
+            transfer_acc_to_create,
+            &transfer_data,
+        )?;
+
+        let verifier = TransferVerifier {
+            bot_oracle: bot_oracle_data,
+            additional_oracles,
+            transfer_data: transfer_data.clone(),
+        };
+        Self::check_attestations(
+            program_id,
+            reward_manager,
+            attestations,
+            senders.clone(),
+            senders.len() + verifier.extra_signatures(),
+            verifier,
+        )?;
+
+        Self::check_recipient_policy(
+            program_id,
+            reward_manager,
+            recipient_policy,
+            &[transfer_data.eth_recipient],
+        )?;
+
+        Self::charge_spend_counter(
+            program_id,
+            reward_manager,
+            spend_counter,
+            transfer_data.amount,
+        )?;
+        Self::charge_rate_limiter(program_id, reward_manager, rate_limiter, &senders)?;
+
+        if let Some(recipient_creation) = recipient_creation {
+            Self::create_missing_recipient(
+                &reward_manager_data,
+                funder,
+                recipient,
+                recipient_creation,
+                transfer_data.eth_recipient,
+            )?;
+        }
+
+        let fee = Self::charge_transfer_fee(
+            program_id,
+            reward_manager,
+            reward_manager_authority,
+            vault_token_account,
+            fee_account,
+            &transfer_data.id,
+            transfer_data.amount,
+        )?;
+
+        Self::disburse(
+            program_id,
+            reward_manager,
+            reward_manager_authority,
+            vault_token_account,
+            recipient,
+            funder,
+            transfer_acc_to_create,
+            event_log,
+            &transfer_data,
+            fee,
+        )?;
+
+        if let Some(memo_program) = memo_program {
+            Self::log_transfer_memo(memo_program, &transfer_data.id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Logs the transfer ID and its challenge through the SPL Memo program,
+    /// wallets show the memo in the transaction history
+    fn log_transfer_memo(memo_program: &AccountInfo, transfer_id: &str) -> ProgramResult {
+        if *memo_program.key != spl_memo::id() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        invoke(
+            &spl_memo::build_memo(transfer_memo(transfer_id).as_bytes(), &[]),
+            &[memo_program.clone()],
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_transfer_to_wallet<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        bot_oracle: &AccountInfo<'a>,
+        funder: &AccountInfo<'a>,
+        transfer_acc_to_create: &AccountInfo<'a>,
+        instruction_info: &AccountInfo<'a>,
+        spend_counter: &AccountInfo<'a>,
+        fee_account: &AccountInfo<'a>,
+        challenge_registry: &AccountInfo<'a>,
+        event_log: &AccountInfo<'a>,
+        rate_limiter: &AccountInfo<'a>,
+        transfer_data: TransferToWallet,
+        senders: Vec<&AccountInfo<'a>>,
+    ) -> ProgramResult {
+        let bot_oracle_data = Self::check_transfer_preconditions(
+            program_id,
+            reward_manager,
+            bot_oracle,
+            funder,
+            &[transfer_data.amount],
+        )?;
+        Self::check_challenge_registry(
+            program_id,
+            reward_manager,
+            challenge_registry,
+            &transfer_data.id,
+            &bot_oracle_data.eth_address,
+        )?;
+        let (additional_oracles, senders) =
+            Self::split_additional_oracles(program_id, reward_manager, senders)?;
+
+        let vault_token_acc_data = TokenAccount::unpack(&vault_token_account.data.borrow())?;
+        Self::check_funding(
+            reward_manager,
+            vault_token_account.key,
+            &vault_token_acc_data,
+            transfer_data.amount,
+        )?;
+
+        Self::check_transfer_receipt(
+            program_id,
+            reward_manager.key,
+            transfer_acc_to_create,
+            &transfer_data.id,
+        )?;
+        if get_associated_token_address(&transfer_data.wallet, &vault_token_acc_data.mint)
+            != *recipient.key
+        {
+            return Err(AudiusProgramError::WrongRecipientKey.into());
+        }
+
+        let verifier = WalletTransferVerifier {
+            bot_oracle: bot_oracle_data,
+            additional_oracles,
+            transfer_data: transfer_data.clone(),
+        };
+        Self::check_secp_signs(
+            program_id,
+            reward_manager,
+            instruction_info,
+            senders.clone(),
+            senders.len() + verifier.extra_signatures(),
+            verifier,
+        )?;
+
+        Self::charge_spend_counter(
+            program_id,
+            reward_manager,
+            spend_counter,
+            transfer_data.amount,
+        )?;
+        Self::charge_rate_limiter(program_id, reward_manager, rate_limiter, &senders)?;
+
+        let fee = Self::charge_transfer_fee(
+            program_id,
+            reward_manager,
+            reward_manager_authority,
+            vault_token_account,
+            fee_account,
+            &transfer_data.id,
+            transfer_data.amount,
+        )?;
+        let amount = transfer_data
+            .amount
+            .checked_sub(fee)
+            .ok_or(AudiusProgramError::MathOverflow)?;
+
+        Self::send_with_receipt(
+            program_id,
+            reward_manager,
+            reward_manager_authority,
+            vault_token_account,
+            recipient,
+            funder,
+            transfer_acc_to_create,
+            &transfer_data.id,
+            amount,
+        )?;
+
+        Self::record_transfer_evaluation(
+            program_id,
+            reward_manager,
+            event_log,
+            &transfer_data.id,
+            EthereumAddress::default(),
+            transfer_data.wallet,
+            amount,
+        )?;
+
+        Event::WalletTransferEvaluated {
+            reward_manager: *reward_manager.key,
+            id: transfer_data.id,
+            wallet: transfer_data.wallet,
+            amount,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_transfer_batch<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        bot_oracle: &AccountInfo<'a>,
+        funder: &AccountInfo<'a>,
+        instruction_info: &AccountInfo<'a>,
+        spend_counter: &AccountInfo<'a>,
+        recipient_policy: &AccountInfo<'a>,
+        fee_account: &AccountInfo<'a>,
+        challenge_registry: &AccountInfo<'a>,
+        event_log: &AccountInfo<'a>,
+        rate_limiter: &AccountInfo<'a>,
+        transfers: Vec<Transfer>,
+        transfer_accounts: &[&AccountInfo<'a>],
+        senders: Vec<&AccountInfo<'a>>,
+    ) -> ProgramResult {
+        if transfers.is_empty() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let amounts = transfers
+            .iter()
+            .map(|transfer| transfer.amount)
+            .collect::<Vec<_>>();
+        let amount = amounts
+            .iter()
+            .try_fold(0u64, |total, amount| total.checked_add(*amount))
+            .ok_or(AudiusProgramError::MathOverflow)?;
+
+        let bot_oracle_data = Self::check_transfer_preconditions(
+            program_id,
+            reward_manager,
+            bot_oracle,
+            funder,
+            &amounts,
+        )?;
+        for transfer_data in transfers.iter() {
+            Self::check_challenge_registry(
+                program_id,
+                reward_manager,
+                challenge_registry,
+                &transfer_data.id,
+                &bot_oracle_data.eth_address,
+            )?;
+        }
+        let (additional_oracles, senders) =
+            Self::split_additional_oracles(program_id, reward_manager, senders)?;
+
+        let vault_token_acc_data = TokenAccount::unpack(&vault_token_account.data.borrow())?;
+        Self::check_funding(
+            reward_manager,
+            vault_token_account.key,
+            &vault_token_acc_data,
+            amount,
+        )?;
+
+        let claimable_tokens_program =
+            RewardManager::unpack(&reward_manager.data.borrow())?.claimable_tokens_program;
+        for (transfer_data, accounts) in transfers.iter().zip(transfer_accounts.chunks(2)) {
+            Self::check_transfer_accounts(
+                program_id,
+                reward_manager.key,
+                &claimable_tokens_program,
+                &vault_token_acc_data.mint,
+                accounts[0],
+                accounts[1],
+                transfer_data,
+            )?;
+        }
+
+        let verifier = TransferBatchVerifier {
+            bot_oracle: bot_oracle_data,
+            additional_oracles,
+            batch_digest: transfer_batch_digest(&transfers)?,
+        };
+        Self::check_secp_signs(
+            program_id,
+            reward_manager,
+            instruction_info,
+            senders.clone(),
+            senders.len() + verifier.extra_signatures(),
+            verifier,
+        )?;
+
+        let eth_recipients = transfers
+            .iter()
+            .map(|transfer| transfer.eth_recipient)
+            .collect::<Vec<_>>();
+        Self::check_recipient_policy(
+            program_id,
+            reward_manager,
+            recipient_policy,
+            &eth_recipients,
+        )?;
+
+        Self::charge_spend_counter(program_id, reward_manager, spend_counter, amount)?;
+        Self::charge_rate_limiter(program_id, reward_manager, rate_limiter, &senders)?;
+
+        for (transfer_data, accounts) in transfers.iter().zip(transfer_accounts.chunks(2)) {
+            let fee = Self::charge_transfer_fee(
+                program_id,
+                reward_manager,
+                reward_manager_authority,
+                vault_token_account,
+                fee_account,
+                &transfer_data.id,
+                transfer_data.amount,
+            )?;
+            Self::disburse(
+                program_id,
+                reward_manager,
+                reward_manager_authority,
+                vault_token_account,
+                accounts[0],
+                funder,
+                accounts[1],
+                event_log,
+                transfer_data,
+                fee,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_submit_distribution_root<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        bot_oracle: &AccountInfo<'a>,
+        funder: &AccountInfo<'a>,
+        distribution_info: &AccountInfo<'a>,
+        instruction_info: &AccountInfo<'a>,
+        rate_limiter: &AccountInfo<'a>,
+        params: SubmitDistributionRoot,
+        senders: Vec<&AccountInfo<'a>>,
+    ) -> ProgramResult {
+        let bot_oracle_data = Self::check_transfer_preconditions(
+            program_id,
+            reward_manager,
+            bot_oracle,
+            funder,
+            &[],
+        )?;
+        let (additional_oracles, senders) =
+            Self::split_additional_oracles(program_id, reward_manager, senders)?;
+
+        let bot_oracle_eth_address = bot_oracle_data.eth_address;
+
+        let seeds = [DISTRIBUTION_SEED_PREFIX.as_bytes(), params.id.as_bytes()].concat();
+        let pair = get_address_pair(program_id, reward_manager.key, seeds.clone())?;
+        if *distribution_info.key != pair.derive.address {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let verifier = DistributionRootVerifier {
+            bot_oracle: bot_oracle_data,
+            additional_oracles,
+            distribution_id: params.id,
+            root: params.root,
+        };
+        Self::check_secp_signs(
+            program_id,
+            reward_manager,
+            instruction_info,
+            senders.clone(),
+            senders.len() + verifier.extra_signatures(),
+            verifier,
+        )?;
+        Self::charge_rate_limiter(program_id, reward_manager, rate_limiter, &senders)?;
+
+        create_account_with_seed(
+            program_id,
+            funder,
+            distribution_info,
+            reward_manager_authority,
+            reward_manager.key,
+            seeds,
+            Rent::get()?.minimum_balance(DistributionRoot::LEN),
+            DistributionRoot::LEN as _,
+            program_id,
+        )?;
+
+        DistributionRoot::new(*reward_manager.key, params.root, bot_oracle_eth_address)
+            .serialize(&mut *distribution_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_claim_from_root<'a>(
+        program_id: &Pubkey,
+        reward_manager: &AccountInfo<'a>,
+        reward_manager_authority: &AccountInfo<'a>,
+        distribution_info: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        vault_token_account: &AccountInfo<'a>,
+        funder: &AccountInfo<'a>,
+        transfer_acc_to_create: &AccountInfo<'a>,
+        spend_counter: &AccountInfo<'a>,
+        recipient_policy: &AccountInfo<'a>,
+        fee_account: &AccountInfo<'a>,
+        challenge_registry: &AccountInfo<'a>,
+        event_log: &AccountInfo<'a>,
+        params: ClaimFromRoot,
+    ) -> ProgramResult {
+        Self::check_transfers_allowed(reward_manager, funder, &[params.transfer.amount])?;
+
+        is_owner!(*program_id, reward_manager, distribution_info)?;
+
+        let distribution = DistributionRoot::unpack(&distribution_info.data.borrow())?;
+        if !distribution.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+        if distribution.reward_manager != *reward_manager.key {
+            return Err(AudiusProgramError::WrongRewardManagerKey.into());
+        }
+
+        // NOTE: the transfer was attested along with the root
+        Self::check_challenge_registry(
+            program_id,
+            reward_manager,
+            challenge_registry,
+            &params.transfer.id,
+            &distribution.bot_oracle,
+        )?;
+
+        if !verify_distribution_proof(
+            &distribution.root,
+            distribution_leaf(&params.transfer)?,
+            &params.proof,
+        ) {
+            return Err(AudiusProgramError::InvalidMerkleProof.into());
+        }
+
+        let vault_token_acc_data = TokenAccount::unpack(&vault_token_account.data.borrow())?;
+        Self::check_funding(
+            reward_manager,
+            vault_token_account.key,
+            &vault_token_acc_data,
+            params.transfer.amount,
+        )?;
+
+        let claimable_tokens_program =
+            RewardManager::unpack(&reward_manager.data.borrow())?.claimable_tokens_program;
+        Self::check_transfer_accounts(
+            program_id,
+            reward_manager.key,
+            &claimable_tokens_program,
+            &vault_token_acc_data.mint,
+            recipient,
+            transfer_acc_to_create,
+            &params.transfer,
+        )?;
+
+        Self::check_recipient_policy(
+            program_id,
+            reward_manager,
+            recipient_policy,
+            &[params.transfer.eth_recipient],
+        )?;
+
+        Self::charge_spend_counter(
+            program_id,
+            reward_manager,
+            spend_counter,
+            params.transfer.amount,
+        )?;
+
+        let fee = Self::charge_transfer_fee(
+            program_id,
+            reward_manager,
+            reward_manager_authority,
+            vault_token_account,
+            fee_account,
+            &params.transfer.id,
+            params.transfer.amount,
+        )?;
+
+        Self::disburse(
+            program_id,
+            reward_manager,
+            reward_manager_authority,
+            vault_token_account,
+            recipient,
+            funder,
+            transfer_acc_to_create,
+            event_log,
+            &params.transfer,
+            fee,
+        )
+    }
+
+    /// Processes an instruction
+    pub fn process_instruction(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        input: &[u8],
+    ) -> ProgramResult {
+        let instruction = decode(input)?;
+        #[cfg(feature = "verbose-logs")]
+        crate::log::log_params(&instruction);
+        check_transfer_ids(&instruction)?;
+
+        let account_info_iter = &mut accounts.iter();
+        match instruction {
+            Instructions::InitRewardManager(InitRewardManager {
+                min_votes,
+                claimable_tokens_program,
+            }) => {
+                msg!("Instruction: InitRewardManager");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let token_account = next_account_info(account_info_iter)?;
+                let mint = next_account_info(account_info_iter)?;
+                let manager = next_account_info(account_info_iter)?;
+                let authority = next_account_info(account_info_iter)?;
+                let token_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_init_instruction(
+                    program_id,
+                    reward_manager,
+                    token_account,
+                    mint,
+                    manager,
+                    authority,
+                    token_program,
+                    rent,
+                    min_votes,
+                    claimable_tokens_program,
+                )
+            }
+            Instructions::CreateSender(CreateSender {
+                eth_address,
+                operator,
+            }) => {
+                msg!("Instruction: CreateSender");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let authority = next_account_info(account_info_iter)?;
+                let funder_account = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                let sys_prog = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                let legacy_sender = next_account_info(account_info_iter)?;
+                let operator_account = next_account_info(account_info_iter)?;
+                let delegate = account_info_iter.next();
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_create_sender(
+                    program_id,
+                    eth_address,
+                    operator,
+                    reward_manager,
+                    manager_account,
+                    authority,
+                    funder_account,
+                    sender,
+                    sys_prog,
+                    rent,
+                    legacy_sender,
+                    operator_account,
+                    delegate,
+                )
+            }
+            Instructions::DeleteSender => {
+                msg!("Instruction: DeleteSender");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                let refunder = next_account_info(account_info_iter)?;
+                let sys_prog = next_account_info(account_info_iter)?;
+                let operator_account = next_account_info(account_info_iter)?;
+                let delegate = account_info_iter.next();
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_delete_sender(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    sender,
+                    refunder,
+                    sys_prog,
+                    operator_account,
+                    delegate,
+                )
+            }
+            Instructions::AddSender(AddSender {
+                eth_address,
+                operator,
+            }) => {
+                msg!("Instruction: AddSender");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let authority = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let new_sender = next_account_info(account_info_iter)?;
+                let instructions_info = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let legacy_sender = next_account_info(account_info_iter)?;
+                let operator_account = next_account_info(account_info_iter)?;
+                let signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+
+                Self::process_add_sender(
+                    program_id,
+                    reward_manager,
+                    authority,
+                    funder,
+                    new_sender,
+                    instructions_info,
+                    rent,
+                    legacy_sender,
+                    operator_account,
+                    signers,
+                    eth_address,
+                    operator,
+                )
+            }
+            Instructions::Transfer(Transfer {
+                amount,
+                id,
+                eth_recipient,
+                message_version,
+                create_recipient,
+                memo,
+            }) => {
+                msg!("Instruction: Transfer");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let reward_manager_authority = next_account_info(account_info_iter)?;
+                let recipient = next_account_info(account_info_iter)?;
+                let vault_token_account = next_account_info(account_info_iter)?;
+                let bot_oracle = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let transfer_acc_to_create = next_account_info(account_info_iter)?;
+                let instruction_info = next_account_info(account_info_iter)?;
+                let spl_token_program = next_account_info(account_info_iter)?;
+                let system_program = next_account_info(account_info_iter)?;
+                let spend_counter = next_account_info(account_info_iter)?;
+                let recipient_policy = next_account_info(account_info_iter)?;
+                let fee_account = next_account_info(account_info_iter)?;
+                let challenge_registry = next_account_info(account_info_iter)?;
+                let event_log = next_account_info(account_info_iter)?;
+                let rate_limiter = next_account_info(account_info_iter)?;
+
+                let mut signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+                let memo_program = if memo {
+                    Some(signers.pop().ok_or(ProgramError::NotEnoughAccountKeys)?)
+                } else {
+                    None
+                };
+                let recipient_creation = if create_recipient {
+                    Some(RecipientCreation::split_off(
+                        &mut signers,
+                        spl_token_program,
+                        system_program,
+                    )?)
+                } else {
+                    None
+                };
+
+                Self::process_transfer(
+                    program_id,
+                    reward_manager,
+                    reward_manager_authority,
+                    recipient,
+                    vault_token_account,
+                    bot_oracle,
+                    funder,
+                    transfer_acc_to_create,
+                    Attestations::SecpInstructions(instruction_info),
+                    spend_counter,
+                    recipient_policy,
+                    fee_account,
+                    challenge_registry,
+                    event_log,
+                    rate_limiter,
+                    recipient_creation,
+                    memo_program,
+                    Transfer {
+                        amount,
+                        id,
+                        eth_recipient,
+                        message_version,
+                        create_recipient,
+                        memo,
+                    },
+                    signers,
+                )
+            }
+            Instructions::UpdateRewardManager(params) => {
+                msg!("Instruction: UpdateRewardManager");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_update_reward_manager(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    params,
+                )
+            }
+            Instructions::Pause => {
+                msg!("Instruction: Pause");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let delegate = account_info_iter.next();
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_set_paused(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    delegate,
+                    true,
+                )
+            }
+            Instructions::Unpause => {
+                msg!("Instruction: Unpause");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let delegate = account_info_iter.next();
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_set_paused(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    delegate,
+                    false,
+                )
+            }
+            Instructions::TransferBatch(TransferBatch { transfers }) => {
+                msg!("Instruction: TransferBatch");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let reward_manager_authority = next_account_info(account_info_iter)?;
+                let vault_token_account = next_account_info(account_info_iter)?;
+                let bot_oracle = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let instruction_info = next_account_info(account_info_iter)?;
+                let _spl_token_program = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let spend_counter = next_account_info(account_info_iter)?;
+                let recipient_policy = next_account_info(account_info_iter)?;
+                let fee_account = next_account_info(account_info_iter)?;
+                let challenge_registry = next_account_info(account_info_iter)?;
+                let event_log = next_account_info(account_info_iter)?;
+                let rate_limiter = next_account_info(account_info_iter)?;
+
+                let remaining = account_info_iter.collect::<Vec<&AccountInfo>>();
+                // NOTE: recipient and transfer receipt for every transfer
+                if remaining.len() < transfers.len() * 2 {
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                }
+                let (transfer_accounts, signers) = remaining.split_at(transfers.len() * 2);
+
+                Self::process_transfer_batch(
+                    program_id,
+                    reward_manager,
+                    reward_manager_authority,
+                    vault_token_account,
+                    bot_oracle,
+                    funder,
+                    instruction_info,
+                    spend_counter,
+                    recipient_policy,
+                    fee_account,
+                    challenge_registry,
+                    event_log,
+                    rate_limiter,
+                    transfers,
+                    transfer_accounts,
+                    signers.to_vec(),
+                )
+            }
+            Instructions::DeleteSenderPublic => {
+                msg!("Instruction: DeleteSenderPublic");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                let refunder = next_account_info(account_info_iter)?;
+                let instructions_info = next_account_info(account_info_iter)?;
+                let operator_account = next_account_info(account_info_iter)?;
+
+                let signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+
+                Self::process_delete_sender_public(
+                    program_id,
+                    reward_manager,
+                    sender,
+                    refunder,
+                    instructions_info,
+                    operator_account,
+                    signers,
+                )
+            }
+            Instructions::CreateOracle(CreateOracle {
+                eth_address,
+                operator,
+            }) => {
+                msg!("Instruction: CreateOracle");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let authority = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let oracle = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                let delegate = account_info_iter.next();
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_create_oracle(
+                    program_id,
+                    eth_address,
+                    operator,
+                    reward_manager,
+                    manager_account,
+                    authority,
+                    funder,
+                    oracle,
+                    rent,
+                    delegate,
+                )
+            }
+            Instructions::DeleteOracle => {
+                msg!("Instruction: DeleteOracle");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let oracle = next_account_info(account_info_iter)?;
+                let refunder = next_account_info(account_info_iter)?;
+                let delegate = account_info_iter.next();
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_delete_oracle(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    oracle,
+                    refunder,
+                    delegate,
+                )
+            }
+            Instructions::SetSpendLimit(params) => {
+                msg!("Instruction: SetSpendLimit");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let authority = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let spend_counter = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_set_spend_limit(
+                    program_id,
+                    params,
+                    reward_manager,
+                    manager_account,
+                    authority,
+                    funder,
+                    spend_counter,
+                    rent,
+                )
+            }
+            Instructions::SetRecipientBlocked(params) => {
+                msg!("Instruction: SetRecipientBlocked");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let authority = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let recipient_policy = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_set_recipient_blocked(
+                    program_id,
+                    params,
+                    reward_manager,
+                    manager_account,
+                    authority,
+                    funder,
+                    recipient_policy,
+                    rent,
+                )
+            }
+            Instructions::SubmitDistributionRoot(params) => {
+                msg!("Instruction: SubmitDistributionRoot");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let reward_manager_authority = next_account_info(account_info_iter)?;
+                let bot_oracle = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let distribution = next_account_info(account_info_iter)?;
+                let instruction_info = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rate_limiter = next_account_info(account_info_iter)?;
+
+                let signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+
+                Self::process_submit_distribution_root(
+                    program_id,
+                    reward_manager,
+                    reward_manager_authority,
+                    bot_oracle,
+                    funder,
+                    distribution,
+                    instruction_info,
+                    rate_limiter,
+                    params,
+                    signers,
+                )
+            }
+            Instructions::ClaimFromRoot(params) => {
+                msg!("Instruction: ClaimFromRoot");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let reward_manager_authority = next_account_info(account_info_iter)?;
+                let distribution = next_account_info(account_info_iter)?;
+                let recipient = next_account_info(account_info_iter)?;
+                let vault_token_account = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let transfer_acc_to_create = next_account_info(account_info_iter)?;
+                let _spl_token_program = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let spend_counter = next_account_info(account_info_iter)?;
+                let recipient_policy = next_account_info(account_info_iter)?;
+                let fee_account = next_account_info(account_info_iter)?;
+                let challenge_registry = next_account_info(account_info_iter)?;
+                let event_log = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_claim_from_root(
+                    program_id,
+                    reward_manager,
+                    reward_manager_authority,
+                    distribution,
+                    recipient,
+                    vault_token_account,
+                    funder,
+                    transfer_acc_to_create,
+                    spend_counter,
+                    recipient_policy,
+                    fee_account,
+                    challenge_registry,
+                    event_log,
+                    params,
+                )
+            }
+            Instructions::RotateSenderKey(RotateSenderKey { new_eth_address }) => {
+                msg!("Instruction: RotateSenderKey");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                let new_sender = next_account_info(account_info_iter)?;
+                let legacy_new_sender = next_account_info(account_info_iter)?;
+                let instructions_info = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                let operator_account = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_rotate_sender_key(
+                    program_id,
+                    reward_manager,
+                    funder,
+                    sender,
+                    new_sender,
+                    legacy_new_sender,
+                    instructions_info,
+                    rent,
+                    operator_account,
+                    new_eth_address,
+                )
+            }
+            Instructions::TransferToWallet(params) => {
+                msg!("Instruction: TransferToWallet");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let reward_manager_authority = next_account_info(account_info_iter)?;
+                let recipient = next_account_info(account_info_iter)?;
+                let vault_token_account = next_account_info(account_info_iter)?;
+                let bot_oracle = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let transfer_acc_to_create = next_account_info(account_info_iter)?;
+                let instruction_info = next_account_info(account_info_iter)?;
+                let _spl_token_program = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let spend_counter = next_account_info(account_info_iter)?;
+                let fee_account = next_account_info(account_info_iter)?;
+                let challenge_registry = next_account_info(account_info_iter)?;
+                let event_log = next_account_info(account_info_iter)?;
+                let rate_limiter = next_account_info(account_info_iter)?;
+
+                let signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+
+                Self::process_transfer_to_wallet(
+                    program_id,
+                    reward_manager,
+                    reward_manager_authority,
+                    recipient,
+                    vault_token_account,
+                    bot_oracle,
+                    funder,
+                    transfer_acc_to_create,
+                    instruction_info,
+                    spend_counter,
+                    fee_account,
+                    challenge_registry,
+                    event_log,
+                    rate_limiter,
+                    params,
+                    signers,
+                )
+            }
+            Instructions::CreateDelegate(params) => {
+                msg!("Instruction: CreateDelegate");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let delegate = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_create_delegate(
+                    program_id,
+                    params,
+                    reward_manager,
+                    manager_account,
+                    funder,
+                    delegate,
+                    rent,
+                )
+            }
+            Instructions::RevokeDelegate => {
+                msg!("Instruction: RevokeDelegate");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let delegate = next_account_info(account_info_iter)?;
+                let refunder = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_revoke_delegate(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    delegate,
+                    refunder,
+                )
+            }
+            Instructions::ProposeManager(ProposeManager { new_manager }) => {
+                msg!("Instruction: ProposeManager");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_propose_manager(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    new_manager,
+                )
+            }
+            Instructions::AcceptManager => {
+                msg!("Instruction: AcceptManager");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let new_manager = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_accept_manager(program_id, reward_manager, new_manager)
+            }
+            Instructions::CreateManagerMultisig(params) => {
+                msg!("Instruction: CreateManagerMultisig");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let multisig = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_create_manager_multisig(
+                    program_id,
+                    params,
+                    reward_manager,
+                    manager_account,
+                    funder,
+                    multisig,
+                    rent,
+                )
+            }
+            Instructions::ProposeAdminAction(params) => {
+                msg!("Instruction: ProposeAdminAction");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let multisig = next_account_info(account_info_iter)?;
+                let admin_action = next_account_info(account_info_iter)?;
+                let proposer = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_propose_admin_action(
+                    program_id,
+                    params,
+                    reward_manager,
+                    multisig,
+                    admin_action,
+                    proposer,
+                    funder,
+                    rent,
+                )
+            }
+            Instructions::ApproveAdminAction => {
+                msg!("Instruction: ApproveAdminAction");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let multisig = next_account_info(account_info_iter)?;
+                let admin_action = next_account_info(account_info_iter)?;
+                let approver = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_approve_admin_action(
+                    program_id,
+                    reward_manager,
+                    multisig,
+                    admin_action,
+                    approver,
+                )
+            }
+            Instructions::ExecuteAdminAction => {
+                msg!("Instruction: ExecuteAdminAction");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let multisig = next_account_info(account_info_iter)?;
+                let admin_action = next_account_info(account_info_iter)?;
+                let proposer = next_account_info(account_info_iter)?;
+                let _reward_manager_program = next_account_info(account_info_iter)?;
+
+                Self::process_execute_admin_action(
+                    program_id,
+                    reward_manager,
+                    multisig,
+                    admin_action,
+                    proposer,
+                    accounts,
+                )
+            }
+            Instructions::SetSenderWeight(SetSenderWeight { weight }) => {
+                msg!("Instruction: SetSenderWeight");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_set_sender_weight(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    sender,
+                    weight,
+                )
+            }
+            Instructions::SuspendSender => {
+                msg!("Instruction: SuspendSender");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_set_sender_status(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    sender,
+                    SenderStatus::Suspended,
+                )
+            }
+            Instructions::ReinstateSender => {
+                msg!("Instruction: ReinstateSender");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_set_sender_status(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    sender,
+                    SenderStatus::Active,
+                )
+            }
+            Instructions::SetOperatorSendersLimit(SetOperatorSendersLimit {
+                operator,
+                max_senders,
+            }) => {
+                msg!("Instruction: SetOperatorSendersLimit");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let funder_account = next_account_info(account_info_iter)?;
+                let operator_account = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_set_operator_senders_limit(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    funder_account,
+                    operator_account,
+                    rent,
+                    operator,
+                    max_senders,
+                )
+            }
+            Instructions::TransferWithSignatures(TransferWithSignatures {
+                transfer,
+                signatures,
+            }) => {
+                msg!("Instruction: TransferWithSignatures");
+
+                let reward_manager = next_account_info(account_info_iter)?;
+                let reward_manager_authority = next_account_info(account_info_iter)?;
+                let recipient = next_account_info(account_info_iter)?;
+                let vault_token_account = next_account_info(account_info_iter)?;
+                let bot_oracle = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let transfer_acc_to_create = next_account_info(account_info_iter)?;
+                let spl_token_program = next_account_info(account_info_iter)?;
+                let system_program = next_account_info(account_info_iter)?;
+                let spend_counter = next_account_info(account_info_iter)?;
+                let recipient_policy = next_account_info(account_info_iter)?;
+                let fee_account = next_account_info(account_info_iter)?;
+                let challenge_registry = next_account_info(account_info_iter)?;
+                let event_log = next_account_info(account_info_iter)?;
+                let rate_limiter = next_account_info(account_info_iter)?;
 
-        is_owner!(*program_id, reward_manager, bot_oracle)?;
+                let mut signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+                let memo_program = if transfer.memo {
+                    Some(signers.pop().ok_or(ProgramError::NotEnoughAccountKeys)?)
+                } else {
+                    None
+                };
+                let recipient_creation = if transfer.create_recipient {
+                    Some(RecipientCreation::split_off(
+                        &mut signers,
+                        spl_token_program,
+                        system_program,
+                    )?)
+                } else {
+                    None
+                };
 
-        let generated_bot_oracle_key = get_address_pair(
-            program_id,
-            reward_manager.key,
-            [
-                SENDER_SEED_PREFIX.as_ref(),
-                bot_oracle_data.eth_address.as_ref(),
-            ]
-            .concat(),
-        )?;
+                Self::process_transfer(
+                    program_id,
+                    reward_manager,
+                    reward_manager_authority,
+                    recipient,
+                    vault_token_account,
+                    bot_oracle,
+                    funder,
+                    transfer_acc_to_create,
+                    Attestations::Signatures(signatures),
+                    spend_counter,
+                    recipient_policy,
+                    fee_account,
+                    challenge_registry,
+                    event_log,
+                    rate_limiter,
+                    recipient_creation,
+                    memo_program,
+                    transfer,
+                    signers,
+                )
+            }
+            Instructions::AnnounceTreasuryWithdrawal(params) => {
+                msg!("Instruction: AnnounceTreasuryWithdrawal");
 
-        if generated_bot_oracle_key.derive.address != *bot_oracle.key {
-            return Err(ProgramError::InvalidSeeds);
-        }
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-        let generated_transfer_acc_to_create = get_address_pair(
-            program_id,
-            reward_manager.key,
-            [
-                TRANSFER_SEED_PREFIX.as_bytes().as_ref(),
-                transfer_data.id.as_ref(),
-            ]
-            .concat(),
-        )?;
+                Self::process_announce_treasury_withdrawal(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    params,
+                )
+            }
+            Instructions::WithdrawTreasury => {
+                msg!("Instruction: WithdrawTreasury");
 
-        if generated_transfer_acc_to_create.derive.address != *transfer_acc_to_create.key {
-            return Err(ProgramError::InvalidSeeds);
-        }
+                let reward_manager = next_account_info(account_info_iter)?;
+                let reward_manager_authority = next_account_info(account_info_iter)?;
+                let vault_token_account = next_account_info(account_info_iter)?;
+                let destination = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let _spl_token_program = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-        let vault_token_acc_data = TokenAccount::unpack(&vault_token_account.data.borrow())?;
+                Self::process_withdraw_treasury(
+                    program_id,
+                    reward_manager,
+                    reward_manager_authority,
+                    vault_token_account,
+                    destination,
+                    manager_account,
+                )
+            }
+            Instructions::MigrateTokenAccount => {
+                msg!("Instruction: MigrateTokenAccount");
 
-        let generated_recipient_key = claimable_tokens::utils::program::get_address_pair(
-            &claimable_tokens::id(),
-            &vault_token_acc_data.mint,
-            transfer_data.eth_recipient,
-        )?;
+                let reward_manager = next_account_info(account_info_iter)?;
+                let reward_manager_authority = next_account_info(account_info_iter)?;
+                let vault_token_account = next_account_info(account_info_iter)?;
+                let new_token_account = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let _spl_token_program = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-        if generated_recipient_key.derive.address != *recipient.key {
-            return Err(AudiusProgramError::WrongRecipientKey.into());
-        }
+                Self::process_migrate_token_account(
+                    program_id,
+                    reward_manager,
+                    reward_manager_authority,
+                    vault_token_account,
+                    new_token_account,
+                    manager_account,
+                )
+            }
+            Instructions::SetTransferFee(params) => {
+                msg!("Instruction: SetTransferFee");
 
-        let verifier = build_verify_secp_transfer(bot_oracle_data, transfer_data.clone());
-        Self::check_secp_signs(
-            program_id,
-            reward_manager,
-            instruction_info,
-            senders.clone(),
-            // NOTE: +1 it's bot oracle
-            senders.len() + 1,
-            verifier,
-        )?;
+                let reward_manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let vault_token_account = next_account_info(account_info_iter)?;
+                let fee_account = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-        token_transfer(
-            program_id,
-            reward_manager.key,
-            vault_token_account,
-            recipient,
-            reward_manager_authority,
-            transfer_data.amount,
-        )?;
+                Self::process_set_transfer_fee(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    vault_token_account,
+                    fee_account,
+                    params,
+                )
+            }
+            Instructions::VerifyOnly(transfer) => {
+                msg!("Instruction: VerifyOnly");
 
-        create_account_with_seed(
-            program_id,
-            funder,
-            transfer_acc_to_create,
-            reward_manager_authority,
-            reward_manager.key,
-            [
-                TRANSFER_SEED_PREFIX.as_bytes().as_ref(),
-                transfer_data.id.as_ref(),
-            ]
-            .concat(),
-            TRANSFER_ACC_BALANCE as u64,
-            TRANSFER_ACC_SPACE as u64,
-            program_id,
-        )
-    }
+                let reward_manager = next_account_info(account_info_iter)?;
+                let bot_oracle = next_account_info(account_info_iter)?;
+                let instruction_info = next_account_info(account_info_iter)?;
 
-    /// Processes an instruction
-    pub fn process_instruction(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        input: &[u8],
-    ) -> ProgramResult {
-        let instruction = Instructions::try_from_slice(input)?;
-        let account_info_iter = &mut accounts.iter();
-        match instruction {
-            Instructions::InitRewardManager(InitRewardManager { min_votes }) => {
-                msg!("Instruction: InitRewardManager");
+                let signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+
+                Self::process_verify_only(
+                    program_id,
+                    reward_manager,
+                    bot_oracle,
+                    instruction_info,
+                    transfer,
+                    signers,
+                )
+            }
+            Instructions::SetChallenge(params) => {
+                msg!("Instruction: SetChallenge");
 
                 let reward_manager = next_account_info(account_info_iter)?;
-                let token_account = next_account_info(account_info_iter)?;
-                let mint = next_account_info(account_info_iter)?;
-                let manager = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
                 let authority = next_account_info(account_info_iter)?;
-                let _spl_token = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let challenge_registry = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
                 let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-                Self::process_init_instruction(
+                Self::process_set_challenge(
                     program_id,
+                    params,
                     reward_manager,
-                    token_account,
-                    mint,
-                    manager,
+                    manager_account,
                     authority,
-                    _spl_token,
+                    funder,
+                    challenge_registry,
                     rent,
-                    min_votes,
                 )
             }
-            Instructions::CreateSender(CreateSender {
-                eth_address,
-                operator,
-            }) => {
-                msg!("Instruction: CreateSender");
+            Instructions::CreateEventLog => {
+                msg!("Instruction: CreateEventLog");
 
                 let reward_manager = next_account_info(account_info_iter)?;
                 let manager_account = next_account_info(account_info_iter)?;
                 let authority = next_account_info(account_info_iter)?;
-                let funder_account = next_account_info(account_info_iter)?;
-                let sender = next_account_info(account_info_iter)?;
-                let sys_prog = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let event_log = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
                 let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-                Self::process_create_sender(
+                Self::process_create_event_log(
                     program_id,
-                    eth_address,
-                    operator,
                     reward_manager,
                     manager_account,
                     authority,
-                    funder_account,
-                    sender,
-                    sys_prog,
+                    funder,
+                    event_log,
                     rent,
                 )
             }
-            Instructions::DeleteSender => {
-                msg!("Instruction: DeleteSender");
+            Instructions::SetRateLimit(params) => {
+                msg!("Instruction: SetRateLimit");
 
                 let reward_manager = next_account_info(account_info_iter)?;
                 let manager_account = next_account_info(account_info_iter)?;
-                let sender = next_account_info(account_info_iter)?;
-                let refunder = next_account_info(account_info_iter)?;
-                let sys_prog = next_account_info(account_info_iter)?;
+                let authority = next_account_info(account_info_iter)?;
+                let funder = next_account_info(account_info_iter)?;
+                let rate_limiter = next_account_info(account_info_iter)?;
+                let _system_program = next_account_info(account_info_iter)?;
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-                Self::process_delete_sender(
+                Self::process_set_rate_limit(
                     program_id,
+                    params,
                     reward_manager,
                     manager_account,
-                    sender,
-                    refunder,
-                    sys_prog,
+                    authority,
+                    funder,
+                    rate_limiter,
+                    rent,
                 )
             }
-            Instructions::AddSender(AddSender {
-                eth_address,
-                operator,
+            Instructions::InitRewardManagerV2(InitRewardManager {
+                min_votes,
+                claimable_tokens_program,
             }) => {
-                msg!("Instruction: AddSender");
+                msg!("Instruction: InitRewardManagerV2");
 
                 let reward_manager = next_account_info(account_info_iter)?;
+                let token_account = next_account_info(account_info_iter)?;
+                let mint = next_account_info(account_info_iter)?;
+                let manager = next_account_info(account_info_iter)?;
                 let authority = next_account_info(account_info_iter)?;
                 let funder = next_account_info(account_info_iter)?;
-                let new_sender = next_account_info(account_info_iter)?;
-                let instructions_info = next_account_info(account_info_iter)?;
-                let rent = next_account_info(account_info_iter)?;
+                let token_program = next_account_info(account_info_iter)?;
                 let _system_program = next_account_info(account_info_iter)?;
-                let signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+                let rent = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-                Self::process_add_sender(
+                Self::process_init_v2_instruction(
                     program_id,
                     reward_manager,
+                    token_account,
+                    mint,
+                    manager,
                     authority,
                     funder,
-                    new_sender,
-                    instructions_info,
+                    token_program,
                     rent,
-                    signers,
-                    eth_address,
-                    operator,
+                    min_votes,
+                    claimable_tokens_program,
                 )
             }
-            Instructions::Transfer(Transfer {
-                amount,
-                id,
-                eth_recipient,
-            }) => {
-                msg!("Instruction: Transfer");
+            Instructions::RepairSender(params) => {
+                msg!("Instruction: RepairSender");
 
                 let reward_manager = next_account_info(account_info_iter)?;
-                let reward_manager_authority = next_account_info(account_info_iter)?;
-                let recipient = next_account_info(account_info_iter)?;
-                let vault_token_account = next_account_info(account_info_iter)?;
-                let bot_oracle = next_account_info(account_info_iter)?;
-                let funder = next_account_info(account_info_iter)?;
-                let transfer_acc_to_create = next_account_info(account_info_iter)?;
-                let instruction_info = next_account_info(account_info_iter)?;
-                let _spl_token_program = next_account_info(account_info_iter)?;
-                let _system_program = next_account_info(account_info_iter)?;
+                let manager_account = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                let operator = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
 
-                let signers = account_info_iter.collect::<Vec<&AccountInfo>>();
+                Self::process_repair_sender(
+                    program_id,
+                    reward_manager,
+                    manager_account,
+                    sender,
+                    operator,
+                    params,
+                )
+            }
+            Instructions::UpdateSenderMetadata(UpdateSenderMetadata { endpoint_hash }) => {
+                msg!("Instruction: UpdateSenderMetadata");
 
-                Self::process_transfer(
+                let reward_manager = next_account_info(account_info_iter)?;
+                let sender = next_account_info(account_info_iter)?;
+                let instructions = next_account_info(account_info_iter)?;
+                check_no_remaining_accounts(account_info_iter)?;
+
+                Self::process_update_sender_metadata(
                     program_id,
                     reward_manager,
-                    reward_manager_authority,
-                    recipient,
-                    vault_token_account,
-                    bot_oracle,
-                    funder,
-                    transfer_acc_to_create,
-                    instruction_info,
-                    Transfer {
-                        amount,
-                        id,
-                        eth_recipient,
-                    },
-                    signers,
+                    sender,
+                    instructions,
+                    endpoint_hash,
                 )
             }
         }
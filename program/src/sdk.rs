@@ -0,0 +1,91 @@
+//! Off-chain helpers building `getProgramAccounts` filters over the program accounts
+//! and validating attestations ahead of submitting them
+
+use crate::state::{SenderAccount, TransferReceipt};
+use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use solana_program::pubkey::Pubkey;
+
+pub mod preflight;
+
+fn memcmp(offset: usize, bytes: &[u8]) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp {
+        offset,
+        bytes: MemcmpEncodedBytes::Binary(bs58::encode(bytes).into_string()),
+        encoding: None,
+    })
+}
+
+/// Filters matching the senders of `reward_manager`
+pub fn senders_filters(reward_manager: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(SenderAccount::LEN as _),
+        memcmp(
+            SenderAccount::REWARD_MANAGER_OFFSET,
+            reward_manager.as_ref(),
+        ),
+    ]
+}
+
+/// Filters matching the senders of `reward_manager` created before endpoint metadata
+pub fn metadataless_senders_filters(reward_manager: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(SenderAccount::METADATALESS_LEN as _),
+        memcmp(
+            SenderAccount::REWARD_MANAGER_OFFSET,
+            reward_manager.as_ref(),
+        ),
+    ]
+}
+
+/// Filters matching the senders of `reward_manager` created before suspension
+pub fn statusless_senders_filters(reward_manager: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(SenderAccount::STATUSLESS_LEN as _),
+        memcmp(
+            SenderAccount::REWARD_MANAGER_OFFSET,
+            reward_manager.as_ref(),
+        ),
+    ]
+}
+
+/// Filters matching the senders of `reward_manager` created before activity tracking
+pub fn untracked_senders_filters(reward_manager: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(SenderAccount::UNTRACKED_LEN as _),
+        memcmp(
+            SenderAccount::REWARD_MANAGER_OFFSET,
+            reward_manager.as_ref(),
+        ),
+    ]
+}
+
+/// Filters matching the senders of `reward_manager` created before weights
+pub fn unweighted_senders_filters(reward_manager: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(SenderAccount::UNWEIGHTED_LEN as _),
+        memcmp(
+            SenderAccount::REWARD_MANAGER_OFFSET,
+            reward_manager.as_ref(),
+        ),
+    ]
+}
+
+/// Filters matching the legacy senders of `reward_manager`, bot oracles share
+/// their layout so matches must be checked against the sender address derivation
+pub fn legacy_senders_filters(reward_manager: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(SenderAccount::LEGACY_LEN as _),
+        memcmp(
+            SenderAccount::REWARD_MANAGER_OFFSET,
+            reward_manager.as_ref(),
+        ),
+    ]
+}
+
+/// Filters matching the receipts of transfers paid to the `recipient` token account
+pub fn transfer_receipts_filters(recipient: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(TransferReceipt::LEN as _),
+        memcmp(TransferReceipt::RECIPIENT_OFFSET, recipient.as_ref()),
+    ]
+}
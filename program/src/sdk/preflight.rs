@@ -0,0 +1,61 @@
+//! Off-chain validation of transfer attestations running the program checks, so
+//! relayers can reject bad attestation bundles before paying transaction fees
+
+use crate::{
+    error::AudiusProgramError,
+    instruction::{RecoverableSignature, Transfer},
+    state::{OracleAccount, RewardManager, SenderAccount},
+    utils::{
+        check_attestations_count, check_message_version, validate_secp_offsets,
+        verify_attestations, SecpPayload, TransferVerifier,
+    },
+};
+use solana_program::{entrypoint::ProgramResult, instruction::Instruction, secp256k1_program};
+
+/// Payloads of the secp256k1 instructions of a transaction, in their order
+pub fn secp_payloads(instructions: &[Instruction]) -> Result<Vec<SecpPayload>, AudiusProgramError> {
+    instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| instruction.program_id == secp256k1_program::id())
+        .map(|(index, instruction)| {
+            validate_secp_offsets(&instruction.data, index as u16)?;
+            SecpPayload::parse(&instruction.data)
+        })
+        .collect()
+}
+
+/// Checks the attestations of `transfer` as the `Transfer` instruction would with
+/// `oracle`, `additional_oracles` and `senders` passed, including the signatures
+/// the secp256k1 program checks
+pub fn validate_attestations(
+    messages: &[SecpPayload],
+    senders: &[SenderAccount],
+    oracle: &OracleAccount,
+    additional_oracles: &[OracleAccount],
+    reward_manager: &RewardManager,
+    transfer: &Transfer,
+) -> ProgramResult {
+    check_message_version(reward_manager, transfer.message_version)?;
+    if additional_oracles.len() < reward_manager.min_oracle_votes.saturating_sub(1) as usize {
+        return Err(AudiusProgramError::NotEnoughSigners.into());
+    }
+    check_attestations_count(reward_manager, senders.len(), messages.len())?;
+
+    for message in messages {
+        SecpPayload::recover(
+            &RecoverableSignature {
+                signer: message.signer,
+                signature: message.signature,
+            },
+            message.message.clone(),
+        )?;
+    }
+
+    let verifier = TransferVerifier {
+        bot_oracle: oracle.clone(),
+        additional_oracles: additional_oracles.to_vec(),
+        transfer_data: transfer.clone(),
+    };
+    verify_attestations(reward_manager, senders, messages.to_vec(), verifier)
+}
@@ -1,16 +1,54 @@
 //! State transition types
 
+#[cfg(feature = "idl")]
+use borsh::BorshSchema;
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{program_pack::IsInitialized, pubkey::Pubkey};
+use solana_program::{
+    hash::Hash, program_error::ProgramError, program_pack::IsInitialized, pubkey::Pubkey,
+};
 
-use crate::{utils::EthereumAddress, PROGRAM_VERSION};
+use crate::{error::AudiusProgramError, utils::EthereumAddress, PROGRAM_VERSION};
+
+pub mod decode;
 
 /// Accounts are created with data zeroed out, so uninitialized state instances
 /// will have the version set to 0.
 pub const UNINITIALIZED_VERSION: u8 = 0;
 
+/// Maximum number of attestations evaluated per instruction set on initialization
+pub const DEFAULT_MAX_ATTESTATIONS: u8 = 10;
+
+/// Slots a proposed manager waits before it may accept, about a day
+pub const MANAGER_CHANGE_DELAY: u64 = 216_000;
+
+/// Slots an announced treasury withdrawal waits before it may execute set on
+/// initialization, about a day
+pub const DEFAULT_WITHDRAWAL_DELAY: u64 = 216_000;
+
+/// Maximum number of keys of a manager multisig
+pub const MAX_MULTISIG_SIGNERS: usize = 11;
+/// Maximum number of senders listed by an operator account
+pub const MAX_OPERATOR_SENDERS: usize = 8;
+/// Maximum number of Ethereum recipients a recipient policy blocks
+pub const MAX_BLOCKED_RECIPIENTS: usize = 64;
+/// Basis points of the whole transfer amount, the upper bound of the transfer fee
+pub const MAX_TRANSFER_FEE_BPS: u16 = 10_000;
+/// Maximum number of challenges of a challenge registry
+pub const MAX_CHALLENGES: usize = 16;
+/// Maximum length of a challenge ID in bytes
+pub const MAX_CHALLENGE_ID_LEN: usize = 8;
+/// Maximum number of bot oracles authorized to attest transfers of a challenge
+pub const MAX_CHALLENGE_ORACLES: usize = 4;
+/// Separates the challenge ID from the rest of a transfer ID
+pub const CHALLENGE_ID_SEPARATOR: char = ':';
+/// Number of the latest transfer evaluations an event log keeps
+pub const EVENT_LOG_CAPACITY: usize = 64;
+/// Number of operators a rate limiter tracks within a window
+pub const MAX_RATE_LIMITED_OPERATORS: usize = 64;
+
 /// The the root entity within the program
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
 pub struct RewardManager {
     /// Version
     pub version: u8,
@@ -20,11 +58,61 @@ pub struct RewardManager {
     pub manager: Pubkey,
     /// Number of signer votes required for sending rewards
     pub min_votes: u8,
+    /// Maximum number of attestations evaluated per instruction
+    pub max_attestations: u8,
+    /// Transfers are halted while set
+    pub paused: bool,
+    /// Number of registered senders
+    pub sender_count: u32,
+    /// Maximum amount of a single transfer
+    pub max_transfer_amount: u64,
+    /// Minimum number of slots between `CreateSender` calls
+    pub sender_creation_gap: u64,
+    /// Slot of the last `CreateSender` call
+    pub last_sender_creation_slot: u64,
+    /// Number of bot oracle votes required for sending rewards
+    pub min_oracle_votes: u8,
+    /// Vault balance transfers must leave in place
+    pub min_balance_threshold: u64,
+    /// Manager proposed to take over, default key while no change is pending
+    pub pending_manager: Pubkey,
+    /// Slot the pending manager may accept from
+    pub manager_activation_slot: u64,
+    /// Transfers attested with the deprecated `_` joined messages are accepted while set
+    pub v1_messages_allowed: bool,
+    /// Summed weight of the attesting senders required for sending rewards,
+    /// zero leaves the quorum to `min_votes` alone
+    pub min_vote_weight: u32,
+    /// Slots an announced treasury withdrawal waits before it may execute
+    pub withdrawal_delay: u64,
+    /// Amount of the announced treasury withdrawal, zero while none is pending
+    pub pending_withdrawal_amount: u64,
+    /// Token account the announced treasury withdrawal pays to
+    pub pending_withdrawal_destination: Pubkey,
+    /// Slot the announced treasury withdrawal may execute from
+    pub withdrawal_unlock_slot: u64,
+    /// Total amount of tokens disbursed by transfers
+    pub total_disbursed: u64,
+    /// Number of transfers disbursed
+    pub transfer_count: u64,
+    /// Basis points of every transfer amount sent to the fee account, zero disables the fee
+    pub transfer_fee_bps: u16,
+    /// Token account of the vault mint receiving transfer fees, default key while no fee is set
+    pub fee_account: Pubkey,
+    /// Program the transfer recipients are claimable tokens accounts of
+    pub claimable_tokens_program: Pubkey,
+    /// Token program owning the vault, every token instruction is invoked on it
+    pub token_program: Pubkey,
 }
 
 impl RewardManager {
     /// The struct size on bytes
-    pub const LEN: usize = 66;
+    pub const LEN: usize = 320;
+    /// Sizes of the reward managers created before the fields added since,
+    /// oldest first
+    pub const LEGACY_LENS: [usize; 15] = [
+        66, 67, 68, 72, 80, 96, 97, 105, 145, 146, 150, 206, 222, 256, 288,
+    ];
 
     /// Creates new `RewardManager`
     pub fn new(token_account: Pubkey, manager: Pubkey, min_votes: u8) -> Self {
@@ -33,8 +121,78 @@ impl RewardManager {
             token_account,
             manager,
             min_votes,
+            max_attestations: DEFAULT_MAX_ATTESTATIONS,
+            paused: false,
+            sender_count: 0,
+            max_transfer_amount: u64::MAX,
+            sender_creation_gap: 0,
+            last_sender_creation_slot: 0,
+            min_oracle_votes: 1,
+            min_balance_threshold: 0,
+            pending_manager: Pubkey::default(),
+            manager_activation_slot: 0,
+            v1_messages_allowed: true,
+            min_vote_weight: 0,
+            withdrawal_delay: DEFAULT_WITHDRAWAL_DELAY,
+            pending_withdrawal_amount: 0,
+            pending_withdrawal_destination: Pubkey::default(),
+            withdrawal_unlock_slot: 0,
+            total_disbursed: 0,
+            transfer_count: 0,
+            transfer_fee_bps: 0,
+            fee_account: Pubkey::default(),
+            claimable_tokens_program: claimable_tokens::id(),
+            token_program: spl_token::id(),
         }
     }
+
+    /// Deserializes the reward manager, reward managers created before the
+    /// latest fields get the defaults of the fields added since
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if Self::LEGACY_LENS.contains(&data.len()) {
+            let defaults = Self::new(Pubkey::default(), Pubkey::default(), 0).try_to_vec()?;
+            return Ok(Self::try_from_slice(
+                &[data, &defaults[data.len()..]].concat(),
+            )?);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+
+    /// Serializes the reward manager into the account data. Legacy reward
+    /// managers keep their size: the counters added since aren't stored, and
+    /// every other field added since must keep its default
+    pub fn pack(&self, dst: &mut [u8]) -> Result<(), ProgramError> {
+        if !Self::LEGACY_LENS.contains(&dst.len()) {
+            return Ok(self.serialize(&mut &mut dst[..])?);
+        }
+
+        let data = self.try_to_vec()?;
+        let mut stored = Self::unpack(&data[..dst.len()])?;
+        stored.sender_count = self.sender_count;
+        stored.last_sender_creation_slot = self.last_sender_creation_slot;
+        stored.total_disbursed = self.total_disbursed;
+        stored.transfer_count = self.transfer_count;
+        if stored != *self {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        dst.copy_from_slice(&data[..dst.len()]);
+        Ok(())
+    }
+
+    /// Part of the transfer `amount` sent to the fee account, rounded down in
+    /// favour of the recipient
+    pub fn transfer_fee(&self, amount: u64) -> u64 {
+        // NOTE: the product fits `u128` and the fee never exceeds `amount`
+        (amount as u128 * self.transfer_fee_bps.min(MAX_TRANSFER_FEE_BPS) as u128
+            / MAX_TRANSFER_FEE_BPS as u128) as u64
+    }
+
+    /// Adds a disbursed transfer to the payout totals
+    pub fn record_disbursement(&mut self, amount: u64) {
+        self.total_disbursed = self.total_disbursed.saturating_add(amount);
+        self.transfer_count = self.transfer_count.saturating_add(1);
+    }
 }
 
 impl IsInitialized for RewardManager {
@@ -43,8 +201,19 @@ impl IsInitialized for RewardManager {
     }
 }
 
+/// Whether the sender attestations are accepted
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub enum SenderStatus {
+    /// Attestations are accepted
+    Active,
+    /// Attestations are rejected until the manager reinstates the sender
+    Suspended,
+}
+
 /// Some doc
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
 pub struct SenderAccount {
     /// Version
     pub version: u8,
@@ -54,25 +223,107 @@ pub struct SenderAccount {
     pub eth_address: EthereumAddress,
     /// Sender operator
     pub operator: EthereumAddress,
+    /// Bump seed of the program derived sender address
+    pub bump: u8,
+    /// Vote weight of the sender attestations, set by the manager
+    pub weight: u16,
+    /// Number of verified attestations of the sender
+    pub attestation_count: u64,
+    /// Slot of the last verified attestation of the sender
+    pub last_attested_slot: u64,
+    /// Whether the sender attestations are accepted, set by the manager
+    pub status: SenderStatus,
+    /// Keccak256 hash of the sender service endpoint URL, set by the sender,
+    /// zero while unset
+    pub endpoint_hash: [u8; 32],
 }
 
 impl SenderAccount {
     /// The struct size on bytes
-    pub const LEN: usize = 73;
+    pub const LEN: usize = 125;
+    /// Size of the senders created before endpoint metadata, they have a zero endpoint hash
+    pub const METADATALESS_LEN: usize = 93;
+    /// Size of the senders created before suspension, they are active
+    pub const STATUSLESS_LEN: usize = 92;
+    /// Size of the senders created before activity tracking, they have zero counters
+    pub const UNTRACKED_LEN: usize = 76;
+    /// Size of the senders created before weights, they have the default weight
+    pub const UNWEIGHTED_LEN: usize = 74;
+    /// Size of the senders created with seed, they have no bump seed
+    pub const LEGACY_LEN: usize = 73;
+    /// Weight of the newly created senders
+    pub const DEFAULT_WEIGHT: u16 = 1;
+    /// Offset of `reward_manager` in the account data
+    pub const REWARD_MANAGER_OFFSET: usize = 1;
 
     /// Creates new `SenderAccount`
     pub fn new(
         reward_manager: Pubkey,
         eth_address: EthereumAddress,
         operator: EthereumAddress,
+        bump: u8,
     ) -> Self {
         Self {
             version: PROGRAM_VERSION,
             reward_manager,
             eth_address,
             operator,
+            bump,
+            weight: Self::DEFAULT_WEIGHT,
+            attestation_count: 0,
+            last_attested_slot: 0,
+            status: SenderStatus::Active,
+            endpoint_hash: [0u8; 32],
+        }
+    }
+
+    /// Deserializes the sender, legacy senders get a zero bump seed, senders
+    /// created before weights get the default weight, senders created before
+    /// activity tracking get zero counters, senders created before suspension
+    /// are active and senders created before endpoint metadata have a zero
+    /// endpoint hash
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let weight = Self::DEFAULT_WEIGHT.to_le_bytes();
+        let counters = [0u8; Self::STATUSLESS_LEN - Self::UNTRACKED_LEN];
+        let status = [SenderStatus::Active as u8];
+        let endpoint_hash = [0u8; Self::LEN - Self::METADATALESS_LEN];
+        match data.len() {
+            Self::LEGACY_LEN => Ok(Self::try_from_slice(
+                &[data, &[0], &weight, &counters, &status, &endpoint_hash].concat(),
+            )?),
+            Self::UNWEIGHTED_LEN => Ok(Self::try_from_slice(
+                &[data, &weight, &counters, &status, &endpoint_hash].concat(),
+            )?),
+            Self::UNTRACKED_LEN => Ok(Self::try_from_slice(
+                &[data, &counters, &status, &endpoint_hash].concat(),
+            )?),
+            Self::STATUSLESS_LEN => Ok(Self::try_from_slice(
+                &[data, &status, &endpoint_hash].concat(),
+            )?),
+            Self::METADATALESS_LEN => Ok(Self::try_from_slice(&[data, &endpoint_hash].concat())?),
+            _ => Ok(Self::try_from_slice(data)?),
         }
     }
+
+    /// Serializes the sender into the account data, senders created before
+    /// activity tracking, suspension or endpoint metadata keep their size and
+    /// don't store the fields added since
+    pub fn pack(&self, dst: &mut [u8]) -> Result<(), ProgramError> {
+        if dst.len() == Self::UNTRACKED_LEN
+            || dst.len() == Self::STATUSLESS_LEN
+            || dst.len() == Self::METADATALESS_LEN
+        {
+            let len = dst.len();
+            dst.copy_from_slice(&self.try_to_vec()?[..len]);
+            return Ok(());
+        }
+        Ok(self.serialize(&mut &mut dst[..])?)
+    }
+
+    /// Whether the sender attestations are accepted
+    pub fn is_active(&self) -> bool {
+        self.status == SenderStatus::Active
+    }
 }
 
 impl IsInitialized for SenderAccount {
@@ -80,3 +331,895 @@ impl IsInitialized for SenderAccount {
         self.version != UNINITIALIZED_VERSION
     }
 }
+
+/// Senders registered for an operator, created along with the first one
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct OperatorAccount {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Operator Ethereum address
+    pub operator: EthereumAddress,
+    /// Number of senders the operator may register, set by the manager
+    pub max_senders: u8,
+    /// Number of addresses set in `senders`
+    pub senders_count: u8,
+    /// Ethereum addresses of the registered senders
+    pub senders: [EthereumAddress; MAX_OPERATOR_SENDERS],
+    /// Bump seed of the program derived operator address
+    pub bump: u8,
+}
+
+impl OperatorAccount {
+    /// The struct size on bytes
+    pub const LEN: usize = 216;
+
+    /// Creates new `OperatorAccount`
+    pub fn new(reward_manager: Pubkey, operator: EthereumAddress, bump: u8) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            operator,
+            max_senders: MAX_OPERATOR_SENDERS as u8,
+            senders_count: 0,
+            senders: [EthereumAddress::default(); MAX_OPERATOR_SENDERS],
+            bump,
+        }
+    }
+
+    /// Ethereum addresses of the registered senders
+    pub fn senders(&self) -> &[EthereumAddress] {
+        &self.senders[..self.senders_count as usize]
+    }
+
+    /// Lists the sender unless the operator reached its limit
+    pub fn add_sender(&mut self, eth_address: EthereumAddress) -> Result<(), AudiusProgramError> {
+        if self.senders_count >= self.max_senders {
+            return Err(AudiusProgramError::OperatorSendersLimitReached);
+        }
+
+        self.senders[self.senders_count as usize] = eth_address;
+        self.senders_count += 1;
+
+        Ok(())
+    }
+
+    /// Unlists the sender, `false` if it isn't listed
+    pub fn remove_sender(&mut self, eth_address: &EthereumAddress) -> bool {
+        let position = match self
+            .senders()
+            .iter()
+            .position(|sender| sender == eth_address)
+        {
+            Some(position) => position,
+            None => return false,
+        };
+
+        self.senders_count -= 1;
+        self.senders[position] = self.senders[self.senders_count as usize];
+        self.senders[self.senders_count as usize] = EthereumAddress::default();
+
+        true
+    }
+
+    /// Lists `new_eth_address` in place of the sender, `false` if it isn't listed
+    pub fn replace_sender(
+        &mut self,
+        eth_address: &EthereumAddress,
+        new_eth_address: EthereumAddress,
+    ) -> bool {
+        let count = self.senders_count as usize;
+        match self.senders[..count]
+            .iter_mut()
+            .find(|sender| *sender == eth_address)
+        {
+            Some(sender) => {
+                *sender = new_eth_address;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl IsInitialized for OperatorAccount {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Hot key allowed to call some of the admin instructions instead of the manager
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct DelegateAccount {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Delegated key
+    pub delegate: Pubkey,
+    /// Bitflags of the instructions the delegate may call
+    pub permissions: u8,
+    /// Bump seed of the program derived delegate address
+    pub bump: u8,
+}
+
+impl DelegateAccount {
+    /// The struct size on bytes
+    pub const LEN: usize = 67;
+
+    /// `CreateSender` permission
+    pub const CREATE_SENDER: u8 = 1;
+    /// `DeleteSender` permission
+    pub const DELETE_SENDER: u8 = 1 << 1;
+    /// `CreateOracle` permission
+    pub const CREATE_ORACLE: u8 = 1 << 2;
+    /// `DeleteOracle` permission
+    pub const DELETE_ORACLE: u8 = 1 << 3;
+    /// `Pause` and `Unpause` permission
+    pub const PAUSE: u8 = 1 << 4;
+    /// Every permission a delegate may be granted
+    pub const ALL_PERMISSIONS: u8 = Self::CREATE_SENDER
+        | Self::DELETE_SENDER
+        | Self::CREATE_ORACLE
+        | Self::DELETE_ORACLE
+        | Self::PAUSE;
+
+    /// Creates new `DelegateAccount`
+    pub fn new(reward_manager: Pubkey, delegate: Pubkey, permissions: u8, bump: u8) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            delegate,
+            permissions,
+            bump,
+        }
+    }
+
+    /// Whether the delegate holds `permission`
+    pub fn can(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
+
+impl IsInitialized for DelegateAccount {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Keys acting as the manager together, the reward manager manager is set to
+/// this account and admin instructions run as `ExecuteAdminAction` once approved
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct ManagerMultisig {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Number of approvals an admin action needs
+    pub threshold: u8,
+    /// Number of keys set in `signers`
+    pub signers_count: u8,
+    /// Keys allowed to propose and approve admin actions
+    pub signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+    /// Index the next admin action address is derived with
+    pub proposal_count: u64,
+    /// Bump seed of the program derived multisig address
+    pub bump: u8,
+}
+
+impl ManagerMultisig {
+    /// The struct size on bytes
+    pub const LEN: usize = 396;
+
+    /// Creates new `ManagerMultisig`
+    pub fn new(
+        reward_manager: Pubkey,
+        threshold: u8,
+        signers: &[Pubkey],
+        bump: u8,
+    ) -> Result<Self, ProgramError> {
+        let mut multisig = Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            threshold,
+            signers_count: 0,
+            signers: [Pubkey::default(); MAX_MULTISIG_SIGNERS],
+            proposal_count: 0,
+            bump,
+        };
+        multisig.set_signers(threshold, signers)?;
+        Ok(multisig)
+    }
+
+    /// Replaces the keys, `signers` must not exceed `MAX_MULTISIG_SIGNERS`
+    /// nor contain the default key, which marks free slots
+    pub fn set_signers(&mut self, threshold: u8, signers: &[Pubkey]) -> Result<(), ProgramError> {
+        if signers.len() > MAX_MULTISIG_SIGNERS || signers.contains(&Pubkey::default()) {
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.threshold = threshold;
+        self.signers_count = signers.len() as u8;
+        self.signers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        self.signers[..signers.len()].copy_from_slice(signers);
+        Ok(())
+    }
+
+    /// Whether `key` is one of the multisig keys
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers[..self.signers_count as usize].contains(key)
+    }
+}
+
+impl IsInitialized for ManagerMultisig {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Account of the instruction an admin action runs
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct AdminActionAccount {
+    /// Account key
+    pub pubkey: Pubkey,
+    /// Whether the account signs, the multisig signs through the program
+    pub is_signer: bool,
+    /// Whether the account is writable
+    pub is_writable: bool,
+}
+
+/// Program instruction proposed by a multisig key, run once enough keys approve it
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct AdminAction {
+    /// Version
+    pub version: u8,
+    /// Manager multisig voting on the action
+    pub multisig: Pubkey,
+    /// Key which proposed the action, refunded on execution
+    pub proposer: Pubkey,
+    /// Keys which approved the action, default keys are free slots
+    pub approvers: [Pubkey; MAX_MULTISIG_SIGNERS],
+    /// Accounts of the instruction
+    pub accounts: Vec<AdminActionAccount>,
+    /// Instruction data
+    pub data: Vec<u8>,
+}
+
+impl AdminAction {
+    /// Creates new `AdminAction` approved by its proposer
+    pub fn new(
+        multisig: Pubkey,
+        proposer: Pubkey,
+        accounts: Vec<AdminActionAccount>,
+        data: Vec<u8>,
+    ) -> Self {
+        let mut approvers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        approvers[0] = proposer;
+        Self {
+            version: PROGRAM_VERSION,
+            multisig,
+            proposer,
+            approvers,
+            accounts,
+            data,
+        }
+    }
+
+    /// Number of approvers which are still keys of `multisig`
+    pub fn approvals(&self, multisig: &ManagerMultisig) -> usize {
+        self.approvers
+            .iter()
+            .filter(|approver| **approver != Pubkey::default() && multisig.is_signer(approver))
+            .count()
+    }
+}
+
+impl IsInitialized for AdminAction {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Bot oracle approving transfers, registered apart from the senders
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct OracleAccount {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Ethereum address
+    pub eth_address: EthereumAddress,
+    /// Oracle operator
+    pub operator: EthereumAddress,
+}
+
+impl OracleAccount {
+    /// The struct size on bytes
+    pub const LEN: usize = 73;
+
+    /// Creates new `OracleAccount`
+    pub fn new(
+        reward_manager: Pubkey,
+        eth_address: EthereumAddress,
+        operator: EthereumAddress,
+    ) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            eth_address,
+            operator,
+        }
+    }
+}
+
+impl IsInitialized for OracleAccount {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Record of a disbursed transfer, its existence prevents transfer ID reuse
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct TransferReceipt {
+    /// Version
+    pub version: u8,
+    /// Recipient token account
+    pub recipient: Pubkey,
+    /// Transferred amount
+    pub amount: u64,
+    /// Slot the transfer was made at
+    pub slot: u64,
+}
+
+impl TransferReceipt {
+    /// The struct size on bytes
+    pub const LEN: usize = 49;
+    /// Offset of `recipient` in the account data
+    pub const RECIPIENT_OFFSET: usize = 1;
+
+    /// Creates new `TransferReceipt`
+    pub fn new(recipient: Pubkey, amount: u64, slot: u64) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            recipient,
+            amount,
+            slot,
+        }
+    }
+}
+
+impl IsInitialized for TransferReceipt {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Merkle root of transfers approved by a single attestation round, claimed one by one
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct DistributionRoot {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Root of the tree of `Transfer` leaves
+    pub root: Hash,
+    /// Bot oracle which attested the root, claims are checked against the
+    /// challenge registry on its behalf
+    pub bot_oracle: EthereumAddress,
+}
+
+impl DistributionRoot {
+    /// The struct size on bytes
+    pub const LEN: usize = 85;
+    /// Size of the roots submitted before the bot oracle was recorded, they have
+    /// a zero bot oracle
+    pub const LEGACY_LEN: usize = 65;
+
+    /// Creates new `DistributionRoot`
+    pub fn new(reward_manager: Pubkey, root: Hash, bot_oracle: EthereumAddress) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            root,
+            bot_oracle,
+        }
+    }
+
+    /// Deserializes the root, legacy roots get a zero bot oracle
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() == Self::LEGACY_LEN {
+            return Ok(Self::try_from_slice(
+                &[data, &[0u8; Self::LEN - Self::LEGACY_LEN]].concat(),
+            )?);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+}
+
+impl IsInitialized for DistributionRoot {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Tokens disbursed by a reward manager within the current spending window
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct SpendCounter {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Maximum amount disbursed per window
+    pub budget: u64,
+    /// Window length in slots, 0 tracks spending per epoch
+    pub window_slots: u64,
+    /// Epoch or first slot of the window `spent` belongs to
+    pub window_start: u64,
+    /// Amount disbursed within the window
+    pub spent: u64,
+}
+
+impl SpendCounter {
+    /// The struct size on bytes
+    pub const LEN: usize = 65;
+
+    /// Creates new `SpendCounter`
+    pub fn new(reward_manager: Pubkey, budget: u64, window_slots: u64) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            budget,
+            window_slots,
+            window_start: 0,
+            spent: 0,
+        }
+    }
+
+    /// Start of the window `slot` of `epoch` falls into
+    pub fn window_for(&self, slot: u64, epoch: u64) -> u64 {
+        if self.window_slots == 0 {
+            epoch
+        } else {
+            slot - slot % self.window_slots
+        }
+    }
+
+    /// Accounts `amount` against the budget, starting over when a new window begins
+    pub fn charge(&mut self, amount: u64, slot: u64, epoch: u64) -> Result<(), AudiusProgramError> {
+        let window = self.window_for(slot, epoch);
+        if window != self.window_start {
+            self.window_start = window;
+            self.spent = 0;
+        }
+
+        self.spent = self
+            .spent
+            .checked_add(amount)
+            .filter(|spent| *spent <= self.budget)
+            .ok_or(AudiusProgramError::SpendLimitExceeded)?;
+
+        Ok(())
+    }
+}
+
+impl IsInitialized for SpendCounter {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Ethereum recipients the reward manager doesn't pay out to, maintained by the manager
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct RecipientPolicy {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Number of addresses set in `blocked`
+    pub blocked_count: u8,
+    /// Blocked Ethereum recipients, the first `blocked_count` sorted ascending
+    pub blocked: [EthereumAddress; MAX_BLOCKED_RECIPIENTS],
+}
+
+impl RecipientPolicy {
+    /// The struct size on bytes
+    pub const LEN: usize = 1314;
+
+    /// Creates new `RecipientPolicy`
+    pub fn new(reward_manager: Pubkey) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            blocked_count: 0,
+            blocked: [[0u8; 20]; MAX_BLOCKED_RECIPIENTS],
+        }
+    }
+
+    /// Blocked Ethereum recipients, sorted ascending
+    pub fn blocked(&self) -> &[EthereumAddress] {
+        &self.blocked[..self.blocked_count as usize]
+    }
+
+    /// Whether transfers to `eth_recipient` are rejected
+    pub fn is_blocked(&self, eth_recipient: &EthereumAddress) -> bool {
+        self.blocked().binary_search(eth_recipient).is_ok()
+    }
+
+    /// Blocks `eth_recipient`, blocking it again changes nothing
+    pub fn block(&mut self, eth_recipient: EthereumAddress) -> Result<(), AudiusProgramError> {
+        let index = match self.blocked().binary_search(&eth_recipient) {
+            Ok(_) => return Ok(()),
+            Err(index) => index,
+        };
+        let count = self.blocked_count as usize;
+        if count == MAX_BLOCKED_RECIPIENTS {
+            return Err(AudiusProgramError::RecipientPolicyFull);
+        }
+
+        self.blocked.copy_within(index..count, index + 1);
+        self.blocked[index] = eth_recipient;
+        self.blocked_count += 1;
+        Ok(())
+    }
+
+    /// Unblocks `eth_recipient`, unblocking a recipient not blocked changes nothing
+    pub fn unblock(&mut self, eth_recipient: &EthereumAddress) {
+        if let Ok(index) = self.blocked().binary_search(eth_recipient) {
+            let count = self.blocked_count as usize;
+            self.blocked.copy_within(index + 1..count, index);
+            self.blocked[count - 1] = [0u8; 20];
+            self.blocked_count -= 1;
+        }
+    }
+}
+
+impl IsInitialized for RecipientPolicy {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Challenge of the challenge registry
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct Challenge {
+    /// Challenge ID, zero padded
+    pub id: [u8; MAX_CHALLENGE_ID_LEN],
+    /// Number of addresses set in `oracles`
+    pub oracle_count: u8,
+    /// Bot oracles authorized to attest transfers of the challenge
+    pub oracles: [EthereumAddress; MAX_CHALLENGE_ORACLES],
+}
+
+impl Challenge {
+    const EMPTY: Self = Self {
+        id: [0u8; MAX_CHALLENGE_ID_LEN],
+        oracle_count: 0,
+        oracles: [[0u8; 20]; MAX_CHALLENGE_ORACLES],
+    };
+
+    /// Challenge ID
+    pub fn id(&self) -> &[u8] {
+        let len = self
+            .id
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(MAX_CHALLENGE_ID_LEN);
+        &self.id[..len]
+    }
+
+    /// Bot oracles authorized to attest transfers of the challenge
+    pub fn oracles(&self) -> &[EthereumAddress] {
+        &self.oracles[..self.oracle_count as usize]
+    }
+}
+
+/// Challenges transfer IDs are namespaced by, each with the bot oracles authorized
+/// to attest its transfers, maintained by the manager
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct ChallengeRegistry {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Number of challenges set in `challenges`
+    pub challenge_count: u8,
+    /// Registered challenges, the first `challenge_count` set
+    pub challenges: [Challenge; MAX_CHALLENGES],
+}
+
+impl ChallengeRegistry {
+    /// The struct size on bytes
+    pub const LEN: usize = 1458;
+
+    /// Creates new `ChallengeRegistry`
+    pub fn new(reward_manager: Pubkey) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            challenge_count: 0,
+            challenges: [Challenge::EMPTY; MAX_CHALLENGES],
+        }
+    }
+
+    /// Registered challenges
+    pub fn challenges(&self) -> &[Challenge] {
+        &self.challenges[..self.challenge_count as usize]
+    }
+
+    /// Challenge registered as `id`
+    pub fn challenge(&self, id: &str) -> Option<&Challenge> {
+        self.challenges()
+            .iter()
+            .find(|challenge| challenge.id() == id.as_bytes())
+    }
+
+    /// Registers challenge `id` attested by `oracles`, replacing the oracles of
+    /// a registered one. Empty `oracles` remove the challenge
+    pub fn set(&mut self, id: &str, oracles: &[EthereumAddress]) -> Result<(), AudiusProgramError> {
+        if id.is_empty()
+            || id.len() > MAX_CHALLENGE_ID_LEN
+            || id.contains(|c: char| c == CHALLENGE_ID_SEPARATOR || c == '\0')
+            || oracles.len() > MAX_CHALLENGE_ORACLES
+        {
+            return Err(AudiusProgramError::InvalidChallenge);
+        }
+
+        let count = self.challenge_count as usize;
+        let index = self
+            .challenges()
+            .iter()
+            .position(|challenge| challenge.id() == id.as_bytes());
+
+        if oracles.is_empty() {
+            if let Some(index) = index {
+                self.challenges.copy_within(index + 1..count, index);
+                self.challenges[count - 1] = Challenge::EMPTY;
+                self.challenge_count -= 1;
+            }
+            return Ok(());
+        }
+
+        let index = match index {
+            Some(index) => index,
+            None if count == MAX_CHALLENGES => {
+                return Err(AudiusProgramError::ChallengeRegistryFull)
+            }
+            None => {
+                self.challenge_count += 1;
+                count
+            }
+        };
+
+        let mut challenge = Challenge::EMPTY;
+        challenge.id[..id.len()].copy_from_slice(id.as_bytes());
+        challenge.oracle_count = oracles.len() as u8;
+        challenge.oracles[..oracles.len()].copy_from_slice(oracles);
+        self.challenges[index] = challenge;
+        Ok(())
+    }
+
+    /// Checks `transfer_id` is prefixed with a registered challenge ID and
+    /// `bot_oracle` is authorized to attest transfers of the challenge
+    pub fn authorize(
+        &self,
+        transfer_id: &str,
+        bot_oracle: &EthereumAddress,
+    ) -> Result<(), AudiusProgramError> {
+        let challenge = transfer_id
+            .find(CHALLENGE_ID_SEPARATOR)
+            .and_then(|len| self.challenge(&transfer_id[..len]))
+            .ok_or(AudiusProgramError::UnregisteredChallenge)?;
+        if !challenge.oracles().contains(bot_oracle) {
+            return Err(AudiusProgramError::OracleNotAuthorizedForChallenge);
+        }
+
+        Ok(())
+    }
+}
+
+impl IsInitialized for ChallengeRegistry {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Transfer evaluation recorded in the event log
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct EventLogEntry {
+    /// Recipient Ethereum address, zero for transfers to a wallet
+    pub eth_recipient: EthereumAddress,
+    /// Recipient wallet of `TransferToWallet`, default key for transfers to an
+    /// Ethereum address
+    pub wallet: Pubkey,
+    /// Amount sent to the recipient, the transfer fee excluded
+    pub amount: u64,
+    /// Hash of the transfer ID, see `transfer_id_hash`
+    pub id_hash: Hash,
+    /// Slot of the transfer
+    pub slot: u64,
+}
+
+/// Ring buffer of the latest transfer evaluations, so light clients can reconcile
+/// recent payouts without scanning the transaction history
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct EventLog {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Number of transfer evaluations recorded since creation, the next entry is
+    /// written at `recorded % EVENT_LOG_CAPACITY`
+    pub recorded: u64,
+    /// Recorded transfer evaluations, the oldest overwritten first
+    pub entries: [EventLogEntry; EVENT_LOG_CAPACITY],
+}
+
+impl EventLog {
+    /// The struct size on bytes
+    pub const LEN: usize = 6441;
+
+    /// Creates new `EventLog`
+    pub fn new(reward_manager: Pubkey) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            recorded: 0,
+            entries: [EventLogEntry::default(); EVENT_LOG_CAPACITY],
+        }
+    }
+
+    /// Records the transfer evaluation, overwriting the oldest one when full
+    pub fn record(&mut self, entry: EventLogEntry) {
+        self.entries[(self.recorded % EVENT_LOG_CAPACITY as u64) as usize] = entry;
+        self.recorded = self.recorded.wrapping_add(1);
+    }
+
+    /// Recorded transfer evaluations, the oldest first
+    pub fn entries(&self) -> Vec<EventLogEntry> {
+        let next = (self.recorded % EVENT_LOG_CAPACITY as u64) as usize;
+        if self.recorded < EVENT_LOG_CAPACITY as u64 {
+            return self.entries[..next].to_vec();
+        }
+
+        [&self.entries[next..], &self.entries[..next]].concat()
+    }
+}
+
+impl IsInitialized for EventLog {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
+
+/// Attestations of an operator senders within the rate limiter window
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct OperatorAttestations {
+    /// Operator Ethereum address
+    pub operator: EthereumAddress,
+    /// Number of transfers attested by the operator senders within the window
+    pub attestations: u32,
+}
+
+/// Attestations of every operator within the current window, so a single
+/// compromised operator can't flood transfers faster than the manager reacts
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "idl", derive(BorshSchema))]
+pub struct RateLimiter {
+    /// Version
+    pub version: u8,
+    /// Reward manager
+    pub reward_manager: Pubkey,
+    /// Maximum number of transfers the senders of an operator attest per window
+    pub max_attestations: u32,
+    /// Window length in slots, 0 counts attestations per epoch
+    pub window_slots: u64,
+    /// Epoch or first slot of the window `operators` belong to
+    pub window_start: u64,
+    /// Number of entries set in `operators`
+    pub operator_count: u8,
+    /// Operators which senders attested within the window
+    pub operators: [OperatorAttestations; MAX_RATE_LIMITED_OPERATORS],
+}
+
+impl RateLimiter {
+    /// The struct size on bytes
+    pub const LEN: usize = 1590;
+
+    /// Creates new `RateLimiter`
+    pub fn new(reward_manager: Pubkey, max_attestations: u32, window_slots: u64) -> Self {
+        Self {
+            version: PROGRAM_VERSION,
+            reward_manager,
+            max_attestations,
+            window_slots,
+            window_start: 0,
+            operator_count: 0,
+            operators: [OperatorAttestations::default(); MAX_RATE_LIMITED_OPERATORS],
+        }
+    }
+
+    /// Operators which senders attested within the window
+    pub fn operators(&self) -> &[OperatorAttestations] {
+        &self.operators[..self.operator_count as usize]
+    }
+
+    /// Forgets the attestations of the window
+    pub fn reset(&mut self) {
+        self.operator_count = 0;
+        self.operators = [OperatorAttestations::default(); MAX_RATE_LIMITED_OPERATORS];
+    }
+
+    /// Start of the window `slot` of `epoch` falls into
+    pub fn window_for(&self, slot: u64, epoch: u64) -> u64 {
+        if self.window_slots == 0 {
+            epoch
+        } else {
+            slot - slot % self.window_slots
+        }
+    }
+
+    /// Counts an attestation of every operator, starting over when a new window begins
+    pub fn charge(
+        &mut self,
+        operators: &[EthereumAddress],
+        slot: u64,
+        epoch: u64,
+    ) -> Result<(), AudiusProgramError> {
+        let window = self.window_for(slot, epoch);
+        if window != self.window_start {
+            self.window_start = window;
+            self.reset();
+        }
+
+        let max_attestations = self.max_attestations;
+        for operator in operators {
+            let count = self.operator_count as usize;
+            let position = match self
+                .operators()
+                .iter()
+                .position(|entry| entry.operator == *operator)
+            {
+                Some(position) => position,
+                None if count < MAX_RATE_LIMITED_OPERATORS => {
+                    self.operators[count] = OperatorAttestations {
+                        operator: *operator,
+                        attestations: 0,
+                    };
+                    self.operator_count += 1;
+                    count
+                }
+                None => return Err(AudiusProgramError::RateLimiterFull),
+            };
+
+            let entry = &mut self.operators[position];
+            entry.attestations = entry
+                .attestations
+                .checked_add(1)
+                .filter(|attestations| *attestations <= max_attestations)
+                .ok_or(AudiusProgramError::OperatorRateLimited)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl IsInitialized for RateLimiter {
+    fn is_initialized(&self) -> bool {
+        self.version != UNINITIALIZED_VERSION
+    }
+}
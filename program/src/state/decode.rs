@@ -0,0 +1,79 @@
+//! Checked decoding of raw account data for off-chain consumers
+
+use crate::{
+    state::{RewardManager, SenderAccount, UNINITIALIZED_VERSION},
+    PROGRAM_VERSION,
+};
+use thiserror::Error;
+
+/// Reasons account data fails to decode
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    /// Data size matches none of the account layouts
+    #[error("Account data is {actual} bytes, expected {expected}")]
+    InvalidLength {
+        /// Size of the account layout
+        expected: usize,
+        /// Size of the data
+        actual: usize,
+    },
+    /// Account was allocated but never initialized
+    #[error("Account is not initialized")]
+    Uninitialized,
+    /// Account written by a program version this crate doesn't know
+    #[error("Unsupported account version {0}")]
+    UnsupportedVersion(u8),
+    /// Data doesn't deserialize into the account layout
+    #[error("Malformed account data: {0}")]
+    Malformed(String),
+}
+
+fn check_version(data: &[u8]) -> Result<(), DecodeError> {
+    match data.first() {
+        Some(&UNINITIALIZED_VERSION) => Err(DecodeError::Uninitialized),
+        Some(&PROGRAM_VERSION) => Ok(()),
+        Some(version) => Err(DecodeError::UnsupportedVersion(*version)),
+        None => Err(DecodeError::Uninitialized),
+    }
+}
+
+fn check_length(data: &[u8], expected: usize) -> Result<(), DecodeError> {
+    if data.len() != expected {
+        return Err(DecodeError::InvalidLength {
+            expected,
+            actual: data.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Decodes `RewardManager` account data, reward managers created before the
+/// latest fields get the defaults of the fields added since
+pub fn try_decode_reward_manager(data: &[u8]) -> Result<RewardManager, DecodeError> {
+    if !RewardManager::LEGACY_LENS.contains(&data.len()) {
+        check_length(data, RewardManager::LEN)?;
+    }
+    check_version(data)?;
+
+    RewardManager::unpack(data).map_err(|err| DecodeError::Malformed(err.to_string()))
+}
+
+/// Decodes `SenderAccount` data, legacy senders get a zero bump seed, senders
+/// created before weights get the default weight, senders created before
+/// activity tracking get zero counters, senders created before suspension
+/// are active and senders created before endpoint metadata have a zero
+/// endpoint hash
+pub fn try_decode_sender(data: &[u8]) -> Result<SenderAccount, DecodeError> {
+    if data.len() != SenderAccount::LEGACY_LEN
+        && data.len() != SenderAccount::UNWEIGHTED_LEN
+        && data.len() != SenderAccount::UNTRACKED_LEN
+        && data.len() != SenderAccount::STATUSLESS_LEN
+        && data.len() != SenderAccount::METADATALESS_LEN
+    {
+        check_length(data, SenderAccount::LEN)?;
+    }
+    check_version(data)?;
+
+    SenderAccount::unpack(data).map_err(|err| DecodeError::Malformed(err.to_string()))
+}
@@ -2,28 +2,143 @@
 
 use crate::{
     error::{to_audius_program_error, AudiusProgramError},
-    instruction::Transfer,
-    processor::SENDER_SEED_PREFIX,
-    state::SenderAccount,
+    instruction::{
+        ClaimFromRoot, Instructions, MessageVersion, RecoverableSignature, Transfer, TransferBatch,
+        TransferToWallet, TransferWithSignatures,
+    },
+    processor::{
+        ADMIN_ACTION_SEED_PREFIX, DELEGATE_SEED_PREFIX, DELETE_SENDER_MESSAGE_PREFIX,
+        DISTRIBUTION_ROOT_MESSAGE_PREFIX, MULTISIG_SEED_PREFIX, OPERATOR_SEED_PREFIX,
+        ROTATE_SENDER_MESSAGE_PREFIX, SENDER_METADATA_MESSAGE_PREFIX, SENDER_SEED_PREFIX,
+        TRANSFER_BATCH_MESSAGE_PREFIX, TRANSFER_MESSAGE_V2_VERSION, TRANSFER_SEED_PREFIX,
+        WALLET_TRANSFER_MESSAGE_PREFIX,
+    },
+    state::{OracleAccount, RewardManager, SenderAccount, CHALLENGE_ID_SEPARATOR},
 };
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
+    clock::Slot,
     entrypoint::ProgramResult,
+    hash::{hash, hashv, Hash},
     instruction::Instruction,
-    msg,
+    keccak, msg,
     program::invoke_signed,
     program_error::ProgramError,
     program_pack::IsInitialized,
     pubkey::{Pubkey, PubkeyError},
-    secp256k1_program, system_instruction, sysvar,
+    rent::Rent,
+    secp256k1_program,
+    secp256k1_recover::secp256k1_recover,
+    system_instruction,
+    sysvar::{self, Sysvar},
 };
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::{collections::BTreeMap, convert::TryInto};
+use std::fmt;
 
 /// Represent compressed ethereum pubkey
 pub type EthereumAddress = [u8; 20];
 
+/// Formats an Ethereum address as `0x` prefixed lowercase hex
+pub struct HexAddress<'a>(pub &'a EthereumAddress);
+
+impl fmt::Display for HexAddress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Longest transfer ID in bytes. The transfer receipt seed is the bs58 encoded
+/// ID following `TRANSFER_SEED_PREFIX`, and it has to fit `MAX_SEED_LEN`
+/// characters: 23 bytes encode to 32 characters, 24 bytes to 33
+pub const MAX_TRANSFER_ID_LEN: usize = 21;
+
+/// Serialized size of the secp256k1 program `SecpSignatureOffsets` struct
+pub const SECP_OFFSETS_SIZE: usize = 11;
+/// Start of the secp256k1 instruction data following signatures count and offsets
+pub const SECP_DATA_START: usize = 1 + SECP_OFFSETS_SIZE;
+/// Size of the secp256k1 signature along with its recovery id
+pub const SECP_SIGNATURE_SIZE: usize = 64 + 1;
+/// Offset of the signer Ethereum address in a secp256k1 instruction
+pub const ETH_ADDRESS_OFFSET: usize = SECP_DATA_START;
+/// Offset of the signature in a secp256k1 instruction
+pub const SIGNATURE_OFFSET: usize = ETH_ADDRESS_OFFSET + std::mem::size_of::<EthereumAddress>();
+/// Offset of the signed message in a secp256k1 instruction
+pub const MESSAGE_DATA_OFFSET: usize = SIGNATURE_OFFSET + SECP_SIGNATURE_SIZE;
+/// Maximum number of signatures the program recovers itself, each recovery
+/// costs as much compute as a few secp256k1 instruction lookups
+pub const MAX_RECOVERED_SIGNATURES: usize = 5;
+
+/// Parsed data of a single signature secp256k1 instruction
+#[derive(PartialEq, Debug, Clone)]
+pub struct SecpPayload {
+    /// Ethereum address of the signer
+    pub signer: EthereumAddress,
+    /// Signature along with its recovery id
+    pub signature: [u8; SECP_SIGNATURE_SIZE],
+    /// Signed message
+    pub message: Vec<u8>,
+}
+
+impl SecpPayload {
+    /// Parses secp256k1 instruction data laid out as checked by `validate_secp_offsets`
+    pub fn parse(secp_instruction_data: &[u8]) -> Result<Self, AudiusProgramError> {
+        if secp_instruction_data.len() < MESSAGE_DATA_OFFSET {
+            return Err(AudiusProgramError::InvalidSecpOffsets);
+        }
+
+        let mut signer = EthereumAddress::default();
+        signer.copy_from_slice(&secp_instruction_data[ETH_ADDRESS_OFFSET..SIGNATURE_OFFSET]);
+        let mut signature = [0u8; SECP_SIGNATURE_SIZE];
+        signature.copy_from_slice(&secp_instruction_data[SIGNATURE_OFFSET..MESSAGE_DATA_OFFSET]);
+
+        Ok(Self {
+            signer,
+            signature,
+            message: secp_instruction_data[MESSAGE_DATA_OFFSET..].to_vec(),
+        })
+    }
+
+    /// Recovers the signer of `message` from the signature and checks that it's
+    /// the claimed one, as the secp256k1 program does for its instructions
+    pub fn recover(
+        signature: &RecoverableSignature,
+        message: Vec<u8>,
+    ) -> Result<Self, AudiusProgramError> {
+        let public_key = secp256k1_recover(
+            &keccak::hash(&message).0,
+            signature.signature[SECP_SIGNATURE_SIZE - 1],
+            &signature.signature[..SECP_SIGNATURE_SIZE - 1],
+        )
+        .map_err(|_| AudiusProgramError::SignatureVerificationFailed)?;
+
+        let public_key_hash = keccak::hash(&public_key.to_bytes()).0;
+        if public_key_hash[12..] != signature.signer {
+            return Err(AudiusProgramError::SignatureVerificationFailed);
+        }
+
+        Ok(Self {
+            signer: signature.signer,
+            signature: signature.signature,
+            message,
+        })
+    }
+
+    /// Checks that the signed message is the expected one
+    pub fn check_message(&self, expected_message: &[u8]) -> ProgramResult {
+        if self.message != *expected_message {
+            return Err(AudiusProgramError::SignatureVerificationFailed.into());
+        }
+
+        Ok(())
+    }
+}
+
 /// Base PDA related with some mint
 pub struct Base {
     pub address: Pubkey,
@@ -63,6 +178,47 @@ macro_rules! is_owner {
     }
 }
 
+/// Checks that the account passed in place of a sysvar is the sysvar `id`
+pub fn check_sysvar_id(account_info: &AccountInfo, id: &Pubkey) -> ProgramResult {
+    if account_info.key != id {
+        return Err(AudiusProgramError::InvalidSysvar.into());
+    }
+
+    Ok(())
+}
+
+/// Reads rent from the account checked to be the rent sysvar
+pub fn rent_from_account_info(account_info: &AccountInfo) -> Result<Rent, ProgramError> {
+    check_sysvar_id(account_info, &sysvar::rent::id())?;
+    Rent::from_account_info(account_info)
+}
+
+/// Checks that the instruction consumed all the passed accounts
+pub fn check_no_remaining_accounts(
+    account_info_iter: &mut std::slice::Iter<AccountInfo>,
+) -> ProgramResult {
+    if account_info_iter.next().is_some() {
+        return Err(AudiusProgramError::TooManyAccounts.into());
+    }
+    Ok(())
+}
+
+/// Checks the account data is exactly `len` bytes long
+pub fn check_account_size(account_info: &AccountInfo, len: usize) -> ProgramResult {
+    if account_info.data_len() != len {
+        return Err(AudiusProgramError::InvalidAccountSize.into());
+    }
+    Ok(())
+}
+
+/// Checks the account balance covers the rent exemption of its data
+pub fn check_rent_exempt(rent: &Rent, account_info: &AccountInfo) -> ProgramResult {
+    if !rent.is_exempt(account_info.lamports(), account_info.data_len()) {
+        return Err(AudiusProgramError::NotRentExempt.into());
+    }
+    Ok(())
+}
+
 /// Return `Base` account with seed and corresponding derive
 /// with seed
 pub fn get_address_pair(
@@ -85,6 +241,157 @@ pub fn get_address_pair(
     })
 }
 
+/// Return program derived address of the sender registered for `eth_address`
+/// and its bump seed
+pub fn get_sender_address(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    eth_address: &EthereumAddress,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            reward_manager.as_ref(),
+            SENDER_SEED_PREFIX.as_bytes(),
+            eth_address.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Return program derived address of the reward manager multisig and its bump seed
+pub fn get_multisig_address(program_id: &Pubkey, reward_manager: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[reward_manager.as_ref(), MULTISIG_SEED_PREFIX.as_bytes()],
+        program_id,
+    )
+}
+
+/// Return program derived address of the multisig admin action number `index` and its bump seed
+pub fn get_admin_action_address(
+    program_id: &Pubkey,
+    multisig: &Pubkey,
+    index: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            multisig.as_ref(),
+            ADMIN_ACTION_SEED_PREFIX.as_bytes(),
+            index.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Return program derived address of the account of `delegate` and its bump seed
+pub fn get_delegate_address(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    delegate: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            reward_manager.as_ref(),
+            DELEGATE_SEED_PREFIX.as_bytes(),
+            delegate.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Return program derived address of the account listing senders of `operator` and its bump seed
+pub fn get_operator_address(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    operator: &EthereumAddress,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            reward_manager.as_ref(),
+            OPERATOR_SEED_PREFIX.as_bytes(),
+            operator.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Return address of the sender created with seed before senders became program derived
+pub fn get_legacy_sender_address(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    eth_address: &EthereumAddress,
+) -> Result<Pubkey, PubkeyError> {
+    let pair = get_address_pair(
+        program_id,
+        reward_manager,
+        [SENDER_SEED_PREFIX.as_bytes(), eth_address.as_ref()].concat(),
+    )?;
+    Ok(pair.derive.address)
+}
+
+/// Return address of the receipt account of the transfer `transfer_id`
+pub fn get_transfer_account_address(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    transfer_id: &str,
+) -> Result<Pubkey, PubkeyError> {
+    let pair = get_address_pair(
+        program_id,
+        reward_manager,
+        [TRANSFER_SEED_PREFIX.as_bytes(), transfer_id.as_bytes()].concat(),
+    )?;
+    Ok(pair.derive.address)
+}
+
+/// Checks every transfer ID of the instruction fits the transfer receipt seed
+pub fn check_transfer_ids(instruction: &Instructions) -> ProgramResult {
+    let too_long = |id: &String| id.len() > MAX_TRANSFER_ID_LEN;
+    let rejected = match instruction {
+        Instructions::Transfer(transfer)
+        | Instructions::VerifyOnly(transfer)
+        | Instructions::TransferWithSignatures(TransferWithSignatures { transfer, .. })
+        | Instructions::ClaimFromRoot(ClaimFromRoot { transfer, .. }) => too_long(&transfer.id),
+        Instructions::TransferToWallet(transfer) => too_long(&transfer.id),
+        Instructions::TransferBatch(TransferBatch { transfers }) => {
+            transfers.iter().any(|transfer| too_long(&transfer.id))
+        }
+        _ => false,
+    };
+    if rejected {
+        return Err(AudiusProgramError::TransferIdTooLong.into());
+    }
+
+    Ok(())
+}
+
+/// Checks the sender account is at the address derived for its Ethereum address,
+/// legacy senders are told apart by their size
+pub fn check_sender_address(
+    program_id: &Pubkey,
+    reward_manager: &Pubkey,
+    sender_info: &AccountInfo,
+    sender: &SenderAccount,
+) -> ProgramResult {
+    let expected_address = if sender_info.data_len() == SenderAccount::LEGACY_LEN {
+        get_legacy_sender_address(program_id, reward_manager, &sender.eth_address)?
+    } else {
+        Pubkey::create_program_address(
+            &[
+                reward_manager.as_ref(),
+                SENDER_SEED_PREFIX.as_bytes(),
+                sender.eth_address.as_ref(),
+                &[sender.bump],
+            ],
+            program_id,
+        )?
+    };
+
+    if expected_address != *sender_info.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(())
+}
+
 /// Return PDA(that named `Base`) corresponding to specific `reward manager`
 /// and it bump seed
 pub fn get_base_address(program_id: &Pubkey, reward_manager: &Pubkey) -> (Pubkey, u8) {
@@ -106,6 +413,7 @@ pub fn get_derived_address(
 #[allow(clippy::too_many_arguments)]
 pub fn token_transfer<'a>(
     program_id: &Pubkey,
+    token_program: &Pubkey,
     reward_manager: &Pubkey,
     source: &AccountInfo<'a>,
     destination: &AccountInfo<'a>,
@@ -118,7 +426,7 @@ pub fn token_transfer<'a>(
     let signers = &[&authority_signature_seeds[..]];
 
     let tx = spl_token::instruction::transfer(
-        &spl_token::id(),
+        token_program,
         source.key,
         destination.key,
         authority.key,
@@ -163,6 +471,36 @@ pub fn create_account_with_seed<'a>(
     )
 }
 
+/// Reads program id and data of the instruction at `index` straight from the
+/// instructions sysvar data, its accounts are skipped rather than deserialized
+pub fn load_instruction_parts(
+    sysvar_data: &[u8],
+    index: usize,
+) -> Result<(Pubkey, &[u8]), AudiusProgramError> {
+    let read_u16 = |offset: usize| {
+        sysvar_data
+            .get(offset..offset + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+            .ok_or(AudiusProgramError::InstructionLoadError)
+    };
+
+    if index >= read_u16(0)? {
+        return Err(AudiusProgramError::InstructionLoadError);
+    }
+    let start = read_u16(2 + index * 2)?;
+    // NOTE: every account is a meta byte followed by the key
+    let program_id_start = start + 2 + read_u16(start)? * (1 + 32);
+    let program_id = sysvar_data
+        .get(program_id_start..program_id_start + 32)
+        .ok_or(AudiusProgramError::InstructionLoadError)?;
+    let data_start = program_id_start + 32 + 2;
+    let data = sysvar_data
+        .get(data_start..data_start + read_u16(program_id_start + 32)?)
+        .ok_or(AudiusProgramError::InstructionLoadError)?;
+
+    Ok((Pubkey::new(program_id), data))
+}
+
 pub fn get_secp_instructions(
     index_current_instruction: u16,
     necessary_instructions_count: usize,
@@ -170,15 +508,18 @@ pub fn get_secp_instructions(
 ) -> Result<Vec<Instruction>, AudiusProgramError> {
     let mut secp_instructions: Vec<Instruction> = Vec::new();
 
+    let sysvar_data = instruction_info.data.borrow();
     for ind in 0..index_current_instruction {
-        let instruction = sysvar::instructions::load_instruction_at(
-            ind as usize,
-            &instruction_info.data.borrow(),
-        )
-        .map_err(to_audius_program_error)?;
+        let (program_id, data) = load_instruction_parts(&sysvar_data, ind as usize)?;
 
-        if instruction.program_id == secp256k1_program::id() {
-            secp_instructions.push(instruction);
+        // NOTE: only secp instructions are copied out, their accounts are never read
+        if program_id == secp256k1_program::id() {
+            validate_secp_offsets(data, ind)?;
+            secp_instructions.push(Instruction {
+                program_id,
+                accounts: Vec::new(),
+                data: data.to_vec(),
+            });
         }
     }
 
@@ -189,70 +530,159 @@ pub fn get_secp_instructions(
     Ok(secp_instructions)
 }
 
-pub fn get_eth_addresses<'a>(
+/// Checks that the offsets table of the secp256k1 instruction placed at
+/// `instruction_index` describes exactly one signature whose address,
+/// signature and message sections all live contiguously in that instruction
+pub fn validate_secp_offsets(
+    secp_instruction_data: &[u8],
+    instruction_index: u16,
+) -> Result<(), AudiusProgramError> {
+    if secp_instruction_data.len() < SECP_DATA_START || secp_instruction_data[0] != 1 {
+        return Err(AudiusProgramError::InvalidSecpOffsets);
+    }
+
+    let read_offset = |at: usize| {
+        u16::from_le_bytes([secp_instruction_data[at], secp_instruction_data[at + 1]]) as usize
+    };
+    let read_index = |at: usize| secp_instruction_data[at] as u16;
+
+    let signature_offset = read_offset(1);
+    let eth_address_offset = read_offset(4);
+    let message_data_offset = read_offset(7);
+    let message_data_size = read_offset(9);
+
+    if read_index(3) != instruction_index
+        || read_index(6) != instruction_index
+        || read_index(11) != instruction_index
+    {
+        return Err(AudiusProgramError::InvalidSecpOffsets);
+    }
+
+    if eth_address_offset != ETH_ADDRESS_OFFSET
+        || signature_offset != SIGNATURE_OFFSET
+        || message_data_offset != MESSAGE_DATA_OFFSET
+        || message_data_offset + message_data_size != secp_instruction_data.len()
+    {
+        return Err(AudiusProgramError::InvalidSecpOffsets);
+    }
+
+    Ok(())
+}
+
+/// Loads the sender accounts of the signers checking they belong to the reward manager
+pub fn load_senders<'a>(
     program_id: &Pubkey,
     reward_manager_key: &Pubkey,
-    signers: Vec<&AccountInfo<'a>>,
-) -> Result<(Vec<EthereumAddress>, BTreeSet<EthereumAddress>), ProgramError> {
-    let mut senders_eth_addresses: Vec<EthereumAddress> = Vec::new();
-    let mut operators = BTreeSet::<EthereumAddress>::new();
+    signers: &[&AccountInfo<'a>],
+) -> Result<Vec<SenderAccount>, ProgramError> {
+    let mut senders = Vec::with_capacity(signers.len());
 
     for signer in signers {
-        let signer_data = SenderAccount::try_from_slice(&signer.data.borrow())?;
+        let signer_data = SenderAccount::unpack(&signer.data.borrow())?;
         if !signer_data.is_initialized() {
             return Err(ProgramError::UninitializedAccount);
         }
 
         is_owner!(*program_id, signer)?;
 
-        let generated_sender_key = get_address_pair(
-            program_id,
-            reward_manager_key,
-            [
-                SENDER_SEED_PREFIX.as_ref(),
-                signer_data.eth_address.as_ref(),
-            ]
-            .concat(),
-        )?;
-        if generated_sender_key.derive.address != *signer.key {
-            return Err(ProgramError::InvalidSeeds);
+        check_sender_address(program_id, reward_manager_key, signer, &signer_data)?;
+        senders.push(signer_data);
+    }
+
+    Ok(senders)
+}
+
+/// Ethereum addresses, operators and total vote weight of distinct senders
+fn get_eth_addresses(
+    senders: &[SenderAccount],
+) -> Result<(Vec<EthereumAddress>, BTreeSet<EthereumAddress>, u32), ProgramError> {
+    let mut senders_eth_addresses: Vec<EthereumAddress> = Vec::new();
+    let mut operators = BTreeSet::<EthereumAddress>::new();
+    let mut vote_weight: u32 = 0;
+
+    for sender in senders {
+        if !sender.is_active() {
+            return Err(AudiusProgramError::SenderSuspended.into());
         }
-        if senders_eth_addresses.contains(&signer_data.eth_address) {
+        if senders_eth_addresses.contains(&sender.eth_address) {
             return Err(AudiusProgramError::RepeatedSenders.into());
         }
-        if !operators.insert(signer_data.operator) {
+        if !operators.insert(sender.operator) {
             return Err(AudiusProgramError::OperatorCollision.into());
         }
-        senders_eth_addresses.push(signer_data.eth_address);
+        senders_eth_addresses.push(sender.eth_address);
+        vote_weight = vote_weight
+            .checked_add(sender.weight as u32)
+            .ok_or(AudiusProgramError::MathOverflow)?;
     }
 
-    Ok((senders_eth_addresses, operators))
+    Ok((senders_eth_addresses, operators, vote_weight))
 }
 
-pub fn get_signer_from_secp_instruction(secp_instruction_data: Vec<u8>) -> EthereumAddress {
-    let eth_address_offset = 12;
-    let instruction_signer =
-        secp_instruction_data[eth_address_offset..eth_address_offset + 20].to_vec();
-    let instruction_signer: EthereumAddress = instruction_signer.as_slice().try_into().unwrap();
-    instruction_signer
+/// Checks the reward manager still accepts attestations encoded with `message_version`
+pub fn check_message_version(
+    reward_manager: &RewardManager,
+    message_version: MessageVersion,
+) -> ProgramResult {
+    if message_version == MessageVersion::V1 && !reward_manager.v1_messages_allowed {
+        return Err(AudiusProgramError::DeprecatedMessageVersion.into());
+    }
+
+    Ok(())
 }
 
-pub fn validate_eth_signature(
-    expected_message: &[u8],
-    secp_instruction_data: Vec<u8>,
-) -> Result<(), ProgramError> {
-    //NOTE: meta (12) + address (20) + signature (65) = 97
-    let message_data_offset = 97;
-    let instruction_message = secp_instruction_data[message_data_offset..].to_vec();
-    if instruction_message != *expected_message {
-        return Err(AudiusProgramError::SignatureVerificationFailed.into());
+/// Checks the number of signers and attestations against the reward manager settings
+pub fn check_attestations_count(
+    reward_manager: &RewardManager,
+    signers: usize,
+    attestations: usize,
+) -> ProgramResult {
+    if reward_manager.sender_count < reward_manager.min_votes as _ {
+        return Err(AudiusProgramError::NotEnoughRegisteredSenders.into());
+    }
+    if signers < reward_manager.min_votes as _ {
+        return Err(AudiusProgramError::NotEnoughSigners.into());
+    }
+    if attestations > reward_manager.max_attestations as _ {
+        return Err(AudiusProgramError::TooManyAttestations.into());
     }
 
     Ok(())
 }
 
-pub trait VerifierFn =
-    FnOnce(Vec<Instruction>, Vec<EthereumAddress>, BTreeSet<EthereumAddress>) -> ProgramResult;
+/// Checks that the senders carry enough vote weight and the payloads are signed
+/// by them and the extra signers of `verifier`
+pub fn verify_attestations(
+    reward_manager: &RewardManager,
+    senders: &[SenderAccount],
+    payloads: Vec<SecpPayload>,
+    verifier: impl Verifier,
+) -> ProgramResult {
+    let (senders_eth_addresses, operators, vote_weight) = get_eth_addresses(senders)?;
+    if vote_weight < reward_manager.min_vote_weight {
+        return Err(AudiusProgramError::NotEnoughVoteWeight.into());
+    }
+
+    verifier.verify(payloads, senders_eth_addresses, operators)
+}
+
+/// Records the verified attestation in the sender accounts of the signers passed
+/// writable, senders created before activity tracking have no room for it
+pub fn record_attestations(
+    signers: &[&AccountInfo],
+    senders: &mut [SenderAccount],
+    slot: Slot,
+) -> ProgramResult {
+    for (signer, sender) in signers.iter().zip(senders.iter_mut()) {
+        if !signer.is_writable || signer.data_len() < SenderAccount::STATUSLESS_LEN {
+            continue;
+        }
+        sender.attestation_count = sender.attestation_count.saturating_add(1);
+        sender.last_attested_slot = slot;
+        sender.pack(&mut signer.data.borrow_mut())?;
+    }
+    Ok(())
+}
 
 fn vec_into_checkmap(vec: &Vec<EthereumAddress>) -> BTreeMap<EthereumAddress, bool> {
     let mut map = BTreeMap::new();
@@ -278,81 +708,480 @@ fn check_signer(
     Ok(())
 }
 
-pub fn build_verify_secp_transfer(
-    bot_oracle: SenderAccount,
-    transfer_data: Transfer,
-) -> impl VerifierFn {
-    return Box::new(
-        move |instructions: Vec<Instruction>,
-              signers: Vec<EthereumAddress>,
-              mut operators: BTreeSet<EthereumAddress>| {
-            let mut successful_verifications = 0;
-            let mut checkmap = vec_into_checkmap(&signers);
-
-            let bot_oracle_message = [
-                transfer_data.eth_recipient.as_ref(),
-                b"_",
-                transfer_data.amount.to_le_bytes().as_ref(),
-                b"_",
-                transfer_data.id.as_ref(),
-            ]
-            .concat();
-
-            let senders_message = [
-                transfer_data.eth_recipient.as_ref(),
-                b"_",
-                transfer_data.amount.to_le_bytes().as_ref(),
-                b"_",
-                transfer_data.id.as_ref(),
-                b"_",
-                bot_oracle.eth_address.as_ref(),
-            ]
-            .concat();
-
-            for instruction in instructions {
-                let eth_signer = get_signer_from_secp_instruction(instruction.data.clone());
-                if eth_signer == bot_oracle.eth_address {
-                    validate_eth_signature(bot_oracle_message.as_ref(), instruction.data.clone())?;
-                    if !operators.insert(bot_oracle.operator) {
-                        return Err(AudiusProgramError::OperatorCollision.into());
-                    }
-                    successful_verifications += 1;
-                }
-                if signers.contains(&eth_signer) {
-                    check_signer(&mut checkmap, &eth_signer)?;
-                    validate_eth_signature(senders_message.as_ref(), instruction.data)?;
-                    successful_verifications += 1;
-                }
-            }
+/// Verification of the secp instructions attached to an attestation-gated instruction
+pub trait Verifier: Sized {
+    /// Message every expected sender must sign
+    fn sender_message(&self) -> Vec<u8>;
 
-            // NOTE: +1 it's bot oracle
-            if successful_verifications != signers.len() + 1 {
-                return Err(AudiusProgramError::SignatureVerificationFailed.into());
-            }
+    /// Verifies signature of a signer which is expected in addition to senders.
+    /// Returns `false` if the signer isn't the one this verifier is waiting for
+    fn verify_extra_signer(
+        &self,
+        _payload: &SecpPayload,
+        _operators: &mut BTreeSet<EthereumAddress>,
+    ) -> Result<bool, ProgramError> {
+        Ok(false)
+    }
 
-            Ok(())
-        },
-    );
-}
-
-pub fn build_verify_secp_add_sender(
-    reward_manager_key: Pubkey,
-    new_sender: EthereumAddress,
-) -> impl VerifierFn {
-    return Box::new(
-        move |instructions: Vec<Instruction>,
-              signers: Vec<EthereumAddress>,
-              _operators: BTreeSet<EthereumAddress>| {
-            let mut checkmap = vec_into_checkmap(&signers);
-
-            let expected_message = [reward_manager_key.as_ref(), new_sender.as_ref()].concat();
-            for instruction in instructions {
-                let eth_signer = get_signer_from_secp_instruction(instruction.data.clone());
-                check_signer(&mut checkmap, &eth_signer)?;
-                validate_eth_signature(expected_message.as_ref(), instruction.data)?;
+    /// Number of signatures expected in addition to senders ones
+    fn extra_signatures(&self) -> usize {
+        0
+    }
+
+    /// Message `signer` is expected to sign, signatures the program recovers
+    /// itself are recovered over it
+    fn signer_message(&self, _signer: &EthereumAddress) -> Vec<u8> {
+        self.sender_message()
+    }
+
+    /// Checks that every payload is signed either by an extra signer
+    /// or by one of the senders, each of them exactly once
+    fn verify(
+        self,
+        payloads: Vec<SecpPayload>,
+        signers: Vec<EthereumAddress>,
+        mut operators: BTreeSet<EthereumAddress>,
+    ) -> ProgramResult {
+        let mut successful_verifications = 0;
+        let mut checkmap = vec_into_checkmap(&signers);
+        let sender_message = self.sender_message();
+
+        for payload in payloads {
+            let is_extra_signer = self.verify_extra_signer(&payload, &mut operators)?;
+            if is_extra_signer {
+                successful_verifications += 1;
             }
+            if !is_extra_signer || signers.contains(&payload.signer) {
+                check_signer(&mut checkmap, &payload.signer)?;
+                payload.check_message(sender_message.as_ref())?;
+                successful_verifications += 1;
+            }
+        }
 
-            Ok(())
-        },
-    );
+        if successful_verifications != signers.len() + self.extra_signatures() {
+            return Err(AudiusProgramError::SignatureVerificationFailed.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks bot oracle signature if the payload is signed by one of the bot oracles.
+/// Each oracle operator is counted once, together with the senders ones
+fn verify_bot_oracle(
+    bot_oracle: &OracleAccount,
+    additional_oracles: &[OracleAccount],
+    payload: &SecpPayload,
+    bot_oracle_message: &[u8],
+    operators: &mut BTreeSet<EthereumAddress>,
+) -> Result<bool, ProgramError> {
+    let oracle = match std::iter::once(bot_oracle)
+        .chain(additional_oracles.iter())
+        .find(|oracle| oracle.eth_address == payload.signer)
+    {
+        Some(oracle) => oracle,
+        None => return Ok(false),
+    };
+
+    payload.check_message(bot_oracle_message)?;
+    if !operators.insert(oracle.operator) {
+        return Err(AudiusProgramError::OperatorCollision.into());
+    }
+
+    Ok(true)
+}
+
+/// Message the bot oracles sign to attest `transfer`, byte for byte the one the
+/// program checks, so off-chain signers don't have to encode it themselves
+pub fn build_oracle_message(transfer: &Transfer) -> Vec<u8> {
+    match transfer.message_version {
+        MessageVersion::V1 => [
+            transfer.eth_recipient.as_ref(),
+            b"_",
+            transfer.amount.to_le_bytes().as_ref(),
+            b"_",
+            transfer.id.as_ref(),
+        ]
+        .concat(),
+        MessageVersion::V2 => [
+            [TRANSFER_MESSAGE_V2_VERSION].as_ref(),
+            transfer.eth_recipient.as_ref(),
+            transfer.amount.to_le_bytes().as_ref(),
+            (transfer.id.len() as u32).to_le_bytes().as_ref(),
+            transfer.id.as_ref(),
+        ]
+        .concat(),
+    }
+}
+
+/// Message the senders sign to attest `transfer` approved by the bot oracle with
+/// the `bot_oracle` Ethereum address, byte for byte the one the program checks
+pub fn build_senders_message(transfer: &Transfer, bot_oracle: &EthereumAddress) -> Vec<u8> {
+    let delimiter: &[u8] = match transfer.message_version {
+        MessageVersion::V1 => b"_",
+        MessageVersion::V2 => &[],
+    };
+    [
+        &build_oracle_message(transfer)[..],
+        delimiter,
+        bot_oracle.as_ref(),
+    ]
+    .concat()
+}
+
+/// Verifies senders and bot oracle attestations of a transfer
+pub struct TransferVerifier {
+    pub bot_oracle: OracleAccount,
+    pub additional_oracles: Vec<OracleAccount>,
+    pub transfer_data: Transfer,
+}
+
+impl TransferVerifier {
+    /// Message the bot oracle must sign
+    pub fn bot_oracle_message(&self) -> Vec<u8> {
+        build_oracle_message(&self.transfer_data)
+    }
+}
+
+impl Verifier for TransferVerifier {
+    fn sender_message(&self) -> Vec<u8> {
+        build_senders_message(&self.transfer_data, &self.bot_oracle.eth_address)
+    }
+
+    fn verify_extra_signer(
+        &self,
+        payload: &SecpPayload,
+        operators: &mut BTreeSet<EthereumAddress>,
+    ) -> Result<bool, ProgramError> {
+        verify_bot_oracle(
+            &self.bot_oracle,
+            &self.additional_oracles,
+            payload,
+            self.bot_oracle_message().as_ref(),
+            operators,
+        )
+    }
+
+    fn extra_signatures(&self) -> usize {
+        // NOTE: +1 it's bot oracle
+        self.additional_oracles.len() + 1
+    }
+
+    fn signer_message(&self, signer: &EthereumAddress) -> Vec<u8> {
+        let is_oracle = std::iter::once(&self.bot_oracle)
+            .chain(self.additional_oracles.iter())
+            .any(|oracle| oracle.eth_address == *signer);
+        if is_oracle {
+            self.bot_oracle_message()
+        } else {
+            self.sender_message()
+        }
+    }
+}
+
+/// Recovers the signers of `signatures` over the messages `verifier` expects from them
+pub fn recover_payloads(
+    verifier: &impl Verifier,
+    signatures: &[RecoverableSignature],
+) -> Result<Vec<SecpPayload>, AudiusProgramError> {
+    signatures
+        .iter()
+        .map(|signature| {
+            SecpPayload::recover(signature, verifier.signer_message(&signature.signer))
+        })
+        .collect()
+}
+
+/// Verifies senders and bot oracle attestations of a transfer to a Solana wallet
+pub struct WalletTransferVerifier {
+    pub bot_oracle: OracleAccount,
+    pub additional_oracles: Vec<OracleAccount>,
+    pub transfer_data: TransferToWallet,
+}
+
+impl WalletTransferVerifier {
+    /// Message the bot oracle must sign
+    pub fn bot_oracle_message(&self) -> Vec<u8> {
+        [
+            WALLET_TRANSFER_MESSAGE_PREFIX.as_bytes(),
+            self.transfer_data.wallet.as_ref(),
+            b"_",
+            self.transfer_data.amount.to_le_bytes().as_ref(),
+            b"_",
+            self.transfer_data.id.as_ref(),
+        ]
+        .concat()
+    }
+}
+
+impl Verifier for WalletTransferVerifier {
+    fn sender_message(&self) -> Vec<u8> {
+        [
+            &self.bot_oracle_message()[..],
+            b"_",
+            self.bot_oracle.eth_address.as_ref(),
+        ]
+        .concat()
+    }
+
+    fn verify_extra_signer(
+        &self,
+        payload: &SecpPayload,
+        operators: &mut BTreeSet<EthereumAddress>,
+    ) -> Result<bool, ProgramError> {
+        verify_bot_oracle(
+            &self.bot_oracle,
+            &self.additional_oracles,
+            payload,
+            self.bot_oracle_message().as_ref(),
+            operators,
+        )
+    }
+
+    fn extra_signatures(&self) -> usize {
+        // NOTE: +1 it's bot oracle
+        self.additional_oracles.len() + 1
+    }
+}
+
+/// Serializes the transfer as hashed by batch digests and distribution leaves,
+/// `memo` was added later and is left out so earlier digests and roots stay valid
+fn serialize_hashed_transfer(transfer: &Transfer, dst: &mut Vec<u8>) -> ProgramResult {
+    transfer.amount.serialize(dst)?;
+    transfer.id.serialize(dst)?;
+    transfer.eth_recipient.serialize(dst)?;
+    transfer.message_version.serialize(dst)?;
+    transfer.create_recipient.serialize(dst)?;
+    Ok(())
+}
+
+/// Digest of a transfers batch signed by senders and bot oracle
+pub fn transfer_batch_digest(transfers: &[Transfer]) -> Result<Hash, ProgramError> {
+    let mut data = (transfers.len() as u32).try_to_vec()?;
+    for transfer in transfers {
+        serialize_hashed_transfer(transfer, &mut data)?;
+    }
+    Ok(hash(&data))
+}
+
+/// Verifies senders and bot oracle attestations of a transfers batch
+pub struct TransferBatchVerifier {
+    pub bot_oracle: OracleAccount,
+    pub additional_oracles: Vec<OracleAccount>,
+    pub batch_digest: Hash,
+}
+
+impl TransferBatchVerifier {
+    /// Message the bot oracle must sign
+    pub fn bot_oracle_message(&self) -> Vec<u8> {
+        [
+            TRANSFER_BATCH_MESSAGE_PREFIX.as_bytes(),
+            self.batch_digest.as_ref(),
+        ]
+        .concat()
+    }
+}
+
+impl Verifier for TransferBatchVerifier {
+    fn sender_message(&self) -> Vec<u8> {
+        [
+            &self.bot_oracle_message()[..],
+            b"_",
+            self.bot_oracle.eth_address.as_ref(),
+        ]
+        .concat()
+    }
+
+    fn verify_extra_signer(
+        &self,
+        payload: &SecpPayload,
+        operators: &mut BTreeSet<EthereumAddress>,
+    ) -> Result<bool, ProgramError> {
+        verify_bot_oracle(
+            &self.bot_oracle,
+            &self.additional_oracles,
+            payload,
+            self.bot_oracle_message().as_ref(),
+            operators,
+        )
+    }
+
+    fn extra_signatures(&self) -> usize {
+        // NOTE: +1 it's bot oracle
+        self.additional_oracles.len() + 1
+    }
+}
+
+/// Hash of the transfer ID recorded in the event log
+pub fn transfer_id_hash(id: &str) -> Hash {
+    hash(id.as_bytes())
+}
+
+/// Leaf of a distribution Merkle tree approving the transfer
+pub fn distribution_leaf(transfer: &Transfer) -> Result<Hash, ProgramError> {
+    // NOTE: prefixes keep a leaf from being passed off as an inner node
+    let mut data = Vec::new();
+    serialize_hashed_transfer(transfer, &mut data)?;
+    Ok(hashv(&[&[0], data.as_ref()]))
+}
+
+/// Memo the SPL Memo program logs for a transfer setting `memo`, naming the
+/// challenge of namespaced transfer IDs
+pub fn transfer_memo(transfer_id: &str) -> String {
+    match transfer_id.find(CHALLENGE_ID_SEPARATOR) {
+        Some(len) => format!(
+            "challenge: {}, transfer_id: {}",
+            &transfer_id[..len],
+            transfer_id
+        ),
+        None => format!("transfer_id: {}", transfer_id),
+    }
+}
+
+/// Parent of two distribution Merkle tree nodes, independent of their order
+pub fn distribution_parent(left: &Hash, right: &Hash) -> Hash {
+    let (first, second) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    hashv(&[&[1], first.as_ref(), second.as_ref()])
+}
+
+/// Checks the Merkle proof of the leaf against the root
+pub fn verify_distribution_proof(root: &Hash, leaf: Hash, proof: &[Hash]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| distribution_parent(&node, sibling))
+        == *root
+}
+
+/// Verifies senders and bot oracle attestations of a distribution Merkle root
+pub struct DistributionRootVerifier {
+    pub bot_oracle: OracleAccount,
+    pub additional_oracles: Vec<OracleAccount>,
+    pub distribution_id: String,
+    pub root: Hash,
+}
+
+impl DistributionRootVerifier {
+    /// Message the bot oracle must sign
+    pub fn bot_oracle_message(&self) -> Vec<u8> {
+        [
+            DISTRIBUTION_ROOT_MESSAGE_PREFIX.as_bytes(),
+            self.root.as_ref(),
+            b"_",
+            self.distribution_id.as_bytes(),
+        ]
+        .concat()
+    }
+}
+
+impl Verifier for DistributionRootVerifier {
+    fn sender_message(&self) -> Vec<u8> {
+        [
+            &self.bot_oracle_message()[..],
+            b"_",
+            self.bot_oracle.eth_address.as_ref(),
+        ]
+        .concat()
+    }
+
+    fn verify_extra_signer(
+        &self,
+        payload: &SecpPayload,
+        operators: &mut BTreeSet<EthereumAddress>,
+    ) -> Result<bool, ProgramError> {
+        verify_bot_oracle(
+            &self.bot_oracle,
+            &self.additional_oracles,
+            payload,
+            self.bot_oracle_message().as_ref(),
+            operators,
+        )
+    }
+
+    fn extra_signatures(&self) -> usize {
+        // NOTE: +1 it's bot oracle
+        self.additional_oracles.len() + 1
+    }
+}
+
+/// Verifies senders attestations of a new sender and the operator it belongs to
+pub struct AddSenderVerifier {
+    pub reward_manager: Pubkey,
+    pub new_sender: EthereumAddress,
+    pub operator: EthereumAddress,
+}
+
+impl Verifier for AddSenderVerifier {
+    fn sender_message(&self) -> Vec<u8> {
+        [
+            self.reward_manager.as_ref(),
+            self.new_sender.as_ref(),
+            self.operator.as_ref(),
+        ]
+        .concat()
+    }
+}
+
+/// Verifies senders attestations of a sender removal
+pub struct DeleteSenderVerifier {
+    pub reward_manager: Pubkey,
+    pub sender: EthereumAddress,
+}
+
+impl Verifier for DeleteSenderVerifier {
+    fn sender_message(&self) -> Vec<u8> {
+        [
+            DELETE_SENDER_MESSAGE_PREFIX.as_bytes(),
+            self.reward_manager.as_ref(),
+            self.sender.as_ref(),
+        ]
+        .concat()
+    }
+}
+
+/// Verifies the sender attestation of its move to a new Ethereum address,
+/// signed over the sender attestation count so that it can't be replayed
+pub struct RotateSenderVerifier {
+    pub reward_manager: Pubkey,
+    pub attestation_count: u64,
+    pub new_sender: EthereumAddress,
+}
+
+impl Verifier for RotateSenderVerifier {
+    fn sender_message(&self) -> Vec<u8> {
+        [
+            ROTATE_SENDER_MESSAGE_PREFIX.as_bytes(),
+            self.reward_manager.as_ref(),
+            self.attestation_count.to_le_bytes().as_ref(),
+            self.new_sender.as_ref(),
+        ]
+        .concat()
+    }
+}
+
+/// Verifies the sender attestation of its new endpoint metadata
+pub struct SenderMetadataVerifier {
+    pub reward_manager: Pubkey,
+    pub current_endpoint_hash: [u8; 32],
+    pub endpoint_hash: [u8; 32],
+}
+
+impl Verifier for SenderMetadataVerifier {
+    fn sender_message(&self) -> Vec<u8> {
+        [
+            SENDER_METADATA_MESSAGE_PREFIX.as_bytes(),
+            self.reward_manager.as_ref(),
+            self.current_endpoint_hash.as_ref(),
+            self.endpoint_hash.as_ref(),
+        ]
+        .concat()
+    }
+}
+
+/// Hash of the sender service endpoint URL stored in `SenderAccount::endpoint_hash`
+pub fn endpoint_hash(endpoint: &str) -> [u8; 32] {
+    keccak::hash(endpoint.as_bytes()).0
 }
@@ -0,0 +1,51 @@
+//! Read-only views over raw account data shared by on-chain and off-chain consumers
+
+use crate::state::{RewardManager, SenderAccount};
+use solana_program::{
+    program_error::ProgramError, program_pack::IsInitialized, program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token::state::Account as TokenAccount;
+
+/// Checks whether the transfer receipt account with the given owner and balance
+/// records an already disbursed transfer
+pub fn is_transfer_disbursed(program_id: &Pubkey, owner: &Pubkey, lamports: u64) -> bool {
+    *owner == *program_id && lamports > 0
+}
+
+/// Counts initialized senders of the `reward_manager` among the raw accounts data
+pub fn sender_count<'a, I>(reward_manager: &Pubkey, accounts_data: I) -> usize
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    accounts_data
+        .into_iter()
+        .filter_map(|data| SenderAccount::unpack(data).ok())
+        .filter(|sender| sender.is_initialized() && sender.reward_manager == *reward_manager)
+        .count()
+}
+
+/// Checks that `vault_amount` covers `amount` and keeps the reward manager balance floor
+pub fn is_funded(reward_manager: &RewardManager, vault_amount: u64, amount: u64) -> bool {
+    vault_amount
+        .checked_sub(amount)
+        .map_or(false, |left| left >= reward_manager.min_balance_threshold)
+}
+
+/// Checks that the reward manager token account holds `amount` tokens above its balance floor
+pub fn pool_balance_check(
+    reward_manager_data: &[u8],
+    token_account_key: &Pubkey,
+    token_account_data: &[u8],
+    amount: u64,
+) -> Result<bool, ProgramError> {
+    let reward_manager = RewardManager::unpack(reward_manager_data)?;
+    if !reward_manager.is_initialized() {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    if reward_manager.token_account != *token_account_key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let token_account = TokenAccount::unpack(token_account_data)?;
+    Ok(is_funded(&reward_manager, token_account.amount, amount))
+}
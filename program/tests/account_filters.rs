@@ -0,0 +1,56 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::state::{SenderAccount, TransferReceipt};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn sender_offsets() {
+    let reward_manager = Pubkey::new_unique();
+    let data = SenderAccount::new(reward_manager, [1; 20], [2; 20], 254)
+        .try_to_vec()
+        .unwrap();
+
+    assert_eq!(data.len(), SenderAccount::LEN);
+    assert_eq!(
+        &data[SenderAccount::REWARD_MANAGER_OFFSET..][..32],
+        reward_manager.as_ref()
+    );
+}
+
+#[test]
+fn transfer_receipt_offsets() {
+    let recipient = Pubkey::new_unique();
+    let data = TransferReceipt::new(recipient, 10_000, 42)
+        .try_to_vec()
+        .unwrap();
+
+    assert_eq!(data.len(), TransferReceipt::LEN);
+    assert_eq!(
+        &data[TransferReceipt::RECIPIENT_OFFSET..][..32],
+        recipient.as_ref()
+    );
+}
+
+#[cfg(feature = "sdk")]
+#[test]
+fn sender_filters_match() {
+    use audius_reward_manager::sdk::senders_filters;
+    use solana_client::rpc_filter::{MemcmpEncodedBytes, RpcFilterType};
+
+    let reward_manager = Pubkey::new_unique();
+    let data = SenderAccount::new(reward_manager, [1; 20], [2; 20], 254)
+        .try_to_vec()
+        .unwrap();
+
+    for filter in senders_filters(&reward_manager) {
+        match filter {
+            RpcFilterType::DataSize(size) => assert_eq!(size as usize, data.len()),
+            RpcFilterType::Memcmp(memcmp) => assert_eq!(
+                memcmp.bytes,
+                MemcmpEncodedBytes::Binary(
+                    bs58::encode(&data[memcmp.offset..][..32]).into_string()
+                )
+            ),
+        }
+    }
+}
@@ -3,19 +3,24 @@ mod utils;
 use std::mem::MaybeUninit;
 
 use audius_reward_manager::{
+    error::AudiusProgramError,
     instruction,
-    processor::SENDER_SEED_PREFIX,
-    state::SenderAccount,
-    utils::{get_address_pair, EthereumAddress},
+    state::{RewardManager, SenderAccount},
+    utils::{get_sender_address, EthereumAddress},
 };
+use borsh::BorshSerialize;
 use rand::{thread_rng, Rng};
 use secp256k1::{PublicKey, SecretKey};
 use solana_program::program_pack::Pack;
 use solana_program::{instruction::Instruction, pubkey::Pubkey};
 use solana_program_test::*;
 use solana_sdk::{
-    secp256k1_instruction::construct_eth_pubkey, signature::Keypair, signer::Signer,
-    transaction::Transaction,
+    account::Account,
+    instruction::InstructionError,
+    secp256k1_instruction::construct_eth_pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
 };
 use utils::*;
 
@@ -39,22 +44,20 @@ async fn success() {
         let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
         let eth_address = construct_eth_pubkey(&secp_pubkey);
 
-        let pair = get_address_pair(
+        let (sender, _) = get_sender_address(
             &audius_reward_manager::id(),
             &reward_manager.pubkey(),
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-        )
-        .unwrap();
+            &eth_address,
+        );
 
-        signers[item.0] = pair.derive.address;
+        signers[item.0] = sender;
     }
 
-    let pair = get_address_pair(
+    let (sender, bump) = get_sender_address(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-    )
-    .unwrap();
+        &eth_address,
+    );
 
     let mut context = program_test.start_with_context().await;
     let rent = context.banks_client.get_rent().await.unwrap();
@@ -97,7 +100,12 @@ async fn success() {
     let mut instructions = Vec::<Instruction>::new();
 
     // Insert signs instructions
-    let message = [reward_manager.pubkey().as_ref(), eth_address.as_ref()].concat();
+    let message = [
+        reward_manager.pubkey().as_ref(),
+        eth_address.as_ref(),
+        operator.as_ref(),
+    ]
+    .concat();
     for item in keys.iter().enumerate() {
         let priv_key = SecretKey::parse(item.1).unwrap();
         let inst = new_secp256k1_instruction_2_0(&priv_key, message.as_ref(), item.0 as _);
@@ -125,11 +133,308 @@ async fn success() {
     context.banks_client.process_transaction(tx).await.unwrap();
 
     assert_eq!(
-        SenderAccount::new(reward_manager.pubkey(), eth_address, operator),
+        SenderAccount::new(reward_manager.pubkey(), eth_address, operator, bump),
         context
             .banks_client
-            .get_account_data_with_borsh(pair.derive.address)
+            .get_account_data_with_borsh(sender)
             .await
             .unwrap()
     );
 }
+
+#[tokio::test]
+async fn fail_unattested_operator() {
+    let program_test = program_test();
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+    let token_account = Keypair::new();
+
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+    let eth_address: EthereumAddress = rng.gen();
+    let operator: EthereumAddress = rng.gen();
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let mut signers: [Pubkey; 3] = unsafe { MaybeUninit::zeroed().assume_init() };
+    for item in keys.iter().enumerate() {
+        let sender_priv_key = SecretKey::parse(item.1).unwrap();
+        let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
+        let eth_address = construct_eth_pubkey(&secp_pubkey);
+
+        let (sender, _) = get_sender_address(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &eth_address,
+        );
+
+        signers[item.0] = sender;
+    }
+
+    let mut context = program_test.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        &mut context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        &mut context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3 as u8,
+    )
+    .await;
+
+    // Create senders
+    for key in &keys {
+        let sender_priv_key = SecretKey::parse(&key).unwrap();
+        let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
+        let eth_address = construct_eth_pubkey(&secp_pubkey);
+        let operator: EthereumAddress = rng.gen();
+        create_sender(
+            &mut context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            operator,
+        )
+        .await;
+    }
+
+    let mut instructions = Vec::<Instruction>::new();
+
+    // Insert signs instructions
+    let message = [
+        reward_manager.pubkey().as_ref(),
+        eth_address.as_ref(),
+        operator.as_ref(),
+    ]
+    .concat();
+    for item in keys.iter().enumerate() {
+        let priv_key = SecretKey::parse(item.1).unwrap();
+        let inst = new_secp256k1_instruction_2_0(&priv_key, message.as_ref(), item.0 as _);
+        instructions.push(inst);
+    }
+
+    instructions.push(
+        instruction::add_sender(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &context.payer.pubkey(),
+            eth_address,
+            rng.gen(),
+            &signers,
+        )
+        .unwrap(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::SignatureVerificationFailed as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_too_many_attestations() {
+    let program_test = program_test();
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+    let token_account = Keypair::new();
+
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+    let eth_address: EthereumAddress = rng.gen();
+    let operator: EthereumAddress = rng.gen();
+    let keys: [[u8; 32]; 5] = rng.gen();
+    let mut signers: [Pubkey; 5] = unsafe { MaybeUninit::zeroed().assume_init() };
+    for item in keys.iter().enumerate() {
+        let sender_priv_key = SecretKey::parse(item.1).unwrap();
+        let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
+        let eth_address = construct_eth_pubkey(&secp_pubkey);
+
+        let (sender, _) = get_sender_address(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &eth_address,
+        );
+
+        signers[item.0] = sender;
+    }
+
+    let mut context = program_test.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        &mut context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        &mut context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3 as u8,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &manager_account.pubkey(),
+            instruction::UpdateRewardManager {
+                max_attestations: Some(4),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Create senders
+    for key in &keys {
+        let sender_priv_key = SecretKey::parse(&key).unwrap();
+        let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
+        let eth_address = construct_eth_pubkey(&secp_pubkey);
+        let operator: EthereumAddress = rng.gen();
+        create_sender(
+            &mut context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            operator,
+        )
+        .await;
+    }
+
+    let mut instructions = Vec::<Instruction>::new();
+
+    // Insert signs instructions
+    let message = [
+        reward_manager.pubkey().as_ref(),
+        eth_address.as_ref(),
+        operator.as_ref(),
+    ]
+    .concat();
+    for item in keys.iter().enumerate() {
+        let priv_key = SecretKey::parse(item.1).unwrap();
+        let inst = new_secp256k1_instruction_2_0(&priv_key, message.as_ref(), item.0 as _);
+        instructions.push(inst);
+    }
+
+    instructions.push(
+        instruction::add_sender(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &context.payer.pubkey(),
+            eth_address,
+            operator,
+            &signers,
+        )
+        .unwrap(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            5,
+            InstructionError::Custom(AudiusProgramError::TooManyAttestations as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_no_registered_senders() {
+    let mut program_test = program_test();
+    let mut rng = thread_rng();
+
+    let reward_manager = Pubkey::new_unique();
+    let eth_address: EthereumAddress = rng.gen();
+    let operator: EthereumAddress = rng.gen();
+
+    let reward_manager_data = RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3);
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: reward_manager_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::add_sender(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &context.payer.pubkey(),
+            eth_address,
+            operator,
+            std::iter::empty::<&Pubkey>(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::NotEnoughRegisteredSenders as _)
+        )
+    );
+}
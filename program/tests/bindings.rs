@@ -0,0 +1,10 @@
+#![cfg(feature = "idl")]
+
+#[test]
+fn bindings_up_to_date() {
+    assert_eq!(
+        audius_reward_manager::idl::typescript::bindings(),
+        include_str!("../../bindings/ts/src/index.ts"),
+        "regenerate with `cargo run --example bindings --features idl > ../bindings/ts/src/index.ts`"
+    );
+}
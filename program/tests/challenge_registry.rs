@@ -0,0 +1,342 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{
+        self, ClaimFromRoot, MessageVersion, SetChallenge, SubmitDistributionRoot, Transfer,
+        TransferToWallet,
+    },
+    state::{ChallengeRegistry, MAX_CHALLENGES},
+    utils::{distribution_leaf, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+const RECIPIENT: [u8; 20] = [7u8; 20];
+const DISTRIBUTION_ID: &str = "d1s7r1bu710n";
+
+async fn start() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    (context, fixture, recipient)
+}
+
+async fn set_challenge(
+    context: &mut ProgramTestContext,
+    fixture: &TestRewardManager,
+    id: &str,
+    oracles: Vec<EthereumAddress>,
+) {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::set_challenge(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            &context.payer.pubkey(),
+            SetChallenge {
+                id: String::from(id),
+                oracles,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn transfer_data(id: &str) -> Transfer {
+    Transfer {
+        amount: TRANSFER_AMOUNT,
+        id: String::from(id),
+        eth_recipient: RECIPIENT,
+        message_version: MessageVersion::V2,
+        create_recipient: false,
+        memo: false,
+    }
+}
+
+fn transfer_instructions(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    recipient: &Pubkey,
+    id: &str,
+) -> Vec<Instruction> {
+    fixture.transfer_instructions(&context.payer.pubkey(), recipient, transfer_data(id))
+}
+
+/// Submits a distribution root of the single transfer `id` attested by the
+/// first bot oracle and returns its claim
+async fn distribution_claim(
+    context: &mut ProgramTestContext,
+    fixture: &TestRewardManager,
+    id: &str,
+) -> Instruction {
+    let transfer = transfer_data(id);
+    let tx = Transaction::new_signed_with_payer(
+        &fixture.submit_distribution_root_instructions(
+            &context.payer.pubkey(),
+            SubmitDistributionRoot {
+                id: String::from(DISTRIBUTION_ID),
+                root: distribution_leaf(&transfer).unwrap(),
+            },
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    fixture.claim_from_root_instruction(
+        &context.payer.pubkey(),
+        DISTRIBUTION_ID,
+        ClaimFromRoot {
+            transfer,
+            proof: Vec::new(),
+        },
+    )
+}
+
+async fn assert_transfer_error(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    error: AudiusProgramError,
+) {
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(error as _)
+        )
+    );
+}
+
+#[test]
+fn registry_sets_and_removes_challenges() {
+    let mut registry = ChallengeRegistry::new(Pubkey::new_unique());
+    assert_eq!(registry.try_to_vec().unwrap().len(), ChallengeRegistry::LEN);
+
+    registry.set("a", &[[1u8; 20]]).unwrap();
+    registry.set("b", &[[2u8; 20]]).unwrap();
+    registry.set("a", &[[3u8; 20], [4u8; 20]]).unwrap();
+    assert_eq!(registry.challenges().len(), 2);
+    assert_eq!(
+        registry.challenge("a").unwrap().oracles(),
+        &[[3u8; 20], [4u8; 20]]
+    );
+
+    registry.set("a", &[]).unwrap();
+    assert!(registry.challenge("a").is_none());
+    assert_eq!(registry.authorize("b:1", &[2u8; 20]), Ok(()));
+    assert_eq!(
+        registry.authorize("b1", &[2u8; 20]),
+        Err(AudiusProgramError::UnregisteredChallenge)
+    );
+
+    for id in ["", "toolongid", "a:b"].iter() {
+        assert_eq!(
+            registry.set(id, &[[1u8; 20]]),
+            Err(AudiusProgramError::InvalidChallenge)
+        );
+    }
+
+    let mut registry = ChallengeRegistry::new(Pubkey::new_unique());
+    for index in 0..MAX_CHALLENGES {
+        registry.set(&index.to_string(), &[[1u8; 20]]).unwrap();
+    }
+    assert_eq!(
+        registry.set("full", &[[1u8; 20]]),
+        Err(AudiusProgramError::ChallengeRegistryFull)
+    );
+}
+
+#[tokio::test]
+async fn success_registered_challenge() {
+    let (mut context, fixture, recipient) = start().await;
+    let bot_oracle = fixture.oracles[0].eth_address;
+    set_challenge(&mut context, &fixture, "listen", vec![bot_oracle]).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &transfer_instructions(&context, &fixture, &recipient, "listen:4r4t23df32543f55"),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn fail_unregistered_challenge() {
+    let (mut context, fixture, recipient) = start().await;
+    let bot_oracle = fixture.oracles[0].eth_address;
+    set_challenge(&mut context, &fixture, "listen", vec![bot_oracle]).await;
+
+    let instructions =
+        transfer_instructions(&context, &fixture, &recipient, "upload:4r4t23df32543f55");
+    assert_transfer_error(
+        &mut context,
+        &instructions,
+        AudiusProgramError::UnregisteredChallenge,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_oracle_not_authorized() {
+    let (mut context, fixture, recipient) = start().await;
+    set_challenge(&mut context, &fixture, "listen", vec![[1u8; 20]]).await;
+
+    let instructions =
+        transfer_instructions(&context, &fixture, &recipient, "listen:4r4t23df32543f55");
+    assert_transfer_error(
+        &mut context,
+        &instructions,
+        AudiusProgramError::OracleNotAuthorizedForChallenge,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_batch_unregistered_challenge() {
+    let (mut context, fixture, _) = start().await;
+    let bot_oracle = fixture.oracles[0].eth_address;
+    set_challenge(&mut context, &fixture, "listen", vec![bot_oracle]).await;
+
+    // NOTE: every transfer of the batch is checked, not only the first one
+    let instructions = fixture.transfer_batch_instructions(
+        &context.payer.pubkey(),
+        vec![
+            Transfer {
+                amount: TRANSFER_AMOUNT / 2,
+                ..transfer_data("listen:4r4t23df32543f55")
+            },
+            Transfer {
+                amount: TRANSFER_AMOUNT / 2,
+                ..transfer_data("upload:9f8e7d6c5b4a3921")
+            },
+        ],
+    );
+    assert_transfer_error(
+        &mut context,
+        &instructions,
+        AudiusProgramError::UnregisteredChallenge,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_wallet_unregistered_challenge() {
+    let (mut context, fixture, _) = start().await;
+    let bot_oracle = fixture.oracles[0].eth_address;
+    set_challenge(&mut context, &fixture, "listen", vec![bot_oracle]).await;
+
+    let instructions = fixture.transfer_to_wallet_instructions(
+        &context.payer.pubkey(),
+        TransferToWallet {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("upload:4r4t23df32543f55"),
+            wallet: Pubkey::new_unique(),
+        },
+    );
+    assert_transfer_error(
+        &mut context,
+        &instructions,
+        AudiusProgramError::UnregisteredChallenge,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_wallet_oracle_not_authorized() {
+    let (mut context, fixture, _) = start().await;
+    set_challenge(&mut context, &fixture, "listen", vec![[1u8; 20]]).await;
+
+    let instructions = fixture.transfer_to_wallet_instructions(
+        &context.payer.pubkey(),
+        TransferToWallet {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("listen:4r4t23df32543f55"),
+            wallet: Pubkey::new_unique(),
+        },
+    );
+    assert_transfer_error(
+        &mut context,
+        &instructions,
+        AudiusProgramError::OracleNotAuthorizedForChallenge,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_claim_unregistered_challenge() {
+    let (mut context, fixture, _) = start().await;
+    let bot_oracle = fixture.oracles[0].eth_address;
+    set_challenge(&mut context, &fixture, "listen", vec![bot_oracle]).await;
+
+    let claim = distribution_claim(&mut context, &fixture, "upload:4r4t23df32543f55").await;
+    assert_transfer_error(
+        &mut context,
+        &[claim],
+        AudiusProgramError::UnregisteredChallenge,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_claim_oracle_not_authorized() {
+    let (mut context, fixture, _) = start().await;
+    set_challenge(&mut context, &fixture, "listen", vec![[1u8; 20]]).await;
+
+    // NOTE: checked against the bot oracle which attested the root, not the claimer
+    let claim = distribution_claim(&mut context, &fixture, "listen:4r4t23df32543f55").await;
+    assert_transfer_error(
+        &mut context,
+        &[claim],
+        AudiusProgramError::OracleNotAuthorizedForChallenge,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn success_claim_registered_challenge() {
+    let (mut context, fixture, _) = start().await;
+    let bot_oracle = fixture.oracles[0].eth_address;
+    set_challenge(&mut context, &fixture, "listen", vec![bot_oracle]).await;
+
+    let claim = distribution_claim(&mut context, &fixture, "listen:4r4t23df32543f55").await;
+    let tx = Transaction::new_signed_with_payer(
+        &[claim],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
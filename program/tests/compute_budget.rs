@@ -0,0 +1,237 @@
+#![cfg(feature = "test-bpf")]
+//! Measures compute units of `Transfer` for growing sender sets, run with
+//! `cargo test-bpf --test compute_budget -- --nocapture` to see the figures.
+//! Compute units are only metered when the program runs as BPF, natively every
+//! budget suffices and the measurements come out at the search granularity.
+mod utils;
+use audius_reward_manager::{
+    instruction::{self, Instructions, MAX_COMPUTE_UNITS},
+    processor::ORACLE_SEED_PREFIX,
+    utils::{get_address_pair, get_sender_address},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    secp256k1_instruction::*, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+use utils::*;
+
+/// Units the binary search stops refining at
+const GRANULARITY: u32 = 1_000;
+
+/// The budget applies to the setup transactions as well, none of them needs more
+const SETUP_COMPUTE_UNITS: u32 = 40_000;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+/// Registers bot oracle and `senders_count` senders, funds the vault and returns the
+/// attestation instructions followed by the transfer instruction
+async fn prepare_transfer(
+    context: &mut ProgramTestContext,
+    senders_count: usize,
+) -> Vec<Instruction> {
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let key: [u8; 32] = rng.gen();
+    let oracle_priv_key = SecretKey::parse(&key).unwrap();
+    let eth_oracle_address = construct_eth_pubkey(&PublicKey::from_secret_key(&oracle_priv_key));
+    let oracle = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+    )
+    .unwrap();
+    create_oracle(
+        context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        eth_oracle_address,
+        rng.gen(),
+    )
+    .await;
+
+    let mut keys = Vec::new();
+    let mut signers = Vec::new();
+    for _ in 0..senders_count {
+        let key: [u8; 32] = rng.gen();
+        let sender_priv_key = SecretKey::parse(&key).unwrap();
+        let eth_address = construct_eth_pubkey(&PublicKey::from_secret_key(&sender_priv_key));
+        signers.push(
+            get_sender_address(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &eth_address,
+            )
+            .0,
+        );
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            rng.gen(),
+        )
+        .await;
+        keys.push(sender_priv_key);
+    }
+
+    mint_tokens_to(
+        context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        TRANSFER_AMOUNT,
+    )
+    .await
+    .unwrap();
+
+    let recipient_eth_key = [7u8; 20];
+    let recipient_sol_key = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        &mint.pubkey(),
+        recipient_eth_key,
+    )
+    .unwrap();
+    create_recipient_with_claimable_program(context, &mint.pubkey(), recipient_eth_key).await;
+
+    let transfer_id = "4r4t23df32543f55";
+
+    let bot_oracle_message = [
+        recipient_eth_key.as_ref(),
+        b"_",
+        TRANSFER_AMOUNT.to_le_bytes().as_ref(),
+        b"_",
+        transfer_id.as_ref(),
+    ]
+    .concat();
+    let senders_message = [
+        bot_oracle_message.as_ref(),
+        b"_",
+        eth_oracle_address.as_ref(),
+    ]
+    .concat();
+
+    let mut instructions = vec![new_secp256k1_instruction_2_0(
+        &oracle_priv_key,
+        bot_oracle_message.as_ref(),
+        0,
+    )];
+    for (index, key) in keys.iter().enumerate() {
+        instructions.push(new_secp256k1_instruction_2_0(
+            key,
+            senders_message.as_ref(),
+            (index + 1) as _,
+        ));
+    }
+
+    instructions.push(
+        instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &recipient_sol_key.derive.address,
+            &token_account.pubkey(),
+            &oracle.derive.address,
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            signers.into_iter(),
+            instruction::Transfer {
+                amount: TRANSFER_AMOUNT,
+                id: String::from(transfer_id),
+                eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap(),
+    );
+
+    instructions
+}
+
+/// Whether the transfer attested by `senders_count` senders fits into `units`
+async fn transfer_fits(senders_count: usize, units: u32) -> bool {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    program_test.set_bpf_compute_max_units(units as u64);
+
+    let mut context = program_test.start_with_context().await;
+    let instructions = prepare_transfer(&mut context, senders_count).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.is_ok()
+}
+
+/// Smallest budget, up to `GRANULARITY`, the transfer succeeds with
+async fn measure_transfer(senders_count: usize) -> u32 {
+    let (mut low, mut high) = (SETUP_COMPUTE_UNITS, MAX_COMPUTE_UNITS);
+    assert!(transfer_fits(senders_count, high).await);
+
+    while high - low > GRANULARITY {
+        let units = low + (high - low) / 2;
+        if transfer_fits(senders_count, units).await {
+            high = units;
+        } else {
+            low = units;
+        }
+    }
+
+    high
+}
+
+#[tokio::test]
+async fn transfer_within_recommended_units() {
+    let instruction = Instructions::Transfer(instruction::Transfer {
+        amount: TRANSFER_AMOUNT,
+        id: String::from("4r4t23df32543f55"),
+        eth_recipient: [7u8; 20],
+        message_version: instruction::MessageVersion::V1,
+        create_recipient: false,
+        memo: false,
+    });
+
+    for senders_count in [3, 6, 9].iter().copied() {
+        let units = measure_transfer(senders_count).await;
+        let recommended = instruction.recommended_compute_units(senders_count);
+        println!(
+            "Transfer: {} senders, {} compute units, {} recommended",
+            senders_count, units, recommended
+        );
+        assert!(units <= recommended);
+    }
+}
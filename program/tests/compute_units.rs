@@ -0,0 +1,42 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::{
+    instruction::{Instructions, MessageVersion, Transfer, TransferBatch, MAX_COMPUTE_UNITS},
+    state::DEFAULT_MAX_ATTESTATIONS,
+};
+
+fn transfer() -> Transfer {
+    Transfer {
+        amount: 10_000,
+        id: String::from("4r4t23df32543f55"),
+        eth_recipient: [7u8; 20],
+        message_version: MessageVersion::V1,
+        create_recipient: false,
+        memo: false,
+    }
+}
+
+#[test]
+fn grows_with_signers() {
+    let instruction = Instructions::Transfer(transfer());
+    assert!(instruction.recommended_compute_units(3) > instruction.recommended_compute_units(2));
+
+    let batch = Instructions::TransferBatch(TransferBatch {
+        transfers: vec![transfer(), transfer()],
+    });
+    assert!(batch.recommended_compute_units(3) > instruction.recommended_compute_units(3));
+}
+
+#[test]
+fn fits_max_attestations() {
+    let signers = DEFAULT_MAX_ATTESTATIONS as usize - 1;
+    assert!(
+        Instructions::Transfer(transfer()).recommended_compute_units(signers) < MAX_COMPUTE_UNITS
+    );
+    assert_eq!(
+        Instructions::TransferBatch(TransferBatch {
+            transfers: vec![transfer(); 1_000],
+        })
+        .recommended_compute_units(signers),
+        MAX_COMPUTE_UNITS
+    );
+}
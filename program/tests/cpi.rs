@@ -0,0 +1,105 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    instruction,
+    processor::EVENT_LOG_SEED_PREFIX,
+    state::{EventLog, RewardManager},
+    utils::get_address_pair,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+use solana_program_test::*;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use utils::*;
+
+/// Program integrating with the reward manager through the `no-entrypoint` crate,
+/// creates the event log of the reward manager it is given
+fn process_consumer(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let reward_manager_program = next_account_info(account_info_iter)?;
+    let reward_manager_info = next_account_info(account_info_iter)?;
+    let manager_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let funder_info = next_account_info(account_info_iter)?;
+    let event_log_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+
+    let reward_manager = RewardManager::unpack(&reward_manager_info.data.borrow())?;
+    if reward_manager.manager != *manager_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    invoke(
+        &instruction::create_event_log(
+            reward_manager_program.key,
+            reward_manager_info.key,
+            manager_info.key,
+            funder_info.key,
+        )?,
+        &[
+            reward_manager_info.clone(),
+            manager_info.clone(),
+            authority_info.clone(),
+            funder_info.clone(),
+            event_log_info.clone(),
+            system_program_info.clone(),
+            rent_info.clone(),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn success_invoked_by_another_program() {
+    let consumer = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("consumer", consumer, processor!(process_consumer));
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder().build(&mut context).await;
+    let pair = get_address_pair(
+        &audius_reward_manager::id(),
+        &fixture.reward_manager,
+        EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: consumer,
+            accounts: vec![
+                AccountMeta::new_readonly(audius_reward_manager::id(), false),
+                AccountMeta::new_readonly(fixture.reward_manager, false),
+                AccountMeta::new_readonly(fixture.manager.pubkey(), true),
+                AccountMeta::new_readonly(pair.base.address, false),
+                AccountMeta::new(context.payer.pubkey(), true),
+                AccountMeta::new(pair.derive.address, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+            ],
+            data: vec![],
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let event_log = context
+        .banks_client
+        .get_account_data_with_borsh::<EventLog>(pair.derive.address)
+        .await
+        .unwrap();
+    assert_eq!(event_log.reward_manager, fixture.reward_manager);
+}
@@ -1,16 +1,22 @@
 #![cfg(feature = "test-bpf")]
 mod utils;
 use audius_reward_manager::{
+    error::AudiusProgramError,
     instruction,
-    processor::SENDER_SEED_PREFIX,
     state::{RewardManager, SenderAccount},
-    utils::{get_address_pair, EthereumAddress},
+    utils::{get_legacy_sender_address, get_sender_address, EthereumAddress},
 };
 use borsh::BorshSerialize;
 use rand::{thread_rng, Rng};
-use solana_program::pubkey::Pubkey;
+use solana_program::{pubkey::Pubkey, sysvar};
 use solana_program_test::*;
-use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
 use utils::program_test;
 
 #[tokio::test]
@@ -54,19 +60,265 @@ async fn success() {
 
     context.banks_client.process_transaction(tx).await.unwrap();
 
-    let pair = get_address_pair(
+    let (sender, bump) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
+
+    assert_eq!(
+        SenderAccount::new(reward_manager, eth_address, operator, bump),
+        context
+            .banks_client
+            .get_account_data_with_borsh(sender)
+            .await
+            .unwrap()
+    );
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.sender_count, 1);
+}
+
+#[tokio::test]
+async fn success_separate_funder() {
+    let mut program_test = program_test();
+    let mut rng = thread_rng();
+
+    let reward_manager = Pubkey::new_unique();
+    let token_account = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let funder_account = Keypair::new();
+    let eth_address: EthereumAddress = rng.gen();
+    let operator: EthereumAddress = rng.gen();
+
+    let reward_manager_data = RewardManager::new(token_account, manager_account.pubkey(), 3);
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: reward_manager_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test.add_account(
+        funder_account.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_sender(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &funder_account.pubkey(),
+            eth_address,
+            operator,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account, &funder_account],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (sender, bump) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
+
+    assert_eq!(
+        SenderAccount::new(reward_manager, eth_address, operator, bump),
+        context
+            .banks_client
+            .get_account_data_with_borsh(sender)
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn fail_rate_limited() {
+    let mut program_test = program_test();
+    let mut rng = thread_rng();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+
+    let mut reward_manager_data =
+        RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3);
+    reward_manager_data.sender_creation_gap = 1_000_000;
+    reward_manager_data.last_sender_creation_slot = 1;
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: reward_manager_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_sender(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            rng.gen(),
+            rng.gen(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::SenderCreationRateLimited as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_legacy_sender_exists() {
+    let mut program_test = program_test();
+    let mut rng = thread_rng();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let eth_address: EthereumAddress = rng.gen();
+
+    let reward_manager_data = RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3);
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: reward_manager_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // NOTE: senders created with seed have no bump seed stored
+    let legacy_sender_data = SenderAccount::new(reward_manager, eth_address, rng.gen(), 0)
+        .try_to_vec()
+        .unwrap();
+    program_test.add_account(
+        get_legacy_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address)
+            .unwrap(),
+        Account {
+            lamports: 9000,
+            data: legacy_sender_data[..SenderAccount::LEGACY_LEN].to_vec(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_sender(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            eth_address,
+            rng.gen(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::SenderAlreadyExists as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_forged_rent_sysvar() {
+    let mut program_test = program_test();
+    let mut rng = thread_rng();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+
+    let reward_manager_data = RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3);
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: reward_manager_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let mut create_sender = instruction::create_sender(
         &audius_reward_manager::id(),
         &reward_manager,
-        [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+        &manager_account.pubkey(),
+        &context.payer.pubkey(),
+        rng.gen(),
+        rng.gen(),
     )
     .unwrap();
+    create_sender
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == sysvar::rent::id())
+        .unwrap()
+        .pubkey = Pubkey::new_unique();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_sender],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
 
     assert_eq!(
-        SenderAccount::new(reward_manager, eth_address, operator),
         context
             .banks_client
-            .get_account_data_with_borsh(pair.derive.address)
+            .process_transaction(tx)
             .await
-            .unwrap()
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::InvalidSysvar as _)
+        )
     );
 }
@@ -0,0 +1,62 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::state::{
+    decode::{try_decode_reward_manager, try_decode_sender, DecodeError},
+    RewardManager, SenderAccount,
+};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn decode_reward_manager() {
+    let reward_manager = RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3);
+    let data = reward_manager.try_to_vec().unwrap();
+    assert_eq!(try_decode_reward_manager(&data), Ok(reward_manager.clone()));
+    assert_eq!(
+        try_decode_reward_manager(&data[..RewardManager::LEGACY_LENS[0]]),
+        Ok(reward_manager)
+    );
+
+    assert_eq!(
+        try_decode_reward_manager(&data[1..]),
+        Err(DecodeError::InvalidLength {
+            expected: RewardManager::LEN,
+            actual: RewardManager::LEN - 1,
+        })
+    );
+    assert_eq!(
+        try_decode_reward_manager(&[0; RewardManager::LEN]),
+        Err(DecodeError::Uninitialized)
+    );
+
+    let mut data = data;
+    data[0] = 7;
+    assert_eq!(
+        try_decode_reward_manager(&data),
+        Err(DecodeError::UnsupportedVersion(7))
+    );
+}
+
+#[test]
+fn decode_sender() {
+    let sender = SenderAccount::new(Pubkey::new_unique(), [1; 20], [2; 20], 254);
+    let data = sender.try_to_vec().unwrap();
+    assert_eq!(try_decode_sender(&data), Ok(sender.clone()));
+
+    let legacy = SenderAccount { bump: 0, ..sender };
+    assert_eq!(
+        try_decode_sender(&data[..SenderAccount::LEGACY_LEN]),
+        Ok(legacy)
+    );
+
+    assert_eq!(
+        try_decode_sender(&[0; SenderAccount::LEN]),
+        Err(DecodeError::Uninitialized)
+    );
+    assert_eq!(
+        try_decode_sender(&[]),
+        Err(DecodeError::InvalidLength {
+            expected: SenderAccount::LEN,
+            actual: 0,
+        })
+    );
+}
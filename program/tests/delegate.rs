@@ -0,0 +1,249 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    state::{DelegateAccount, RewardManager},
+    utils::{get_delegate_address, get_sender_address, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use rand::{thread_rng, Rng};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+fn program_test_with_reward_manager(
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+) -> ProgramTest {
+    let mut program_test = program_test();
+    program_test.add_account(
+        *reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test
+}
+
+async fn create_delegate(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+    delegate: &Pubkey,
+    permissions: u8,
+) {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_delegate(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            instruction::CreateDelegate {
+                delegate: *delegate,
+                permissions,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+fn delegated_create_sender(
+    context: &ProgramTestContext,
+    reward_manager: &Pubkey,
+    delegate: &Keypair,
+    eth_address: EthereumAddress,
+) -> Instruction {
+    instruction::delegated(
+        &audius_reward_manager::id(),
+        reward_manager,
+        &delegate.pubkey(),
+        instruction::create_sender(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &delegate.pubkey(),
+            &context.payer.pubkey(),
+            eth_address,
+            thread_rng().gen(),
+        )
+        .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn success_create_sender_by_delegate() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = program_test_with_reward_manager(&reward_manager, &manager_account)
+        .start_with_context()
+        .await;
+
+    let delegate = Keypair::new();
+    create_delegate(
+        &mut context,
+        &reward_manager,
+        &manager_account,
+        &delegate.pubkey(),
+        DelegateAccount::CREATE_SENDER,
+    )
+    .await;
+
+    let (delegate_account, bump) = get_delegate_address(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &delegate.pubkey(),
+    );
+    assert_eq!(
+        DelegateAccount::new(
+            reward_manager,
+            delegate.pubkey(),
+            DelegateAccount::CREATE_SENDER,
+            bump
+        ),
+        context
+            .banks_client
+            .get_account_data_with_borsh(delegate_account)
+            .await
+            .unwrap()
+    );
+
+    let eth_address: EthereumAddress = thread_rng().gen();
+    let tx = Transaction::new_signed_with_payer(
+        &[delegated_create_sender(
+            &context,
+            &reward_manager,
+            &delegate,
+            eth_address,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (sender, _) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
+    assert!(get_account(&mut context, &sender).await.is_some());
+}
+
+#[tokio::test]
+async fn fail_missing_permission() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = program_test_with_reward_manager(&reward_manager, &manager_account)
+        .start_with_context()
+        .await;
+
+    let delegate = Keypair::new();
+    create_delegate(
+        &mut context,
+        &reward_manager,
+        &manager_account,
+        &delegate.pubkey(),
+        DelegateAccount::CREATE_SENDER,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::delegated(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &delegate.pubkey(),
+            instruction::pause(
+                &audius_reward_manager::id(),
+                &reward_manager,
+                &delegate.pubkey(),
+            )
+            .unwrap(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::MissingPermission as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_revoked() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = program_test_with_reward_manager(&reward_manager, &manager_account)
+        .start_with_context()
+        .await;
+
+    let delegate = Keypair::new();
+    create_delegate(
+        &mut context,
+        &reward_manager,
+        &manager_account,
+        &delegate.pubkey(),
+        DelegateAccount::ALL_PERMISSIONS,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::revoke_delegate(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            &delegate.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delegated_create_sender(
+            &context,
+            &reward_manager,
+            &delegate,
+            thread_rng().gen(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+}
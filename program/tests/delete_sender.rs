@@ -1,16 +1,22 @@
 #![cfg(feature = "test-bpf")]
 mod utils;
 use audius_reward_manager::{
+    error::AudiusProgramError,
     instruction,
-    processor::SENDER_SEED_PREFIX,
     state::{RewardManager, SenderAccount},
-    utils::{get_address_pair, EthereumAddress},
+    utils::{get_sender_address, EthereumAddress},
 };
 use borsh::BorshSerialize;
 use rand::{thread_rng, Rng};
-use solana_program::pubkey::Pubkey;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
 use solana_program_test::*;
-use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
 use utils::program_test;
 
 #[tokio::test]
@@ -25,14 +31,11 @@ async fn success() {
     let eth_address: EthereumAddress = rng.gen();
     let operator: EthereumAddress = rng.gen();
 
-    let pair = get_address_pair(
-        &audius_reward_manager::id(),
-        &reward_manager,
-        [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-    )
-    .unwrap();
+    let (sender, bump) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
 
-    let reward_manager_data = RewardManager::new(token_account, manager_account.pubkey(), 3);
+    let mut reward_manager_data = RewardManager::new(token_account, manager_account.pubkey(), 3);
+    reward_manager_data.sender_count = 1;
     program_test.add_account(
         reward_manager,
         Account {
@@ -44,9 +47,9 @@ async fn success() {
         },
     );
 
-    let sender_data = SenderAccount::new(reward_manager, eth_address, operator);
+    let sender_data = SenderAccount::new(reward_manager, eth_address, operator, bump);
     program_test.add_account(
-        pair.derive.address,
+        sender,
         Account {
             lamports: 9000,
             data: sender_data.try_to_vec().unwrap(),
@@ -64,6 +67,7 @@ async fn success() {
             &manager_account.pubkey(),
             &refunder_account,
             eth_address,
+            operator,
         )
         .unwrap()],
         Some(&context.payer.pubkey()),
@@ -73,10 +77,91 @@ async fn success() {
 
     context.banks_client.process_transaction(tx).await.unwrap();
 
-    let account = context
+    let account = context.banks_client.get_account(sender).await.unwrap();
+    assert!(account.is_none());
+
+    let reward_manager_data = context
         .banks_client
-        .get_account(pair.derive.address)
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
         .await
         .unwrap();
-    assert!(account.is_none());
+    assert_eq!(reward_manager_data.sender_count, 0);
+}
+
+#[tokio::test]
+async fn fail_too_many_accounts() {
+    let mut program_test = program_test();
+    let mut rng = thread_rng();
+
+    let token_account = Pubkey::new_unique();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let refunder_account = Pubkey::new_unique();
+    let eth_address: EthereumAddress = rng.gen();
+    let operator: EthereumAddress = rng.gen();
+
+    let (sender, bump) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
+
+    let mut reward_manager_data = RewardManager::new(token_account, manager_account.pubkey(), 3);
+    reward_manager_data.sender_count = 1;
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: reward_manager_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let sender_data = SenderAccount::new(reward_manager, eth_address, operator, bump);
+    program_test.add_account(
+        sender,
+        Account {
+            lamports: 9000,
+            data: sender_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let mut delete_sender = instruction::delete_sender(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &manager_account.pubkey(),
+        &refunder_account,
+        eth_address,
+        operator,
+    )
+    .unwrap();
+    // NOTE: the first extra account takes the optional delegate place
+    for _ in 0..2 {
+        delete_sender
+            .accounts
+            .push(AccountMeta::new_readonly(Pubkey::new_unique(), false));
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &[delete_sender],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::TooManyAccounts as _)
+        )
+    );
 }
@@ -0,0 +1,193 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::DELETE_SENDER_MESSAGE_PREFIX,
+    state::RewardManager,
+    utils::{get_sender_address, EthereumAddress},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    secp256k1_instruction::construct_eth_pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+/// Registers four senders under a reward manager requiring three votes
+async fn prepare(
+    context: &mut ProgramTestContext,
+    reward_manager: &Keypair,
+) -> Vec<(SecretKey, EthereumAddress, Pubkey, EthereumAddress)> {
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+    let token_account = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    create_mint(
+        context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let mut senders = Vec::new();
+    for _ in 0..4 {
+        let key: [u8; 32] = rng.gen();
+        let priv_key = SecretKey::parse(&key).unwrap();
+        let eth_address = construct_eth_pubkey(&PublicKey::from_secret_key(&priv_key));
+        let address = get_sender_address(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &eth_address,
+        )
+        .0;
+        let operator: EthereumAddress = rng.gen();
+
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            operator,
+        )
+        .await;
+        senders.push((priv_key, eth_address, address, operator));
+    }
+
+    senders
+}
+
+fn delete_instructions(
+    reward_manager: &Pubkey,
+    refunder: &Pubkey,
+    evicted: EthereumAddress,
+    evicted_operator: EthereumAddress,
+    voters: &[(SecretKey, EthereumAddress, Pubkey, EthereumAddress)],
+) -> Vec<Instruction> {
+    let message = [
+        DELETE_SENDER_MESSAGE_PREFIX.as_bytes(),
+        reward_manager.as_ref(),
+        evicted.as_ref(),
+    ]
+    .concat();
+
+    let mut instructions = Vec::new();
+    for (index, (priv_key, _, _, _)) in voters.iter().enumerate() {
+        instructions.push(new_secp256k1_instruction_2_0(
+            priv_key,
+            message.as_ref(),
+            index as _,
+        ));
+    }
+
+    let signers: Vec<Pubkey> = voters.iter().map(|(_, _, address, _)| *address).collect();
+    instructions.push(
+        instruction::delete_sender_public(
+            &audius_reward_manager::id(),
+            reward_manager,
+            refunder,
+            evicted,
+            evicted_operator,
+            &signers,
+        )
+        .unwrap(),
+    );
+
+    instructions
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = program_test().start_with_context().await;
+    let reward_manager = Keypair::new();
+    let refunder = Pubkey::new_unique();
+
+    let senders = prepare(&mut context, &reward_manager).await;
+    let (_, evicted_eth_address, evicted, evicted_operator) = senders[3];
+
+    let tx = Transaction::new_signed_with_payer(
+        &delete_instructions(
+            &reward_manager.pubkey(),
+            &refunder,
+            evicted_eth_address,
+            evicted_operator,
+            &senders[..3],
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(evicted)
+        .await
+        .unwrap()
+        .is_none());
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.sender_count, 3);
+}
+
+#[tokio::test]
+async fn fail_evicted_sender_votes() {
+    let mut context = program_test().start_with_context().await;
+    let reward_manager = Keypair::new();
+    let refunder = Pubkey::new_unique();
+
+    let senders = prepare(&mut context, &reward_manager).await;
+    let (_, evicted_eth_address, _, evicted_operator) = senders[3];
+
+    let tx = Transaction::new_signed_with_payer(
+        &delete_instructions(
+            &reward_manager.pubkey(),
+            &refunder,
+            evicted_eth_address,
+            evicted_operator,
+            &senders[1..],
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            3,
+            InstructionError::Custom(AudiusProgramError::WrongSigner as _)
+        )
+    );
+}
@@ -0,0 +1,294 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::{DISTRIBUTION_ROOT_MESSAGE_PREFIX, ORACLE_SEED_PREFIX},
+    utils::{
+        distribution_leaf, distribution_parent, get_address_pair, get_sender_address,
+        EthereumAddress,
+    },
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{hash::Hash, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    secp256k1_instruction::*,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const DISTRIBUTION_ID: &str = "d1s7r1bu710n";
+
+struct Distribution {
+    reward_manager: Pubkey,
+    token_account: Pubkey,
+    mint: Pubkey,
+    transfers: Vec<instruction::Transfer>,
+    leaves: Vec<Hash>,
+}
+
+/// Registers bot oracle and three senders, funds the vault and submits
+/// the root of a two transfers distribution
+async fn submit_distribution(context: &mut ProgramTestContext) -> Distribution {
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let key: [u8; 32] = rng.gen();
+    let oracle_priv_key = SecretKey::parse(&key).unwrap();
+    let eth_oracle_address = construct_eth_pubkey(&PublicKey::from_secret_key(&oracle_priv_key));
+    let oracle = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+    )
+    .unwrap();
+    create_oracle(
+        context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        eth_oracle_address,
+        rng.gen(),
+    )
+    .await;
+
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let operators: [EthereumAddress; 3] = rng.gen();
+    let mut signers = Vec::new();
+    for (key, operator) in keys.iter().zip(operators.iter()) {
+        let eth_address =
+            construct_eth_pubkey(&PublicKey::from_secret_key(&SecretKey::parse(key).unwrap()));
+        signers.push(
+            get_sender_address(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &eth_address,
+            )
+            .0,
+        );
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            *operator,
+        )
+        .await;
+    }
+
+    mint_tokens_to(
+        context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let transfers = vec![
+        instruction::Transfer {
+            amount: 1_000,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: [7u8; 20],
+            message_version: instruction::MessageVersion::V1,
+            create_recipient: false,
+            memo: false,
+        },
+        instruction::Transfer {
+            amount: 2_000,
+            id: String::from("9f8e7d6c5b4a3921"),
+            eth_recipient: [8u8; 20],
+            message_version: instruction::MessageVersion::V1,
+            create_recipient: false,
+            memo: false,
+        },
+    ];
+    for transfer in transfers.iter() {
+        create_recipient_with_claimable_program(context, &mint.pubkey(), transfer.eth_recipient)
+            .await;
+    }
+    let leaves: Vec<Hash> = transfers
+        .iter()
+        .map(|transfer| distribution_leaf(transfer).unwrap())
+        .collect();
+    let root = distribution_parent(&leaves[0], &leaves[1]);
+
+    let bot_oracle_message = [
+        DISTRIBUTION_ROOT_MESSAGE_PREFIX.as_bytes(),
+        root.as_ref(),
+        b"_",
+        DISTRIBUTION_ID.as_bytes(),
+    ]
+    .concat();
+    let senders_message = [
+        bot_oracle_message.as_ref(),
+        b"_",
+        eth_oracle_address.as_ref(),
+    ]
+    .concat();
+
+    let mut instructions = vec![new_secp256k1_instruction_2_0(
+        &oracle_priv_key,
+        bot_oracle_message.as_ref(),
+        0,
+    )];
+    for (index, key) in keys.iter().enumerate() {
+        instructions.push(new_secp256k1_instruction_2_0(
+            &SecretKey::parse(key).unwrap(),
+            senders_message.as_ref(),
+            (index + 1) as _,
+        ));
+    }
+    instructions.push(
+        instruction::submit_distribution_root(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &oracle.derive.address,
+            &context.payer.pubkey(),
+            signers,
+            instruction::SubmitDistributionRoot {
+                id: String::from(DISTRIBUTION_ID),
+                root,
+            },
+        )
+        .unwrap(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    Distribution {
+        reward_manager: reward_manager.pubkey(),
+        token_account: token_account.pubkey(),
+        mint: mint.pubkey(),
+        transfers,
+        leaves,
+    }
+}
+
+fn claim(
+    context: &ProgramTestContext,
+    distribution: &Distribution,
+    transfer: instruction::Transfer,
+    proof: Vec<Hash>,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::claim_from_root(
+            &audius_reward_manager::id(),
+            &distribution.reward_manager,
+            &distribution.token_account,
+            &distribution.mint,
+            &context.payer.pubkey(),
+            &Pubkey::default(),
+            DISTRIBUTION_ID,
+            instruction::ClaimFromRoot { transfer, proof },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn success() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let distribution = submit_distribution(&mut context).await;
+
+    for (index, transfer) in distribution.transfers.iter().enumerate() {
+        let tx = claim(
+            &context,
+            &distribution,
+            transfer.clone(),
+            vec![distribution.leaves[1 - index]],
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let recipient: spl_token::state::Account = context
+            .banks_client
+            .get_packed_account_data(
+                claimable_tokens::utils::program::get_address_pair(
+                    &claimable_tokens::id(),
+                    &distribution.mint,
+                    transfer.eth_recipient,
+                )
+                .unwrap()
+                .derive
+                .address,
+            )
+            .await
+            .unwrap();
+        assert_eq!(recipient.amount, transfer.amount);
+    }
+}
+
+#[tokio::test]
+async fn fail_invalid_proof() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let distribution = submit_distribution(&mut context).await;
+
+    let mut transfer = distribution.transfers[0].clone();
+    transfer.amount = distribution.transfers[1].amount;
+    let tx = claim(
+        &context,
+        &distribution,
+        transfer,
+        vec![distribution.leaves[1]],
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::InvalidMerkleProof as _)
+        )
+    );
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::error::{decode_custom_error, AudiusProgramError};
+use num_traits::FromPrimitive;
+use solana_program::program_error::ProgramError;
+
+/// Explorers and clients key on these values, the table must only grow
+const ERROR_CODES: &[(AudiusProgramError, u32)] = &[
+    (AudiusProgramError::SignCollission, 0),
+    (AudiusProgramError::WrongSigner, 1),
+    (AudiusProgramError::IncorectSenderAccount, 2),
+    (AudiusProgramError::IncorectManagerAccount, 3),
+    (AudiusProgramError::WrongRewardManagerKey, 4),
+    (AudiusProgramError::WrongRecipientKey, 5),
+    (AudiusProgramError::NotEnoughSigners, 6),
+    (AudiusProgramError::Secp256InstructionMissing, 7),
+    (AudiusProgramError::InstructionLoadError, 8),
+    (AudiusProgramError::RepeatedSenders, 9),
+    (AudiusProgramError::SignatureVerificationFailed, 10),
+    (AudiusProgramError::OperatorCollision, 11),
+    (AudiusProgramError::InvalidSecpOffsets, 12),
+    (AudiusProgramError::TooManyAccounts, 13),
+    (AudiusProgramError::TooManyAttestations, 14),
+    (AudiusProgramError::ProgramPaused, 15),
+    (AudiusProgramError::AlreadyClaimed, 16),
+    (AudiusProgramError::CrossProgramInvocation, 17),
+    (AudiusProgramError::NotEnoughRegisteredSenders, 18),
+    (AudiusProgramError::AmountExceedsCap, 19),
+    (AudiusProgramError::SenderCreationRateLimited, 20),
+    (AudiusProgramError::SpendLimitExceeded, 21),
+    (AudiusProgramError::InvalidMerkleProof, 22),
+    (AudiusProgramError::SenderAlreadyExists, 23),
+    (AudiusProgramError::InsufficientFunding, 24),
+    (AudiusProgramError::MissingPermission, 25),
+    (AudiusProgramError::NoPendingManager, 26),
+    (AudiusProgramError::ManagerChangeTimelocked, 27),
+    (AudiusProgramError::DeprecatedMessageVersion, 28),
+    (AudiusProgramError::NotEnoughApprovals, 29),
+    (AudiusProgramError::NotEnoughVoteWeight, 30),
+    (AudiusProgramError::OperatorSendersLimitReached, 31),
+    (AudiusProgramError::InvalidAccountSize, 32),
+    (AudiusProgramError::NotRentExempt, 33),
+    (AudiusProgramError::InvalidSysvar, 34),
+    (AudiusProgramError::NoPendingWithdrawal, 35),
+    (AudiusProgramError::WithdrawalTimelocked, 36),
+    (AudiusProgramError::WrongVaultAccount, 37),
+    (AudiusProgramError::VaultNotEmpty, 38),
+    (AudiusProgramError::SenderSuspended, 39),
+    (AudiusProgramError::RecipientBlocked, 40),
+    (AudiusProgramError::RecipientPolicyFull, 41),
+    (AudiusProgramError::WrongFeeAccount, 42),
+    (AudiusProgramError::MathOverflow, 43),
+    (AudiusProgramError::TransferIdTooLong, 44),
+    (AudiusProgramError::InvalidChallenge, 45),
+    (AudiusProgramError::ChallengeRegistryFull, 46),
+    (AudiusProgramError::UnregisteredChallenge, 47),
+    (AudiusProgramError::OracleNotAuthorizedForChallenge, 48),
+    (AudiusProgramError::ZeroAmount, 49),
+    (AudiusProgramError::InvalidDestination, 50),
+    (AudiusProgramError::OperatorRateLimited, 51),
+    (AudiusProgramError::RateLimiterFull, 52),
+];
+
+#[test]
+fn codes_are_stable() {
+    for (error, code) in ERROR_CODES {
+        assert_eq!(
+            ProgramError::from(error.clone()),
+            ProgramError::Custom(*code)
+        );
+        assert_eq!(AudiusProgramError::from_u32(*code), Some(error.clone()));
+    }
+}
+
+#[test]
+fn codes_are_exhaustive() {
+    assert_eq!(
+        AudiusProgramError::from_u32(ERROR_CODES.len() as u32),
+        None,
+        "new error variant must be added to the codes table"
+    );
+}
+
+#[test]
+fn decode_custom_codes() {
+    for (error, code) in ERROR_CODES {
+        assert_eq!(decode_custom_error(*code), Some(error.clone()));
+    }
+    assert_eq!(decode_custom_error(ERROR_CODES.len() as u32), None);
+    assert_eq!(decode_custom_error(u32::MAX), None);
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::event::{Event, EVENT_LOG_PREFIX};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn parse_logged_event() {
+    let event = Event::TransferEvaluated {
+        reward_manager: Pubkey::new_unique(),
+        id: String::from("4r4t23df32543f55"),
+        eth_recipient: [7u8; 20],
+        amount: 10_000,
+    };
+    let log = format!(
+        "Program log: {}{}",
+        EVENT_LOG_PREFIX,
+        bs58::encode(event.try_to_vec().unwrap()).into_string()
+    );
+
+    assert_eq!(Event::from_log(&log), Some(event));
+    assert_eq!(Event::from_log("Program log: Instruction: Transfer"), None);
+}
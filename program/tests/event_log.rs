@@ -0,0 +1,190 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    instruction::{self, MessageVersion, Transfer, TransferToWallet},
+    processor::EVENT_LOG_SEED_PREFIX,
+    state::{EventLog, EventLogEntry, EVENT_LOG_CAPACITY},
+    utils::{get_address_pair, transfer_id_hash},
+};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_associated_token_account::create_associated_token_account;
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+const RECIPIENT: [u8; 20] = [7u8; 20];
+const TRANSFER_ID: &str = "4r4t23df32543f55";
+
+fn entry(amount: u64) -> EventLogEntry {
+    EventLogEntry {
+        eth_recipient: RECIPIENT,
+        wallet: Pubkey::default(),
+        amount,
+        id_hash: transfer_id_hash(TRANSFER_ID),
+        slot: 0,
+    }
+}
+
+async fn start() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    (context, fixture, recipient)
+}
+
+async fn transfer(
+    context: &mut ProgramTestContext,
+    fixture: &TestRewardManager,
+    recipient: &Pubkey,
+) {
+    let tx = Transaction::new_signed_with_payer(
+        &fixture.transfer_instructions(
+            &context.payer.pubkey(),
+            recipient,
+            Transfer {
+                amount: TRANSFER_AMOUNT,
+                id: String::from(TRANSFER_ID),
+                eth_recipient: RECIPIENT,
+                message_version: MessageVersion::V2,
+                create_recipient: false,
+                memo: false,
+            },
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[test]
+fn log_keeps_latest_entries() {
+    let mut event_log = EventLog::new(Pubkey::new_unique());
+    assert_eq!(event_log.try_to_vec().unwrap().len(), EventLog::LEN);
+    assert!(event_log.entries().is_empty());
+
+    for amount in 0..EVENT_LOG_CAPACITY as u64 + 2 {
+        event_log.record(entry(amount));
+    }
+
+    let entries = event_log.entries();
+    assert_eq!(entries.len(), EVENT_LOG_CAPACITY);
+    assert_eq!(entries.first(), Some(&entry(2)));
+    assert_eq!(entries.last(), Some(&entry(EVENT_LOG_CAPACITY as u64 + 1)));
+}
+
+async fn create_event_log(context: &mut ProgramTestContext, fixture: &TestRewardManager) {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_event_log(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            &context.payer.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn get_entries(
+    context: &mut ProgramTestContext,
+    fixture: &TestRewardManager,
+) -> Vec<EventLogEntry> {
+    let address = get_address_pair(
+        &audius_reward_manager::id(),
+        &fixture.reward_manager,
+        EVENT_LOG_SEED_PREFIX.as_bytes().to_vec(),
+    )
+    .unwrap()
+    .derive
+    .address;
+    context
+        .banks_client
+        .get_account_data_with_borsh::<EventLog>(address)
+        .await
+        .unwrap()
+        .entries()
+}
+
+#[tokio::test]
+async fn success_records_transfer() {
+    let (mut context, fixture, recipient) = start().await;
+    create_event_log(&mut context, &fixture).await;
+
+    transfer(&mut context, &fixture, &recipient).await;
+
+    let entries = get_entries(&mut context, &fixture).await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries[0],
+        EventLogEntry {
+            slot: entries[0].slot,
+            ..entry(TRANSFER_AMOUNT)
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_records_wallet_transfer() {
+    let (mut context, fixture, _) = start().await;
+    create_event_log(&mut context, &fixture).await;
+
+    let wallet = Pubkey::new_unique();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_associated_token_account(
+            &context.payer.pubkey(),
+            &wallet,
+            &fixture.mint,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &fixture.transfer_to_wallet_instructions(
+            &context.payer.pubkey(),
+            TransferToWallet {
+                amount: TRANSFER_AMOUNT,
+                id: String::from(TRANSFER_ID),
+                wallet,
+            },
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let entries = get_entries(&mut context, &fixture).await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries[0],
+        EventLogEntry {
+            eth_recipient: [0u8; 20],
+            wallet,
+            slot: entries[0].slot,
+            ..entry(TRANSFER_AMOUNT)
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_without_event_log() {
+    let (mut context, fixture, recipient) = start().await;
+
+    transfer(&mut context, &fixture, &recipient).await;
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    get_transfer_account_address,
+    instruction::{MessageVersion, Transfer},
+    state::TransferReceipt,
+};
+use solana_program_test::*;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+#[tokio::test]
+async fn success_transfer_with_many_signers() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .min_votes(5)
+        .senders(5)
+        .oracles(2)
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    assert_eq!(fixture.senders.len(), 5);
+    assert_eq!(fixture.oracles.len(), 2);
+
+    let recipient_eth_key = [7u8; 20];
+    let recipient = fixture
+        .create_recipient(&mut context, recipient_eth_key)
+        .await;
+    let transfer = Transfer {
+        amount: TRANSFER_AMOUNT,
+        id: String::from("4r4t23df32543f55"),
+        eth_recipient: recipient_eth_key,
+        message_version: MessageVersion::V2,
+        create_recipient: false,
+        memo: false,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &fixture.transfer_instructions(&context.payer.pubkey(), &recipient, transfer.clone()),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let receipt = get_transfer_account_address(
+        &audius_reward_manager::id(),
+        &fixture.reward_manager,
+        &transfer.id,
+    )
+    .unwrap();
+    let receipt_data = context
+        .banks_client
+        .get_account_data_with_borsh::<TransferReceipt>(receipt)
+        .await
+        .unwrap();
+    assert_eq!(receipt_data.recipient, recipient);
+    assert_eq!(receipt_data.amount, TRANSFER_AMOUNT);
+}
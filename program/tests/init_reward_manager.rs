@@ -1,20 +1,25 @@
 #![cfg(feature = "test-bpf")]
 
 mod utils;
-use audius_reward_manager::instruction;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    state::{RewardManager, DEFAULT_MAX_ATTESTATIONS},
+};
 use borsh::BorshSerialize;
 use solana_program::program_option::COption;
 use solana_program::program_pack::IsInitialized;
 use solana_sdk::signature::Keypair;
-use utils::program_test;
+use utils::{create_mint, program_test};
 
 use solana_program::instruction::InstructionError;
 use solana_program::{program_pack::Pack, pubkey::Pubkey};
 use solana_program_test::*;
 use solana_sdk::{
     account::Account,
+    rent::Rent,
     signature::Signer,
-    system_instruction::create_account,
+    system_instruction::{create_account, SystemError},
     transaction::{Transaction, TransactionError},
 };
 
@@ -27,6 +32,7 @@ async fn success() {
     let reward_manager = Keypair::new();
     let token_account = Keypair::new();
     let mint = Pubkey::new_unique();
+    let claimable_tokens_program = Pubkey::new_unique();
     let min_votes = 3;
 
     let mut data = vec![0u8; spl_token::state::Mint::LEN];
@@ -73,6 +79,8 @@ async fn success() {
                 &token_account.pubkey(),
                 &mint,
                 &manager,
+                &spl_token::id(),
+                &claimable_tokens_program,
                 min_votes,
             )
             .unwrap(),
@@ -85,11 +93,10 @@ async fn success() {
     context.banks_client.process_transaction(tx).await.unwrap();
 
     assert_eq!(
-        audius_reward_manager::state::RewardManager::new(
-            token_account.pubkey(),
-            manager,
-            min_votes
-        ),
+        RewardManager {
+            claimable_tokens_program,
+            ..RewardManager::new(token_account.pubkey(), manager, min_votes)
+        },
         context
             .banks_client
             .get_account_data_with_borsh(reward_manager.pubkey())
@@ -138,6 +145,8 @@ async fn fail_already_initialized() {
             &token_account,
             &mint,
             &manager,
+            &spl_token::id(),
+            &claimable_tokens::id(),
             3,
         )
         .unwrap()],
@@ -156,3 +165,326 @@ async fn fail_already_initialized() {
         TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
     );
 }
+
+/// Creates the reward manager account with `lamports` and `len` bytes and
+/// initializes it with `min_votes` in the same transaction
+async fn init_with_account(
+    lamports: impl Fn(&Rent) -> u64,
+    len: usize,
+    min_votes: u8,
+) -> TransactionError {
+    let mut context = program_test().start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let reward_manager = Keypair::new();
+    let token_account = Keypair::new();
+    let mint = Keypair::new();
+    let mint_authority = Pubkey::new_unique();
+    create_mint(
+        &mut context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority,
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_account(
+                &context.payer.pubkey(),
+                &reward_manager.pubkey(),
+                lamports(&rent),
+                len as _,
+                &audius_reward_manager::id(),
+            ),
+            create_account(
+                &context.payer.pubkey(),
+                &token_account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as _,
+                &spl_token::id(),
+            ),
+            instruction::init(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &token_account.pubkey(),
+                &mint.pubkey(),
+                &Pubkey::new_unique(),
+                &spl_token::id(),
+                &claimable_tokens::id(),
+                min_votes,
+            )
+            .unwrap(),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &reward_manager, &token_account],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn fail_invalid_account_size() {
+    let len = RewardManager::LEN + 1;
+    assert_eq!(
+        init_with_account(|rent| rent.minimum_balance(len), len, 3).await,
+        TransactionError::InstructionError(
+            2,
+            InstructionError::Custom(AudiusProgramError::InvalidAccountSize as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_not_rent_exempt() {
+    assert_eq!(
+        init_with_account(
+            |rent| rent.minimum_balance(RewardManager::LEN) - 1,
+            RewardManager::LEN,
+            3
+        )
+        .await,
+        TransactionError::InstructionError(
+            2,
+            InstructionError::Custom(AudiusProgramError::NotRentExempt as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_min_votes_over_max_attestations() {
+    // NOTE: one attestation slot is taken by the bot oracle
+    assert_eq!(
+        init_with_account(
+            |rent| rent.minimum_balance(RewardManager::LEN),
+            RewardManager::LEN,
+            DEFAULT_MAX_ATTESTATIONS
+        )
+        .await,
+        TransactionError::InstructionError(2, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_v2_min_votes_over_max_attestations() {
+    let mut context = program_test().start_with_context().await;
+
+    let reward_manager = Keypair::new();
+    let token_account = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::init_v2(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &token_account.pubkey(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &context.payer.pubkey(),
+            &spl_token::id(),
+            &claimable_tokens::id(),
+            DEFAULT_MAX_ATTESTATIONS,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &reward_manager, &token_account],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn success_v2() {
+    let mut context = program_test().start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let reward_manager = Keypair::new();
+    let token_account = Keypair::new();
+    let mint = Keypair::new();
+    let manager = Pubkey::new_unique();
+    create_mint(
+        &mut context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &Pubkey::new_unique(),
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::init_v2(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &manager,
+            &context.payer.pubkey(),
+            &spl_token::id(),
+            &claimable_tokens::id(),
+            3,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &reward_manager, &token_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(reward_manager.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, audius_reward_manager::id());
+    assert_eq!(account.data.len(), RewardManager::LEN);
+    assert!(rent.is_exempt(account.lamports, RewardManager::LEN));
+    assert_eq!(
+        RewardManager {
+            claimable_tokens_program: claimable_tokens::id(),
+            ..RewardManager::new(token_account.pubkey(), manager, 3)
+        },
+        context
+            .banks_client
+            .get_account_data_with_borsh(reward_manager.pubkey())
+            .await
+            .unwrap()
+    );
+
+    let token_data: spl_token::state::Account = context
+        .banks_client
+        .get_packed_account_data(token_account.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(token_data.mint, mint.pubkey());
+}
+
+#[tokio::test]
+async fn fail_v2_account_in_use() {
+    let mut program_test = program_test();
+    let reward_manager = Keypair::new();
+    program_test.add_account(
+        reward_manager.pubkey(),
+        Account {
+            lamports: 9000,
+            data: vec![0u8; RewardManager::LEN],
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let token_account = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::init_v2(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &token_account.pubkey(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &context.payer.pubkey(),
+            &spl_token::id(),
+            &claimable_tokens::id(),
+            3,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &reward_manager, &token_account],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SystemError::AccountAlreadyInUse as _)
+        )
+    );
+    assert!(context
+        .banks_client
+        .get_account(token_account.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn success_v2_separate_funder() {
+    let mut program_test = program_test();
+    let funder = Keypair::new();
+    let funder_lamports = 1_000_000_000;
+    program_test.add_account(
+        funder.pubkey(),
+        Account {
+            lamports: funder_lamports,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let reward_manager = Keypair::new();
+    let token_account = Keypair::new();
+    let mint = Keypair::new();
+    create_mint(
+        &mut context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &Pubkey::new_unique(),
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::init_v2(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &Pubkey::new_unique(),
+            &funder.pubkey(),
+            &spl_token::id(),
+            &claimable_tokens::id(),
+            3,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &funder, &reward_manager, &token_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(funder.pubkey())
+            .await
+            .unwrap(),
+        funder_lamports
+            - rent.minimum_balance(RewardManager::LEN)
+            - rent.minimum_balance(spl_token::state::Account::LEN)
+    );
+}
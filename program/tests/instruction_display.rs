@@ -0,0 +1,50 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::instruction::{
+    decode, Instructions, MessageVersion, SetChallenge, Transfer,
+};
+use borsh::BorshSerialize;
+use solana_program::program_error::ProgramError;
+
+fn transfer() -> Transfer {
+    Transfer {
+        amount: 10_000,
+        id: String::from("listen:4r4t23df32543f55"),
+        eth_recipient: [0xab; 20],
+        message_version: MessageVersion::V2,
+        create_recipient: false,
+        memo: false,
+    }
+}
+
+#[test]
+fn decode_instruction_data() {
+    let instruction = Instructions::Transfer(transfer());
+    let data = instruction.try_to_vec().unwrap();
+    assert_eq!(decode(&data).unwrap(), instruction);
+
+    assert!(matches!(
+        decode(&data[..data.len() - 1]),
+        Err(ProgramError::BorshIoError(_))
+    ));
+}
+
+#[test]
+fn display_instructions() {
+    assert_eq!(
+        Instructions::Transfer(transfer()).to_string(),
+        "Transfer: amount: 10000, transfer_id: listen:4r4t23df32543f55, \
+         eth_recipient: 0xabababababababababababababababababababab, \
+         message_version: V2, create_recipient: false, memo: false"
+    );
+    assert_eq!(
+        Instructions::SetChallenge(SetChallenge {
+            id: String::from("listen"),
+            oracles: vec![[1u8; 20], [2u8; 20]],
+        })
+        .to_string(),
+        "SetChallenge: id: listen, oracles: [0x0101010101010101010101010101010101010101, \
+         0x0202020202020202020202020202020202020202]"
+    );
+    assert_eq!(Instructions::Pause.to_string(), "Pause");
+    assert_eq!(Instructions::Pause.params().to_string(), "");
+}
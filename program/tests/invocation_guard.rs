@@ -0,0 +1,253 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::ORACLE_SEED_PREFIX,
+    utils::{get_address_pair, get_sender_address, EthereumAddress},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey, sysvar};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    secp256k1_instruction::*,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+/// Registers bot oracle and three senders, funds the vault and returns
+/// the attestation instructions followed by the transfer instruction
+/// along with the recipient token account
+async fn prepare_transfer(context: &mut ProgramTestContext) -> (Vec<Instruction>, Pubkey) {
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let key: [u8; 32] = rng.gen();
+    let oracle_priv_key = SecretKey::parse(&key).unwrap();
+    let eth_oracle_address = construct_eth_pubkey(&PublicKey::from_secret_key(&oracle_priv_key));
+    let oracle = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+    )
+    .unwrap();
+    create_oracle(
+        context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        eth_oracle_address,
+        rng.gen(),
+    )
+    .await;
+
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let operators: [EthereumAddress; 3] = rng.gen();
+    let mut signers = Vec::new();
+    for (key, operator) in keys.iter().zip(operators.iter()) {
+        let eth_address =
+            construct_eth_pubkey(&PublicKey::from_secret_key(&SecretKey::parse(key).unwrap()));
+        signers.push(
+            get_sender_address(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &eth_address,
+            )
+            .0,
+        );
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            *operator,
+        )
+        .await;
+    }
+
+    mint_tokens_to(
+        context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        TRANSFER_AMOUNT * 2,
+    )
+    .await
+    .unwrap();
+
+    let recipient_eth_key = [7u8; 20];
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        &mint.pubkey(),
+        recipient_eth_key,
+    )
+    .unwrap()
+    .derive
+    .address;
+    create_recipient_with_claimable_program(context, &mint.pubkey(), recipient_eth_key).await;
+
+    let transfer_id = "4r4t23df32543f55";
+
+    let bot_oracle_message = [
+        recipient_eth_key.as_ref(),
+        b"_",
+        TRANSFER_AMOUNT.to_le_bytes().as_ref(),
+        b"_",
+        transfer_id.as_ref(),
+    ]
+    .concat();
+    let senders_message = [
+        bot_oracle_message.as_ref(),
+        b"_",
+        eth_oracle_address.as_ref(),
+    ]
+    .concat();
+
+    let mut instructions = vec![new_secp256k1_instruction_2_0(
+        &oracle_priv_key,
+        bot_oracle_message.as_ref(),
+        0,
+    )];
+    for (index, key) in keys.iter().enumerate() {
+        instructions.push(new_secp256k1_instruction_2_0(
+            &SecretKey::parse(key).unwrap(),
+            senders_message.as_ref(),
+            (index + 1) as _,
+        ));
+    }
+
+    instructions.push(
+        instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &recipient,
+            &token_account.pubkey(),
+            &oracle.derive.address,
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            signers,
+            instruction::Transfer {
+                amount: TRANSFER_AMOUNT,
+                id: String::from(transfer_id),
+                eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap(),
+    );
+
+    (instructions, recipient)
+}
+
+async fn recipient_balance(context: &mut ProgramTestContext, recipient: &Pubkey) -> u64 {
+    let account = get_account(context, recipient).await.unwrap();
+    spl_token::state::Account::unpack(&account.data.as_slice())
+        .unwrap()
+        .amount
+}
+
+#[tokio::test]
+async fn fail_spoofed_instructions_sysvar() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let (mut instructions, recipient) = prepare_transfer(&mut context).await;
+
+    let transfer = instructions.last_mut().unwrap();
+    let sysvar_meta = transfer
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == sysvar::instructions::id())
+        .unwrap();
+    sysvar_meta.pubkey = Keypair::new().pubkey();
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            4,
+            InstructionError::Custom(AudiusProgramError::InvalidSysvar as _)
+        )
+    );
+    assert_eq!(recipient_balance(&mut context, &recipient).await, 0);
+}
+
+#[tokio::test]
+async fn fail_repeated_transfer_in_one_transaction() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let (mut instructions, recipient) = prepare_transfer(&mut context).await;
+
+    // Second transfer reuses the attestations of the first one
+    let transfer = instructions.last().unwrap().clone();
+    instructions.push(transfer);
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            5,
+            InstructionError::Custom(AudiusProgramError::AlreadyClaimed as _)
+        )
+    );
+    assert_eq!(recipient_balance(&mut context, &recipient).await, 0);
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    instruction::{self, UpdateRewardManager},
+    state::RewardManager,
+};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const BASELINE_LEN: usize = 66;
+
+/// Reward manager created by the first program version, before any field was added
+async fn start(reward_manager: &Pubkey, manager_account: &Keypair) -> ProgramTestContext {
+    let data = RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3)
+        .try_to_vec()
+        .unwrap();
+    let mut program_test = program_test();
+    program_test.add_account(
+        *reward_manager,
+        Account {
+            lamports: 9000,
+            data: data[..BASELINE_LEN].to_vec(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test.start_with_context().await
+}
+
+async fn get_reward_manager(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+) -> (RewardManager, usize) {
+    let account = get_account(context, reward_manager).await.unwrap();
+    (
+        RewardManager::unpack(&account.data).unwrap(),
+        account.data.len(),
+    )
+}
+
+#[test]
+fn unpack_pads_every_legacy_layout() {
+    let reward_manager = RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3);
+    let data = reward_manager.try_to_vec().unwrap();
+
+    for len in RewardManager::LEGACY_LENS.iter() {
+        assert_eq!(
+            RewardManager::unpack(&data[..*len]).unwrap(),
+            reward_manager
+        );
+    }
+}
+
+#[tokio::test]
+async fn success_update_stored_field() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = start(&reward_manager, &manager_account).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            UpdateRewardManager {
+                min_votes: Some(2),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (reward_manager, len) = get_reward_manager(&mut context, &reward_manager).await;
+    assert_eq!(len, BASELINE_LEN);
+    assert_eq!(reward_manager.min_votes, 2);
+}
+
+#[tokio::test]
+async fn success_create_sender() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = start(&reward_manager, &manager_account).await;
+
+    create_sender(
+        &mut context,
+        &reward_manager,
+        &manager_account,
+        [1u8; 20],
+        [9u8; 20],
+    )
+    .await;
+
+    // NOTE: the sender counter isn't stored by legacy reward managers
+    let (reward_manager, len) = get_reward_manager(&mut context, &reward_manager).await;
+    assert_eq!(len, BASELINE_LEN);
+    assert_eq!(reward_manager.sender_count, 0);
+}
+
+#[tokio::test]
+async fn fail_pause() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = start(&reward_manager, &manager_account).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::pause(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::AccountDataTooSmall)
+    );
+}
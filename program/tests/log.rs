@@ -0,0 +1,14 @@
+#![cfg(all(feature = "test-bpf", feature = "verbose-logs"))]
+use audius_reward_manager::log::HexAddress;
+
+#[test]
+fn hex_address() {
+    let mut address = [0u8; 20];
+    address[0] = 0xab;
+    address[19] = 0x01;
+
+    assert_eq!(
+        HexAddress(&address).to_string(),
+        "0xab00000000000000000000000000000000000001"
+    );
+}
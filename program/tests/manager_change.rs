@@ -0,0 +1,190 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    state::{RewardManager, MANAGER_CHANGE_DELAY},
+};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::program_test;
+
+async fn start_with_proposal(
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+    new_manager: &Pubkey,
+) -> ProgramTestContext {
+    let mut program_test = program_test();
+    program_test.add_account(
+        *reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::propose_manager(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &manager_account.pubkey(),
+            new_manager,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    context
+}
+
+fn accept(
+    context: &ProgramTestContext,
+    reward_manager: &Pubkey,
+    new_manager: &Keypair,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::accept_manager(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &new_manager.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, new_manager],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn success() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let new_manager = Keypair::new();
+    let mut context =
+        start_with_proposal(&reward_manager, &manager_account, &new_manager.pubkey()).await;
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.manager, manager_account.pubkey());
+    assert_eq!(reward_manager_data.pending_manager, new_manager.pubkey());
+
+    context.warp_to_slot(reward_manager_data.manager_activation_slot);
+
+    let tx = accept(&context, &reward_manager, &new_manager);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.manager, new_manager.pubkey());
+    assert_eq!(reward_manager_data.pending_manager, Pubkey::default());
+    assert_eq!(reward_manager_data.manager_activation_slot, 0);
+}
+
+#[tokio::test]
+async fn fail_timelocked() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let new_manager = Keypair::new();
+    let context =
+        start_with_proposal(&reward_manager, &manager_account, &new_manager.pubkey()).await;
+
+    let tx = accept(&context, &reward_manager, &new_manager);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::ManagerChangeTimelocked as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_not_proposed_manager() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context =
+        start_with_proposal(&reward_manager, &manager_account, &Pubkey::new_unique()).await;
+
+    context.warp_to_slot(MANAGER_CHANGE_DELAY + 1);
+
+    let tx = accept(&context, &reward_manager, &Keypair::new());
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::IncorectManagerAccount as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposal_cancelled() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let new_manager = Keypair::new();
+    let mut context =
+        start_with_proposal(&reward_manager, &manager_account, &new_manager.pubkey()).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::propose_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &manager_account.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    context.warp_to_slot(MANAGER_CHANGE_DELAY + 1);
+
+    let tx = accept(&context, &reward_manager, &new_manager);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::NoPendingManager as _)
+        )
+    );
+}
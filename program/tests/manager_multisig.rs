@@ -0,0 +1,428 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    state::{DelegateAccount, ManagerMultisig, RewardManager},
+    utils::{get_admin_action_address, get_multisig_address},
+};
+use borsh::BorshSerialize;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+fn add_reward_manager(program_test: &mut ProgramTest, reward_manager: Pubkey, manager: Pubkey) {
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), manager, 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Program test with the manager of the reward manager handed over to a 2 of 3 multisig
+fn program_test_with_multisig(reward_manager: Pubkey, signers: &[Keypair]) -> ProgramTest {
+    let mut program_test = program_test();
+
+    let (multisig, bump) = get_multisig_address(&audius_reward_manager::id(), &reward_manager);
+    add_reward_manager(&mut program_test, reward_manager, multisig);
+
+    let signers = signers.iter().map(Keypair::pubkey).collect::<Vec<_>>();
+    program_test.add_account(
+        multisig,
+        Account {
+            lamports: Rent::default().minimum_balance(ManagerMultisig::LEN),
+            data: ManagerMultisig::new(reward_manager, 2, &signers, bump)
+                .unwrap()
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test
+}
+
+/// Proposes `instruction` as the first admin action of the multisig and returns its address
+async fn propose(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+    proposer: &Keypair,
+    instruction: &Instruction,
+) -> Pubkey {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::propose_admin_action(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &proposer.pubkey(),
+            &context.payer.pubkey(),
+            0,
+            instruction,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, proposer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (multisig, _) = get_multisig_address(&audius_reward_manager::id(), reward_manager);
+    get_admin_action_address(&audius_reward_manager::id(), &multisig, 0).0
+}
+
+fn execute(
+    context: &ProgramTestContext,
+    reward_manager: &Pubkey,
+    admin_action: &Pubkey,
+    proposer: &Pubkey,
+    instruction: &Instruction,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::execute_admin_action(
+            &audius_reward_manager::id(),
+            reward_manager,
+            admin_action,
+            proposer,
+            instruction,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn success_create() {
+    let mut program_test = program_test();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    add_reward_manager(&mut program_test, reward_manager, manager_account.pubkey());
+
+    let mut context = program_test.start_with_context().await;
+
+    let signers = vec![
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_manager_multisig(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            instruction::CreateManagerMultisig {
+                threshold: 2,
+                signers: signers.clone(),
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (multisig, bump) = get_multisig_address(&audius_reward_manager::id(), &reward_manager);
+    assert_eq!(
+        ManagerMultisig::new(reward_manager, 2, &signers, bump).unwrap(),
+        context
+            .banks_client
+            .get_account_data_with_borsh(multisig)
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn fail_create_default_signer() {
+    let mut program_test = program_test();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    add_reward_manager(&mut program_test, reward_manager, manager_account.pubkey());
+
+    let mut context = program_test.start_with_context().await;
+
+    // NOTE: default keys mark the free approver slots of admin actions
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_manager_multisig(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            instruction::CreateManagerMultisig {
+                threshold: 2,
+                signers: vec![Pubkey::new_unique(), Pubkey::default()],
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn success_pause() {
+    let reward_manager = Pubkey::new_unique();
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let mut context = program_test_with_multisig(reward_manager, &signers)
+        .start_with_context()
+        .await;
+
+    let (multisig, _) = get_multisig_address(&audius_reward_manager::id(), &reward_manager);
+    let pause =
+        instruction::pause(&audius_reward_manager::id(), &reward_manager, &multisig).unwrap();
+    let admin_action = propose(&mut context, &reward_manager, &signers[0], &pause).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::approve_admin_action(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &signers[1].pubkey(),
+            &admin_action,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signers[1]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = execute(
+        &context,
+        &reward_manager,
+        &admin_action,
+        &signers[0].pubkey(),
+        &pause,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert!(reward_manager_data.paused);
+    assert!(get_account(&mut context, &admin_action).await.is_none());
+}
+
+#[tokio::test]
+async fn fail_not_enough_approvals() {
+    let reward_manager = Pubkey::new_unique();
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let mut context = program_test_with_multisig(reward_manager, &signers)
+        .start_with_context()
+        .await;
+
+    let (multisig, _) = get_multisig_address(&audius_reward_manager::id(), &reward_manager);
+    let pause =
+        instruction::pause(&audius_reward_manager::id(), &reward_manager, &multisig).unwrap();
+    let admin_action = propose(&mut context, &reward_manager, &signers[0], &pause).await;
+
+    let tx = execute(
+        &context,
+        &reward_manager,
+        &admin_action,
+        &signers[0].pubkey(),
+        &pause,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::NotEnoughApprovals as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_proposed_by_other_key() {
+    let reward_manager = Pubkey::new_unique();
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let context = program_test_with_multisig(reward_manager, &signers)
+        .start_with_context()
+        .await;
+
+    let (multisig, _) = get_multisig_address(&audius_reward_manager::id(), &reward_manager);
+    let other_key = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::propose_admin_action(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &other_key.pubkey(),
+            &context.payer.pubkey(),
+            0,
+            &instruction::pause(&audius_reward_manager::id(), &reward_manager, &multisig).unwrap(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &other_key],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::IncorectManagerAccount as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_switch_disables_delegates() {
+    let mut program_test = program_test();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    add_reward_manager(&mut program_test, reward_manager, manager_account.pubkey());
+
+    let mut context = program_test.start_with_context().await;
+
+    let (multisig, _) = get_multisig_address(&audius_reward_manager::id(), &reward_manager);
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let delegate = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            instruction::create_delegate(
+                &audius_reward_manager::id(),
+                &reward_manager,
+                &manager_account.pubkey(),
+                &context.payer.pubkey(),
+                instruction::CreateDelegate {
+                    delegate: delegate.pubkey(),
+                    permissions: DelegateAccount::PAUSE,
+                },
+            )
+            .unwrap(),
+            instruction::create_manager_multisig(
+                &audius_reward_manager::id(),
+                &reward_manager,
+                &manager_account.pubkey(),
+                &context.payer.pubkey(),
+                instruction::CreateManagerMultisig {
+                    threshold: 2,
+                    signers: signers.iter().map(Keypair::pubkey).collect(),
+                },
+            )
+            .unwrap(),
+            instruction::propose_manager(
+                &audius_reward_manager::id(),
+                &reward_manager,
+                &manager_account.pubkey(),
+                &multisig,
+            )
+            .unwrap(),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.pending_manager, multisig);
+    context.warp_to_slot(reward_manager_data.manager_activation_slot);
+
+    // NOTE: the multisig accepts the manager change as an admin action
+    let accept =
+        instruction::accept_manager(&audius_reward_manager::id(), &reward_manager, &multisig)
+            .unwrap();
+    let admin_action = propose(&mut context, &reward_manager, &signers[0], &accept).await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::approve_admin_action(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &signers[1].pubkey(),
+            &admin_action,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signers[1]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+    let tx = execute(
+        &context,
+        &reward_manager,
+        &admin_action,
+        &signers[0].pubkey(),
+        &accept,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.manager, multisig);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::delegated(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &delegate.pubkey(),
+            instruction::pause(
+                &audius_reward_manager::id(),
+                &reward_manager,
+                &delegate.pubkey(),
+            )
+            .unwrap(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::MissingPermission as _)
+        )
+    );
+}
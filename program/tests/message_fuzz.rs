@@ -0,0 +1,234 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::{
+    instruction::{MessageVersion, Transfer, TransferToWallet},
+    state::OracleAccount,
+    utils::{
+        build_oracle_message, build_senders_message, EthereumAddress, SecpPayload,
+        TransferVerifier, Verifier, WalletTransferVerifier, SECP_SIGNATURE_SIZE,
+    },
+};
+use proptest::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+fn transfer_verifier(transfer: &Transfer, oracle: EthereumAddress) -> TransferVerifier {
+    TransferVerifier {
+        bot_oracle: OracleAccount::new(Pubkey::default(), oracle, [0u8; 20]),
+        additional_oracles: Vec::new(),
+        transfer_data: transfer.clone(),
+    }
+}
+
+fn wallet_transfer_verifier(
+    transfer: &TransferToWallet,
+    oracle: EthereumAddress,
+) -> WalletTransferVerifier {
+    WalletTransferVerifier {
+        bot_oracle: OracleAccount::new(Pubkey::default(), oracle, [0u8; 20]),
+        additional_oracles: Vec::new(),
+        transfer_data: transfer.clone(),
+    }
+}
+
+fn payload(message: Vec<u8>) -> SecpPayload {
+    SecpPayload {
+        signer: EthereumAddress::default(),
+        signature: [0u8; SECP_SIGNATURE_SIZE],
+        message,
+    }
+}
+
+/// Small domains so that distinct fields often share bytes and delimiters
+fn eth_address() -> impl Strategy<Value = EthereumAddress> {
+    prop_oneof![Just([b'_'; 20]), Just([b'a'; 20]), any::<EthereumAddress>()]
+}
+
+fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![Just(0x5f), Just(0x5f5f), any::<u64>()]
+}
+
+fn id() -> impl Strategy<Value = String> {
+    "[_a]{0,6}"
+}
+
+fn transfer(message_version: MessageVersion) -> impl Strategy<Value = Transfer> {
+    (amount(), id(), eth_address()).prop_map(move |(amount, id, eth_recipient)| Transfer {
+        amount,
+        id,
+        eth_recipient,
+        message_version,
+        create_recipient: false,
+        memo: false,
+    })
+}
+
+fn wallet_transfer() -> impl Strategy<Value = TransferToWallet> {
+    (amount(), id(), any::<[u8; 32]>()).prop_map(|(amount, id, wallet)| TransferToWallet {
+        amount,
+        id,
+        wallet: Pubkey::new(&wallet),
+    })
+}
+
+#[test]
+fn v2_message_layout() {
+    let transfer = Transfer {
+        amount: 0x0102,
+        id: String::from("ab"),
+        eth_recipient: [7u8; 20],
+        message_version: MessageVersion::V2,
+        create_recipient: false,
+        memo: false,
+    };
+    let oracle_message = [
+        &[2u8][..],
+        &[7u8; 20],
+        &[2, 1, 0, 0, 0, 0, 0, 0],
+        &[2, 0, 0, 0],
+        b"ab",
+    ]
+    .concat();
+
+    assert_eq!(build_oracle_message(&transfer), oracle_message);
+    assert_eq!(
+        build_senders_message(&transfer, &[9u8; 20]),
+        [&oracle_message[..], &[9u8; 20]].concat()
+    );
+}
+
+proptest! {
+    #[test]
+    fn message_builders_match_verifier(
+        transfer in prop_oneof![transfer(MessageVersion::V1), transfer(MessageVersion::V2)],
+        oracle in eth_address(),
+        operator in any::<EthereumAddress>(),
+        reward_manager in any::<[u8; 32]>(),
+    ) {
+        let verifier = TransferVerifier {
+            bot_oracle: OracleAccount::new(Pubkey::new(&reward_manager), oracle, operator),
+            additional_oracles: Vec::new(),
+            transfer_data: transfer.clone(),
+        };
+
+        prop_assert_eq!(build_oracle_message(&transfer), verifier.bot_oracle_message());
+        prop_assert_eq!(build_senders_message(&transfer, &oracle), verifier.sender_message());
+    }
+
+    #[test]
+    fn transfer_messages_are_injective(
+        (first, first_oracle) in (transfer(MessageVersion::V1), eth_address()),
+        (second, second_oracle) in (transfer(MessageVersion::V1), eth_address()),
+    ) {
+        let first = transfer_verifier(&first, first_oracle);
+        let second = transfer_verifier(&second, second_oracle);
+
+        prop_assert_eq!(
+            first.bot_oracle_message() == second.bot_oracle_message(),
+            first.transfer_data == second.transfer_data
+        );
+        prop_assert_eq!(
+            first.sender_message() == second.sender_message(),
+            first.transfer_data == second.transfer_data && first_oracle == second_oracle
+        );
+    }
+
+    #[test]
+    fn transfer_v2_messages_are_injective(
+        (first, first_oracle) in (transfer(MessageVersion::V2), eth_address()),
+        (second, second_oracle) in (transfer(MessageVersion::V2), eth_address()),
+    ) {
+        let first = transfer_verifier(&first, first_oracle);
+        let second = transfer_verifier(&second, second_oracle);
+
+        prop_assert_eq!(
+            first.bot_oracle_message() == second.bot_oracle_message(),
+            first.transfer_data == second.transfer_data
+        );
+        prop_assert_eq!(
+            first.sender_message() == second.sender_message(),
+            first.transfer_data == second.transfer_data && first_oracle == second_oracle
+        );
+        prop_assert_ne!(first.bot_oracle_message(), second.sender_message());
+    }
+
+    #[test]
+    fn wallet_transfer_messages_are_injective(
+        (first, first_oracle) in (wallet_transfer(), eth_address()),
+        (second, second_oracle) in (wallet_transfer(), eth_address()),
+    ) {
+        let first = wallet_transfer_verifier(&first, first_oracle);
+        let second = wallet_transfer_verifier(&second, second_oracle);
+
+        prop_assert_eq!(
+            first.bot_oracle_message() == second.bot_oracle_message(),
+            first.transfer_data == second.transfer_data
+        );
+        prop_assert_eq!(
+            first.sender_message() == second.sender_message(),
+            first.transfer_data == second.transfer_data && first_oracle == second_oracle
+        );
+    }
+
+    #[test]
+    fn only_exact_message_accepted(
+        transfer in prop_oneof![transfer(MessageVersion::V1), transfer(MessageVersion::V2)],
+        oracle in eth_address(),
+        index in any::<prop::sample::Index>(),
+        byte in any::<u8>(),
+    ) {
+        let expected = transfer_verifier(&transfer, oracle).sender_message();
+        prop_assert!(payload(expected.clone()).check_message(&expected).is_ok());
+
+        let position = index.index(expected.len());
+        let mut changed = expected.clone();
+        changed[position] = byte;
+        prop_assert_eq!(
+            payload(changed).check_message(&expected).is_ok(),
+            expected[position] == byte
+        );
+
+        let mut extended = expected.clone();
+        extended.push(byte);
+        prop_assert!(payload(extended).check_message(&expected).is_err());
+
+        prop_assert!(payload(expected[..position].to_vec()).check_message(&expected).is_err());
+    }
+
+    /// NOTE: fields are joined with `_` while the id is free form, so a bot oracle
+    /// message of one transfer is the senders message of another one
+    #[test]
+    fn bot_oracle_message_ambiguous_with_senders_message(
+        transfer in transfer(MessageVersion::V1),
+        oracle in "[_a-z]{20}",
+    ) {
+        let mut oracle_address = EthereumAddress::default();
+        oracle_address.copy_from_slice(oracle.as_bytes());
+
+        let shifted = Transfer {
+            id: [transfer.id.as_str(), "_", oracle.as_str()].concat(),
+            ..transfer.clone()
+        };
+        prop_assert_eq!(
+            transfer_verifier(&shifted, oracle_address).bot_oracle_message(),
+            transfer_verifier(&transfer, oracle_address).sender_message()
+        );
+    }
+
+    /// NOTE: the length prefix tells the shifted ID apart
+    #[test]
+    fn v2_bot_oracle_message_distinct_from_senders_message(
+        transfer in transfer(MessageVersion::V2),
+        oracle in "[_a-z]{20}",
+    ) {
+        let mut oracle_address = EthereumAddress::default();
+        oracle_address.copy_from_slice(oracle.as_bytes());
+
+        let shifted = Transfer {
+            id: [transfer.id.as_str(), oracle.as_str()].concat(),
+            ..transfer.clone()
+        };
+        prop_assert_ne!(
+            transfer_verifier(&shifted, oracle_address).bot_oracle_message(),
+            transfer_verifier(&transfer, oracle_address).sender_message()
+        );
+    }
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError, instruction, state::RewardManager, utils::get_base_address,
+};
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const VAULT_BALANCE: u64 = 10_000;
+
+/// Funds the vault and creates a token account of `mint` owned by `owner`,
+/// the reward manager authority if not set
+async fn start_with_token_account(
+    mint: Option<&Keypair>,
+    owner: Option<Pubkey>,
+) -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut context = program_test().start_with_context().await;
+    let fixture = TestRewardManager::builder()
+        .senders(0)
+        .oracles(0)
+        .vault_balance(VAULT_BALANCE)
+        .build(&mut context)
+        .await;
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mint = match mint {
+        Some(mint) => {
+            create_mint(
+                &mut context,
+                mint,
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                &fixture.mint_authority.pubkey(),
+            )
+            .await
+            .unwrap();
+            mint.pubkey()
+        }
+        None => fixture.mint,
+    };
+
+    let (authority, _) = get_base_address(&audius_reward_manager::id(), &fixture.reward_manager);
+    let new_token_account = Keypair::new();
+    create_token_account(
+        &mut context,
+        &new_token_account,
+        &mint,
+        &owner.unwrap_or(authority),
+        &rent,
+    )
+    .await
+    .unwrap();
+
+    (context, fixture, new_token_account.pubkey())
+}
+
+fn migrate(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    new_token_account: &Pubkey,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::migrate_token_account(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.token_account,
+            new_token_account,
+            &fixture.manager.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    )
+}
+
+async fn token_balance(context: &mut ProgramTestContext, account: &Pubkey) -> u64 {
+    let account = get_account(context, account).await.unwrap();
+    spl_token::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+#[tokio::test]
+async fn success() {
+    let (mut context, fixture, new_token_account) = start_with_token_account(None, None).await;
+
+    let tx = migrate(&context, &fixture, &new_token_account);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, &new_token_account).await,
+        VAULT_BALANCE
+    );
+    assert_eq!(token_balance(&mut context, &fixture.token_account).await, 0);
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(fixture.reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.token_account, new_token_account);
+}
+
+#[tokio::test]
+async fn fail_other_mint_not_empty() {
+    let mint = Keypair::new();
+    let (context, fixture, new_token_account) = start_with_token_account(Some(&mint), None).await;
+
+    let tx = migrate(&context, &fixture, &new_token_account);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::VaultNotEmpty as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_wrong_owner() {
+    let (context, fixture, new_token_account) =
+        start_with_token_account(None, Some(Pubkey::new_unique())).await;
+
+    let tx = migrate(&context, &fixture, &new_token_account);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
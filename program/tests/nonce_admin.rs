@@ -0,0 +1,146 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    instruction::UpdateRewardManager,
+    state::{RewardManager, SenderAccount},
+    utils::{get_sender_address, EthereumAddress},
+};
+use solana_program::{pubkey::Pubkey, system_instruction};
+use solana_program_test::*;
+use solana_sdk::{
+    nonce,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const ETH_ADDRESS: EthereumAddress = [1u8; 20];
+const OPERATOR: EthereumAddress = [9u8; 20];
+const NONCE_SLOT: u64 = 100;
+
+/// Reward manager and a durable nonce account owned by the payer,
+/// its nonce no longer the recent blockhash
+async fn start() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut context = program_test().start_with_context().await;
+    let fixture = TestRewardManager::builder().build(&mut context).await;
+
+    let nonce_account = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &system_instruction::create_nonce_account(
+            &context.payer.pubkey(),
+            &nonce_account.pubkey(),
+            &context.payer.pubkey(),
+            rent.minimum_balance(nonce::State::size()),
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &nonce_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    context.warp_to_slot(NONCE_SLOT);
+
+    (context, fixture, nonce_account.pubkey())
+}
+
+async fn get_nonce(
+    context: &mut ProgramTestContext,
+    nonce_account: &Pubkey,
+) -> solana_program::hash::Hash {
+    let account = get_account(context, nonce_account).await.unwrap();
+    nonce_hash(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn success() {
+    let (mut context, fixture, nonce_account) = start().await;
+    let payer = context.payer.pubkey();
+
+    let nonce = get_nonce(&mut context, &nonce_account).await;
+    let mut tx = NonceTxBuilder::new(nonce_account, payer, payer)
+        .create_sender(
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            &payer,
+            ETH_ADDRESS,
+            OPERATOR,
+        )
+        .unwrap()
+        .update_reward_manager(
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            UpdateRewardManager {
+                min_votes: Some(2),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .build(nonce);
+    // NOTE: the manager signs offline, the payer completes the transaction later
+    tx.partial_sign(&[&fixture.manager], nonce);
+    tx.partial_sign(&[&context.payer], nonce);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(fixture.reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager.min_votes, 2);
+
+    let (sender, _) = get_sender_address(
+        &audius_reward_manager::id(),
+        &fixture.reward_manager,
+        &ETH_ADDRESS,
+    );
+    let sender = context
+        .banks_client
+        .get_account_data_with_borsh::<SenderAccount>(sender)
+        .await
+        .unwrap();
+    assert_eq!(sender.operator, OPERATOR);
+
+    assert_ne!(get_nonce(&mut context, &nonce_account).await, nonce);
+}
+
+#[tokio::test]
+async fn fail_nonce_advanced() {
+    let (mut context, fixture, nonce_account) = start().await;
+    let payer = context.payer.pubkey();
+
+    let nonce = get_nonce(&mut context, &nonce_account).await;
+    let update = |min_votes| {
+        let mut tx = NonceTxBuilder::new(nonce_account, payer, payer)
+            .update_reward_manager(
+                &fixture.reward_manager,
+                &fixture.manager.pubkey(),
+                UpdateRewardManager {
+                    min_votes: Some(min_votes),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .build(nonce);
+        tx.partial_sign(&[&fixture.manager, &context.payer], nonce);
+        tx
+    };
+    let first = update(2);
+    let second = update(1);
+
+    context
+        .banks_client
+        .process_transaction(first)
+        .await
+        .unwrap();
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(second)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::BlockhashNotFound
+    );
+}
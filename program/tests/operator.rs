@@ -0,0 +1,175 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    state::{OperatorAccount, RewardManager},
+    utils::{get_operator_address, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+fn program_test_with_reward_manager(reward_manager: &Pubkey, manager: &Pubkey) -> ProgramTest {
+    let mut program_test = program_test();
+    program_test.add_account(
+        *reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), *manager, 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test
+}
+
+fn create_sender_tx(
+    context: &ProgramTestContext,
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::create_sender(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            eth_address,
+            operator,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, manager_account],
+        context.last_blockhash,
+    )
+}
+
+async fn get_operator(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+    operator: &EthereumAddress,
+) -> OperatorAccount {
+    let (operator_account, _) =
+        get_operator_address(&audius_reward_manager::id(), reward_manager, operator);
+    context
+        .banks_client
+        .get_account_data_with_borsh(operator_account)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn success_lists_senders() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = program_test_with_reward_manager(&reward_manager, &manager_account.pubkey())
+        .start_with_context()
+        .await;
+
+    let operator = [9u8; 20];
+    for eth_address in [[1u8; 20], [2u8; 20]].iter() {
+        let tx = create_sender_tx(
+            &context,
+            &reward_manager,
+            &manager_account,
+            *eth_address,
+            operator,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    let operator_data = get_operator(&mut context, &reward_manager, &operator).await;
+    assert_eq!(operator_data.senders(), &[[1u8; 20], [2u8; 20]]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::delete_sender(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            [1u8; 20],
+            operator,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let operator_data = get_operator(&mut context, &reward_manager, &operator).await;
+    assert_eq!(operator_data.senders(), &[[2u8; 20]]);
+}
+
+#[tokio::test]
+async fn fail_senders_limit_reached() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = program_test_with_reward_manager(&reward_manager, &manager_account.pubkey())
+        .start_with_context()
+        .await;
+
+    let operator = [9u8; 20];
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::set_operator_senders_limit(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            operator,
+            1,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = create_sender_tx(
+        &context,
+        &reward_manager,
+        &manager_account,
+        [1u8; 20],
+        operator,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = create_sender_tx(
+        &context,
+        &reward_manager,
+        &manager_account,
+        [2u8; 20],
+        operator,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::OperatorSendersLimitReached as _)
+        )
+    );
+
+    let operator_data = get_operator(&mut context, &reward_manager, &operator).await;
+    assert_eq!(operator_data.max_senders, 1);
+    assert_eq!(operator_data.senders(), &[[1u8; 20]]);
+}
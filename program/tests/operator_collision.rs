@@ -0,0 +1,107 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{MessageVersion, Transfer},
+    state::{OracleAccount, RewardManager, SenderAccount},
+    utils::{
+        verify_attestations, EthereumAddress, SecpPayload, TransferVerifier, Verifier,
+        SECP_SIGNATURE_SIZE,
+    },
+};
+use proptest::prelude::*;
+use solana_program::pubkey::Pubkey;
+use std::collections::BTreeSet;
+
+const ORACLE: EthereumAddress = [0xee; 20];
+
+fn payload(signer: EthereumAddress, message: Vec<u8>) -> SecpPayload {
+    SecpPayload {
+        signer,
+        signature: [0u8; SECP_SIGNATURE_SIZE],
+        message,
+    }
+}
+
+/// Picks addresses out of a few so that random senders often share them
+fn shared_address(base: u8) -> impl Strategy<Value = EthereumAddress> {
+    (0..4u8).prop_map(move |index| [base + index; 20])
+}
+
+fn senders() -> impl Strategy<Value = Vec<SenderAccount>> {
+    prop::collection::vec((shared_address(0x10), shared_address(0x20)), 1..6).prop_map(|senders| {
+        senders
+            .into_iter()
+            .map(|(eth_address, operator)| {
+                SenderAccount::new(Pubkey::default(), eth_address, operator, 0)
+            })
+            .collect()
+    })
+}
+
+/// Oracle operator either shared with the senders or its own
+fn oracle_operator() -> impl Strategy<Value = EthereumAddress> {
+    prop_oneof![shared_address(0x20), Just([0xef; 20])]
+}
+
+fn is_distinct(mut addresses: impl Iterator<Item = EthereumAddress>) -> bool {
+    let mut seen = BTreeSet::new();
+    addresses.all(|address| seen.insert(address))
+}
+
+proptest! {
+    #[test]
+    fn collisions_are_rejected(
+        senders in senders(),
+        oracle_operator in oracle_operator(),
+        oracle_first in any::<bool>(),
+    ) {
+        let verifier = TransferVerifier {
+            bot_oracle: OracleAccount::new(Pubkey::default(), ORACLE, oracle_operator),
+            additional_oracles: Vec::new(),
+            transfer_data: Transfer {
+                amount: 10_000,
+                id: String::from("4r4t23df32543f55"),
+                eth_recipient: [7u8; 20],
+                message_version: MessageVersion::V2,
+                create_recipient: false,
+                memo: false,
+            },
+        };
+
+        let sender_message = verifier.sender_message();
+        let mut payloads: Vec<SecpPayload> = senders
+            .iter()
+            .map(|sender| payload(sender.eth_address, sender_message.clone()))
+            .collect();
+        let oracle_payload = payload(ORACLE, verifier.bot_oracle_message());
+        if oracle_first {
+            payloads.insert(0, oracle_payload);
+        } else {
+            payloads.push(oracle_payload);
+        }
+
+        let distinct_senders = is_distinct(senders.iter().map(|sender| sender.eth_address));
+        let distinct_operators = is_distinct(
+            senders
+                .iter()
+                .map(|sender| sender.operator)
+                .chain(std::iter::once(oracle_operator)),
+        );
+
+        let reward_manager = RewardManager::new(Pubkey::default(), Pubkey::default(), 1);
+        let result = verify_attestations(&reward_manager, &senders, payloads, verifier);
+
+        if distinct_senders && distinct_operators {
+            prop_assert_eq!(result, Ok(()));
+        } else if distinct_senders {
+            prop_assert_eq!(result, Err(AudiusProgramError::OperatorCollision.into()));
+        } else if distinct_operators {
+            prop_assert_eq!(result, Err(AudiusProgramError::RepeatedSenders.into()));
+        } else {
+            prop_assert!(
+                result == Err(AudiusProgramError::OperatorCollision.into())
+                    || result == Err(AudiusProgramError::RepeatedSenders.into())
+            );
+        }
+    }
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    instruction,
+    processor::ORACLE_SEED_PREFIX,
+    state::{OracleAccount, RewardManager, SenderAccount},
+    utils::{get_address_pair, get_sender_address, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use rand::{thread_rng, Rng};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+fn add_reward_manager(program_test: &mut ProgramTest, reward_manager: Pubkey, manager: Pubkey) {
+    let reward_manager_data = RewardManager::new(Pubkey::new_unique(), manager, 3);
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: reward_manager_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+#[tokio::test]
+async fn success_create_delete() {
+    let mut program_test = program_test();
+    let mut rng = thread_rng();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let refunder_account = Pubkey::new_unique();
+    let eth_address: EthereumAddress = rng.gen();
+    let operator: EthereumAddress = rng.gen();
+    add_reward_manager(&mut program_test, reward_manager, manager_account.pubkey());
+
+    let mut context = program_test.start_with_context().await;
+    create_oracle(
+        &mut context,
+        &reward_manager,
+        &manager_account,
+        eth_address,
+        operator,
+    )
+    .await;
+
+    let pair = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        [ORACLE_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+    )
+    .unwrap();
+    assert_eq!(
+        OracleAccount::new(reward_manager, eth_address, operator),
+        context
+            .banks_client
+            .get_account_data_with_borsh(pair.derive.address)
+            .await
+            .unwrap()
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::delete_oracle(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            &refunder_account,
+            eth_address,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(get_account(&mut context, &pair.derive.address)
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn fail_sender_as_oracle() {
+    let mut program_test = program_test();
+    let mut rng = thread_rng();
+
+    let reward_manager = Pubkey::new_unique();
+    let eth_address: EthereumAddress = rng.gen();
+    add_reward_manager(&mut program_test, reward_manager, Pubkey::new_unique());
+
+    let (sender, bump) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
+    program_test.add_account(
+        sender,
+        Account {
+            lamports: 9000,
+            data: SenderAccount::new(reward_manager, eth_address, rng.gen(), bump)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &sender,
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            vec![Pubkey::new_unique()],
+            instruction::Transfer {
+                amount: 10,
+                id: String::from("4r4t23df32543f55"),
+                eth_recipient: [7u8; 20],
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidSeeds)
+    );
+}
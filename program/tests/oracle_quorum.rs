@@ -0,0 +1,249 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::ORACLE_SEED_PREFIX,
+    utils::{get_address_pair, get_sender_address, EthereumAddress},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    secp256k1_instruction::*,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+/// Registers two bot oracles and three senders, requires both oracles to vote and
+/// returns the attestation instructions followed by the transfer instruction.
+/// Only the first oracle votes unless `both_oracles_vote` is set
+async fn prepare_transfer(
+    context: &mut ProgramTestContext,
+    both_oracles_vote: bool,
+) -> Vec<Instruction> {
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &manager_account.pubkey(),
+            instruction::UpdateRewardManager {
+                min_oracle_votes: Some(2),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let oracle_keys: [[u8; 32]; 2] = rng.gen();
+    let mut oracles = Vec::new();
+    for key in oracle_keys.iter() {
+        let eth_address =
+            construct_eth_pubkey(&PublicKey::from_secret_key(&SecretKey::parse(key).unwrap()));
+        oracles.push((
+            eth_address,
+            get_address_pair(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                [ORACLE_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
+            )
+            .unwrap()
+            .derive
+            .address,
+        ));
+        create_oracle(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            rng.gen(),
+        )
+        .await;
+    }
+
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let operators: [EthereumAddress; 3] = rng.gen();
+    // NOTE: the second oracle leads the attesting accounts
+    let mut attesters = vec![oracles[1].1];
+    for (key, operator) in keys.iter().zip(operators.iter()) {
+        let eth_address =
+            construct_eth_pubkey(&PublicKey::from_secret_key(&SecretKey::parse(key).unwrap()));
+        attesters.push(
+            get_sender_address(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &eth_address,
+            )
+            .0,
+        );
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            *operator,
+        )
+        .await;
+    }
+
+    mint_tokens_to(
+        context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        TRANSFER_AMOUNT,
+    )
+    .await
+    .unwrap();
+
+    let recipient_eth_key = [7u8; 20];
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        &mint.pubkey(),
+        recipient_eth_key,
+    )
+    .unwrap()
+    .derive
+    .address;
+    create_recipient_with_claimable_program(context, &mint.pubkey(), recipient_eth_key).await;
+
+    let transfer_id = "4r4t23df32543f55";
+
+    let bot_oracle_message = [
+        recipient_eth_key.as_ref(),
+        b"_",
+        TRANSFER_AMOUNT.to_le_bytes().as_ref(),
+        b"_",
+        transfer_id.as_ref(),
+    ]
+    .concat();
+    // NOTE: senders attest to the first oracle only
+    let senders_message = [bot_oracle_message.as_ref(), b"_", oracles[0].0.as_ref()].concat();
+
+    let voting_oracles = if both_oracles_vote { 2 } else { 1 };
+    let mut instructions = Vec::new();
+    for key in oracle_keys.iter().take(voting_oracles) {
+        instructions.push(new_secp256k1_instruction_2_0(
+            &SecretKey::parse(key).unwrap(),
+            bot_oracle_message.as_ref(),
+            instructions.len() as _,
+        ));
+    }
+    for key in keys.iter() {
+        instructions.push(new_secp256k1_instruction_2_0(
+            &SecretKey::parse(key).unwrap(),
+            senders_message.as_ref(),
+            instructions.len() as _,
+        ));
+    }
+
+    instructions.push(
+        instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &recipient,
+            &token_account.pubkey(),
+            &oracles[0].1,
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            attesters,
+            instruction::Transfer {
+                amount: TRANSFER_AMOUNT,
+                id: String::from(transfer_id),
+                eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap(),
+    );
+
+    instructions
+}
+
+#[tokio::test]
+async fn success_transfer_with_oracle_quorum() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let instructions = prepare_transfer(&mut context, true).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn fail_missing_oracle_vote() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let instructions = prepare_transfer(&mut context, false).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            4,
+            InstructionError::Custom(AudiusProgramError::Secp256InstructionMissing as _)
+        )
+    );
+}
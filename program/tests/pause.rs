@@ -0,0 +1,136 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{error::AudiusProgramError, instruction, state::RewardManager};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::program_test;
+
+#[tokio::test]
+async fn success_pause_unpause() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    let token_account = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(token_account, manager_account.pubkey(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::pause(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager_data: RewardManager = context
+        .banks_client
+        .get_account_data_with_borsh(reward_manager)
+        .await
+        .unwrap();
+    assert!(reward_manager_data.paused);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::unpause(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        RewardManager::new(token_account, manager_account.pubkey(), 3),
+        context
+            .banks_client
+            .get_account_data_with_borsh(reward_manager)
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn fail_transfer_while_paused() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    let mut reward_manager_data = RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3);
+    reward_manager_data.paused = true;
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: reward_manager_data.try_to_vec().unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            vec![Pubkey::new_unique()],
+            instruction::Transfer {
+                amount: 10,
+                id: String::from("4r4t23df32543f55"),
+                eth_recipient: [7u8; 20],
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::ProgramPaused as _)
+        )
+    );
+}
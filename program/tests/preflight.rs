@@ -0,0 +1,154 @@
+#![cfg(all(feature = "test-bpf", feature = "sdk"))]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{MessageVersion, Transfer},
+    sdk::preflight::{secp_payloads, validate_attestations},
+    state::{OracleAccount, RewardManager, SenderAccount},
+    utils::{SecpPayload, TransferVerifier, Verifier},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_sdk::secp256k1_instruction::construct_eth_pubkey;
+use utils::*;
+
+struct Bundle {
+    reward_manager: RewardManager,
+    oracle: OracleAccount,
+    senders: Vec<SenderAccount>,
+    transfer: Transfer,
+    oracle_key: SecretKey,
+    sender_keys: Vec<SecretKey>,
+}
+
+impl Bundle {
+    fn new() -> Self {
+        let mut rng = thread_rng();
+        let reward_manager_key = Pubkey::new_unique();
+        let mut reward_manager = RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3);
+        reward_manager.sender_count = 3;
+
+        let oracle_key = SecretKey::parse(&rng.gen()).unwrap();
+        let oracle = OracleAccount::new(
+            reward_manager_key,
+            construct_eth_pubkey(&PublicKey::from_secret_key(&oracle_key)),
+            rng.gen(),
+        );
+
+        let mut senders = Vec::new();
+        let mut sender_keys = Vec::new();
+        for _ in 0..3 {
+            let key = SecretKey::parse(&rng.gen()).unwrap();
+            senders.push(SenderAccount::new(
+                reward_manager_key,
+                construct_eth_pubkey(&PublicKey::from_secret_key(&key)),
+                rng.gen(),
+                255,
+            ));
+            sender_keys.push(key);
+        }
+
+        Self {
+            reward_manager,
+            oracle,
+            senders,
+            transfer: Transfer {
+                amount: 10_000,
+                id: String::from("4r4t23df32543f55"),
+                eth_recipient: [7u8; 20],
+                message_version: MessageVersion::V2,
+                create_recipient: false,
+                memo: false,
+            },
+            oracle_key,
+            sender_keys,
+        }
+    }
+
+    /// Bot oracle attestation followed by every sender one
+    fn messages(&self) -> Vec<SecpPayload> {
+        let verifier = TransferVerifier {
+            bot_oracle: self.oracle.clone(),
+            additional_oracles: Vec::new(),
+            transfer_data: self.transfer.clone(),
+        };
+        let mut instructions = vec![new_secp256k1_instruction_2_0(
+            &self.oracle_key,
+            &verifier.bot_oracle_message(),
+            0,
+        )];
+        for key in self.sender_keys.iter() {
+            instructions.push(new_secp256k1_instruction_2_0(
+                key,
+                &verifier.sender_message(),
+                instructions.len() as _,
+            ));
+        }
+        secp_payloads(&instructions).unwrap()
+    }
+
+    fn validate(&self, messages: &[SecpPayload]) -> Result<(), ProgramError> {
+        validate_attestations(
+            messages,
+            &self.senders,
+            &self.oracle,
+            &[],
+            &self.reward_manager,
+            &self.transfer,
+        )
+    }
+}
+
+#[test]
+fn success() {
+    let bundle = Bundle::new();
+    bundle.validate(&bundle.messages()).unwrap();
+}
+
+#[test]
+fn fail_sign_collision() {
+    let bundle = Bundle::new();
+    let mut messages = bundle.messages();
+    messages[2] = messages[1].clone();
+
+    assert_eq!(
+        bundle.validate(&messages).unwrap_err(),
+        ProgramError::Custom(AudiusProgramError::SignCollission as _)
+    );
+}
+
+#[test]
+fn fail_operator_collision() {
+    let mut bundle = Bundle::new();
+    bundle.senders[1].operator = bundle.senders[0].operator;
+
+    assert_eq!(
+        bundle.validate(&bundle.messages()).unwrap_err(),
+        ProgramError::Custom(AudiusProgramError::OperatorCollision as _)
+    );
+}
+
+#[test]
+fn fail_wrong_message() {
+    let mut bundle = Bundle::new();
+    let messages = bundle.messages();
+    bundle.transfer.amount += 1;
+
+    assert_eq!(
+        bundle.validate(&messages).unwrap_err(),
+        ProgramError::Custom(AudiusProgramError::SignatureVerificationFailed as _)
+    );
+}
+
+#[test]
+fn fail_forged_signature() {
+    let bundle = Bundle::new();
+    let mut messages = bundle.messages();
+    messages[1].signature[0] ^= 1;
+
+    assert_eq!(
+        bundle.validate(&messages).unwrap_err(),
+        ProgramError::Custom(AudiusProgramError::SignatureVerificationFailed as _)
+    );
+}
@@ -0,0 +1,184 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{
+        self, MessageVersion, SetRateLimit, SubmitDistributionRoot, Transfer, TransferToWallet,
+    },
+    processor::RATE_LIMITER_SEED_PREFIX,
+    state::{RateLimiter, MAX_RATE_LIMITED_OPERATORS},
+    utils::get_address_pair,
+};
+use borsh::BorshSerialize;
+use solana_program::{hash::Hash, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+const RECIPIENT: [u8; 20] = [7u8; 20];
+
+fn transfer_instructions(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    recipient: &Pubkey,
+    id: &str,
+) -> Vec<Instruction> {
+    fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        recipient,
+        Transfer {
+            amount: TRANSFER_AMOUNT,
+            id: String::from(id),
+            eth_recipient: RECIPIENT,
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        },
+    )
+}
+
+#[test]
+fn limiter_counts_operators_per_window() {
+    let mut rate_limiter = RateLimiter::new(Pubkey::new_unique(), 2, 100);
+    assert_eq!(rate_limiter.try_to_vec().unwrap().len(), RateLimiter::LEN);
+
+    rate_limiter.charge(&[[1u8; 20], [2u8; 20]], 10, 0).unwrap();
+    rate_limiter.charge(&[[1u8; 20]], 20, 0).unwrap();
+    assert_eq!(
+        rate_limiter.charge(&[[2u8; 20], [1u8; 20]], 30, 0),
+        Err(AudiusProgramError::OperatorRateLimited)
+    );
+
+    rate_limiter.charge(&[[1u8; 20]], 100, 0).unwrap();
+    assert_eq!(rate_limiter.window_start, 100);
+    assert_eq!(rate_limiter.operators().len(), 1);
+    assert_eq!(rate_limiter.operators()[0].attestations, 1);
+
+    let operators = (0..=MAX_RATE_LIMITED_OPERATORS)
+        .map(|index| [index as u8; 20])
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rate_limiter.charge(&operators, 200, 0),
+        Err(AudiusProgramError::RateLimiterFull)
+    );
+}
+
+/// Reward manager limited to one attestation per operator,
+/// already spent by a transfer
+async fn start() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT * 2)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::set_rate_limit(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            &context.payer.pubkey(),
+            SetRateLimit {
+                max_attestations: 1,
+                window_slots: 0,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &transfer_instructions(&context, &fixture, &recipient, "4r4t23df32543f55"),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    (context, fixture, recipient)
+}
+
+async fn assert_rate_limited(context: &mut ProgramTestContext, instructions: Vec<Instruction>) {
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::OperatorRateLimited as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_operator_rate_limited() {
+    let (mut context, fixture, recipient) = start().await;
+
+    let address = get_address_pair(
+        &audius_reward_manager::id(),
+        &fixture.reward_manager,
+        RATE_LIMITER_SEED_PREFIX.as_bytes().to_vec(),
+    )
+    .unwrap()
+    .derive
+    .address;
+    let rate_limiter = context
+        .banks_client
+        .get_account_data_with_borsh::<RateLimiter>(address)
+        .await
+        .unwrap();
+    assert_eq!(rate_limiter.operators().len(), fixture.senders.len());
+
+    let instructions = transfer_instructions(&context, &fixture, &recipient, "5r4t23df32543f55");
+    assert_rate_limited(&mut context, instructions).await;
+}
+
+#[tokio::test]
+async fn fail_wallet_operator_rate_limited() {
+    let (mut context, fixture, _) = start().await;
+
+    let instructions = fixture.transfer_to_wallet_instructions(
+        &context.payer.pubkey(),
+        TransferToWallet {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("5r4t23df32543f55"),
+            wallet: Pubkey::new_unique(),
+        },
+    );
+    assert_rate_limited(&mut context, instructions).await;
+}
+
+#[tokio::test]
+async fn fail_distribution_root_operator_rate_limited() {
+    let (mut context, fixture, _) = start().await;
+
+    let instructions = fixture.submit_distribution_root_instructions(
+        &context.payer.pubkey(),
+        SubmitDistributionRoot {
+            id: String::from("distribution-1"),
+            root: Hash::new(&[1u8; 32]),
+        },
+    );
+    assert_rate_limited(&mut context, instructions).await;
+}
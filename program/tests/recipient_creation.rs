@@ -0,0 +1,166 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::instruction::{self, MessageVersion, Transfer};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+const RECIPIENT: [u8; 20] = [7u8; 20];
+
+async fn start() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        &fixture.mint,
+        RECIPIENT,
+    )
+    .unwrap()
+    .derive
+    .address;
+
+    (context, fixture, recipient)
+}
+
+/// Attestations and the transfer creating the recipient through `claimable_tokens_program`
+fn transfer_instructions(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    recipient: &Pubkey,
+    claimable_tokens_program: &Pubkey,
+) -> Vec<Instruction> {
+    let mut instructions = fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        recipient,
+        Transfer {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: RECIPIENT,
+            message_version: MessageVersion::V2,
+            create_recipient: true,
+            memo: false,
+        },
+    );
+    instructions.last_mut().unwrap().accounts.extend(
+        instruction::recipient_creation_accounts(
+            claimable_tokens_program,
+            &fixture.mint,
+            RECIPIENT,
+        )
+        .unwrap(),
+    );
+    instructions
+}
+
+async fn token_balance(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    context
+        .banks_client
+        .get_packed_account_data::<spl_token::state::Account>(account)
+        .await
+        .unwrap()
+        .amount
+}
+
+#[tokio::test]
+async fn success_missing_recipient() {
+    let (mut context, fixture, recipient) = start().await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &transfer_instructions(&context, &fixture, &recipient, &claimable_tokens::id()),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, recipient).await,
+        TRANSFER_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn success_existing_recipient() {
+    let (mut context, fixture, recipient) = start().await;
+    fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &transfer_instructions(&context, &fixture, &recipient, &claimable_tokens::id()),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, recipient).await,
+        TRANSFER_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn fail_other_claimable_program() {
+    let (mut context, fixture, recipient) = start().await;
+
+    let instructions = transfer_instructions(&context, &fixture, &recipient, &Pubkey::new_unique());
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::IncorrectProgramId
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_missing_creation_accounts() {
+    let (mut context, fixture, recipient) = start().await;
+
+    let mut instructions =
+        transfer_instructions(&context, &fixture, &recipient, &claimable_tokens::id());
+    let transfer = instructions.last_mut().unwrap();
+    transfer.accounts.truncate(transfer.accounts.len() - 4);
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::NotEnoughAccountKeys
+        )
+    );
+}
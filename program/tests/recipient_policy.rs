@@ -0,0 +1,141 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{self, MessageVersion, SetRecipientBlocked, Transfer},
+    state::{RecipientPolicy, MAX_BLOCKED_RECIPIENTS},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+const RECIPIENT: [u8; 20] = [7u8; 20];
+
+/// Funds the vault for a single transfer to `RECIPIENT` and blocks it
+async fn start_with_blocked_recipient() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    let tx = set_blocked(&context, &fixture, true);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    (context, fixture, recipient)
+}
+
+fn set_blocked(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    blocked: bool,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::set_recipient_blocked(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            &context.payer.pubkey(),
+            SetRecipientBlocked {
+                eth_recipient: RECIPIENT,
+                blocked,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    )
+}
+
+fn transfer_instructions(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    recipient: &Pubkey,
+) -> Vec<Instruction> {
+    fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        recipient,
+        Transfer {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: RECIPIENT,
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        },
+    )
+}
+
+#[test]
+fn policy_keeps_recipients_sorted() {
+    let mut policy = RecipientPolicy::new(Pubkey::new_unique());
+    for address in [[3u8; 20], [1u8; 20], [2u8; 20], [1u8; 20]].iter() {
+        policy.block(*address).unwrap();
+    }
+    assert_eq!(policy.blocked(), &[[1u8; 20], [2u8; 20], [3u8; 20]]);
+
+    policy.unblock(&[2u8; 20]);
+    assert_eq!(policy.blocked(), &[[1u8; 20], [3u8; 20]]);
+    assert!(!policy.is_blocked(&[2u8; 20]));
+
+    let mut policy = RecipientPolicy::new(Pubkey::new_unique());
+    for index in 0..MAX_BLOCKED_RECIPIENTS {
+        policy.block([index as u8; 20]).unwrap();
+    }
+    assert_eq!(
+        policy.block([255u8; 20]),
+        Err(AudiusProgramError::RecipientPolicyFull)
+    );
+}
+
+#[tokio::test]
+async fn fail_blocked_recipient() {
+    let (context, fixture, recipient) = start_with_blocked_recipient().await;
+
+    let instructions = transfer_instructions(&context, &fixture, &recipient);
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::RecipientBlocked as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_unblocked_recipient() {
+    let (context, fixture, recipient) = start_with_blocked_recipient().await;
+
+    let tx = set_blocked(&context, &fixture, false);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &transfer_instructions(&context, &fixture, &recipient),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    state::{OperatorAccount, RewardManager, SenderAccount},
+    utils::{get_operator_address, get_sender_address, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const ETH_ADDRESS: EthereumAddress = [1u8; 20];
+const OPERATOR: EthereumAddress = [9u8; 20];
+
+fn program_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: 9000,
+        data,
+        owner: audius_reward_manager::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Reward manager with a sender holding `sender_data` at its derived address and
+/// the operator account listing `operator_senders`
+async fn start(
+    reward_manager: &Pubkey,
+    manager: &Pubkey,
+    sender_data: Vec<u8>,
+    operator_senders: &[EthereumAddress],
+) -> ProgramTestContext {
+    let mut program_test = program_test();
+    program_test.add_account(
+        *reward_manager,
+        program_account(
+            RewardManager::new(Pubkey::new_unique(), *manager, 3)
+                .try_to_vec()
+                .unwrap(),
+        ),
+    );
+
+    let (sender, _) =
+        get_sender_address(&audius_reward_manager::id(), reward_manager, &ETH_ADDRESS);
+    program_test.add_account(sender, program_account(sender_data));
+
+    let (operator, bump) =
+        get_operator_address(&audius_reward_manager::id(), reward_manager, &OPERATOR);
+    let mut operator_data = OperatorAccount::new(*reward_manager, OPERATOR, bump);
+    for eth_address in operator_senders {
+        operator_data.add_sender(*eth_address).unwrap();
+    }
+    program_test.add_account(
+        operator,
+        program_account(operator_data.try_to_vec().unwrap()),
+    );
+
+    program_test.start_with_context().await
+}
+
+fn repair_sender_tx(
+    context: &ProgramTestContext,
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::repair_sender(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &manager_account.pubkey(),
+            ETH_ADDRESS,
+            OPERATOR,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, manager_account],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn success() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = start(
+        &reward_manager,
+        &manager_account.pubkey(),
+        vec![0xff; SenderAccount::LEN],
+        &[ETH_ADDRESS],
+    )
+    .await;
+
+    let tx = repair_sender_tx(&context, &reward_manager, &manager_account);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (sender, bump) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &ETH_ADDRESS);
+    assert_eq!(
+        SenderAccount::new(reward_manager, ETH_ADDRESS, OPERATOR, bump),
+        context
+            .banks_client
+            .get_account_data_with_borsh(sender)
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn fail_sender_not_corrupted() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let (_, bump) = get_sender_address(&audius_reward_manager::id(), &reward_manager, &ETH_ADDRESS);
+    let mut sender = SenderAccount::new(reward_manager, ETH_ADDRESS, OPERATOR, bump);
+    sender.weight = 5;
+    let mut context = start(
+        &reward_manager,
+        &manager_account.pubkey(),
+        sender.try_to_vec().unwrap(),
+        &[ETH_ADDRESS],
+    )
+    .await;
+
+    let tx = repair_sender_tx(&context, &reward_manager, &manager_account);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn fail_sender_not_registered_by_operator() {
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = start(
+        &reward_manager,
+        &manager_account.pubkey(),
+        vec![0u8; SenderAccount::LEN],
+        &[[2u8; 20]],
+    )
+    .await;
+
+    let tx = repair_sender_tx(&context, &reward_manager, &manager_account);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::IncorectSenderAccount as _)
+        )
+    );
+}
@@ -0,0 +1,314 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::ROTATE_SENDER_MESSAGE_PREFIX,
+    state::{OperatorAccount, RewardManager, SenderAccount},
+    utils::{get_operator_address, get_sender_address, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, pubkey::Pubkey, system_instruction};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    secp256k1_instruction::construct_eth_pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+/// Registers a sender with the key `sender_key` and returns its operator
+async fn prepare(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+    sender_key: &SecretKey,
+) -> EthereumAddress {
+    let operator: EthereumAddress = thread_rng().gen();
+    create_sender(
+        context,
+        reward_manager,
+        manager_account,
+        construct_eth_pubkey(&PublicKey::from_secret_key(sender_key)),
+        operator,
+    )
+    .await;
+
+    operator
+}
+
+fn rotate_instructions(
+    funder: &Pubkey,
+    reward_manager: &Pubkey,
+    sender: &Pubkey,
+    signer_key: &SecretKey,
+    attestation_count: u64,
+    new_eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) -> Vec<Instruction> {
+    let message = [
+        ROTATE_SENDER_MESSAGE_PREFIX.as_bytes(),
+        reward_manager.as_ref(),
+        attestation_count.to_le_bytes().as_ref(),
+        new_eth_address.as_ref(),
+    ]
+    .concat();
+
+    vec![
+        new_secp256k1_instruction_2_0(signer_key, message.as_ref(), 0),
+        instruction::rotate_sender_key(
+            &audius_reward_manager::id(),
+            reward_manager,
+            sender,
+            funder,
+            new_eth_address,
+            operator,
+        )
+        .unwrap(),
+    ]
+}
+
+fn program_test_with_reward_manager(
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+) -> ProgramTest {
+    let mut program_test = program_test();
+    program_test.add_account(
+        *reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test
+}
+
+#[tokio::test]
+async fn success() {
+    let mut rng = thread_rng();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = program_test_with_reward_manager(&reward_manager, &manager_account)
+        .start_with_context()
+        .await;
+
+    let key: [u8; 32] = rng.gen();
+    let sender_key = SecretKey::parse(&key).unwrap();
+    let operator = prepare(&mut context, &reward_manager, &manager_account, &sender_key).await;
+    let (sender, _) = get_sender_address(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &construct_eth_pubkey(&PublicKey::from_secret_key(&sender_key)),
+    );
+
+    let new_eth_address: EthereumAddress = rng.gen();
+    let tx = Transaction::new_signed_with_payer(
+        &rotate_instructions(
+            &context.payer.pubkey(),
+            &reward_manager,
+            &sender,
+            &sender_key,
+            0,
+            new_eth_address,
+            operator,
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(get_account(&mut context, &sender).await.is_none());
+
+    let (new_sender, bump) = get_sender_address(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &new_eth_address,
+    );
+    let new_sender_data = context
+        .banks_client
+        .get_account_data_with_borsh::<SenderAccount>(new_sender)
+        .await
+        .unwrap();
+    assert_eq!(
+        SenderAccount {
+            attestation_count: 1,
+            last_attested_slot: new_sender_data.last_attested_slot,
+            ..SenderAccount::new(reward_manager, new_eth_address, operator, bump)
+        },
+        new_sender_data
+    );
+
+    let (operator_account, _) =
+        get_operator_address(&audius_reward_manager::id(), &reward_manager, &operator);
+    let operator_data = context
+        .banks_client
+        .get_account_data_with_borsh::<OperatorAccount>(operator_account)
+        .await
+        .unwrap();
+    assert_eq!(operator_data.senders(), &[new_eth_address]);
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.sender_count, 1);
+}
+
+#[tokio::test]
+async fn fail_signed_by_other_key() {
+    let mut rng = thread_rng();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = program_test_with_reward_manager(&reward_manager, &manager_account)
+        .start_with_context()
+        .await;
+
+    let key: [u8; 32] = rng.gen();
+    let sender_key = SecretKey::parse(&key).unwrap();
+    let operator = prepare(&mut context, &reward_manager, &manager_account, &sender_key).await;
+    let (sender, _) = get_sender_address(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &construct_eth_pubkey(&PublicKey::from_secret_key(&sender_key)),
+    );
+
+    let key: [u8; 32] = rng.gen();
+    let other_key = SecretKey::parse(&key).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &rotate_instructions(
+            &context.payer.pubkey(),
+            &reward_manager,
+            &sender,
+            &other_key,
+            0,
+            rng.gen(),
+            operator,
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(AudiusProgramError::WrongSigner as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_replayed_rotation() {
+    let mut rng = thread_rng();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let mut context = program_test_with_reward_manager(&reward_manager, &manager_account)
+        .start_with_context()
+        .await;
+
+    let first_key = SecretKey::parse(&rng.gen()).unwrap();
+    let second_key = SecretKey::parse(&rng.gen()).unwrap();
+    let operator = prepare(&mut context, &reward_manager, &manager_account, &first_key).await;
+    let first_eth_address = construct_eth_pubkey(&PublicKey::from_secret_key(&first_key));
+    let second_eth_address = construct_eth_pubkey(&PublicKey::from_secret_key(&second_key));
+    let (first_sender, _) = get_sender_address(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &first_eth_address,
+    );
+    let (second_sender, _) = get_sender_address(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &second_eth_address,
+    );
+
+    // NOTE: the sender moves to the second key and back to the first one
+    let rotations = [
+        rotate_instructions(
+            &context.payer.pubkey(),
+            &reward_manager,
+            &first_sender,
+            &first_key,
+            0,
+            second_eth_address,
+            operator,
+        ),
+        rotate_instructions(
+            &context.payer.pubkey(),
+            &reward_manager,
+            &second_sender,
+            &second_key,
+            1,
+            first_eth_address,
+            operator,
+        ),
+    ];
+    for instructions in rotations.iter() {
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    // NOTE: the first rotation signature replayed by another funder
+    let attacker = Keypair::new();
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            &attacker.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &rotate_instructions(
+            &attacker.pubkey(),
+            &reward_manager,
+            &first_sender,
+            &first_key,
+            0,
+            second_eth_address,
+            operator,
+        ),
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(AudiusProgramError::SignatureVerificationFailed as _)
+        )
+    );
+}
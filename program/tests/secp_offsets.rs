@@ -0,0 +1,146 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    utils::{validate_secp_offsets, SecpPayload, MESSAGE_DATA_OFFSET},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_sdk::secp256k1_instruction::construct_eth_pubkey;
+use utils::new_secp256k1_instruction_2_0;
+
+fn signed_data(instruction_index: u8) -> Vec<u8> {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    new_secp256k1_instruction_2_0(&priv_key, b"some_message", instruction_index).data
+}
+
+fn set_offset(data: &mut Vec<u8>, at: usize, value: u16) {
+    data[at..at + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+#[test]
+fn success() {
+    let data = signed_data(2);
+    assert_eq!(validate_secp_offsets(&data, 2), Ok(()));
+}
+
+#[test]
+fn fail_foreign_instruction_index() {
+    let data = signed_data(2);
+    assert_eq!(
+        validate_secp_offsets(&data, 1),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    // Signature is taken from another instruction
+    let mut data = signed_data(2);
+    data[3] = 0;
+    assert_eq!(
+        validate_secp_offsets(&data, 2),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    // Address is taken from another instruction
+    let mut data = signed_data(2);
+    data[6] = 0;
+    assert_eq!(
+        validate_secp_offsets(&data, 2),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    // Message is taken from another instruction
+    let mut data = signed_data(2);
+    data[11] = 0;
+    assert_eq!(
+        validate_secp_offsets(&data, 2),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+}
+
+#[test]
+fn fail_shifted_sections() {
+    let original = signed_data(0);
+
+    let mut data = original.clone();
+    set_offset(&mut data, 1, 33);
+    assert_eq!(
+        validate_secp_offsets(&data, 0),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    let mut data = original.clone();
+    set_offset(&mut data, 4, 13);
+    assert_eq!(
+        validate_secp_offsets(&data, 0),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    let mut data = original.clone();
+    set_offset(&mut data, 7, 96);
+    assert_eq!(
+        validate_secp_offsets(&data, 0),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    let mut data = original;
+    set_offset(&mut data, 9, 1);
+    assert_eq!(
+        validate_secp_offsets(&data, 0),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+}
+
+#[test]
+fn fail_malformed_header() {
+    let mut data = signed_data(0);
+    data[0] = 2;
+    assert_eq!(
+        validate_secp_offsets(&data, 0),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    let data = signed_data(0);
+    assert_eq!(
+        validate_secp_offsets(&data[..11], 0),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    // Second signature offsets appended to the header
+    let mut data = signed_data(0);
+    data[0] = 2;
+    let offsets = data[1..12].to_vec();
+    data.splice(12..12, offsets);
+    assert_eq!(
+        validate_secp_offsets(&data, 0),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+
+    let mut data = signed_data(0);
+    data.push(0);
+    assert_eq!(
+        validate_secp_offsets(&data, 0),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+}
+
+#[test]
+fn payload_parse() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_pubkey(&PublicKey::from_secret_key(&priv_key));
+    let data = new_secp256k1_instruction_2_0(&priv_key, b"some_message", 0).data;
+
+    let payload = SecpPayload::parse(&data).unwrap();
+    assert_eq!(payload.signer, eth_address);
+    assert_eq!(payload.message, b"some_message".to_vec());
+    assert!(payload.check_message(b"some_message").is_ok());
+    assert!(payload.check_message(b"other_message").is_err());
+
+    assert_eq!(
+        SecpPayload::parse(&data[..MESSAGE_DATA_OFFSET - 1]),
+        Err(AudiusProgramError::InvalidSecpOffsets)
+    );
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::utils::{
+    validate_secp_offsets, SecpPayload, ETH_ADDRESS_OFFSET, MESSAGE_DATA_OFFSET, SIGNATURE_OFFSET,
+};
+use secp256k1::SecretKey;
+use serde_derive::Deserialize;
+use utils::{new_secp256k1_instruction_2_0, recoverable_signature};
+
+/// Shared with clients in other languages, keep in sync when the format changes
+const VECTORS: &str = include_str!("fixtures/secp_vectors.json");
+
+#[derive(Deserialize)]
+struct Layout {
+    eth_address_offset: usize,
+    signature_offset: usize,
+    message_data_offset: usize,
+}
+
+#[derive(Deserialize)]
+struct Vector {
+    private_key: String,
+    message: String,
+    instruction_index: u8,
+    eth_address: String,
+    signature: String,
+    instruction_data: String,
+}
+
+#[derive(Deserialize)]
+struct Vectors {
+    layout: Layout,
+    vectors: Vec<Vector>,
+}
+
+fn decode(hex: &str) -> Vec<u8> {
+    assert_eq!(hex.len() % 2, 0, "odd length hex {}", hex);
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn vectors() -> Vectors {
+    serde_json::from_str(VECTORS).unwrap()
+}
+
+#[test]
+fn layout_matches() {
+    let layout = vectors().layout;
+    assert_eq!(ETH_ADDRESS_OFFSET, 12);
+    assert_eq!(SIGNATURE_OFFSET, 32);
+    assert_eq!(MESSAGE_DATA_OFFSET, 97);
+    assert_eq!(layout.eth_address_offset, ETH_ADDRESS_OFFSET);
+    assert_eq!(layout.signature_offset, SIGNATURE_OFFSET);
+    assert_eq!(layout.message_data_offset, MESSAGE_DATA_OFFSET);
+}
+
+#[test]
+fn instructions_match() {
+    for vector in vectors().vectors {
+        let priv_key = SecretKey::parse_slice(&decode(&vector.private_key)).unwrap();
+        let message = decode(&vector.message);
+
+        let data =
+            new_secp256k1_instruction_2_0(&priv_key, &message, vector.instruction_index).data;
+        assert_eq!(data, decode(&vector.instruction_data));
+        assert_eq!(
+            validate_secp_offsets(&data, vector.instruction_index as u16),
+            Ok(())
+        );
+
+        let payload = SecpPayload::parse(&data).unwrap();
+        assert_eq!(payload.signer.to_vec(), decode(&vector.eth_address));
+        assert_eq!(payload.signature.to_vec(), decode(&vector.signature));
+        assert_eq!(payload.message, message);
+    }
+}
+
+#[test]
+fn recoverable_signatures_match() {
+    for vector in vectors().vectors {
+        let priv_key = SecretKey::parse_slice(&decode(&vector.private_key)).unwrap();
+
+        let signature = recoverable_signature(&priv_key, &decode(&vector.message));
+        assert_eq!(signature.signer.to_vec(), decode(&vector.eth_address));
+        assert_eq!(signature.signature.to_vec(), decode(&vector.signature));
+    }
+}
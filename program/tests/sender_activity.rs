@@ -0,0 +1,57 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    instruction::{MessageVersion, Transfer},
+    state::SenderAccount,
+};
+use solana_program_test::*;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+const TRANSFER_SLOT: u64 = 100;
+
+#[tokio::test]
+async fn success_transfer_records_attestations() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+
+    let recipient_eth_key = [7u8; 20];
+    let recipient = fixture
+        .create_recipient(&mut context, recipient_eth_key)
+        .await;
+    let transfer = Transfer {
+        amount: TRANSFER_AMOUNT,
+        id: String::from("4r4t23df32543f55"),
+        eth_recipient: recipient_eth_key,
+        message_version: MessageVersion::V2,
+        create_recipient: false,
+        memo: false,
+    };
+
+    context.warp_to_slot(TRANSFER_SLOT);
+
+    let tx = Transaction::new_signed_with_payer(
+        &fixture.transfer_instructions(&context.payer.pubkey(), &recipient, transfer),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    for sender in fixture.senders.iter() {
+        let sender_data = context
+            .banks_client
+            .get_account_data_with_borsh::<SenderAccount>(sender.account)
+            .await
+            .unwrap();
+        assert_eq!(sender_data.attestation_count, 1);
+        assert!(sender_data.last_attested_slot >= TRANSFER_SLOT);
+    }
+}
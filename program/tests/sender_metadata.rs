@@ -0,0 +1,198 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::SENDER_METADATA_MESSAGE_PREFIX,
+    state::{RewardManager, SenderAccount},
+    utils::{endpoint_hash, get_sender_address, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    secp256k1_instruction::construct_eth_pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const OPERATOR: EthereumAddress = [9u8; 20];
+
+/// Reward manager with a sender registered for `sender_key`
+async fn start(
+    reward_manager: &Pubkey,
+    sender_key: &SecretKey,
+) -> (ProgramTestContext, EthereumAddress) {
+    let manager_account = Keypair::new();
+    let mut program_test = program_test();
+    program_test.add_account(
+        *reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+
+    let eth_address = construct_eth_pubkey(&PublicKey::from_secret_key(sender_key));
+    create_sender(
+        &mut context,
+        reward_manager,
+        &manager_account,
+        eth_address,
+        OPERATOR,
+    )
+    .await;
+
+    (context, eth_address)
+}
+
+fn update_instructions(
+    reward_manager: &Pubkey,
+    eth_address: EthereumAddress,
+    signer_key: &SecretKey,
+    current_endpoint_hash: [u8; 32],
+    endpoint_hash: [u8; 32],
+) -> Vec<Instruction> {
+    let message = [
+        SENDER_METADATA_MESSAGE_PREFIX.as_bytes(),
+        reward_manager.as_ref(),
+        current_endpoint_hash.as_ref(),
+        endpoint_hash.as_ref(),
+    ]
+    .concat();
+
+    vec![
+        new_secp256k1_instruction_2_0(signer_key, message.as_ref(), 0),
+        instruction::update_sender_metadata(
+            &audius_reward_manager::id(),
+            reward_manager,
+            eth_address,
+            endpoint_hash,
+        )
+        .unwrap(),
+    ]
+}
+
+async fn get_sender(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+    eth_address: EthereumAddress,
+) -> SenderAccount {
+    let (sender, _) =
+        get_sender_address(&audius_reward_manager::id(), reward_manager, &eth_address);
+    context
+        .banks_client
+        .get_account_data_with_borsh(sender)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn success() {
+    let reward_manager = Pubkey::new_unique();
+    let sender_key = SecretKey::parse(&thread_rng().gen()).unwrap();
+    let (mut context, eth_address) = start(&reward_manager, &sender_key).await;
+
+    let first = endpoint_hash("https://discovery-1.audius.co");
+    let second = endpoint_hash("https://discovery-2.audius.co");
+    for (current, new) in [([0u8; 32], first), (first, second)].iter() {
+        let tx = Transaction::new_signed_with_payer(
+            &update_instructions(&reward_manager, eth_address, &sender_key, *current, *new),
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let sender = get_sender(&mut context, &reward_manager, eth_address).await;
+        assert_eq!(sender.endpoint_hash, *new);
+    }
+}
+
+#[tokio::test]
+async fn fail_signed_by_other_key() {
+    let mut rng = thread_rng();
+    let reward_manager = Pubkey::new_unique();
+    let sender_key = SecretKey::parse(&rng.gen()).unwrap();
+    let (mut context, eth_address) = start(&reward_manager, &sender_key).await;
+
+    let other_key = SecretKey::parse(&rng.gen()).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &update_instructions(
+            &reward_manager,
+            eth_address,
+            &other_key,
+            [0u8; 32],
+            endpoint_hash("https://discovery-1.audius.co"),
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(AudiusProgramError::WrongSigner as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_signed_over_stale_hash() {
+    let reward_manager = Pubkey::new_unique();
+    let sender_key = SecretKey::parse(&thread_rng().gen()).unwrap();
+    let (mut context, eth_address) = start(&reward_manager, &sender_key).await;
+
+    let first = endpoint_hash("https://discovery-1.audius.co");
+    let tx = Transaction::new_signed_with_payer(
+        &update_instructions(&reward_manager, eth_address, &sender_key, [0u8; 32], first),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // NOTE: signed over the zero hash the sender had before the first update
+    let tx = Transaction::new_signed_with_payer(
+        &update_instructions(
+            &reward_manager,
+            eth_address,
+            &sender_key,
+            [0u8; 32],
+            endpoint_hash("https://discovery-2.audius.co"),
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(AudiusProgramError::SignatureVerificationFailed as _)
+        )
+    );
+}
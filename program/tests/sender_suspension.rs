@@ -0,0 +1,126 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{self, MessageVersion, Transfer},
+    state::{SenderAccount, SenderStatus},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+/// Funds the vault for a single transfer and suspends the first sender
+async fn start_with_suspended_sender() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, [7u8; 20]).await;
+
+    let tx = set_status(&context, &fixture, true);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    (context, fixture, recipient)
+}
+
+fn set_status(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    suspended: bool,
+) -> Transaction {
+    let builder = if suspended {
+        instruction::suspend_sender
+    } else {
+        instruction::reinstate_sender
+    };
+    Transaction::new_signed_with_payer(
+        &[builder(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            fixture.senders[0].eth_address,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    )
+}
+
+fn transfer_instructions(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    recipient: &Pubkey,
+) -> Vec<Instruction> {
+    fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        recipient,
+        Transfer {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: [7u8; 20],
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        },
+    )
+}
+
+#[tokio::test]
+async fn fail_suspended_sender() {
+    let (context, fixture, recipient) = start_with_suspended_sender().await;
+
+    let sender_data = context
+        .banks_client
+        .get_account_data_with_borsh::<SenderAccount>(fixture.senders[0].account)
+        .await
+        .unwrap();
+    assert_eq!(sender_data.status, SenderStatus::Suspended);
+
+    let instructions = transfer_instructions(&context, &fixture, &recipient);
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::SenderSuspended as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_reinstated_sender() {
+    let (context, fixture, recipient) = start_with_suspended_sender().await;
+
+    let tx = set_status(&context, &fixture, false);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &transfer_instructions(&context, &fixture, &recipient),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
@@ -0,0 +1,413 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::ORACLE_SEED_PREFIX,
+    state::{RewardManager, SenderAccount},
+    utils::{get_address_pair, get_sender_address, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    secp256k1_instruction::*,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+fn set_weight(
+    context: &ProgramTestContext,
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+    eth_address: EthereumAddress,
+    weight: u16,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::set_sender_weight(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &manager_account.pubkey(),
+            eth_address,
+            weight,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, manager_account],
+        context.last_blockhash,
+    )
+}
+
+/// Registers bot oracle and three senders, requires the vote weight of four and
+/// returns the attestation instructions followed by the transfer instruction.
+/// The first sender weighs two if `heavy_sender` is set
+async fn prepare_transfer(
+    context: &mut ProgramTestContext,
+    heavy_sender: bool,
+) -> Vec<Instruction> {
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &manager_account.pubkey(),
+            instruction::UpdateRewardManager {
+                min_vote_weight: Some(4),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let key: [u8; 32] = rng.gen();
+    let oracle_priv_key = SecretKey::parse(&key).unwrap();
+    let eth_oracle_address = construct_eth_pubkey(&PublicKey::from_secret_key(&oracle_priv_key));
+    let oracle = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+    )
+    .unwrap();
+    create_oracle(
+        context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        eth_oracle_address,
+        rng.gen(),
+    )
+    .await;
+
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let mut senders = Vec::new();
+    for key in keys.iter() {
+        let eth_address =
+            construct_eth_pubkey(&PublicKey::from_secret_key(&SecretKey::parse(key).unwrap()));
+        senders.push(
+            get_sender_address(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &eth_address,
+            )
+            .0,
+        );
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            rng.gen(),
+        )
+        .await;
+
+        if heavy_sender && senders.len() == 1 {
+            let tx = set_weight(
+                context,
+                &reward_manager.pubkey(),
+                &manager_account,
+                eth_address,
+                2,
+            );
+            context.banks_client.process_transaction(tx).await.unwrap();
+        }
+    }
+
+    mint_tokens_to(
+        context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        TRANSFER_AMOUNT,
+    )
+    .await
+    .unwrap();
+
+    let recipient_eth_key = [7u8; 20];
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        &mint.pubkey(),
+        recipient_eth_key,
+    )
+    .unwrap()
+    .derive
+    .address;
+    create_recipient_with_claimable_program(context, &mint.pubkey(), recipient_eth_key).await;
+
+    let transfer_id = "4r4t23df32543f55";
+
+    let bot_oracle_message = [
+        recipient_eth_key.as_ref(),
+        b"_",
+        TRANSFER_AMOUNT.to_le_bytes().as_ref(),
+        b"_",
+        transfer_id.as_ref(),
+    ]
+    .concat();
+    let senders_message = [
+        bot_oracle_message.as_ref(),
+        b"_",
+        eth_oracle_address.as_ref(),
+    ]
+    .concat();
+
+    let mut instructions = vec![new_secp256k1_instruction_2_0(
+        &oracle_priv_key,
+        bot_oracle_message.as_ref(),
+        0,
+    )];
+    for key in keys.iter() {
+        instructions.push(new_secp256k1_instruction_2_0(
+            &SecretKey::parse(key).unwrap(),
+            senders_message.as_ref(),
+            instructions.len() as _,
+        ));
+    }
+
+    instructions.push(
+        instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &recipient,
+            &token_account.pubkey(),
+            &oracle.derive.address,
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            senders,
+            instruction::Transfer {
+                amount: TRANSFER_AMOUNT,
+                id: String::from(transfer_id),
+                eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap(),
+    );
+
+    instructions
+}
+
+#[tokio::test]
+async fn success_set_weight() {
+    let mut program_test = program_test();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let eth_address = [1u8; 20];
+    create_sender(
+        &mut context,
+        &reward_manager,
+        &manager_account,
+        eth_address,
+        [2u8; 20],
+    )
+    .await;
+
+    let tx = set_weight(&context, &reward_manager, &manager_account, eth_address, 5);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (sender, _) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
+    let sender_data = context
+        .banks_client
+        .get_account_data_with_borsh::<SenderAccount>(sender)
+        .await
+        .unwrap();
+    assert_eq!(sender_data.weight, 5);
+}
+
+#[tokio::test]
+async fn fail_unweighted_sender() {
+    let mut program_test = program_test();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let eth_address = [1u8; 20];
+    let (sender, bump) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
+    let sender_data = SenderAccount::new(reward_manager, eth_address, [2u8; 20], bump)
+        .try_to_vec()
+        .unwrap();
+    program_test.add_account(
+        sender,
+        Account {
+            lamports: 9000,
+            data: sender_data[..SenderAccount::UNWEIGHTED_LEN].to_vec(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let context = program_test.start_with_context().await;
+    let tx = set_weight(&context, &reward_manager, &manager_account, eth_address, 5);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::InvalidAccountSize as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_set_weight_untracked_sender() {
+    let mut program_test = program_test();
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), manager_account.pubkey(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let eth_address = [1u8; 20];
+    let (sender, bump) =
+        get_sender_address(&audius_reward_manager::id(), &reward_manager, &eth_address);
+    let sender_data = SenderAccount::new(reward_manager, eth_address, [2u8; 20], bump)
+        .try_to_vec()
+        .unwrap();
+    program_test.add_account(
+        sender,
+        Account {
+            lamports: 9000,
+            data: sender_data[..SenderAccount::UNTRACKED_LEN].to_vec(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = set_weight(&context, &reward_manager, &manager_account, eth_address, 5);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let sender_account = get_account(&mut context, &sender).await.unwrap();
+    assert_eq!(sender_account.data.len(), SenderAccount::UNTRACKED_LEN);
+    let sender_data = SenderAccount::unpack(&sender_account.data).unwrap();
+    assert_eq!(sender_data.weight, 5);
+    assert_eq!(sender_data.attestation_count, 0);
+}
+
+#[tokio::test]
+async fn success_transfer_with_vote_weight() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let instructions = prepare_transfer(&mut context, true).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn fail_not_enough_vote_weight() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let instructions = prepare_transfer(&mut context, false).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            4,
+            InstructionError::Custom(AudiusProgramError::NotEnoughVoteWeight as _)
+        )
+    );
+}
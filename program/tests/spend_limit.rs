@@ -0,0 +1,328 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::{ORACLE_SEED_PREFIX, SPEND_COUNTER_SEED_PREFIX},
+    state::SpendCounter,
+    utils::{get_address_pair, get_sender_address, EthereumAddress},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    secp256k1_instruction::*,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+async fn set_spend_limit(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+    manager_account: &Keypair,
+    budget: u64,
+    window_slots: u64,
+) {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::set_spend_limit(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &manager_account.pubkey(),
+            &context.payer.pubkey(),
+            instruction::SetSpendLimit {
+                budget,
+                window_slots,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, manager_account],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Registers bot oracle and three senders, limits spending to `budget`, funds the vault
+/// and returns the attestation instructions followed by the transfer instruction
+async fn prepare_transfer(context: &mut ProgramTestContext, budget: u64) -> Vec<Instruction> {
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    set_spend_limit(
+        context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        budget,
+        0,
+    )
+    .await;
+
+    let key: [u8; 32] = rng.gen();
+    let oracle_priv_key = SecretKey::parse(&key).unwrap();
+    let eth_oracle_address = construct_eth_pubkey(&PublicKey::from_secret_key(&oracle_priv_key));
+    let oracle = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+    )
+    .unwrap();
+    create_oracle(
+        context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        eth_oracle_address,
+        rng.gen(),
+    )
+    .await;
+
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let operators: [EthereumAddress; 3] = rng.gen();
+    let mut signers = Vec::new();
+    for (key, operator) in keys.iter().zip(operators.iter()) {
+        let eth_address =
+            construct_eth_pubkey(&PublicKey::from_secret_key(&SecretKey::parse(key).unwrap()));
+        signers.push(
+            get_sender_address(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &eth_address,
+            )
+            .0,
+        );
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            *operator,
+        )
+        .await;
+    }
+
+    mint_tokens_to(
+        context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        TRANSFER_AMOUNT,
+    )
+    .await
+    .unwrap();
+
+    let recipient_eth_key = [7u8; 20];
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        &mint.pubkey(),
+        recipient_eth_key,
+    )
+    .unwrap()
+    .derive
+    .address;
+    create_recipient_with_claimable_program(context, &mint.pubkey(), recipient_eth_key).await;
+
+    let transfer_id = "4r4t23df32543f55";
+
+    let bot_oracle_message = [
+        recipient_eth_key.as_ref(),
+        b"_",
+        TRANSFER_AMOUNT.to_le_bytes().as_ref(),
+        b"_",
+        transfer_id.as_ref(),
+    ]
+    .concat();
+    let senders_message = [
+        bot_oracle_message.as_ref(),
+        b"_",
+        eth_oracle_address.as_ref(),
+    ]
+    .concat();
+
+    let mut instructions = vec![new_secp256k1_instruction_2_0(
+        &oracle_priv_key,
+        bot_oracle_message.as_ref(),
+        0,
+    )];
+    for (index, key) in keys.iter().enumerate() {
+        instructions.push(new_secp256k1_instruction_2_0(
+            &SecretKey::parse(key).unwrap(),
+            senders_message.as_ref(),
+            (index + 1) as _,
+        ));
+    }
+
+    instructions.push(
+        instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &recipient,
+            &token_account.pubkey(),
+            &oracle.derive.address,
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            signers,
+            instruction::Transfer {
+                amount: TRANSFER_AMOUNT,
+                id: String::from(transfer_id),
+                eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap(),
+    );
+
+    instructions
+}
+
+#[tokio::test]
+async fn success_set_spend_limit() {
+    let mut context = program_test().start_with_context().await;
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    create_mint(
+        &mut context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &manager_account.pubkey(),
+    )
+    .await
+    .unwrap();
+    init_reward_manager(
+        &mut context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let spend_counter = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        SPEND_COUNTER_SEED_PREFIX.as_bytes().to_vec(),
+    )
+    .unwrap()
+    .derive
+    .address;
+
+    set_spend_limit(
+        &mut context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        TRANSFER_AMOUNT,
+        100,
+    )
+    .await;
+    assert_eq!(
+        SpendCounter::new(reward_manager.pubkey(), TRANSFER_AMOUNT, 100),
+        context
+            .banks_client
+            .get_account_data_with_borsh(spend_counter)
+            .await
+            .unwrap()
+    );
+
+    set_spend_limit(
+        &mut context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        TRANSFER_AMOUNT * 2,
+        0,
+    )
+    .await;
+    assert_eq!(
+        SpendCounter::new(reward_manager.pubkey(), TRANSFER_AMOUNT * 2, 0),
+        context
+            .banks_client
+            .get_account_data_with_borsh(spend_counter)
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn success_transfer_within_budget() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let instructions = prepare_transfer(&mut context, TRANSFER_AMOUNT).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn fail_transfer_over_budget() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let instructions = prepare_transfer(&mut context, TRANSFER_AMOUNT - 1).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            4,
+            InstructionError::Custom(AudiusProgramError::SpendLimitExceeded as _)
+        )
+    );
+}
@@ -6,11 +6,10 @@ use std::mem::MaybeUninit;
 use assert::*;
 use audius_reward_manager::{
     error::AudiusProgramError,
-    instruction,
-    processor::{
-        SENDER_SEED_PREFIX, TRANSFER_ACC_BALANCE, TRANSFER_ACC_SPACE, TRANSFER_SEED_PREFIX,
-    },
-    utils::{get_address_pair, EthereumAddress},
+    get_transfer_account_address, instruction,
+    processor::ORACLE_SEED_PREFIX,
+    state::{RewardManager, TransferReceipt},
+    utils::{get_address_pair, get_sender_address, EthereumAddress},
 };
 use num_traits::FromPrimitive;
 use rand::{thread_rng, Rng};
@@ -22,7 +21,6 @@ use solana_sdk::{
     secp256k1_instruction::*,
     signature::Keypair,
     signer::Signer,
-    system_instruction::SystemError,
     transaction::{Transaction, TransactionError},
     transport::TransportError,
 };
@@ -74,10 +72,10 @@ async fn transfer_test() {
     let oracle = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -95,14 +93,13 @@ async fn transfer_test() {
         let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
         let eth_address = construct_eth_pubkey(&secp_pubkey);
 
-        let pair = get_address_pair(
+        let (sender, _) = get_sender_address(
             &audius_reward_manager::id(),
             &reward_manager.pubkey(),
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-        )
-        .unwrap();
+            &eth_address,
+        );
 
-        signers[item.0] = pair.derive.address;
+        signers[item.0] = sender;
     }
 
     for item in keys.iter().enumerate() {
@@ -183,11 +180,15 @@ async fn transfer_test() {
             &token_account.pubkey(),
             &oracle.derive.address,
             &context.payer.pubkey(),
+            &Pubkey::new_unique(),
             std::array::IntoIter::new(signers),
             instruction::Transfer {
                 amount: tokens_amount,
                 id: String::from(transfer_id),
                 eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
             },
         )
         .unwrap(),
@@ -202,23 +203,34 @@ async fn transfer_test() {
 
     context.banks_client.process_transaction(tx).await.unwrap();
 
-    let transfer_acc_created = get_address_pair(
+    // NOTE: the receipt is created where clients look it up
+    let transfer_acc_created = get_transfer_account_address(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [
-            TRANSFER_SEED_PREFIX.as_bytes().as_ref(),
-            transfer_id.as_ref(),
-        ]
-        .concat(),
+        transfer_id,
     )
     .unwrap();
 
-    let transfer_acc_data = get_account(&mut context, &transfer_acc_created.derive.address)
+    let transfer_acc_data = get_account(&mut context, &transfer_acc_created)
         .await
         .unwrap();
+    assert_eq!(transfer_acc_data.data.len(), TransferReceipt::LEN);
 
-    assert_eq!(transfer_acc_data.lamports, TRANSFER_ACC_BALANCE as u64);
-    assert_eq!(transfer_acc_data.data.len() as u8, TRANSFER_ACC_SPACE);
+    let receipt: TransferReceipt = context
+        .banks_client
+        .get_account_data_with_borsh(transfer_acc_created)
+        .await
+        .unwrap();
+    assert_eq!(receipt.recipient, recipient_sol_key.derive.address);
+    assert_eq!(receipt.amount, tokens_amount);
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.total_disbursed, tokens_amount);
+    assert_eq!(reward_manager_data.transfer_count, 1);
 }
 
 #[tokio::test]
@@ -263,12 +275,11 @@ async fn secp_missing() {
     let eth_address_1 = construct_eth_pubkey(&secp_pubkey);
     let operator_1: EthereumAddress = rng.gen();
 
-    let first_sender = get_address_pair(
+    let (first_sender, _) = get_sender_address(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_address_1.as_ref()].concat(),
-    )
-    .unwrap();
+        &eth_address_1,
+    );
     create_sender(
         &mut context,
         &reward_manager.pubkey(),
@@ -288,10 +299,10 @@ async fn secp_missing() {
     let second_sender = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -345,11 +356,15 @@ async fn secp_missing() {
                 &token_account.pubkey(),
                 &second_sender.derive.address,
                 &context.payer.pubkey(),
-                vec![first_sender.derive.address],
+                &Pubkey::new_unique(),
+                vec![first_sender],
                 instruction::Transfer {
                     amount: tokens_amount,
                     id: String::from(transfer_id),
                     eth_recipient: recipient_eth_key,
+                    message_version: instruction::MessageVersion::V1,
+                    create_recipient: false,
+                    memo: false,
                 },
             )
             .unwrap(),
@@ -420,10 +435,10 @@ async fn different_oracles_mentiones() {
     let oracle = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -441,14 +456,13 @@ async fn different_oracles_mentiones() {
         let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
         let eth_address = construct_eth_pubkey(&secp_pubkey);
 
-        let pair = get_address_pair(
+        let (sender, _) = get_sender_address(
             &audius_reward_manager::id(),
             &reward_manager.pubkey(),
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-        )
-        .unwrap();
+            &eth_address,
+        );
 
-        signers[item.0] = pair.derive.address;
+        signers[item.0] = sender;
     }
 
     for item in keys.iter().enumerate() {
@@ -545,11 +559,15 @@ async fn different_oracles_mentiones() {
             &token_account.pubkey(),
             &oracle.derive.address,
             &context.payer.pubkey(),
+            &Pubkey::new_unique(),
             std::array::IntoIter::new(signers),
             instruction::Transfer {
                 amount: tokens_amount,
                 id: String::from(transfer_id),
                 eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
             },
         )
         .unwrap(),
@@ -618,12 +636,11 @@ async fn oracle_sign_missing() {
     let eth_address_1 = construct_eth_pubkey(&secp_pubkey);
     let operator_1: EthereumAddress = rng.gen();
 
-    let first_sender = get_address_pair(
+    let (first_sender, _) = get_sender_address(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_address_1.as_ref()].concat(),
-    )
-    .unwrap();
+        &eth_address_1,
+    );
     create_sender(
         &mut context,
         &reward_manager.pubkey(),
@@ -643,10 +660,10 @@ async fn oracle_sign_missing() {
     let second_sender = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_address_2.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_address_2.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -702,11 +719,15 @@ async fn oracle_sign_missing() {
                 &token_account.pubkey(),
                 &second_sender.derive.address,
                 &context.payer.pubkey(),
-                vec![first_sender.derive.address],
+                &Pubkey::new_unique(),
+                vec![first_sender],
                 instruction::Transfer {
                     amount: tokens_amount,
                     id: String::from(transfer_id),
                     eth_recipient: recipient_eth_key,
+                    message_version: instruction::MessageVersion::V1,
+                    create_recipient: false,
+                    memo: false,
                 },
             )
             .unwrap(),
@@ -776,10 +797,10 @@ async fn repeating_operators() {
     let oracle = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -797,14 +818,13 @@ async fn repeating_operators() {
         let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
         let eth_address = construct_eth_pubkey(&secp_pubkey);
 
-        let pair = get_address_pair(
+        let (sender, _) = get_sender_address(
             &audius_reward_manager::id(),
             &reward_manager.pubkey(),
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-        )
-        .unwrap();
+            &eth_address,
+        );
 
-        signers[item.0] = pair.derive.address;
+        signers[item.0] = sender;
     }
 
     for key in &keys {
@@ -885,11 +905,15 @@ async fn repeating_operators() {
             &token_account.pubkey(),
             &oracle.derive.address,
             &context.payer.pubkey(),
+            &Pubkey::new_unique(),
             std::array::IntoIter::new(signers),
             instruction::Transfer {
                 amount: tokens_amount,
                 id: String::from(transfer_id),
                 eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
             },
         )
         .unwrap(),
@@ -962,10 +986,10 @@ async fn repeating_ids() {
     let oracle = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -983,14 +1007,13 @@ async fn repeating_ids() {
         let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
         let eth_address = construct_eth_pubkey(&secp_pubkey);
 
-        let pair = get_address_pair(
+        let (sender, _) = get_sender_address(
             &audius_reward_manager::id(),
             &reward_manager.pubkey(),
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-        )
-        .unwrap();
+            &eth_address,
+        );
 
-        signers[item.0] = pair.derive.address;
+        signers[item.0] = sender;
     }
 
     for item in keys.iter().enumerate() {
@@ -1071,11 +1094,15 @@ async fn repeating_ids() {
             &token_account.pubkey(),
             &oracle.derive.address,
             &context.payer.pubkey(),
+            &Pubkey::new_unique(),
             std::array::IntoIter::new(signers),
             instruction::Transfer {
                 amount: tokens_amount,
                 id: String::from(transfer_id),
                 eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
             },
         )
         .unwrap(),
@@ -1112,7 +1139,7 @@ async fn repeating_ids() {
             .unwrap(),
         TransactionError::InstructionError(
             4,
-            InstructionError::Custom(SystemError::AccountAlreadyInUse as _)
+            InstructionError::Custom(AudiusProgramError::AlreadyClaimed as _)
         )
     );
 }
@@ -1163,10 +1190,10 @@ async fn fail_different_amounts_in_sender_messages() {
     let oracle = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -1184,14 +1211,13 @@ async fn fail_different_amounts_in_sender_messages() {
         let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
         let eth_address = construct_eth_pubkey(&secp_pubkey);
 
-        let pair = get_address_pair(
+        let (sender, _) = get_sender_address(
             &audius_reward_manager::id(),
             &reward_manager.pubkey(),
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-        )
-        .unwrap();
+            &eth_address,
+        );
 
-        signers[item.0] = pair.derive.address;
+        signers[item.0] = sender;
     }
 
     for item in keys.iter().enumerate() {
@@ -1272,11 +1298,15 @@ async fn fail_different_amounts_in_sender_messages() {
             &token_account.pubkey(),
             &oracle.derive.address,
             &context.payer.pubkey(),
+            &Pubkey::new_unique(),
             std::array::IntoIter::new(signers),
             instruction::Transfer {
                 amount: tokens_amount,
                 id: String::from(transfer_id),
                 eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
             },
         )
         .unwrap(),
@@ -1341,10 +1371,10 @@ async fn fail_different_amount_in_oracle_message() {
     let oracle = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -1362,14 +1392,13 @@ async fn fail_different_amount_in_oracle_message() {
         let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
         let eth_address = construct_eth_pubkey(&secp_pubkey);
 
-        let pair = get_address_pair(
+        let (sender, _) = get_sender_address(
             &audius_reward_manager::id(),
             &reward_manager.pubkey(),
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-        )
-        .unwrap();
+            &eth_address,
+        );
 
-        signers[item.0] = pair.derive.address;
+        signers[item.0] = sender;
     }
 
     for item in keys.iter().enumerate() {
@@ -1450,11 +1479,15 @@ async fn fail_different_amount_in_oracle_message() {
             &token_account.pubkey(),
             &oracle.derive.address,
             &context.payer.pubkey(),
+            &Pubkey::new_unique(),
             std::array::IntoIter::new(signers),
             instruction::Transfer {
                 amount: tokens_amount,
                 id: String::from(transfer_id),
                 eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
             },
         )
         .unwrap(),
@@ -1526,10 +1559,10 @@ async fn fail_different_bot_oracle_in_sender_messages() {
     let oracle = get_address_pair(
         &audius_reward_manager::id(),
         &reward_manager.pubkey(),
-        [SENDER_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
     )
     .unwrap();
-    create_sender(
+    create_oracle(
         &mut context,
         &reward_manager.pubkey(),
         &manager_account,
@@ -1547,14 +1580,13 @@ async fn fail_different_bot_oracle_in_sender_messages() {
         let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
         let eth_address = construct_eth_pubkey(&secp_pubkey);
 
-        let pair = get_address_pair(
+        let (sender, _) = get_sender_address(
             &audius_reward_manager::id(),
             &reward_manager.pubkey(),
-            [SENDER_SEED_PREFIX.as_ref(), eth_address.as_ref()].concat(),
-        )
-        .unwrap();
+            &eth_address,
+        );
 
-        signers[item.0] = pair.derive.address;
+        signers[item.0] = sender;
     }
 
     for item in keys.iter().enumerate() {
@@ -1635,11 +1667,15 @@ async fn fail_different_bot_oracle_in_sender_messages() {
             &token_account.pubkey(),
             &oracle.derive.address,
             &context.payer.pubkey(),
+            &Pubkey::new_unique(),
             std::array::IntoIter::new(signers),
             instruction::Transfer {
                 amount: tokens_amount,
                 id: String::from(transfer_id),
                 eth_recipient: recipient_eth_key,
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
             },
         )
         .unwrap(),
@@ -0,0 +1,307 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use std::mem::MaybeUninit;
+
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    get_transfer_account_address, instruction,
+    processor::{ORACLE_SEED_PREFIX, TRANSFER_BATCH_MESSAGE_PREFIX},
+    state::RewardManager,
+    utils::{get_address_pair, get_sender_address, transfer_batch_digest, EthereumAddress},
+};
+use borsh::BorshSerialize;
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    secp256k1_instruction::*,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+#[tokio::test]
+async fn success() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut rng = thread_rng();
+
+    let mut context = program_test.start_with_context().await;
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        &mut context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        &mut context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3 as u8,
+    )
+    .await;
+
+    // Generate data and create oracle
+    let key: [u8; 32] = rng.gen();
+    let oracle_priv_key = SecretKey::parse(&key).unwrap();
+    let secp_oracle_pubkey = PublicKey::from_secret_key(&oracle_priv_key);
+    let eth_oracle_address = construct_eth_pubkey(&secp_oracle_pubkey);
+    let oracle_operator: EthereumAddress = rng.gen();
+
+    let oracle = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+    )
+    .unwrap();
+    create_oracle(
+        &mut context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        eth_oracle_address,
+        oracle_operator,
+    )
+    .await;
+
+    // Generate data and create senders
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let operators: [EthereumAddress; 3] = rng.gen();
+    let mut signers: [Pubkey; 3] = unsafe { MaybeUninit::zeroed().assume_init() };
+    for item in keys.iter().enumerate() {
+        let sender_priv_key = SecretKey::parse(item.1).unwrap();
+        let secp_pubkey = PublicKey::from_secret_key(&sender_priv_key);
+        let eth_address = construct_eth_pubkey(&secp_pubkey);
+
+        let (sender, _) = get_sender_address(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &eth_address,
+        );
+
+        signers[item.0] = sender;
+
+        create_sender(
+            &mut context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            operators[item.0],
+        )
+        .await;
+    }
+
+    mint_tokens_to(
+        &mut context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        10_000,
+    )
+    .await
+    .unwrap();
+
+    let recipients: [EthereumAddress; 2] = [[7u8; 20], [8u8; 20]];
+    let mut recipient_keys = Vec::new();
+    for recipient_eth_key in recipients.iter() {
+        recipient_keys.push(
+            claimable_tokens::utils::program::get_address_pair(
+                &claimable_tokens::id(),
+                &mint.pubkey(),
+                *recipient_eth_key,
+            )
+            .unwrap()
+            .derive
+            .address,
+        );
+        create_recipient_with_claimable_program(&mut context, &mint.pubkey(), *recipient_eth_key)
+            .await;
+    }
+
+    let transfers = vec![
+        instruction::Transfer {
+            amount: 1_000,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: recipients[0],
+            message_version: instruction::MessageVersion::V1,
+            create_recipient: false,
+            memo: false,
+        },
+        instruction::Transfer {
+            amount: 2_000,
+            id: String::from("9f8e7d6c5b4a3921"),
+            eth_recipient: recipients[1],
+            message_version: instruction::MessageVersion::V1,
+            create_recipient: false,
+            memo: false,
+        },
+    ];
+
+    let bot_oracle_message = [
+        TRANSFER_BATCH_MESSAGE_PREFIX.as_bytes(),
+        transfer_batch_digest(&transfers).unwrap().as_ref(),
+    ]
+    .concat();
+    let senders_message = [
+        bot_oracle_message.as_ref(),
+        b"_",
+        eth_oracle_address.as_ref(),
+    ]
+    .concat();
+
+    let mut instructions = Vec::<Instruction>::new();
+
+    let oracle_sign =
+        new_secp256k1_instruction_2_0(&oracle_priv_key, bot_oracle_message.as_ref(), 0);
+    instructions.push(oracle_sign);
+
+    let iter = keys.iter().enumerate().map(|i| (i.0 + 1, i.1));
+    for item in iter {
+        let priv_key = SecretKey::parse(item.1).unwrap();
+        let inst = new_secp256k1_instruction_2_0(&priv_key, senders_message.as_ref(), item.0 as _);
+        instructions.push(inst);
+    }
+
+    instructions.push(
+        instruction::transfer_batch(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &oracle.derive.address,
+            &context.payer.pubkey(),
+            &Pubkey::default(),
+            std::array::IntoIter::new(signers),
+            transfers.clone(),
+        )
+        .unwrap(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let first_recipient: spl_token::state::Account = context
+        .banks_client
+        .get_packed_account_data(recipient_keys[0])
+        .await
+        .unwrap();
+    assert_eq!(first_recipient.amount, 1_000);
+
+    let second_recipient: spl_token::state::Account = context
+        .banks_client
+        .get_packed_account_data(recipient_keys[1])
+        .await
+        .unwrap();
+    assert_eq!(second_recipient.amount, 2_000);
+
+    for transfer in transfers.iter() {
+        let receipt = get_transfer_account_address(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &transfer.id,
+        )
+        .unwrap();
+        assert!(get_account(&mut context, &receipt).await.is_some());
+    }
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.total_disbursed, 3_000);
+    assert_eq!(reward_manager_data.transfer_count, 2);
+}
+
+#[tokio::test]
+async fn fail_total_overflow() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let transfers = vec![
+        instruction::Transfer {
+            amount: u64::MAX,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: [7u8; 20],
+            message_version: instruction::MessageVersion::V1,
+            create_recipient: false,
+            memo: false,
+        },
+        instruction::Transfer {
+            amount: 1,
+            id: String::from("9f8e7d6c5b4a3921"),
+            eth_recipient: [8u8; 20],
+            message_version: instruction::MessageVersion::V1,
+            create_recipient: false,
+            memo: false,
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::transfer_batch(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &context.payer.pubkey(),
+            &Pubkey::default(),
+            vec![Pubkey::new_unique()],
+            transfers,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::MathOverflow as _)
+        )
+    );
+}
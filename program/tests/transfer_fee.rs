@@ -0,0 +1,373 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{self, MessageVersion, SetTransferFee, Transfer, TransferToWallet},
+    state::{RewardManager, MAX_TRANSFER_FEE_BPS},
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_001;
+const FEE_BPS: u16 = 250;
+const RECIPIENT: [u8; 20] = [7u8; 20];
+
+/// Funds the vault for a single transfer to `RECIPIENT` and creates a token
+/// account of the vault mint to receive the fee
+async fn start() -> (ProgramTestContext, TestRewardManager, Pubkey, Pubkey) {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    let fee_account = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    create_token_account(
+        &mut context,
+        &fee_account,
+        &fixture.mint,
+        &Pubkey::new_unique(),
+        &rent,
+    )
+    .await
+    .unwrap();
+
+    (context, fixture, recipient, fee_account.pubkey())
+}
+
+fn set_fee(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    fee_account: &Pubkey,
+    fee_bps: u16,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::set_transfer_fee(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            &fixture.token_account,
+            fee_account,
+            SetTransferFee { fee_bps },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    )
+}
+
+fn transfer_instructions(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    recipient: &Pubkey,
+) -> Vec<Instruction> {
+    fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        recipient,
+        Transfer {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: RECIPIENT,
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        },
+    )
+}
+
+async fn token_balance(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    context
+        .banks_client
+        .get_packed_account_data::<spl_token::state::Account>(account)
+        .await
+        .unwrap()
+        .amount
+}
+
+#[test]
+fn fee_rounds_down() {
+    let mut reward_manager = RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3);
+    assert_eq!(reward_manager.transfer_fee(1_000), 0);
+
+    reward_manager.transfer_fee_bps = FEE_BPS;
+    assert_eq!(reward_manager.transfer_fee(10_000), 250);
+    assert_eq!(reward_manager.transfer_fee(10_039), 250);
+    assert_eq!(reward_manager.transfer_fee(39), 0);
+    assert_eq!(reward_manager.transfer_fee(40), 1);
+}
+
+#[test]
+fn fee_does_not_overflow() {
+    let mut reward_manager = RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3);
+
+    reward_manager.transfer_fee_bps = MAX_TRANSFER_FEE_BPS;
+    assert_eq!(reward_manager.transfer_fee(u64::MAX), u64::MAX);
+
+    reward_manager.transfer_fee_bps = MAX_TRANSFER_FEE_BPS - 1;
+    assert_eq!(
+        reward_manager.transfer_fee(u64::MAX),
+        (u64::MAX as u128 * 9_999 / 10_000) as u64
+    );
+    assert!(reward_manager.transfer_fee(u64::MAX) < u64::MAX);
+}
+
+#[tokio::test]
+async fn success() {
+    let (mut context, mut fixture, recipient, fee_account) = start().await;
+
+    let tx = set_fee(&context, &fixture, &fee_account, FEE_BPS);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(fixture.reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.transfer_fee_bps, FEE_BPS);
+    assert_eq!(reward_manager_data.fee_account, fee_account);
+
+    fixture.fee_account = fee_account;
+    let tx = Transaction::new_signed_with_payer(
+        &transfer_instructions(&context, &fixture, &recipient),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_balance(&mut context, fee_account).await, 250);
+    assert_eq!(
+        token_balance(&mut context, recipient).await,
+        TRANSFER_AMOUNT - 250
+    );
+    assert_eq!(token_balance(&mut context, fixture.token_account).await, 0);
+}
+
+#[tokio::test]
+async fn success_batch() {
+    let (mut context, mut fixture, recipient, fee_account) = start().await;
+    let other_recipient = fixture.create_recipient(&mut context, [8u8; 20]).await;
+
+    let tx = set_fee(&context, &fixture, &fee_account, FEE_BPS);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // NOTE: the fee is charged per transfer, each rounded down
+    fixture.fee_account = fee_account;
+    let transfers = vec![
+        Transfer {
+            amount: 4_001,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: RECIPIENT,
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        },
+        Transfer {
+            amount: TRANSFER_AMOUNT - 4_001,
+            id: String::from("9f8e7d6c5b4a3921"),
+            eth_recipient: [8u8; 20],
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        },
+    ];
+    let tx = Transaction::new_signed_with_payer(
+        &fixture.transfer_batch_instructions(&context.payer.pubkey(), transfers),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_balance(&mut context, fee_account).await, 100 + 150);
+    assert_eq!(token_balance(&mut context, recipient).await, 4_001 - 100);
+    assert_eq!(
+        token_balance(&mut context, other_recipient).await,
+        TRANSFER_AMOUNT - 4_001 - 150
+    );
+    assert_eq!(token_balance(&mut context, fixture.token_account).await, 0);
+}
+
+#[tokio::test]
+async fn success_wallet() {
+    let (mut context, mut fixture, _, fee_account) = start().await;
+
+    let wallet = Pubkey::new_unique();
+    let tx = Transaction::new_signed_with_payer(
+        &[create_associated_token_account(
+            &context.payer.pubkey(),
+            &wallet,
+            &fixture.mint,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = set_fee(&context, &fixture, &fee_account, FEE_BPS);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    fixture.fee_account = fee_account;
+    let tx = Transaction::new_signed_with_payer(
+        &fixture.transfer_to_wallet_instructions(
+            &context.payer.pubkey(),
+            TransferToWallet {
+                amount: TRANSFER_AMOUNT,
+                id: String::from("4r4t23df32543f55"),
+                wallet,
+            },
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_balance(&mut context, fee_account).await, 250);
+    assert_eq!(
+        token_balance(
+            &mut context,
+            get_associated_token_address(&wallet, &fixture.mint)
+        )
+        .await,
+        TRANSFER_AMOUNT - 250
+    );
+    assert_eq!(token_balance(&mut context, fixture.token_account).await, 0);
+}
+
+#[tokio::test]
+async fn fail_batch_wrong_fee_account() {
+    let (mut context, fixture, _, fee_account) = start().await;
+
+    let tx = set_fee(&context, &fixture, &fee_account, FEE_BPS);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let instructions = fixture.transfer_batch_instructions(
+        &context.payer.pubkey(),
+        vec![Transfer {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: RECIPIENT,
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        }],
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::WrongFeeAccount as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_wrong_fee_account() {
+    let (mut context, fixture, recipient, fee_account) = start().await;
+
+    let tx = set_fee(&context, &fixture, &fee_account, FEE_BPS);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let instructions = transfer_instructions(&context, &fixture, &recipient);
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::WrongFeeAccount as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_fee_over_whole_amount() {
+    let (mut context, fixture, _, fee_account) = start().await;
+
+    let tx = set_fee(&context, &fixture, &fee_account, MAX_TRANSFER_FEE_BPS + 1);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_max_amount() {
+    let (mut context, mut fixture, recipient, fee_account) = start().await;
+
+    let tx = set_fee(&context, &fixture, &fee_account, FEE_BPS);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    fixture.fee_account = fee_account;
+    let instructions = fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        &recipient,
+        Transfer {
+            amount: u64::MAX,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: RECIPIENT,
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        },
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::InsufficientFunding as _)
+        )
+    );
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    get_transfer_account_address,
+    instruction::{Instructions, MessageVersion, Transfer},
+    utils::MAX_TRANSFER_ID_LEN,
+};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+const RECIPIENT: [u8; 20] = [7u8; 20];
+
+fn transfer(id_len: usize) -> Transfer {
+    Transfer {
+        amount: TRANSFER_AMOUNT,
+        id: "a".repeat(id_len),
+        eth_recipient: RECIPIENT,
+        message_version: MessageVersion::V2,
+        create_recipient: false,
+        memo: false,
+    }
+}
+
+async fn start() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    (context, fixture, recipient)
+}
+
+#[test]
+fn max_id_fits_receipt_seed() {
+    let reward_manager = Pubkey::new_unique();
+    assert_eq!(MAX_TRANSFER_ID_LEN, 21);
+
+    assert!(get_transfer_account_address(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &transfer(MAX_TRANSFER_ID_LEN).id
+    )
+    .is_ok());
+    assert!(get_transfer_account_address(
+        &audius_reward_manager::id(),
+        &reward_manager,
+        &transfer(MAX_TRANSFER_ID_LEN + 1).id
+    )
+    .is_err());
+}
+
+#[tokio::test]
+async fn success_max_id() {
+    let (mut context, fixture, recipient) = start().await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &fixture.transfer_instructions(
+            &context.payer.pubkey(),
+            &recipient,
+            transfer(MAX_TRANSFER_ID_LEN),
+        ),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn fail_id_too_long() {
+    let (mut context, fixture, recipient) = start().await;
+
+    // NOTE: builders can't derive the receipt of a longer ID, only the data is replaced
+    let mut instructions = fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        &recipient,
+        transfer(MAX_TRANSFER_ID_LEN),
+    );
+    instructions.last_mut().unwrap().data =
+        Instructions::Transfer(transfer(MAX_TRANSFER_ID_LEN + 1))
+            .try_to_vec()
+            .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::TransferIdTooLong as _)
+        )
+    );
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    instruction::{self, MessageVersion, Transfer},
+    utils::transfer_memo,
+};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+const RECIPIENT: [u8; 20] = [7u8; 20];
+
+fn memo_transfer_instructions(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    recipient: &Pubkey,
+    memo_program: AccountMeta,
+) -> Vec<Instruction> {
+    let mut instructions = fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        recipient,
+        Transfer {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("listen:4r4t23df32543f55"),
+            eth_recipient: RECIPIENT,
+            message_version: MessageVersion::V2,
+            create_recipient: false,
+            memo: true,
+        },
+    );
+    instructions.last_mut().unwrap().accounts.push(memo_program);
+    instructions
+}
+
+#[test]
+fn memo_names_challenge() {
+    assert_eq!(
+        transfer_memo("listen:4r4t23df32543f55"),
+        "challenge: listen, transfer_id: listen:4r4t23df32543f55"
+    );
+    assert_eq!(
+        transfer_memo("4r4t23df32543f55"),
+        "transfer_id: 4r4t23df32543f55"
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &memo_transfer_instructions(&context, &fixture, &recipient, instruction::memo_account()),
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let recipient_data: spl_token::state::Account = context
+        .banks_client
+        .get_packed_account_data(recipient)
+        .await
+        .unwrap();
+    assert_eq!(recipient_data.amount, TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn fail_wrong_memo_program() {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    let mut context = program_test.start_with_context().await;
+
+    let fixture = TestRewardManager::builder()
+        .vault_balance(TRANSFER_AMOUNT)
+        .build(&mut context)
+        .await;
+    let recipient = fixture.create_recipient(&mut context, RECIPIENT).await;
+
+    let instructions = memo_transfer_instructions(
+        &context,
+        &fixture,
+        &recipient,
+        AccountMeta::new_readonly(spl_token::id(), false),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::IncorrectProgramId
+        )
+    );
+}
@@ -0,0 +1,263 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction,
+    processor::{ORACLE_SEED_PREFIX, WALLET_TRANSFER_MESSAGE_PREFIX},
+    utils::{get_address_pair, get_sender_address, EthereumAddress},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    rent::Rent,
+    secp256k1_instruction::*,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::{Account as TokenAccount, AccountState};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+/// Program test with the associated token account of `wallet` for `mint` in place
+fn program_test_with_wallet(mint: &Pubkey, wallet: &Pubkey) -> ProgramTest {
+    let mut program_test = program_test();
+
+    let mut data = vec![0; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            mint: *mint,
+            owner: *wallet,
+            state: AccountState::Initialized,
+            ..Default::default()
+        },
+        &mut data,
+    )
+    .unwrap();
+    program_test.add_account(
+        get_associated_token_address(wallet, mint),
+        Account {
+            lamports: Rent::default().minimum_balance(TokenAccount::LEN),
+            data,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    program_test
+}
+
+/// Registers bot oracle and three senders, funds the vault and returns the attestation
+/// instructions followed by the transfer to `wallet` instruction
+async fn prepare_transfer(
+    context: &mut ProgramTestContext,
+    mint: &Keypair,
+    wallet: &Pubkey,
+) -> Vec<Instruction> {
+    let mut rng = thread_rng();
+
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        context,
+        mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let key: [u8; 32] = rng.gen();
+    let oracle_priv_key = SecretKey::parse(&key).unwrap();
+    let eth_oracle_address = construct_eth_pubkey(&PublicKey::from_secret_key(&oracle_priv_key));
+    let oracle = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+    )
+    .unwrap();
+    create_oracle(
+        context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        eth_oracle_address,
+        rng.gen(),
+    )
+    .await;
+
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let operators: [EthereumAddress; 3] = rng.gen();
+    let mut signers = Vec::new();
+    for (key, operator) in keys.iter().zip(operators.iter()) {
+        let eth_address =
+            construct_eth_pubkey(&PublicKey::from_secret_key(&SecretKey::parse(key).unwrap()));
+        signers.push(
+            get_sender_address(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &eth_address,
+            )
+            .0,
+        );
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            *operator,
+        )
+        .await;
+    }
+
+    mint_tokens_to(
+        context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        TRANSFER_AMOUNT,
+    )
+    .await
+    .unwrap();
+
+    let transfer_id = "4r4t23df32543f55";
+
+    let bot_oracle_message = [
+        WALLET_TRANSFER_MESSAGE_PREFIX.as_ref(),
+        wallet.as_ref(),
+        b"_",
+        TRANSFER_AMOUNT.to_le_bytes().as_ref(),
+        b"_",
+        transfer_id.as_ref(),
+    ]
+    .concat();
+    let senders_message = [
+        bot_oracle_message.as_ref(),
+        b"_",
+        eth_oracle_address.as_ref(),
+    ]
+    .concat();
+
+    let mut instructions = vec![new_secp256k1_instruction_2_0(
+        &oracle_priv_key,
+        bot_oracle_message.as_ref(),
+        0,
+    )];
+    for (index, key) in keys.iter().enumerate() {
+        instructions.push(new_secp256k1_instruction_2_0(
+            &SecretKey::parse(key).unwrap(),
+            senders_message.as_ref(),
+            (index + 1) as _,
+        ));
+    }
+
+    instructions.push(
+        instruction::transfer_to_wallet(
+            &audius_reward_manager::id(),
+            &reward_manager.pubkey(),
+            &token_account.pubkey(),
+            &mint.pubkey(),
+            &oracle.derive.address,
+            &context.payer.pubkey(),
+            &Pubkey::default(),
+            signers,
+            instruction::TransferToWallet {
+                amount: TRANSFER_AMOUNT,
+                id: String::from(transfer_id),
+                wallet: *wallet,
+            },
+        )
+        .unwrap(),
+    );
+
+    instructions
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Keypair::new();
+    let wallet = Pubkey::new_unique();
+    let mut context = program_test_with_wallet(&mint.pubkey(), &wallet)
+        .start_with_context()
+        .await;
+
+    let instructions = prepare_transfer(&mut context, &mint, &wallet).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let recipient = get_associated_token_address(&wallet, &mint.pubkey());
+    let recipient_data = get_account(&mut context, &recipient).await.unwrap();
+    assert_eq!(
+        TokenAccount::unpack(recipient_data.data.as_slice())
+            .unwrap()
+            .amount,
+        TRANSFER_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn fail_not_associated_token_account() {
+    let mint = Keypair::new();
+    let wallet = Pubkey::new_unique();
+    let mut context = program_test_with_wallet(&mint.pubkey(), &wallet)
+        .start_with_context()
+        .await;
+
+    let mut instructions = prepare_transfer(&mut context, &mint, &wallet).await;
+
+    // NOTE: a token account of the wallet other than its associated one
+    let other_account = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    create_token_account(&mut context, &other_account, &mint.pubkey(), &wallet, &rent)
+        .await
+        .unwrap();
+    instructions.last_mut().unwrap().accounts[2].pubkey = other_account.pubkey();
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            4,
+            InstructionError::Custom(AudiusProgramError::WrongRecipientKey as _)
+        )
+    );
+}
@@ -0,0 +1,296 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    get_transfer_account_address, instruction,
+    instruction::{MessageVersion, RecoverableSignature, Transfer, TransferWithSignatures},
+    processor::ORACLE_SEED_PREFIX,
+    state::TransferReceipt,
+    utils::{get_address_pair, get_sender_address, EthereumAddress, MAX_RECOVERED_SIGNATURES},
+};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    secp256k1_instruction::construct_eth_pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const TRANSFER_AMOUNT: u64 = 10_000;
+
+struct Setup {
+    reward_manager: Pubkey,
+    token_account: Pubkey,
+    recipient: Pubkey,
+    oracle: Pubkey,
+    oracle_key: SecretKey,
+    senders: Vec<Pubkey>,
+    sender_keys: Vec<SecretKey>,
+    transfer: Transfer,
+}
+
+impl Setup {
+    fn bot_oracle_message(&self) -> Vec<u8> {
+        [
+            self.transfer.eth_recipient.as_ref(),
+            b"_",
+            self.transfer.amount.to_le_bytes().as_ref(),
+            b"_",
+            self.transfer.id.as_ref(),
+        ]
+        .concat()
+    }
+
+    fn sender_message(&self) -> Vec<u8> {
+        let eth_oracle_address =
+            construct_eth_pubkey(&PublicKey::from_secret_key(&self.oracle_key));
+        [
+            self.bot_oracle_message().as_ref(),
+            b"_",
+            eth_oracle_address.as_ref(),
+        ]
+        .concat()
+    }
+
+    /// Bot oracle signature followed by every sender one
+    fn signatures(&self) -> Vec<RecoverableSignature> {
+        let mut signatures = vec![recoverable_signature(
+            &self.oracle_key,
+            &self.bot_oracle_message(),
+        )];
+        for key in self.sender_keys.iter() {
+            signatures.push(recoverable_signature(key, &self.sender_message()));
+        }
+        signatures
+    }
+
+    fn instruction(&self, payer: &Pubkey, signatures: Vec<RecoverableSignature>) -> Instruction {
+        instruction::transfer_with_signatures(
+            &audius_reward_manager::id(),
+            &self.reward_manager,
+            &self.recipient,
+            &self.token_account,
+            &self.oracle,
+            payer,
+            &Pubkey::new_unique(),
+            self.senders.clone(),
+            TransferWithSignatures {
+                transfer: self.transfer.clone(),
+                signatures,
+            },
+        )
+        .unwrap()
+    }
+}
+
+/// Registers a bot oracle and three senders and funds the vault for a single transfer
+async fn setup(context: &mut ProgramTestContext) -> Setup {
+    let mut rng = thread_rng();
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+
+    let token_account = Keypair::new();
+    let reward_manager = Keypair::new();
+    let manager_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    create_mint(
+        context,
+        &mint,
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        &mint_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    init_reward_manager(
+        context,
+        &reward_manager,
+        &token_account,
+        &mint.pubkey(),
+        &manager_account.pubkey(),
+        3,
+    )
+    .await;
+
+    let key: [u8; 32] = rng.gen();
+    let oracle_key = SecretKey::parse(&key).unwrap();
+    let eth_oracle_address = construct_eth_pubkey(&PublicKey::from_secret_key(&oracle_key));
+    let oracle = get_address_pair(
+        &audius_reward_manager::id(),
+        &reward_manager.pubkey(),
+        [ORACLE_SEED_PREFIX.as_ref(), eth_oracle_address.as_ref()].concat(),
+    )
+    .unwrap();
+    create_oracle(
+        context,
+        &reward_manager.pubkey(),
+        &manager_account,
+        eth_oracle_address,
+        rng.gen(),
+    )
+    .await;
+
+    let keys: [[u8; 32]; 3] = rng.gen();
+    let mut senders = Vec::new();
+    let mut sender_keys = Vec::new();
+    for key in keys.iter() {
+        let sender_key = SecretKey::parse(key).unwrap();
+        let eth_address = construct_eth_pubkey(&PublicKey::from_secret_key(&sender_key));
+        senders.push(
+            get_sender_address(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &eth_address,
+            )
+            .0,
+        );
+        sender_keys.push(sender_key);
+        create_sender(
+            context,
+            &reward_manager.pubkey(),
+            &manager_account,
+            eth_address,
+            rng.gen(),
+        )
+        .await;
+    }
+
+    mint_tokens_to(
+        context,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority,
+        TRANSFER_AMOUNT,
+    )
+    .await
+    .unwrap();
+
+    let recipient_eth_key: EthereumAddress = [7u8; 20];
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        &mint.pubkey(),
+        recipient_eth_key,
+    )
+    .unwrap()
+    .derive
+    .address;
+    create_recipient_with_claimable_program(context, &mint.pubkey(), recipient_eth_key).await;
+
+    Setup {
+        reward_manager: reward_manager.pubkey(),
+        token_account: token_account.pubkey(),
+        recipient,
+        oracle: oracle.derive.address,
+        oracle_key,
+        senders,
+        sender_keys,
+        transfer: Transfer {
+            amount: TRANSFER_AMOUNT,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: recipient_eth_key,
+            message_version: MessageVersion::V1,
+            create_recipient: false,
+            memo: false,
+        },
+    }
+}
+
+async fn start() -> ProgramTestContext {
+    let mut program_test = program_test();
+    program_test.add_program("claimable_tokens", claimable_tokens::id(), None);
+    program_test.start_with_context().await
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = start().await;
+    let setup = setup(&mut context).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[setup.instruction(&context.payer.pubkey(), setup.signatures())],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let receipt = get_transfer_account_address(
+        &audius_reward_manager::id(),
+        &setup.reward_manager,
+        &setup.transfer.id,
+    )
+    .unwrap();
+    let receipt_data = context
+        .banks_client
+        .get_account_data_with_borsh::<TransferReceipt>(receipt)
+        .await
+        .unwrap();
+    assert_eq!(receipt_data.recipient, setup.recipient);
+    assert_eq!(receipt_data.amount, TRANSFER_AMOUNT);
+}
+
+#[tokio::test]
+async fn fail_wrong_message() {
+    let mut context = start().await;
+    let setup = setup(&mut context).await;
+
+    let mut signatures = setup.signatures();
+    signatures[1] = recoverable_signature(&setup.sender_keys[0], &setup.bot_oracle_message());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[setup.instruction(&context.payer.pubkey(), signatures)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::SignatureVerificationFailed as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_too_many_signatures() {
+    let mut context = start().await;
+    let setup = setup(&mut context).await;
+
+    let mut signatures = setup.signatures();
+    while signatures.len() <= MAX_RECOVERED_SIGNATURES {
+        signatures.push(signatures[1].clone());
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &[setup.instruction(&context.payer.pubkey(), signatures)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::TooManyAttestations as _)
+        )
+    );
+}
@@ -0,0 +1,184 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{self, AnnounceTreasuryWithdrawal},
+    state::RewardManager,
+};
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::*;
+
+const VAULT_BALANCE: u64 = 10_000;
+const WITHDRAWAL_AMOUNT: u64 = 4_000;
+
+/// Funds the vault and announces a partial withdrawal to a new token account
+async fn start_with_announcement() -> (ProgramTestContext, TestRewardManager, Pubkey) {
+    let mut context = program_test().start_with_context().await;
+    let fixture = TestRewardManager::builder()
+        .senders(0)
+        .oracles(0)
+        .vault_balance(VAULT_BALANCE)
+        .build(&mut context)
+        .await;
+
+    let destination = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    create_token_account(
+        &mut context,
+        &destination,
+        &fixture.mint,
+        &Pubkey::new_unique(),
+        &rent,
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::announce_treasury_withdrawal(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            AnnounceTreasuryWithdrawal {
+                amount: WITHDRAWAL_AMOUNT,
+                destination: destination.pubkey(),
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    (context, fixture, destination.pubkey())
+}
+
+fn withdraw(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    destination: &Pubkey,
+) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[instruction::withdraw_treasury(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.token_account,
+            destination,
+            &fixture.manager.pubkey(),
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    )
+}
+
+async fn token_balance(context: &mut ProgramTestContext, account: &Pubkey) -> u64 {
+    let account = get_account(context, account).await.unwrap();
+    spl_token::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+#[tokio::test]
+async fn success() {
+    let (mut context, fixture, destination) = start_with_announcement().await;
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(fixture.reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(
+        reward_manager_data.pending_withdrawal_amount,
+        WITHDRAWAL_AMOUNT
+    );
+    assert_eq!(
+        reward_manager_data.pending_withdrawal_destination,
+        destination
+    );
+
+    context.warp_to_slot(reward_manager_data.withdrawal_unlock_slot);
+
+    let tx = withdraw(&context, &fixture, &destination);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, &destination).await,
+        WITHDRAWAL_AMOUNT
+    );
+    assert_eq!(
+        token_balance(&mut context, &fixture.token_account).await,
+        VAULT_BALANCE - WITHDRAWAL_AMOUNT
+    );
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(fixture.reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.pending_withdrawal_amount, 0);
+    assert_eq!(reward_manager_data.withdrawal_unlock_slot, 0);
+}
+
+#[tokio::test]
+async fn fail_timelocked() {
+    let (context, fixture, destination) = start_with_announcement().await;
+
+    let tx = withdraw(&context, &fixture, &destination);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::WithdrawalTimelocked as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_cancelled() {
+    let (context, fixture, destination) = start_with_announcement().await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::announce_treasury_withdrawal(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            AnnounceTreasuryWithdrawal {
+                amount: 0,
+                destination,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = withdraw(&context, &fixture, &destination);
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::NoPendingWithdrawal as _)
+        )
+    );
+}
@@ -0,0 +1,425 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{error::AudiusProgramError, instruction, state::RewardManager};
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use utils::{program_test, TestRewardManager};
+
+fn add_reward_manager(
+    program_test: &mut ProgramTest,
+    reward_manager: Pubkey,
+    token_account: Pubkey,
+    manager: Pubkey,
+) {
+    program_test.add_account(
+        reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(token_account, manager, 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: audius_reward_manager::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    let token_account = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    add_reward_manager(
+        &mut program_test,
+        reward_manager,
+        token_account,
+        manager_account.pubkey(),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            instruction::UpdateRewardManager {
+                min_votes: Some(5),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(
+        RewardManager::new(token_account, manager_account.pubkey(), 5),
+        context
+            .banks_client
+            .get_account_data_with_borsh(reward_manager)
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn fail_wrong_manager() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    let wrong_manager = Keypair::new();
+    add_reward_manager(
+        &mut program_test,
+        reward_manager,
+        Pubkey::new_unique(),
+        manager_account.pubkey(),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &wrong_manager.pubkey(),
+            instruction::UpdateRewardManager {
+                min_votes: Some(4),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &wrong_manager],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::IncorectManagerAccount as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_zero_min_votes() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    add_reward_manager(
+        &mut program_test,
+        reward_manager,
+        Pubkey::new_unique(),
+        manager_account.pubkey(),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            instruction::UpdateRewardManager {
+                min_votes: Some(0),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_max_attestations_below_min_votes() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    add_reward_manager(
+        &mut program_test,
+        reward_manager,
+        Pubkey::new_unique(),
+        manager_account.pubkey(),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            instruction::UpdateRewardManager {
+                max_attestations: Some(3),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_transfer_above_cap() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    add_reward_manager(
+        &mut program_test,
+        reward_manager,
+        Pubkey::new_unique(),
+        manager_account.pubkey(),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            instruction::UpdateRewardManager {
+                max_transfer_amount: Some(5),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert_eq!(reward_manager_data.max_transfer_amount, 5);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            vec![Pubkey::new_unique()],
+            instruction::Transfer {
+                amount: 10,
+                id: String::from("4r4t23df32543f55"),
+                eth_recipient: [7u8; 20],
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::AmountExceedsCap as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_v1_message_disallowed() {
+    let mut program_test = program_test();
+
+    let reward_manager = Pubkey::new_unique();
+    let manager_account = Keypair::new();
+    add_reward_manager(
+        &mut program_test,
+        reward_manager,
+        Pubkey::new_unique(),
+        manager_account.pubkey(),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &manager_account.pubkey(),
+            instruction::UpdateRewardManager {
+                v1_messages_allowed: Some(false),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let reward_manager_data = context
+        .banks_client
+        .get_account_data_with_borsh::<RewardManager>(reward_manager)
+        .await
+        .unwrap();
+    assert!(!reward_manager_data.v1_messages_allowed);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::transfer(
+            &audius_reward_manager::id(),
+            &reward_manager,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            vec![Pubkey::new_unique()],
+            instruction::Transfer {
+                amount: 10,
+                id: String::from("4r4t23df32543f55"),
+                eth_recipient: [7u8; 20],
+                message_version: instruction::MessageVersion::V1,
+                create_recipient: false,
+                memo: false,
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AudiusProgramError::DeprecatedMessageVersion as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_recipient_of_other_claimable_program() {
+    let mut context = program_test().start_with_context().await;
+    let fixture = TestRewardManager::builder()
+        .vault_balance(10)
+        .build(&mut context)
+        .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::update_reward_manager(
+            &audius_reward_manager::id(),
+            &fixture.reward_manager,
+            &fixture.manager.pubkey(),
+            instruction::UpdateRewardManager {
+                claimable_tokens_program: Some(Pubkey::new_unique()),
+                ..Default::default()
+            },
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.manager],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // NOTE: derived with the default claimable tokens program
+    let recipient = claimable_tokens::utils::program::get_address_pair(
+        &claimable_tokens::id(),
+        &fixture.mint,
+        [7u8; 20],
+    )
+    .unwrap()
+    .derive
+    .address;
+    let instructions = fixture.transfer_instructions(
+        &context.payer.pubkey(),
+        &recipient,
+        instruction::Transfer {
+            amount: 10,
+            id: String::from("4r4t23df32543f55"),
+            eth_recipient: [7u8; 20],
+            message_version: instruction::MessageVersion::V2,
+            create_recipient: false,
+            memo: false,
+        },
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert_eq!(
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::WrongRecipientKey as _)
+        )
+    );
+}
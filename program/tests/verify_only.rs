@@ -0,0 +1,158 @@
+#![cfg(feature = "test-bpf")]
+mod utils;
+use audius_reward_manager::{
+    error::AudiusProgramError,
+    instruction::{self, MessageVersion, Transfer},
+    state::{RewardManager, SenderAccount},
+};
+use borsh::BorshSerialize;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+    transport::TransportError,
+};
+use utils::*;
+
+fn transfer(amount: u64) -> Transfer {
+    Transfer {
+        amount,
+        id: String::from("4r4t23df32543f55"),
+        eth_recipient: [7u8; 20],
+        message_version: MessageVersion::V2,
+        create_recipient: false,
+        memo: false,
+    }
+}
+
+/// Attestations of `attested` followed by the verification of `verified` by `senders`
+fn verify_instructions(
+    context: &ProgramTestContext,
+    fixture: &TestRewardManager,
+    attested: Transfer,
+    verified: Transfer,
+    senders: usize,
+) -> Vec<Instruction> {
+    let mut instructions =
+        fixture.transfer_instructions(&context.payer.pubkey(), &Pubkey::new_unique(), attested);
+    *instructions.last_mut().unwrap() = instruction::verify_only(
+        &audius_reward_manager::id(),
+        &fixture.reward_manager,
+        &fixture.oracles[0].account,
+        fixture
+            .senders
+            .iter()
+            .take(senders)
+            .map(|sender| sender.account),
+        verified,
+    )
+    .unwrap();
+    instructions
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+) -> Result<(), TransportError> {
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = program_test().start_with_context().await;
+    // NOTE: the vault stays empty, funding isn't checked
+    let fixture = TestRewardManager::builder().build(&mut context).await;
+
+    let instructions = verify_instructions(&context, &fixture, transfer(10), transfer(10), 3);
+    process(&mut context, &instructions).await.unwrap();
+
+    for sender in fixture.senders.iter() {
+        let sender_data = context
+            .banks_client
+            .get_account_data_with_borsh::<SenderAccount>(sender.account)
+            .await
+            .unwrap();
+        assert_eq!(sender_data.attestation_count, 0);
+    }
+}
+
+#[tokio::test]
+async fn fail_not_enough_signers() {
+    let mut context = program_test().start_with_context().await;
+    let fixture = TestRewardManager::builder().build(&mut context).await;
+
+    let instructions = verify_instructions(&context, &fixture, transfer(10), transfer(10), 2);
+
+    assert_eq!(
+        process(&mut context, &instructions)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::NotEnoughSigners as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_other_transfer() {
+    let mut context = program_test().start_with_context().await;
+    let fixture = TestRewardManager::builder().build(&mut context).await;
+
+    let instructions = verify_instructions(&context, &fixture, transfer(10), transfer(11), 3);
+
+    assert_eq!(
+        process(&mut context, &instructions)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::Custom(AudiusProgramError::SignatureVerificationFailed as _)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_reward_manager_of_other_program() {
+    let mut program_test = program_test();
+    let fake_reward_manager = Pubkey::new_unique();
+    program_test.add_account(
+        fake_reward_manager,
+        Account {
+            lamports: 9000,
+            data: RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3)
+                .try_to_vec()
+                .unwrap(),
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+    let fixture = TestRewardManager::builder().build(&mut context).await;
+
+    let mut instructions = verify_instructions(&context, &fixture, transfer(10), transfer(10), 3);
+    instructions.last_mut().unwrap().accounts[0].pubkey = fake_reward_manager;
+
+    assert_eq!(
+        process(&mut context, &instructions)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(
+            (instructions.len() - 1) as _,
+            InstructionError::IncorrectProgramId
+        )
+    );
+}
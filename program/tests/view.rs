@@ -0,0 +1,105 @@
+#![cfg(feature = "test-bpf")]
+use audius_reward_manager::{
+    state::{RewardManager, SenderAccount},
+    view::{is_funded, is_transfer_disbursed, pool_balance_check, sender_count},
+};
+use borsh::BorshSerialize;
+use solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey};
+use spl_token::state::{Account as TokenAccount, AccountState};
+
+#[test]
+fn transfer_disbursed() {
+    let program_id = audius_reward_manager::id();
+    assert!(is_transfer_disbursed(&program_id, &program_id, 1));
+    assert!(!is_transfer_disbursed(&program_id, &program_id, 0));
+    assert!(!is_transfer_disbursed(
+        &program_id,
+        &solana_program::system_program::id(),
+        1
+    ));
+}
+
+#[test]
+fn count_senders() {
+    let reward_manager = Pubkey::new_unique();
+    let other_reward_manager = Pubkey::new_unique();
+
+    let accounts = vec![
+        SenderAccount::new(reward_manager, [1u8; 20], [1u8; 20], 0)
+            .try_to_vec()
+            .unwrap(),
+        SenderAccount::new(reward_manager, [2u8; 20], [2u8; 20], 0)
+            .try_to_vec()
+            .unwrap(),
+        SenderAccount::new(other_reward_manager, [3u8; 20], [3u8; 20], 0)
+            .try_to_vec()
+            .unwrap(),
+        RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3)
+            .try_to_vec()
+            .unwrap(),
+        vec![0u8; SenderAccount::LEN],
+    ];
+
+    assert_eq!(
+        sender_count(&reward_manager, accounts.iter().map(|i| i.as_slice())),
+        2
+    );
+}
+
+#[test]
+fn pool_balance() {
+    let token_account = Pubkey::new_unique();
+    let reward_manager_data = RewardManager::new(token_account, Pubkey::new_unique(), 3)
+        .try_to_vec()
+        .unwrap();
+
+    let mut token_account_data = vec![0u8; TokenAccount::LEN];
+    TokenAccount {
+        mint: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        amount: 100,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(token_account_data.as_mut_slice());
+
+    assert_eq!(
+        pool_balance_check(
+            &reward_manager_data,
+            &token_account,
+            &token_account_data,
+            100
+        ),
+        Ok(true)
+    );
+    assert_eq!(
+        pool_balance_check(
+            &reward_manager_data,
+            &token_account,
+            &token_account_data,
+            101
+        ),
+        Ok(false)
+    );
+    assert!(pool_balance_check(
+        &reward_manager_data,
+        &Pubkey::new_unique(),
+        &token_account_data,
+        1
+    )
+    .is_err());
+}
+
+#[test]
+fn funded_above_floor() {
+    let mut reward_manager = RewardManager::new(Pubkey::new_unique(), Pubkey::new_unique(), 3);
+    assert!(is_funded(&reward_manager, 100, 100));
+    assert!(!is_funded(&reward_manager, 100, 101));
+
+    reward_manager.min_balance_threshold = 30;
+    assert!(is_funded(&reward_manager, 100, 70));
+    assert!(!is_funded(&reward_manager, 100, 71));
+}
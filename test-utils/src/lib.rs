@@ -0,0 +1,645 @@
+//! Banks client helpers shared by the program tests
+
+pub use audius_reward_manager_client::nonce::{nonce_hash, NonceTxBuilder};
+pub use audius_reward_manager_client::secp::{
+    new_secp256k1_instruction_2_0, recoverable_signature,
+};
+
+use audius_reward_manager::instruction::{
+    self, ClaimFromRoot, SubmitDistributionRoot, Transfer, TransferToWallet,
+};
+use audius_reward_manager::state::OracleAccount;
+use audius_reward_manager::utils::{
+    transfer_batch_digest, DistributionRootVerifier, EthereumAddress, TransferBatchVerifier,
+    TransferVerifier, Verifier, WalletTransferVerifier,
+};
+use audius_reward_manager::{id, processor::Processor};
+use audius_reward_manager_client::rpc::{oracle_address, sender_address};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use solana_program::{
+    instruction::Instruction, program_pack::Pack, pubkey::Pubkey, rent::Rent, system_instruction,
+};
+use solana_program_test::*;
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    secp256k1_instruction::construct_eth_pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+    transport::TransportError,
+};
+
+pub fn program_test() -> ProgramTest {
+    ProgramTest::new(
+        "audius_reward_manager",
+        id(),
+        processor!(Processor::process_instruction),
+    )
+}
+
+pub async fn get_account(
+    program_context: &mut ProgramTestContext,
+    pubkey: &Pubkey,
+) -> Option<Account> {
+    program_context
+        .banks_client
+        .get_account(*pubkey)
+        .await
+        .expect("account not found")
+}
+
+pub async fn create_sender(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+    manager_acc: &Keypair,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_sender(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &manager_acc.pubkey(),
+            &context.payer.pubkey(),
+            eth_address,
+            operator,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, manager_acc],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+pub async fn create_oracle(
+    context: &mut ProgramTestContext,
+    reward_manager: &Pubkey,
+    manager_acc: &Keypair,
+    eth_address: EthereumAddress,
+    operator: EthereumAddress,
+) {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction::create_oracle(
+            &audius_reward_manager::id(),
+            reward_manager,
+            &manager_acc.pubkey(),
+            &context.payer.pubkey(),
+            eth_address,
+            operator,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, manager_acc],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+pub async fn init_reward_manager(
+    context: &mut ProgramTestContext,
+    reward_manager: &Keypair,
+    token_account: &Keypair,
+    mint: &Pubkey,
+    manager: &Pubkey,
+    min_votes: u8,
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &reward_manager.pubkey(),
+                rent.minimum_balance(audius_reward_manager::state::RewardManager::LEN),
+                audius_reward_manager::state::RewardManager::LEN as _,
+                &audius_reward_manager::id(),
+            ),
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &token_account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as _,
+                &spl_token::id(),
+            ),
+            instruction::init(
+                &audius_reward_manager::id(),
+                &reward_manager.pubkey(),
+                &token_account.pubkey(),
+                mint,
+                &manager,
+                &spl_token::id(),
+                &claimable_tokens::id(),
+                min_votes,
+            )
+            .unwrap(),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, reward_manager, token_account],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+pub async fn create_mint(
+    program_context: &mut ProgramTestContext,
+    mint_account: &Keypair,
+    mint_rent: u64,
+    authority: &Pubkey,
+) -> Result<(), TransportError> {
+    let instructions = vec![
+        system_instruction::create_account(
+            &program_context.payer.pubkey(),
+            &mint_account.pubkey(),
+            mint_rent,
+            spl_token::state::Mint::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_mint(
+            &spl_token::id(),
+            &mint_account.pubkey(),
+            authority,
+            None,
+            0,
+        )
+        .unwrap(),
+    ];
+
+    let mut transaction =
+        Transaction::new_with_payer(&instructions, Some(&program_context.payer.pubkey()));
+
+    transaction.sign(
+        &[&program_context.payer, mint_account],
+        program_context.last_blockhash,
+    );
+    program_context
+        .banks_client
+        .process_transaction(transaction)
+        .await?;
+    Ok(())
+}
+
+pub async fn create_token_account(
+    program_context: &mut ProgramTestContext,
+    account: &Keypair,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    rent: &Rent,
+) -> Result<(), TransportError> {
+    let account_rent = rent.minimum_balance(spl_token::state::Account::LEN);
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &program_context.payer.pubkey(),
+            &account.pubkey(),
+            account_rent,
+            spl_token::state::Account::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_account(
+            &spl_token::id(),
+            &account.pubkey(),
+            mint,
+            owner,
+        )
+        .unwrap(),
+    ];
+
+    let mut transaction =
+        Transaction::new_with_payer(&instructions, Some(&program_context.payer.pubkey()));
+
+    transaction.sign(
+        &[&program_context.payer, account],
+        program_context.last_blockhash,
+    );
+    program_context
+        .banks_client
+        .process_transaction(transaction)
+        .await?;
+    Ok(())
+}
+
+pub async fn mint_tokens_to(
+    program_context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Keypair,
+    amount: u64,
+) -> Result<(), TransportError> {
+    let mut transaction = Transaction::new_with_payer(
+        &[spl_token::instruction::mint_to(
+            &spl_token::id(),
+            mint,
+            destination,
+            &authority.pubkey(),
+            &[&authority.pubkey()],
+            amount,
+        )
+        .unwrap()],
+        Some(&program_context.payer.pubkey()),
+    );
+    transaction.sign(
+        &[&program_context.payer, authority],
+        program_context.last_blockhash,
+    );
+    program_context
+        .banks_client
+        .process_transaction(transaction)
+        .await?;
+    Ok(())
+}
+
+pub async fn create_recipient_with_claimable_program(
+    program_context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    eth_address: EthereumAddress,
+) {
+    let mut transaction = Transaction::new_with_payer(
+        &[claimable_tokens::instruction::init(
+            &claimable_tokens::id(),
+            &program_context.payer.pubkey(),
+            mint,
+            claimable_tokens::instruction::CreateTokenAccount { eth_address },
+        )
+        .unwrap()],
+        Some(&program_context.payer.pubkey()),
+    );
+    transaction.sign(&[&program_context.payer], program_context.last_blockhash);
+    program_context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+}
+
+/// Random Ethereum key with its address and the address of its own operator
+fn random_signer() -> (SecretKey, EthereumAddress, EthereumAddress) {
+    let mut rng = thread_rng();
+    let secret_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_pubkey(&PublicKey::from_secret_key(&secret_key));
+    (secret_key, eth_address, rng.gen())
+}
+
+/// Ethereum signer registered in the reward manager as a sender or bot oracle
+pub struct TestSigner {
+    pub secret_key: SecretKey,
+    pub eth_address: EthereumAddress,
+    pub operator: EthereumAddress,
+    /// Program account of the signer
+    pub account: Pubkey,
+}
+
+/// Initialized reward manager with its mint, registered bot oracles and senders
+pub struct TestRewardManager {
+    pub reward_manager: Pubkey,
+    pub token_account: Pubkey,
+    pub mint: Pubkey,
+    pub mint_authority: Keypair,
+    pub manager: Keypair,
+    pub oracles: Vec<TestSigner>,
+    pub senders: Vec<TestSigner>,
+    /// Fee token account passed to transfers, default key while no transfer fee is set
+    pub fee_account: Pubkey,
+}
+
+/// Builds a [`TestRewardManager`], three senders and one bot oracle by default
+pub struct TestRewardManagerBuilder {
+    min_votes: u8,
+    senders: usize,
+    oracles: usize,
+    vault_balance: u64,
+}
+
+impl Default for TestRewardManagerBuilder {
+    fn default() -> Self {
+        Self {
+            min_votes: 3,
+            senders: 3,
+            oracles: 1,
+            vault_balance: 0,
+        }
+    }
+}
+
+impl TestRewardManagerBuilder {
+    /// Number of sender votes required for transfers
+    pub fn min_votes(mut self, min_votes: u8) -> Self {
+        self.min_votes = min_votes;
+        self
+    }
+
+    /// Number of senders to register
+    pub fn senders(mut self, senders: usize) -> Self {
+        self.senders = senders;
+        self
+    }
+
+    /// Number of bot oracles to register
+    pub fn oracles(mut self, oracles: usize) -> Self {
+        self.oracles = oracles;
+        self
+    }
+
+    /// Tokens minted to the vault token account
+    pub fn vault_balance(mut self, amount: u64) -> Self {
+        self.vault_balance = amount;
+        self
+    }
+
+    /// Creates the mint and the reward manager and registers the signers
+    pub async fn build(self, context: &mut ProgramTestContext) -> TestRewardManager {
+        let mint = Keypair::new();
+        let mint_authority = Keypair::new();
+        let reward_manager = Keypair::new();
+        let token_account = Keypair::new();
+        let manager = Keypair::new();
+
+        let rent = context.banks_client.get_rent().await.unwrap();
+        create_mint(
+            context,
+            &mint,
+            rent.minimum_balance(spl_token::state::Mint::LEN),
+            &mint_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+        init_reward_manager(
+            context,
+            &reward_manager,
+            &token_account,
+            &mint.pubkey(),
+            &manager.pubkey(),
+            self.min_votes,
+        )
+        .await;
+
+        let mut oracles = Vec::new();
+        for _ in 0..self.oracles {
+            let (secret_key, eth_address, operator) = random_signer();
+            create_oracle(
+                context,
+                &reward_manager.pubkey(),
+                &manager,
+                eth_address,
+                operator,
+            )
+            .await;
+            oracles.push(TestSigner {
+                secret_key,
+                eth_address,
+                operator,
+                account: oracle_address(&reward_manager.pubkey(), eth_address).unwrap(),
+            });
+        }
+
+        let mut senders = Vec::new();
+        for _ in 0..self.senders {
+            let (secret_key, eth_address, operator) = random_signer();
+            create_sender(
+                context,
+                &reward_manager.pubkey(),
+                &manager,
+                eth_address,
+                operator,
+            )
+            .await;
+            senders.push(TestSigner {
+                secret_key,
+                eth_address,
+                operator,
+                account: sender_address(&reward_manager.pubkey(), eth_address).unwrap(),
+            });
+        }
+
+        if self.vault_balance > 0 {
+            mint_tokens_to(
+                context,
+                &mint.pubkey(),
+                &token_account.pubkey(),
+                &mint_authority,
+                self.vault_balance,
+            )
+            .await
+            .unwrap();
+        }
+
+        TestRewardManager {
+            reward_manager: reward_manager.pubkey(),
+            token_account: token_account.pubkey(),
+            mint: mint.pubkey(),
+            mint_authority,
+            manager,
+            oracles,
+            senders,
+            fee_account: Pubkey::default(),
+        }
+    }
+}
+
+impl TestRewardManager {
+    pub fn builder() -> TestRewardManagerBuilder {
+        TestRewardManagerBuilder::default()
+    }
+
+    /// Creates the claimable token account of `eth_address`, the claimable tokens
+    /// program must be added to the program test
+    pub async fn create_recipient(
+        &self,
+        context: &mut ProgramTestContext,
+        eth_address: EthereumAddress,
+    ) -> Pubkey {
+        create_recipient_with_claimable_program(context, &self.mint, eth_address).await;
+        claimable_tokens::utils::program::get_address_pair(
+            &claimable_tokens::id(),
+            &self.mint,
+            eth_address,
+        )
+        .unwrap()
+        .derive
+        .address
+    }
+
+    /// Attestations of the first bot oracle and every sender followed by the transfer
+    pub fn transfer_instructions(
+        &self,
+        funder: &Pubkey,
+        recipient: &Pubkey,
+        transfer: Transfer,
+    ) -> Vec<Instruction> {
+        let bot_oracle = &self.oracles[0];
+        let verifier = TransferVerifier {
+            bot_oracle: self.bot_oracle_account(),
+            additional_oracles: Vec::new(),
+            transfer_data: transfer.clone(),
+        };
+
+        let mut instructions =
+            self.attestations(&verifier.bot_oracle_message(), &verifier.sender_message());
+        instructions.push(
+            instruction::transfer(
+                &id(),
+                &self.reward_manager,
+                recipient,
+                &self.token_account,
+                &bot_oracle.account,
+                funder,
+                &self.fee_account,
+                self.senders.iter().map(|sender| sender.account),
+                transfer,
+            )
+            .unwrap(),
+        );
+
+        instructions
+    }
+
+    /// Attestations of the first bot oracle and every sender followed by the
+    /// transfer batch, recipients are derived with the default claimable tokens program
+    pub fn transfer_batch_instructions(
+        &self,
+        funder: &Pubkey,
+        transfers: Vec<Transfer>,
+    ) -> Vec<Instruction> {
+        let bot_oracle = &self.oracles[0];
+        let verifier = TransferBatchVerifier {
+            bot_oracle: self.bot_oracle_account(),
+            additional_oracles: Vec::new(),
+            batch_digest: transfer_batch_digest(&transfers).unwrap(),
+        };
+
+        let mut instructions =
+            self.attestations(&verifier.bot_oracle_message(), &verifier.sender_message());
+        instructions.push(
+            instruction::transfer_batch(
+                &id(),
+                &self.reward_manager,
+                &self.token_account,
+                &self.mint,
+                &bot_oracle.account,
+                funder,
+                &self.fee_account,
+                self.senders.iter().map(|sender| sender.account),
+                transfers,
+            )
+            .unwrap(),
+        );
+
+        instructions
+    }
+
+    /// Attestations of the first bot oracle and every sender followed by the
+    /// transfer to the associated token account of the wallet
+    pub fn transfer_to_wallet_instructions(
+        &self,
+        funder: &Pubkey,
+        transfer: TransferToWallet,
+    ) -> Vec<Instruction> {
+        let bot_oracle = &self.oracles[0];
+        let verifier = WalletTransferVerifier {
+            bot_oracle: self.bot_oracle_account(),
+            additional_oracles: Vec::new(),
+            transfer_data: transfer.clone(),
+        };
+
+        let mut instructions =
+            self.attestations(&verifier.bot_oracle_message(), &verifier.sender_message());
+        instructions.push(
+            instruction::transfer_to_wallet(
+                &id(),
+                &self.reward_manager,
+                &self.token_account,
+                &self.mint,
+                &bot_oracle.account,
+                funder,
+                &self.fee_account,
+                self.senders.iter().map(|sender| sender.account),
+                transfer,
+            )
+            .unwrap(),
+        );
+
+        instructions
+    }
+
+    /// Attestations of the first bot oracle and every sender followed by the
+    /// distribution root submission
+    pub fn submit_distribution_root_instructions(
+        &self,
+        funder: &Pubkey,
+        params: SubmitDistributionRoot,
+    ) -> Vec<Instruction> {
+        let bot_oracle = &self.oracles[0];
+        let verifier = DistributionRootVerifier {
+            bot_oracle: self.bot_oracle_account(),
+            additional_oracles: Vec::new(),
+            distribution_id: params.id.clone(),
+            root: params.root,
+        };
+
+        let mut instructions =
+            self.attestations(&verifier.bot_oracle_message(), &verifier.sender_message());
+        instructions.push(
+            instruction::submit_distribution_root(
+                &id(),
+                &self.reward_manager,
+                &bot_oracle.account,
+                funder,
+                self.senders.iter().map(|sender| sender.account),
+                params,
+            )
+            .unwrap(),
+        );
+
+        instructions
+    }
+
+    /// Claim of a transfer of the distribution `distribution_id`, the recipient is
+    /// derived with the default claimable tokens program
+    pub fn claim_from_root_instruction(
+        &self,
+        funder: &Pubkey,
+        distribution_id: &str,
+        params: ClaimFromRoot,
+    ) -> Instruction {
+        instruction::claim_from_root(
+            &id(),
+            &self.reward_manager,
+            &self.token_account,
+            &self.mint,
+            funder,
+            &self.fee_account,
+            distribution_id,
+            params,
+        )
+        .unwrap()
+    }
+
+    fn bot_oracle_account(&self) -> OracleAccount {
+        let bot_oracle = &self.oracles[0];
+        OracleAccount::new(
+            self.reward_manager,
+            bot_oracle.eth_address,
+            bot_oracle.operator,
+        )
+    }
+
+    /// Secp256k1 instructions of the first bot oracle and every sender
+    fn attestations(&self, bot_oracle_message: &[u8], sender_message: &[u8]) -> Vec<Instruction> {
+        let mut instructions = vec![new_secp256k1_instruction_2_0(
+            &self.oracles[0].secret_key,
+            bot_oracle_message,
+            0,
+        )];
+        for sender in self.senders.iter() {
+            instructions.push(new_secp256k1_instruction_2_0(
+                &sender.secret_key,
+                sender_message,
+                instructions.len() as _,
+            ));
+        }
+        instructions
+    }
+}